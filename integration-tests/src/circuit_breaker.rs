@@ -348,6 +348,44 @@ fn add_liquidity_to_omnipool_should_not_fail_when_liquidity_limit_per_block_exce
 	});
 }
 
+#[test]
+fn add_liquidity_to_omnipool_should_fail_when_oracle_is_too_young() {
+	Hydra::execute_with(|| {
+		//Arrange
+		set_relaychain_block_number(200);
+
+		// initializes the oracles in this block
+		init_omnipool();
+
+		assert_ok!(Balances::set_balance(
+			RawOrigin::Root.into(),
+			ALICE.into(),
+			1_000 * UNITS,
+			0,
+		));
+
+		set_relaychain_block_number(202);
+
+		//Act and assert
+		assert_noop!(
+			Omnipool::add_liquidity(
+				hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+				CORE_ASSET_ID,
+				UNITS,
+			),
+			pallet_ema_oracle::Error::<hydradx_runtime::Runtime>::OracleTooYoung
+		);
+
+		set_relaychain_block_number(220);
+
+		assert_ok!(Omnipool::add_liquidity(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			CORE_ASSET_ID,
+			UNITS,
+		));
+	});
+}
+
 #[test]
 fn remove_liquidity_to_omnipool_should_work_when_liquidity_limit_per_block_not_exceeded() {
 	Hydra::execute_with(|| {
@@ -9,9 +9,11 @@ mod non_native_fee;
 mod omnipool_init;
 mod omnipool_liquidity_mining;
 mod omnipool_price_provider;
+mod omnipool_subpools;
 mod oracle;
 mod otc;
 mod polkadot_test_net;
+mod router;
 mod transact_call_filter;
 mod vesting;
 
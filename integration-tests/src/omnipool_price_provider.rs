@@ -4,8 +4,7 @@ use crate::polkadot_test_net::*;
 
 use frame_support::assert_ok;
 use frame_system::RawOrigin;
-use hydradx_adapters::OraclePriceProviderAdapterForOmnipool;
-use hydradx_runtime::{Omnipool, RuntimeOrigin, Tokens};
+use hydradx_runtime::{Omnipool, OmnipoolOraclePriceProvider, RuntimeOrigin, Tokens};
 use hydradx_traits::{OraclePeriod, PriceOracle};
 use primitives::{AssetId, Balance};
 use sp_runtime::{FixedU128, Permill};
@@ -20,12 +19,7 @@ fn omnipool_oracle_adapter_should_return_price_for_arbitraty_pairs() {
 
 		set_relaychain_block_number(100);
 
-		let price =
-			OraclePriceProviderAdapterForOmnipool::<AssetId, hydradx_runtime::EmaOracle, hydradx_runtime::LRNA>::price(
-				HDX,
-				DAI,
-				OraclePeriod::Short,
-			);
+		let price = OmnipoolOraclePriceProvider::price(HDX, DAI, OraclePeriod::Short);
 
 		assert!(price.is_some());
 	});
@@ -40,12 +34,7 @@ fn omnipool_oracle_adapter_should_return_price_for_when_lrna_is_asset_a() {
 
 		set_relaychain_block_number(100);
 
-		let price =
-			OraclePriceProviderAdapterForOmnipool::<AssetId, hydradx_runtime::EmaOracle, hydradx_runtime::LRNA>::price(
-				LRNA,
-				DAI,
-				OraclePeriod::Short,
-			);
+		let price = OmnipoolOraclePriceProvider::price(LRNA, DAI, OraclePeriod::Short);
 
 		assert!(price.is_some());
 	});
@@ -60,12 +49,7 @@ fn omnipool_oracle_adapter_should_return_price_for_when_lrna_is_asset_b() {
 
 		set_relaychain_block_number(100);
 
-		let price =
-			OraclePriceProviderAdapterForOmnipool::<AssetId, hydradx_runtime::EmaOracle, hydradx_runtime::LRNA>::price(
-				DAI,
-				LRNA,
-				OraclePeriod::Short,
-			);
+		let price = OmnipoolOraclePriceProvider::price(DAI, LRNA, OraclePeriod::Short);
 
 		assert!(price.is_some());
 	});
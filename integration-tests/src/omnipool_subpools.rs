@@ -0,0 +1,214 @@
+#![cfg(test)]
+
+use crate::{oracle::hydradx_run_to_block, polkadot_test_net::*};
+
+use frame_support::{assert_ok, traits::tokens::fungibles::Mutate};
+use hydradx_runtime::{AssetRegistry, Currencies, EmaOracle, Omnipool, OmnipoolSubpools, RuntimeOrigin, Stableswap};
+use hydradx_traits::{AggregatedPriceOracle, OraclePeriod};
+use orml_traits::MultiCurrency;
+use pallet_asset_registry::AssetType;
+use primitives::constants::chain::OMNIPOOL_SOURCE;
+use sp_runtime::{FixedU128, Permill};
+use xcm_emulator::TestExt;
+
+const USDT: AssetId = 10;
+const USDC: AssetId = 11;
+const SUBPOOL: AssetId = 12;
+
+const TOKEN_LIQUIDITY: Balance = 1_000_000 * UNITS;
+
+fn register_asset(name: &[u8], asset_id: AssetId) {
+	assert_ok!(AssetRegistry::register_asset(
+		AssetRegistry::to_bounded_name(name.to_vec()).unwrap(),
+		AssetType::<AssetId>::Token,
+		1_000u128,
+		Some(asset_id),
+		None,
+	));
+}
+
+fn add_omnipool_token(asset_id: AssetId) {
+	assert_ok!(hydradx_runtime::Tokens::mint_into(
+		asset_id,
+		&Omnipool::protocol_account(),
+		TOKEN_LIQUIDITY
+	));
+
+	assert_ok!(Omnipool::add_token(
+		RuntimeOrigin::root(),
+		asset_id,
+		FixedU128::from_inner(45_000_000_000),
+		Permill::from_percent(100),
+		AccountId::from(BOB),
+	));
+}
+
+fn init_subpool() {
+	init_omnipool();
+
+	register_asset(b"USDT", USDT);
+	register_asset(b"USDC", USDC);
+	register_asset(b"SUBPOOL", SUBPOOL);
+
+	add_omnipool_token(USDT);
+	add_omnipool_token(USDC);
+
+	assert_ok!(OmnipoolSubpools::create_subpool(
+		RuntimeOrigin::root(),
+		SUBPOOL,
+		USDT,
+		USDC,
+		Permill::from_percent(100),
+		100,
+		Permill::from_percent(0),
+		Permill::from_percent(0),
+	));
+}
+
+#[test]
+fn create_subpool_should_move_omnipool_liquidity_to_stableswap() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Act
+		init_subpool();
+
+		//Assert
+		let pool_account = Stableswap::pool_account(SUBPOOL);
+
+		assert_eq!(Currencies::free_balance(USDT, &pool_account), TOKEN_LIQUIDITY);
+		assert_eq!(Currencies::free_balance(USDC, &pool_account), TOKEN_LIQUIDITY);
+		assert_eq!(Currencies::free_balance(USDT, &Omnipool::protocol_account()), 0);
+		assert_eq!(Currencies::free_balance(USDC, &Omnipool::protocol_account()), 0);
+
+		assert!(Omnipool::assets(USDT).is_none());
+		assert!(Omnipool::assets(USDC).is_none());
+		assert!(Omnipool::assets(SUBPOOL).is_some());
+
+		assert_eq!(OmnipoolSubpools::subpool_of(USDT), Some(SUBPOOL));
+		assert_eq!(OmnipoolSubpools::subpool_of(USDC), Some(SUBPOOL));
+	});
+}
+
+#[test]
+fn sell_should_work_when_trading_omnipool_asset_for_subpool_asset() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_subpool();
+
+		let amount = 1_000 * UNITS;
+		let dai_balance = Currencies::free_balance(DAI, &AccountId::from(DAVE));
+
+		//Act
+		assert_ok!(OmnipoolSubpools::sell(
+			RuntimeOrigin::signed(DAVE.into()),
+			DAI,
+			USDT,
+			amount,
+			0,
+		));
+
+		//Assert
+		assert_eq!(
+			Currencies::free_balance(DAI, &AccountId::from(DAVE)),
+			dai_balance - amount
+		);
+		assert!(Currencies::free_balance(USDT, &AccountId::from(DAVE)) > 0);
+	});
+}
+
+#[test]
+fn sell_should_work_when_trading_subpool_asset_for_omnipool_asset() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_subpool();
+
+		let amount = 1_000 * UNITS;
+		assert_ok!(hydradx_runtime::Tokens::mint_into(USDC, &DAVE.into(), amount));
+		let dai_balance = Currencies::free_balance(DAI, &AccountId::from(DAVE));
+
+		//Act
+		assert_ok!(OmnipoolSubpools::sell(
+			RuntimeOrigin::signed(DAVE.into()),
+			USDC,
+			DAI,
+			amount,
+			0,
+		));
+
+		//Assert
+		assert_eq!(Currencies::free_balance(USDC, &AccountId::from(DAVE)), 0);
+		assert!(Currencies::free_balance(DAI, &AccountId::from(DAVE)) > dai_balance);
+	});
+}
+
+#[test]
+fn migrate_position_should_convert_position_to_subpool_shares() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+
+		register_asset(b"USDT", USDT);
+		register_asset(b"USDC", USDC);
+		register_asset(b"SUBPOOL", SUBPOOL);
+
+		add_omnipool_token(USDT);
+		add_omnipool_token(USDC);
+
+		let amount = 1_000 * UNITS;
+		assert_ok!(hydradx_runtime::Tokens::mint_into(USDT, &DAVE.into(), amount));
+
+		let position_id = Omnipool::next_position_id();
+		assert_ok!(Omnipool::add_liquidity(
+			RuntimeOrigin::signed(DAVE.into()),
+			USDT,
+			amount,
+		));
+
+		assert_ok!(OmnipoolSubpools::create_subpool(
+			RuntimeOrigin::root(),
+			SUBPOOL,
+			USDT,
+			USDC,
+			Permill::from_percent(100),
+			100,
+			Permill::from_percent(0),
+			Permill::from_percent(0),
+		));
+
+		//Act
+		assert_ok!(OmnipoolSubpools::migrate_position(
+			RuntimeOrigin::signed(DAVE.into()),
+			position_id,
+		));
+
+		//Assert
+		let position = Omnipool::positions(position_id).unwrap();
+		assert_eq!(position.asset_id, SUBPOOL);
+		assert!(position.shares > 0);
+		assert!(position.amount > 0);
+	});
+}
+
+#[test]
+fn create_subpool_should_ingest_share_asset_liquidity_into_oracle() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		hydradx_run_to_block(2);
+
+		//Act
+		init_subpool();
+		hydradx_run_to_block(3);
+
+		//Assert
+		assert!(EmaOracle::get_price(SUBPOOL, LRNA, OraclePeriod::LastBlock, OMNIPOOL_SOURCE).is_ok());
+	});
+}
@@ -137,3 +137,47 @@ fn omnipool_hub_asset_trades_are_ingested_into_oracle() {
 		}
 	});
 }
+
+#[test]
+fn externally_fed_data_is_ingested_into_oracle() {
+	TestNet::reset();
+
+	const EXTERNAL_SOURCE: [u8; 8] = *b"external";
+
+	Hydra::execute_with(|| {
+		// arrange
+		hydradx_run_to_block(2);
+
+		assert_ok!(EmaOracle::add_feeder(
+			RuntimeOrigin::root(),
+			EXTERNAL_SOURCE,
+			AccountId::from(ALICE)
+		));
+
+		// act
+		assert_ok!(EmaOracle::update_external_oracle(
+			RuntimeOrigin::signed(ALICE.into()),
+			EXTERNAL_SOURCE,
+			HDX,
+			DOT,
+			(100 * UNITS, UNITS),
+			(0, 0)
+		));
+		hydradx_run_to_block(3);
+
+		// assert
+		let expected = ((100 * UNITS, UNITS).into(), 0);
+		for supported_period in SUPPORTED_PERIODS {
+			assert_eq!(
+				EmaOracle::get_price(HDX, DOT, *supported_period, EXTERNAL_SOURCE),
+				Ok(expected)
+			);
+		}
+		for unsupported_period in UNSUPPORTED_PERIODS {
+			assert_eq!(
+				EmaOracle::get_price(HDX, DOT, *unsupported_period, EXTERNAL_SOURCE),
+				Err(OracleError::NotPresent)
+			);
+		}
+	});
+}
@@ -19,10 +19,13 @@
 //                                          you may not use this file except in compliance with the License.
 //                                          http://www.apache.org/licenses/LICENSE-2.0
 #![cfg(test)]
+use crate::dca::init_omnipol;
 use crate::polkadot_test_net::*;
 use frame_support::assert_ok;
-use orml_traits::NamedMultiReservableCurrency;
+use hydradx_traits::router::PoolType;
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
 use pallet_otc::NAMED_RESERVE_ID;
+use pallet_route_executor::Trade;
 use xcm_emulator::TestExt;
 
 #[test]
@@ -37,6 +40,9 @@ fn place_order_should_work() {
 			20 * UNITS,
 			100 * UNITS,
 			false,
+			None,
+			None,
+			None,
 		));
 
 		// Assert
@@ -61,6 +67,9 @@ fn partial_fill_order_should_work() {
 			20 * UNITS,
 			100 * UNITS,
 			true,
+			None,
+			None,
+			None,
 		));
 
 		// Act
@@ -92,6 +101,9 @@ fn fill_order_should_work() {
 			20 * UNITS,
 			100 * UNITS,
 			true,
+			None,
+			None,
+			None,
 		));
 
 		// Act
@@ -122,6 +134,9 @@ fn cancel_order_should_work() {
 			20 * UNITS,
 			100 * UNITS,
 			true,
+			None,
+			None,
+			None,
 		));
 
 		// Act
@@ -139,3 +154,91 @@ fn cancel_order_should_work() {
 		);
 	});
 }
+
+#[test]
+fn fill_order_via_route_should_work_when_asset_in_is_bought_in_omnipool() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		// Arrange
+		init_omnipol();
+
+		assert_ok!(hydradx_runtime::OTC::place_order(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			DAI,
+			HDX,
+			20 * UNITS,
+			100 * UNITS,
+			true,
+			None,
+			None,
+			None,
+		));
+
+		let alice_dai_balance_before = hydradx_runtime::Currencies::free_balance(DAI, &ALICE.into());
+		let bob_dai_balance_before = hydradx_runtime::Currencies::free_balance(DAI, &BOB.into());
+
+		// Act
+		assert_ok!(hydradx_runtime::OTC::fill_order_via_route(
+			hydradx_runtime::RuntimeOrigin::signed(BOB.into()),
+			0,
+			HDX,
+			100 * UNITS,
+			vec![Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: DAI,
+			}],
+		));
+
+		// Assert
+		assert!(hydradx_runtime::OTC::orders(0).is_none());
+		assert_eq!(
+			hydradx_runtime::Currencies::reserved_balance_named(&NAMED_RESERVE_ID, HDX, &ALICE.into()),
+			0
+		);
+		assert_eq!(
+			hydradx_runtime::Currencies::free_balance(DAI, &ALICE.into()),
+			alice_dai_balance_before + 20 * UNITS
+		);
+		assert_eq!(
+			hydradx_runtime::Currencies::free_balance(DAI, &BOB.into()),
+			bob_dai_balance_before
+		);
+	});
+}
+
+#[test]
+fn update_order_should_work() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		// Arrange
+		assert_ok!(hydradx_runtime::OTC::place_order(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			DAI,
+			HDX,
+			20 * UNITS,
+			100 * UNITS,
+			true,
+			None,
+			None,
+			None,
+		));
+
+		// Act
+		assert_ok!(hydradx_runtime::OTC::update_order(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			0,
+			30 * UNITS,
+			150 * UNITS,
+		));
+
+		// Assert
+		let order = hydradx_runtime::OTC::orders(0).unwrap();
+		assert_eq!(order.amount_in, 30 * UNITS);
+		assert_eq!(order.amount_out, 150 * UNITS);
+		assert_eq!(
+			hydradx_runtime::Currencies::reserved_balance_named(&NAMED_RESERVE_ID, HDX, &ALICE.into()),
+			150 * UNITS
+		);
+	});
+}
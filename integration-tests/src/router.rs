@@ -0,0 +1,490 @@
+#![cfg(test)]
+
+use crate::assert_balance;
+use crate::oracle::hydradx_run_to_block;
+use crate::polkadot_test_net::*;
+
+use frame_support::{assert_noop, assert_ok, traits::tokens::fungibles::Mutate};
+use hydradx_runtime::{AssetRegistry, Currencies, EmaOracle, Omnipool, Router, RuntimeOrigin, Stableswap, LBP, XYK};
+use hydradx_traits::{router::PoolType, AggregatedPriceOracle, OraclePeriod};
+use orml_traits::MultiCurrency;
+use pallet_asset_registry::AssetType;
+use pallet_route_executor::{Trade, TradeAmountsCalculator};
+use pallet_stableswap::types::AssetLiquidity;
+use polkadot_primitives::v2::BlockNumber;
+use primitives::constants::chain::{LBP_SOURCE, STABLESWAP_SOURCE, XYK_SOURCE};
+use sp_runtime::Permill;
+use xcm_emulator::TestExt;
+
+const USDT: AssetId = 10;
+const STABLE_POOL: AssetId = 11;
+
+const STABLE_POOL_LIQUIDITY: Balance = 100_000_000 * UNITS;
+const XYK_POOL_LIQUIDITY: Balance = 1_000_000 * UNITS;
+const LBP_POOL_LIQUIDITY: Balance = 1_000_000 * UNITS;
+
+const LBP_SALE_START: BlockNumber = 10;
+const LBP_SALE_END: BlockNumber = 40;
+
+fn register_asset(name: &[u8], asset_id: AssetId) {
+	assert_ok!(AssetRegistry::register_asset(
+		AssetRegistry::to_bounded_name(name.to_vec()).unwrap(),
+		AssetType::<AssetId>::Token,
+		1_000u128,
+		Some(asset_id),
+		None,
+	));
+}
+
+fn init_stableswap() {
+	register_asset(b"USDT", USDT);
+	register_asset(b"STABLE_POOL", STABLE_POOL);
+
+	assert_ok!(Stableswap::create_pool(
+		RuntimeOrigin::root(),
+		STABLE_POOL,
+		vec![DAI, USDT],
+		100,
+		Permill::from_percent(0),
+		Permill::from_percent(0),
+	));
+
+	assert_ok!(hydradx_runtime::Tokens::mint_into(
+		USDT,
+		&BOB.into(),
+		STABLE_POOL_LIQUIDITY
+	));
+
+	assert_ok!(Stableswap::add_liquidity(
+		RuntimeOrigin::signed(BOB.into()),
+		STABLE_POOL,
+		vec![
+			AssetLiquidity {
+				asset_id: DAI,
+				amount: STABLE_POOL_LIQUIDITY,
+			},
+			AssetLiquidity {
+				asset_id: USDT,
+				amount: STABLE_POOL_LIQUIDITY,
+			},
+		],
+		0u128,
+	));
+}
+
+fn init_xyk() {
+	assert_ok!(hydradx_runtime::Tokens::mint_into(DOT, &BOB.into(), XYK_POOL_LIQUIDITY));
+
+	assert_ok!(XYK::create_pool(
+		RuntimeOrigin::signed(BOB.into()),
+		DAI,
+		XYK_POOL_LIQUIDITY,
+		DOT,
+		XYK_POOL_LIQUIDITY,
+	));
+}
+
+fn init_lbp() {
+	assert_ok!(hydradx_runtime::Tokens::mint_into(DOT, &BOB.into(), LBP_POOL_LIQUIDITY));
+
+	assert_ok!(LBP::create_pool(
+		RuntimeOrigin::root(),
+		BOB.into(),
+		DAI,
+		LBP_POOL_LIQUIDITY,
+		DOT,
+		LBP_POOL_LIQUIDITY,
+		80_000_000,
+		20_000_000,
+		pallet_lbp::WeightCurveType::Linear,
+		(2, 1_000),
+		CHARLIE.into(),
+		0,
+	));
+
+	assert_ok!(LBP::update_pool_data(
+		RuntimeOrigin::signed(BOB.into()),
+		LBP::get_pair_id(DAI, DOT),
+		None,
+		Some(LBP_SALE_START),
+		Some(LBP_SALE_END),
+		None,
+		None,
+		None,
+		None,
+		None,
+	));
+}
+
+fn omnipool_to_lbp_route() -> Vec<Trade<AssetId>> {
+	vec![
+		Trade {
+			pool: PoolType::Omnipool,
+			asset_in: HDX,
+			asset_out: DAI,
+		},
+		Trade {
+			pool: PoolType::LBP,
+			asset_in: DAI,
+			asset_out: DOT,
+		},
+	]
+}
+
+fn omnipool_to_xyk_route() -> Vec<Trade<AssetId>> {
+	vec![
+		Trade {
+			pool: PoolType::Omnipool,
+			asset_in: HDX,
+			asset_out: DAI,
+		},
+		Trade {
+			pool: PoolType::XYK,
+			asset_in: DAI,
+			asset_out: DOT,
+		},
+	]
+}
+
+fn omnipool_to_stableswap_route() -> Vec<Trade<AssetId>> {
+	vec![
+		Trade {
+			pool: PoolType::Omnipool,
+			asset_in: HDX,
+			asset_out: DAI,
+		},
+		Trade {
+			pool: PoolType::Stableswap(STABLE_POOL),
+			asset_in: DAI,
+			asset_out: USDT,
+		},
+	]
+}
+
+#[test]
+fn sell_should_work_when_route_contains_omnipool_and_stableswap() {
+	let amount_to_sell = 10 * UNITS;
+
+	//Direct trades
+	TestNet::reset();
+	let expected_amount_out = Hydra::execute_with(|| {
+		init_omnipool();
+		init_stableswap();
+
+		assert_ok!(Omnipool::sell(
+			RuntimeOrigin::signed(ALICE.into()),
+			HDX,
+			DAI,
+			amount_to_sell,
+			0,
+		));
+		let dai_received = Currencies::free_balance(DAI, &AccountId::from(ALICE)) - ALICE_INITIAL_DAI_BALANCE;
+
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(ALICE.into()),
+			STABLE_POOL,
+			DAI,
+			USDT,
+			dai_received,
+			0,
+		));
+
+		Currencies::free_balance(USDT, &AccountId::from(ALICE))
+	});
+
+	//Router
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+		init_stableswap();
+
+		//Act
+		assert_ok!(Router::sell(
+			RuntimeOrigin::signed(ALICE.into()),
+			HDX,
+			USDT,
+			amount_to_sell,
+			expected_amount_out,
+			omnipool_to_stableswap_route()
+		));
+
+		//Assert
+		assert!(expected_amount_out > 0);
+		assert_balance!(ALICE.into(), HDX, ALICE_INITIAL_NATIVE_BALANCE - amount_to_sell);
+		assert_balance!(ALICE.into(), DAI, ALICE_INITIAL_DAI_BALANCE);
+		assert_balance!(ALICE.into(), USDT, expected_amount_out);
+	});
+}
+
+#[test]
+fn buy_should_work_when_route_contains_omnipool_and_stableswap() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+		init_stableswap();
+
+		let amount_to_buy = 100_000 * UNITS;
+		let route = omnipool_to_stableswap_route();
+		let trade_amounts = Router::calculate_buy_trade_amounts(&route, amount_to_buy).unwrap();
+		let expected_amount_in = trade_amounts.last().unwrap().amount_in;
+
+		//Act
+		assert_ok!(Router::buy(
+			RuntimeOrigin::signed(ALICE.into()),
+			HDX,
+			USDT,
+			amount_to_buy,
+			expected_amount_in,
+			route
+		));
+
+		//Assert
+		assert_balance!(ALICE.into(), HDX, ALICE_INITIAL_NATIVE_BALANCE - expected_amount_in);
+		assert_balance!(ALICE.into(), DAI, ALICE_INITIAL_DAI_BALANCE);
+		assert_balance!(ALICE.into(), USDT, amount_to_buy);
+	});
+}
+
+#[test]
+fn sell_should_use_stored_route_when_route_contains_omnipool_and_stableswap() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+		init_stableswap();
+
+		let amount_to_sell = 10 * UNITS;
+		let route = omnipool_to_stableswap_route();
+		let trade_amounts = Router::calculate_sell_trade_amounts(&route, amount_to_sell).unwrap();
+		let expected_amount_out = trade_amounts.last().unwrap().amount_out;
+
+		assert_ok!(Router::set_route(RuntimeOrigin::signed(BOB.into()), HDX, USDT, route));
+
+		//Act
+		assert_ok!(Router::sell(
+			RuntimeOrigin::signed(ALICE.into()),
+			HDX,
+			USDT,
+			amount_to_sell,
+			expected_amount_out,
+			vec![]
+		));
+
+		//Assert
+		assert_balance!(ALICE.into(), HDX, ALICE_INITIAL_NATIVE_BALANCE - amount_to_sell);
+		assert_balance!(ALICE.into(), USDT, expected_amount_out);
+	});
+}
+
+#[test]
+fn stableswap_trades_are_ingested_into_oracle() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		hydradx_run_to_block(2);
+		init_stableswap();
+
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(ALICE.into()),
+			STABLE_POOL,
+			DAI,
+			USDT,
+			10 * UNITS,
+			0,
+		));
+
+		//Act
+		hydradx_run_to_block(3);
+
+		//Assert
+		assert!(EmaOracle::get_price(DAI, USDT, OraclePeriod::LastBlock, STABLESWAP_SOURCE).is_ok());
+		assert!(EmaOracle::get_price(DAI, STABLE_POOL, OraclePeriod::LastBlock, STABLESWAP_SOURCE).is_ok());
+		assert!(EmaOracle::get_price(USDT, STABLE_POOL, OraclePeriod::LastBlock, STABLESWAP_SOURCE).is_ok());
+	});
+}
+
+#[test]
+fn sell_should_work_when_route_contains_omnipool_and_xyk() {
+	let amount_to_sell = 10 * UNITS;
+
+	//Direct trades
+	TestNet::reset();
+	let expected_amount_out = Hydra::execute_with(|| {
+		init_omnipool();
+		init_xyk();
+
+		assert_ok!(Omnipool::sell(
+			RuntimeOrigin::signed(ALICE.into()),
+			HDX,
+			DAI,
+			amount_to_sell,
+			0,
+		));
+		let dai_received = Currencies::free_balance(DAI, &AccountId::from(ALICE)) - ALICE_INITIAL_DAI_BALANCE;
+
+		assert_ok!(XYK::sell(
+			RuntimeOrigin::signed(ALICE.into()),
+			DAI,
+			DOT,
+			dai_received,
+			0,
+		));
+
+		Currencies::free_balance(DOT, &AccountId::from(ALICE))
+	});
+
+	//Router
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+		init_xyk();
+
+		//Act
+		assert_ok!(Router::sell(
+			RuntimeOrigin::signed(ALICE.into()),
+			HDX,
+			DOT,
+			amount_to_sell,
+			expected_amount_out,
+			omnipool_to_xyk_route()
+		));
+
+		//Assert
+		assert!(expected_amount_out > 0);
+		assert_balance!(ALICE.into(), HDX, ALICE_INITIAL_NATIVE_BALANCE - amount_to_sell);
+		assert_balance!(ALICE.into(), DAI, ALICE_INITIAL_DAI_BALANCE);
+		assert_balance!(ALICE.into(), DOT, expected_amount_out);
+	});
+}
+
+#[test]
+fn buy_should_work_when_route_contains_omnipool_and_xyk() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+		init_xyk();
+
+		let amount_to_buy = UNITS;
+		let route = omnipool_to_xyk_route();
+		let trade_amounts = Router::calculate_buy_trade_amounts(&route, amount_to_buy).unwrap();
+		let expected_amount_in = trade_amounts.last().unwrap().amount_in;
+
+		//Act
+		assert_ok!(Router::buy(
+			RuntimeOrigin::signed(ALICE.into()),
+			HDX,
+			DOT,
+			amount_to_buy,
+			expected_amount_in,
+			route
+		));
+
+		//Assert
+		assert_balance!(ALICE.into(), HDX, ALICE_INITIAL_NATIVE_BALANCE - expected_amount_in);
+		assert_balance!(ALICE.into(), DAI, ALICE_INITIAL_DAI_BALANCE);
+		assert_balance!(ALICE.into(), DOT, amount_to_buy);
+	});
+}
+
+#[test]
+fn xyk_trades_are_ingested_into_oracle() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		hydradx_run_to_block(2);
+		init_xyk();
+
+		assert_ok!(XYK::sell(RuntimeOrigin::signed(ALICE.into()), DAI, DOT, 10 * UNITS, 0,));
+
+		//Act
+		hydradx_run_to_block(3);
+
+		//Assert
+		assert!(EmaOracle::get_price(DAI, DOT, OraclePeriod::LastBlock, XYK_SOURCE).is_ok());
+	});
+}
+
+#[test]
+fn sell_should_work_when_route_contains_omnipool_and_lbp() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+		init_lbp();
+		hydradx_run_to_block(LBP_SALE_START + 1);
+
+		let amount_to_sell = 10 * UNITS;
+		let route = omnipool_to_lbp_route();
+		let trade_amounts = Router::calculate_sell_trade_amounts(&route, amount_to_sell).unwrap();
+		let expected_amount_out = trade_amounts.last().unwrap().amount_out;
+
+		//Act
+		assert_ok!(Router::sell(
+			RuntimeOrigin::signed(ALICE.into()),
+			HDX,
+			DOT,
+			amount_to_sell,
+			expected_amount_out,
+			route
+		));
+
+		//Assert
+		assert!(expected_amount_out > 0);
+		assert_balance!(ALICE.into(), HDX, ALICE_INITIAL_NATIVE_BALANCE - amount_to_sell);
+		assert_balance!(ALICE.into(), DAI, ALICE_INITIAL_DAI_BALANCE);
+		assert_balance!(ALICE.into(), DOT, expected_amount_out);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_route_contains_lbp_and_sale_is_not_running() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+		init_lbp();
+
+		//Act & Assert
+		assert_noop!(
+			Router::sell(
+				RuntimeOrigin::signed(ALICE.into()),
+				HDX,
+				DOT,
+				10 * UNITS,
+				0,
+				omnipool_to_lbp_route()
+			),
+			pallet_lbp::Error::<hydradx_runtime::Runtime>::SaleIsNotRunning
+		);
+	});
+}
+
+#[test]
+fn lbp_trades_are_ingested_into_oracle() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_lbp();
+		hydradx_run_to_block(LBP_SALE_START + 1);
+
+		assert_ok!(LBP::sell(RuntimeOrigin::signed(ALICE.into()), DAI, DOT, 10 * UNITS, 0,));
+
+		//Act
+		hydradx_run_to_block(LBP_SALE_START + 2);
+
+		//Assert
+		assert!(EmaOracle::get_price(DAI, DOT, OraclePeriod::LastBlock, LBP_SOURCE).is_ok());
+	});
+}
@@ -186,6 +186,20 @@ pub fn liquidity_weighted_average(
 	)
 }
 
+/// Return the median of the given prices or `None` if `prices` is empty.
+///
+/// For an even number of prices the lower of the two middle prices is returned, so the result is
+/// always one of the given prices.
+///
+/// Note: Sorts `prices` in place.
+pub fn median_price(prices: &mut [EmaPrice]) -> Option<EmaPrice> {
+	if prices.is_empty() {
+		return None;
+	}
+	prices.sort_unstable();
+	Some(prices[(prices.len() - 1) / 2])
+}
+
 // Utility functions for working with rational numbers.
 
 /// Subtract `r` from `l` and return a tuple of `U256` for full precision.
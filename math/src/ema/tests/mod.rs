@@ -363,3 +363,35 @@ fn precision_of_ema_over_balancer_expanded_one_year_data_scrape_history_should_b
 		);
 	}
 }
+
+#[test]
+fn median_price_should_return_none_for_no_prices() {
+	assert_eq!(median_price(&mut []), None);
+}
+
+#[test]
+fn median_price_should_return_middle_price_for_odd_number_of_prices() {
+	let mut prices = [EmaPrice::new(3, 1), EmaPrice::new(1, 4), EmaPrice::new(4, 2)];
+	assert_eq!(median_price(&mut prices), Some(EmaPrice::new(4, 2)));
+}
+
+#[test]
+fn median_price_should_return_lower_middle_price_for_even_number_of_prices() {
+	let mut prices = [
+		EmaPrice::new(5, 1),
+		EmaPrice::new(1, 2),
+		EmaPrice::new(3, 2),
+		EmaPrice::new(9, 4),
+	];
+	assert_eq!(median_price(&mut prices), Some(EmaPrice::new(3, 2)));
+}
+
+#[test]
+fn median_price_should_compare_prices_by_value() {
+	let mut prices = [
+		EmaPrice::new(2, 1),
+		EmaPrice::new(300, 100),
+		EmaPrice::new(1_000, 1_000),
+	];
+	assert_eq!(median_price(&mut prices), Some(EmaPrice::new(2, 1)));
+}
@@ -10,7 +10,7 @@ use crate::types::Balance;
 pub fn convert_position(position: Position<Balance>, details: MigrationDetails) -> Option<Position<Balance>> {
 	let shares = position
 		.shares
-		.checked_mul_into(&details.hub_reserve)?
+		.checked_mul_into(&details.subpool_shares)?
 		.checked_div_inner(&details.shares)?
 		.try_into()
 		.ok()?;
@@ -103,6 +103,9 @@ pub fn calculate_asset_migration_details(
 				price: round_to_rational((price_num, price_denom), Rounding::Nearest),
 				shares: asset_state.shares,
 				hub_reserve: delta_q,
+				// Positions are converted to shares of the subpool asset, which is not 1:1 with its hub reserve
+				// once the subpool asset has been traded.
+				subpool_shares: delta_s,
 				share_tokens: delta_u,
 			},
 			Some(AssetStateChange {
@@ -119,6 +122,7 @@ pub fn calculate_asset_migration_details(
 				price: asset_state.price_as_rational(),
 				shares: asset_state.shares,
 				hub_reserve: asset_state.hub_reserve,
+				subpool_shares: asset_state.hub_reserve,
 				share_tokens: asset_state.hub_reserve,
 			},
 			None,
@@ -1,6 +1,6 @@
-use crate::omnipool::types::Position;
-use crate::omnipool_subpools::convert_position;
+use crate::omnipool::types::{AssetReserveState, BalanceUpdate, Position};
 use crate::omnipool_subpools::types::MigrationDetails;
+use crate::omnipool_subpools::{calculate_asset_migration_details, convert_position};
 use crate::types::Balance;
 
 const ONE: Balance = 1_000_000_000_000;
@@ -16,7 +16,8 @@ fn convert_position_should_work() {
 	let details = MigrationDetails {
 		price: (1_000 * ONE, 1_000_000_000 * ONE),
 		shares: 10_000 * ONE,
-		hub_reserve: 5_000 * ONE,
+		hub_reserve: 2_500 * ONE,
+		subpool_shares: 5_000 * ONE,
 		share_tokens: 3_000 * ONE,
 	};
 
@@ -31,3 +32,32 @@ fn convert_position_should_work() {
 		}
 	);
 }
+
+#[test]
+fn calculate_asset_migration_details_should_convert_shares_to_subpool_shares() {
+	let asset_state = AssetReserveState::<Balance> {
+		reserve: 1_000 * ONE,
+		hub_reserve: 500 * ONE,
+		shares: 1_000 * ONE,
+		protocol_shares: 0,
+	};
+
+	let subpool_state = AssetReserveState::<Balance> {
+		reserve: 4_000 * ONE,
+		hub_reserve: 2_000 * ONE,
+		shares: 4_000 * ONE,
+		protocol_shares: 0,
+	};
+
+	let (details, delta) = calculate_asset_migration_details(&asset_state, Some(&subpool_state), 4_000 * ONE).unwrap();
+
+	assert_eq!(details.shares, 1_000 * ONE);
+	assert_eq!(details.hub_reserve, 500 * ONE);
+	assert_eq!(details.subpool_shares, 1_000 * ONE);
+	assert_eq!(details.share_tokens, 1_000 * ONE);
+
+	let delta = delta.unwrap();
+	assert_eq!(delta.delta_hub_reserve, BalanceUpdate::Increase(500 * ONE));
+	assert_eq!(delta.delta_shares, BalanceUpdate::Increase(1_000 * ONE));
+	assert_eq!(delta.delta_reserve, BalanceUpdate::Increase(1_000 * ONE));
+}
@@ -4,5 +4,6 @@ pub struct MigrationDetails {
 	pub price: (Balance, Balance),
 	pub shares: Balance,
 	pub hub_reserve: Balance,
+	pub subpool_shares: Balance,
 	pub share_tokens: Balance,
 }
@@ -80,11 +80,16 @@ pub fn calculate_in_given_out_with_fee<const N: u8, const N_Y: u8>(
 }
 
 /// Calculate amount of shares to be given to LP after LP provided liquidity of some assets to the pool.
+///
+/// If the liquidity is not added proportionally, fee is applied on the difference between updated reserves and reserves
+/// if the same liquidity was added proportionally. Adding liquidity of one asset and removing it proportionally
+/// is then not cheaper than selling the asset.
 pub fn calculate_shares<const N: u8>(
 	initial_reserves: &[Balance],
 	updated_reserves: &[Balance],
 	amplification: Balance,
 	share_issuance: Balance,
+	fee: Permill,
 ) -> Option<Balance> {
 	if initial_reserves.len() != updated_reserves.len() {
 		return None;
@@ -104,12 +109,150 @@ pub fn calculate_shares<const N: u8>(
 		// if first liquidity added
 		Some(updated_d)
 	} else {
-		let (issuance_hp, d_diff, d0) = to_u256!(share_issuance, updated_d.checked_sub(initial_d)?, initial_d);
+		let adjusted_d = if fee.is_zero() {
+			updated_d
+		} else {
+			let (d0, d1) = to_u256!(initial_d, updated_d);
+
+			let adjusted_reserves: Vec<Balance> = updated_reserves
+				.iter()
+				.enumerate()
+				.map(|(idx, reserve)| {
+					// ideal_balance = xp[j] * d1 / d0
+					let ideal_balance = to_u256!(initial_reserves[idx]).checked_mul(d1)?.checked_div(d0)?;
+					let reserve_hp = to_u256!(*reserve);
+					let diff = if reserve_hp > ideal_balance {
+						reserve_hp.checked_sub(ideal_balance)?
+					} else {
+						ideal_balance.checked_sub(reserve_hp)?
+					};
+					reserve.checked_sub(fee.mul_ceil(Balance::try_from(diff).ok()?))
+				})
+				.collect::<Option<Vec<Balance>>>()?;
+
+			calculate_d::<N>(&adjusted_reserves, amplification)?.checked_sub(2_u128)?
+		};
+
+		let (issuance_hp, d_diff, d0) = to_u256!(share_issuance, adjusted_d.checked_sub(initial_d)?, initial_d);
 		let share_amount = issuance_hp.checked_mul(d_diff)?.checked_div(d0)?;
 		Balance::try_from(share_amount).ok()
 	}
 }
 
+/// Given amount of shares and asset reserves, calculate amount of selected asset required to be added to the pool
+/// so that LP receives exactly given amount of shares.
+///
+/// Fee is applied the same way as in `calculate_shares` - LP receives at least `shares` when providing the amount
+/// via `calculate_shares`.
+///
+/// Amount is rounded up in favor of the pool.
+pub fn calculate_add_one_asset<const N: u8, const N_Y: u8>(
+	reserves: &[Balance],
+	shares: Balance,
+	asset_index: usize,
+	share_asset_issuance: Balance,
+	amplification: Balance,
+	fee: Permill,
+) -> Option<Balance> {
+	if share_asset_issuance.is_zero() {
+		return None;
+	}
+
+	if asset_index >= reserves.len() {
+		return None;
+	}
+
+	if reserves.len() <= 1 {
+		return None;
+	}
+
+	let initial_d = calculate_d::<N>(reserves, amplification)?;
+
+	let (shares_hp, issuance_hp, d_hp) = to_u256!(shares, share_asset_issuance, initial_d);
+
+	// d1 = d0 + ceil(shares * d0 / issuance)
+	// calculate_shares rounds updated D down by 2, so we must require D to be higher by the same amount.
+	let d_diff = shares_hp
+		.checked_mul(d_hp)?
+		.checked_add(issuance_hp.checked_sub(U256::one())?)?
+		.checked_div(issuance_hp)?;
+	let d1 = d_hp.checked_add(d_diff)?.checked_add(to_u256!(2u128))?;
+
+	let xp: Vec<Balance> = reserves
+		.iter()
+		.enumerate()
+		.filter(|(idx, _)| *idx != asset_index)
+		.map(|(_, v)| *v)
+		.collect();
+
+	let y = calculate_y::<N_Y>(&xp, Balance::try_from(d1).ok()?, amplification)?;
+
+	let amount = y.checked_sub(reserves[asset_index])?.checked_add(1u128)?;
+
+	if fee.is_zero() {
+		return Some(amount);
+	}
+
+	// Amount without fee is a lower bound. Imbalance fee depends on the amount itself, so the amount is increased
+	// by the remaining shares priced at the amount without fee until LP receives at least `shares`.
+	let (amount_hp, shares_hp) = to_u256!(amount, shares);
+	let mut amount_with_fee = amount;
+	for _ in 0..N_Y {
+		let updated_reserves: Vec<Balance> = reserves
+			.iter()
+			.enumerate()
+			.map(|(idx, reserve)| {
+				if idx == asset_index {
+					reserve.checked_add(amount_with_fee)
+				} else {
+					Some(*reserve)
+				}
+			})
+			.collect::<Option<Vec<Balance>>>()?;
+
+		let received_shares =
+			calculate_shares::<N>(reserves, &updated_reserves, amplification, share_asset_issuance, fee).unwrap_or(0);
+
+		if received_shares >= shares {
+			return Some(amount_with_fee);
+		}
+
+		// delta = ceil((shares - received_shares) * amount / shares)
+		let missing_shares = to_u256!(shares.checked_sub(received_shares)?);
+		let delta = missing_shares
+			.checked_mul(amount_hp)?
+			.checked_add(shares_hp.checked_sub(U256::one())?)?
+			.checked_div(shares_hp)?;
+		amount_with_fee = amount_with_fee.checked_add(Balance::try_from(delta).ok()?)?;
+	}
+
+	None
+}
+
+/// Given amount of shares and asset reserves, calculate corresponding amounts of each asset to be withdrawn.
+///
+/// Liquidity is removed proportionally to the share of the pool, so no fee is applied and the pool invariant is not affected.
+/// Amounts are rounded down in favor of the pool.
+pub fn calculate_liquidity_out(
+	reserves: &[Balance],
+	shares: Balance,
+	share_asset_issuance: Balance,
+) -> Option<Vec<Balance>> {
+	if share_asset_issuance.is_zero() || shares > share_asset_issuance {
+		return None;
+	}
+
+	let (shares_hp, issuance_hp) = to_u256!(shares, share_asset_issuance);
+
+	reserves
+		.iter()
+		.map(|reserve| {
+			let amount = to_u256!(*reserve).checked_mul(shares_hp)?.checked_div(issuance_hp)?;
+			Balance::try_from(amount).ok()
+		})
+		.collect()
+}
+
 /// Given amount of shares and asset reserves, calculate corresponding amount of selected asset to be withdrawn.
 pub fn calculate_withdraw_one_asset<const N: u8, const N_Y: u8>(
 	reserves: &[Balance],
@@ -194,6 +337,113 @@ pub fn calculate_withdraw_one_asset<const N: u8, const N_Y: u8>(
 	Some((dy, fee))
 }
 
+/// Given amount of an asset to be withdrawn from the pool, calculate amount of shares which needs to be burned.
+///
+/// It is the inverse of `calculate_withdraw_one_asset`. Withdraw fee is applied the same way - on the difference
+/// between updated reserves and reserves if the liquidity was removed proportionally.
+///
+/// Amount of shares is rounded up in favor of the pool - LP receives at least `amount` when withdrawing the shares
+/// via `calculate_withdraw_one_asset`.
+pub fn calculate_shares_for_amount<const N: u8, const N_Y: u8>(
+	reserves: &[Balance],
+	asset_index: usize,
+	amount: Balance,
+	amplification: Balance,
+	share_issuance: Balance,
+	fee: Permill,
+) -> Option<Balance> {
+	if share_issuance.is_zero() {
+		return None;
+	}
+
+	if asset_index >= reserves.len() {
+		return None;
+	}
+
+	let n_coins = reserves.len();
+	if n_coins <= 1 {
+		return None;
+	}
+	let fixed_fee = FixedU128::from(fee);
+	let adjusted_fee = fixed_fee
+		.checked_mul(&FixedU128::from(n_coins as u128))?
+		.checked_div(&FixedU128::from(4 * (n_coins - 1) as u128))?;
+
+	let updated_reserves: Vec<Balance> = reserves
+		.iter()
+		.enumerate()
+		.map(|(idx, reserve)| {
+			if idx == asset_index {
+				reserve.checked_sub(amount)
+			} else {
+				Some(*reserve)
+			}
+		})
+		.collect::<Option<Vec<Balance>>>()?;
+
+	let initial_d = calculate_d::<N>(reserves, amplification)?;
+	let updated_d = calculate_d::<N>(&updated_reserves, amplification)?;
+
+	let (d0, d1) = to_u256!(initial_d, updated_d);
+
+	let adjusted_reserves: Vec<Balance> = updated_reserves
+		.iter()
+		.enumerate()
+		.map(|(idx, reserve)| {
+			// ideal_balance = xp[j] * d1 / d0
+			let ideal_balance = to_u256!(reserves[idx]).checked_mul(d1)?.checked_div(d0)?;
+			let reserve_hp = to_u256!(*reserve);
+			let diff = if reserve_hp > ideal_balance {
+				reserve_hp.checked_sub(ideal_balance)?
+			} else {
+				ideal_balance.checked_sub(reserve_hp)?
+			};
+			let diff = Balance::try_from(diff).ok()?;
+			reserve.checked_sub(adjusted_fee.checked_mul_int(diff)?)
+		})
+		.collect::<Option<Vec<Balance>>>()?;
+
+	// calculate_d can return a D value that is above the correct D value by up to 2, so we subtract 2
+	// to make sure more shares are burned rather than less.
+	let adjusted_d = calculate_d::<N>(&adjusted_reserves, amplification)?.saturating_sub(2_u128);
+
+	let (issuance_hp, d_diff) = to_u256!(share_issuance, initial_d.checked_sub(adjusted_d)?);
+	let share_amount = issuance_hp
+		.checked_mul(d_diff)?
+		.checked_add(d0.checked_sub(U256::one())?)?
+		.checked_div(d0)?;
+	let share_amount = Balance::try_from(share_amount).ok()?;
+
+	// Withdrawn amount is rounded down and fee is applied on reserves updated by the shares, not by the amount,
+	// so the shares are increased by the missing amount priced at the estimated shares until LP receives `amount`.
+	let (amount_hp, shares_hp) = to_u256!(amount, share_amount);
+	let mut shares_with_fee = share_amount;
+	for _ in 0..N_Y {
+		let (withdrawn, _) = calculate_withdraw_one_asset::<N, N_Y>(
+			reserves,
+			shares_with_fee,
+			asset_index,
+			share_issuance,
+			amplification,
+			fee,
+		)?;
+
+		if withdrawn >= amount {
+			return Some(shares_with_fee);
+		}
+
+		// delta = ceil((amount - withdrawn) * shares / amount)
+		let missing_amount = to_u256!(amount.checked_sub(withdrawn)?);
+		let delta = missing_amount
+			.checked_mul(shares_hp)?
+			.checked_add(amount_hp.checked_sub(U256::one())?)?
+			.checked_div(amount_hp)?;
+		shares_with_fee = shares_with_fee.checked_add(Balance::try_from(delta).ok()?)?;
+	}
+
+	None
+}
+
 /// amplification * n^n where n is number of assets in pool.
 pub(crate) fn calculate_ann(len: usize, amplification: Balance) -> Option<Balance> {
 	(0..len).try_fold(amplification, |acc, _| acc.checked_mul(len as u128))
@@ -3,6 +3,7 @@ use crate::stableswap::*;
 use crate::types::Balance;
 use proptest::prelude::*;
 use proptest::proptest;
+use sp_arithmetic::Permill;
 
 const D_ITERATIONS: u8 = 255;
 const Y_ITERATIONS: u8 = 64;
@@ -33,6 +34,10 @@ fn amplification() -> impl Strategy<Value = Balance> {
 	2..10000u128
 }
 
+fn fee() -> impl Strategy<Value = Permill> {
+	(1..50_000u32).prop_map(Permill::from_parts)
+}
+
 proptest! {
 	#![proptest_config(ProptestConfig::with_cases(1000))]
 	#[test]
@@ -120,7 +125,7 @@ proptest! {
 		let initial_reserves = &[reserve_a, reserve_b];
 		let updated_reserves = &[reserve_a.checked_add(amount_a).unwrap(), reserve_b.checked_add(amount_b).unwrap()];
 
-		let result = calculate_shares::<D_ITERATIONS>(initial_reserves, updated_reserves, amp, issuance);
+		let result = calculate_shares::<D_ITERATIONS>(initial_reserves, updated_reserves, amp, issuance, Permill::zero());
 
 		assert!(result.is_some());
 	}
@@ -166,3 +171,158 @@ proptest! {
 		assert!(y >= reserve_a);
 	}
 }
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn remove_liquidity_proportionally_should_not_increase_share_price(
+		reserve_a in asset_reserve(),
+		reserve_b in asset_reserve(),
+		reserve_c in asset_reserve(),
+		amp in amplification(),
+		issuance in asset_reserve(),
+		shares_percentage in 1..100u128,
+	) {
+		let reserves = vec![reserve_a, reserve_b, reserve_c];
+		let shares = issuance * shares_percentage / 100;
+
+		let amounts = calculate_liquidity_out(&reserves, shares, issuance).unwrap();
+
+		let updated_reserves: Vec<Balance> = reserves.iter().zip(amounts.iter()).map(|(r, a)| r - a).collect();
+
+		let d0 = calculate_d::<D_ITERATIONS>(&reserves, amp).unwrap();
+		let d1 = calculate_d::<D_ITERATIONS>(&updated_reserves, amp).unwrap();
+
+		// d per share must not decrease: d1 / (issuance - shares) >= d0 / issuance
+		let (d0_hp, d1_hp, issuance_hp, remaining_hp) = (
+			primitive_types::U256::from(d0),
+			primitive_types::U256::from(d1),
+			primitive_types::U256::from(issuance),
+			primitive_types::U256::from(issuance - shares),
+		);
+		// calculate_d can be off by up to 2 units
+		let tolerance = primitive_types::U256::from(2u128) * issuance_hp;
+		assert!(d1_hp * issuance_hp + tolerance >= d0_hp * remaining_hp);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn remove_liquidity_proportionally_should_preserve_reserve_ratios(
+		reserve_a in asset_reserve(),
+		reserve_b in asset_reserve(),
+		issuance in asset_reserve(),
+		shares_percentage in 1..100u128,
+	) {
+		let reserves = vec![reserve_a, reserve_b];
+		let shares = issuance * shares_percentage / 100;
+
+		let amounts = calculate_liquidity_out(&reserves, shares, issuance).unwrap();
+
+		for (reserve, amount) in reserves.iter().zip(amounts.iter()) {
+			assert!(amount <= reserve);
+			// amount / reserve <= shares / issuance
+			assert!(primitive_types::U256::from(*amount) * primitive_types::U256::from(issuance)
+				<= primitive_types::U256::from(*reserve) * primitive_types::U256::from(shares));
+		}
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn add_one_asset_should_yield_at_least_requested_shares(
+		reserve_a in asset_reserve(),
+		reserve_b in asset_reserve(),
+		reserve_c in asset_reserve(),
+		amp in amplification(),
+		issuance in asset_reserve(),
+		shares in trade_amount(),
+	) {
+		let reserves = [reserve_a, reserve_b, reserve_c];
+
+		let amount = calculate_add_one_asset::<D_ITERATIONS, Y_ITERATIONS>(&reserves, shares, 1, issuance, amp, Permill::zero()).unwrap();
+
+		let updated_reserves = [reserve_a, reserve_b + amount, reserve_c];
+
+		let received_shares = calculate_shares::<D_ITERATIONS>(&reserves, &updated_reserves, amp, issuance, Permill::zero()).unwrap();
+
+		assert!(received_shares >= shares);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn add_liquidity_of_one_asset_and_remove_liquidity_should_not_give_more_than_sell(
+		reserve_a in asset_reserve(),
+		reserve_b in asset_reserve(),
+		amp in amplification(),
+		issuance in asset_reserve(),
+		amount in trade_amount(),
+		fee in fee(),
+	) {
+		let reserves = [reserve_a, reserve_b];
+		let updated_reserves = [reserve_a + amount, reserve_b];
+
+		let shares = calculate_shares::<D_ITERATIONS>(&reserves, &updated_reserves, amp, issuance, fee).unwrap();
+		let amounts = calculate_liquidity_out(&updated_reserves, shares, issuance + shares).unwrap();
+
+		// LP effectively sold `amount - amounts[0]` of asset A for `amounts[1]` of asset B
+		let sold = amount - amounts[0];
+		let (bought, _) = calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(&reserves, 0, 1, sold, amp, fee).unwrap();
+
+		assert!(amounts[1] <= bought);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn add_one_asset_and_remove_liquidity_should_not_give_more_than_sell(
+		reserve_a in asset_reserve(),
+		reserve_b in asset_reserve(),
+		amp in amplification(),
+		issuance in asset_reserve(),
+		shares in trade_amount(),
+		fee in fee(),
+	) {
+		let reserves = [reserve_a, reserve_b];
+
+		let amount = calculate_add_one_asset::<D_ITERATIONS, Y_ITERATIONS>(&reserves, shares, 0, issuance, amp, fee).unwrap();
+
+		let updated_reserves = [reserve_a + amount, reserve_b];
+		let amounts = calculate_liquidity_out(&updated_reserves, shares, issuance + shares).unwrap();
+
+		// LP effectively sold `amount - amounts[0]` of asset A for `amounts[1]` of asset B
+		let sold = amount - amounts[0];
+		let (bought, _) = calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(&reserves, 0, 1, sold, amp, fee).unwrap();
+
+		assert!(amounts[1] <= bought);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn add_one_asset_with_fee_should_yield_at_least_requested_shares(
+		reserve_a in asset_reserve(),
+		reserve_b in asset_reserve(),
+		reserve_c in asset_reserve(),
+		amp in amplification(),
+		issuance in asset_reserve(),
+		shares in trade_amount(),
+		fee in fee(),
+	) {
+		let reserves = [reserve_a, reserve_b, reserve_c];
+
+		let amount = calculate_add_one_asset::<D_ITERATIONS, Y_ITERATIONS>(&reserves, shares, 1, issuance, amp, fee).unwrap();
+
+		let updated_reserves = [reserve_a, reserve_b + amount, reserve_c];
+
+		let received_shares = calculate_shares::<D_ITERATIONS>(&reserves, &updated_reserves, amp, issuance, fee).unwrap();
+
+		assert!(received_shares >= shares);
+	}
+}
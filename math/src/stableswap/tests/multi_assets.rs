@@ -1,6 +1,7 @@
 const D_ITERATIONS: u8 = 128;
 const Y_ITERATIONS: u8 = 64;
 
+use crate::stableswap::tests::ONE;
 use crate::stableswap::*;
 use crate::types::Balance;
 use sp_arithmetic::Permill;
@@ -98,7 +99,7 @@ fn calculate_shares_should_work_when_correct_input_provided() {
 
 	let issuance: Balance = 100_000;
 
-	let result = calculate_shares::<D_ITERATIONS>(&initial_balances, &updated_balances, amp, issuance);
+	let result = calculate_shares::<D_ITERATIONS>(&initial_balances, &updated_balances, amp, issuance, Permill::zero());
 
 	assert!(result.is_some());
 
@@ -118,7 +119,7 @@ fn calculate_shares_should_work_when_share_issuance_is_zero() {
 
 	let issuance: Balance = 0;
 
-	let result = calculate_shares::<D_ITERATIONS>(&initial_balances, &updated_balances, amp, issuance);
+	let result = calculate_shares::<D_ITERATIONS>(&initial_balances, &updated_balances, amp, issuance, Permill::zero());
 
 	assert!(result.is_some());
 
@@ -138,7 +139,7 @@ fn calculate_shares_should_fail_when_balances_len_is_not_equal() {
 
 	let issuance: Balance = 100_000;
 
-	let result = calculate_shares::<D_ITERATIONS>(&initial_balances, &updated_balances, amp, issuance);
+	let result = calculate_shares::<D_ITERATIONS>(&initial_balances, &updated_balances, amp, issuance, Permill::zero());
 
 	assert!(result.is_none());
 }
@@ -154,7 +155,7 @@ fn calculate_shares_should_fail_when_updated_balances_are_less() {
 
 	let issuance: Balance = 100_000;
 
-	let result = calculate_shares::<D_ITERATIONS>(&initial_balances, &updated_balances, amp, issuance);
+	let result = calculate_shares::<D_ITERATIONS>(&initial_balances, &updated_balances, amp, issuance, Permill::zero());
 
 	assert!(result.is_none());
 }
@@ -329,7 +330,7 @@ fn calculate_withdraw_should_return_correct_amount_when_removing_provided_shares
 
 	let issuance: Balance = 100_000;
 
-	let result = calculate_shares::<D_ITERATIONS>(&initial_balances, &updated_balances, amp, issuance);
+	let result = calculate_shares::<D_ITERATIONS>(&initial_balances, &updated_balances, amp, issuance, Permill::zero());
 	let shares = result.unwrap();
 
 	let result = calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
@@ -347,3 +348,162 @@ fn calculate_withdraw_should_return_correct_amount_when_removing_provided_shares
 
 	assert_eq!(result, (4993u128, 0u128));
 }
+
+#[test]
+fn calculate_liquidity_out_should_work_when_max_supported_nbr_of_balances_is_provided() {
+	let balances = [10_000u128, 20_000u128, 30_000u128, 40_000u128, 50_000u128];
+
+	let shares_to_withdraw: Balance = 2_000u128;
+	let issuance = 52_000u128;
+
+	let result = calculate_liquidity_out(&balances, shares_to_withdraw, issuance);
+
+	assert!(result.is_some());
+
+	assert_eq!(result.unwrap(), vec![384u128, 769u128, 1153u128, 1538u128, 1923u128]);
+}
+
+#[test]
+fn calculate_liquidity_out_should_withdraw_all_reserves_when_all_shares_are_provided() {
+	let balances = [10_000u128; MAX_BALANCES];
+
+	let issuance = 52_000u128;
+
+	let result = calculate_liquidity_out(&balances, issuance, issuance);
+
+	assert_eq!(result, Some(balances.to_vec()));
+}
+
+#[test]
+fn calculate_liquidity_out_should_fail_when_share_issuance_is_zero() {
+	let balances = [10_000u128; MAX_BALANCES];
+
+	let result = calculate_liquidity_out(&balances, 2_000u128, 0u128);
+
+	assert!(result.is_none());
+}
+
+#[test]
+fn calculate_liquidity_out_should_fail_when_shares_exceed_share_issuance() {
+	let balances = [10_000u128; MAX_BALANCES];
+
+	let result = calculate_liquidity_out(&balances, 52_001u128, 52_000u128);
+
+	assert!(result.is_none());
+}
+
+#[test]
+fn calculate_add_one_asset_should_work_when_max_supported_nbr_of_balances_is_provided() {
+	let amp = 100_u128;
+
+	let balances = [10_000 * ONE; MAX_BALANCES];
+
+	let asset_index: usize = 2;
+
+	let shares: Balance = 2_000 * ONE;
+	let issuance = 50_000 * ONE;
+
+	let result = calculate_add_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+		&balances,
+		shares,
+		asset_index,
+		issuance,
+		amp,
+		Permill::zero(),
+	);
+
+	assert!(result.is_some());
+	let amount = result.unwrap();
+
+	let mut updated_balances = balances;
+	updated_balances[asset_index] += amount;
+
+	let received_shares =
+		calculate_shares::<D_ITERATIONS>(&balances, &updated_balances, amp, issuance, Permill::zero()).unwrap();
+	assert!(received_shares >= shares);
+	assert!(received_shares - shares <= 10u128);
+}
+
+#[test]
+fn calculate_add_one_asset_should_fail_when_share_issuance_is_zero() {
+	let amp = 100_u128;
+
+	let balances = [10_000u128; MAX_BALANCES];
+
+	let result =
+		calculate_add_one_asset::<D_ITERATIONS, Y_ITERATIONS>(&balances, 2_000u128, 2, 0u128, amp, Permill::zero());
+
+	assert!(result.is_none());
+}
+
+#[test]
+fn calculate_add_one_asset_should_fail_when_asset_idx_is_incorrect() {
+	let amp = 100_u128;
+
+	let balances = [10_000u128; MAX_BALANCES];
+
+	let result = calculate_add_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+		&balances,
+		2_000u128,
+		MAX_BALANCES,
+		52_000u128,
+		amp,
+		Permill::zero(),
+	);
+
+	assert!(result.is_none());
+}
+
+#[test]
+fn calculate_shares_for_amount_should_return_shares_to_withdraw_at_least_given_amount() {
+	let amp = 100_u128;
+
+	let balances = [10_000 * ONE; MAX_BALANCES];
+
+	let asset_index: usize = 2;
+	let amount: Balance = 1_000 * ONE;
+	let issuance = 50_000 * ONE;
+
+	for fee in [Permill::zero(), Permill::from_percent(1)] {
+		let shares = calculate_shares_for_amount::<D_ITERATIONS, Y_ITERATIONS>(
+			&balances,
+			asset_index,
+			amount,
+			amp,
+			issuance,
+			fee,
+		)
+		.unwrap();
+
+		let (withdrawn, _) = calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+			&balances,
+			shares,
+			asset_index,
+			issuance,
+			amp,
+			fee,
+		)
+		.unwrap();
+
+		assert!(withdrawn >= amount);
+		assert!(withdrawn - amount <= amount / 1_000);
+	}
+}
+
+#[test]
+fn calculate_shares_for_amount_should_fail_when_amount_exceeds_reserve() {
+	let amp = 100_u128;
+
+	let balances = [10_000u128; MAX_BALANCES];
+
+	let result = calculate_shares_for_amount::<D_ITERATIONS, Y_ITERATIONS>(
+		&balances,
+		2,
+		10_001u128,
+		amp,
+		50_000u128,
+		Permill::zero(),
+	);
+
+	assert!(result.is_none());
+}
@@ -96,7 +96,7 @@ fn test_shares() {
 	let initial_reserves = &[0u128, 0u128];
 	let updated_reserves = &[1000 * ONE, 0u128];
 
-	let result = calculate_shares::<D_ITERATIONS>(initial_reserves, updated_reserves, amp, 0u128);
+	let result = calculate_shares::<D_ITERATIONS>(initial_reserves, updated_reserves, amp, 0u128, Permill::zero());
 
 	assert!(result.is_some());
 	assert_eq!(result.unwrap(), 1_000_000_000_000_000u128);
@@ -7,7 +7,7 @@
 
 use std::sync::Arc;
 
-use hydradx_runtime::{opaque::Block, AccountId, Balance, Index};
+use hydradx_runtime::{opaque::Block, AccountId, AssetId, Balance, BlockNumber, Index};
 pub use sc_rpc::SubscriptionTaskExecutor;
 pub use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
@@ -36,9 +36,13 @@ where
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: pallet_omnipool_rpc::OmnipoolRuntimeApi<Block, AssetId, Balance>,
+	C::Api: pallet_ema_oracle_rpc::EmaOracleRuntimeApi<Block, AssetId, Balance, BlockNumber>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 {
+	use pallet_ema_oracle_rpc::{EmaOracle, EmaOracleApiServer};
+	use pallet_omnipool_rpc::{Omnipool, OmnipoolApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -50,7 +54,9 @@ where
 	} = deps;
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Omnipool::new(client.clone()).into_rpc())?;
+	module.merge(EmaOracle::new(client).into_rpc())?;
 
 	Ok(module)
 }
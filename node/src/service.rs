@@ -32,7 +32,7 @@ use cumulus_relay_chain_interface::{RelayChainInterface, RelayChainResult};
 use cumulus_relay_chain_minimal_node::build_minimal_relay_chain_node;
 use jsonrpsee::RpcModule;
 use polkadot_service::CollatorPair;
-use primitives::{AccountId, Balance, Block, Index};
+use primitives::{AccountId, AssetId, Balance, Block, BlockNumber, Index};
 use sc_consensus::ImportQueue;
 use sc_executor::{NativeElseWasmExecutor, NativeExecutionDispatch, NativeVersion};
 use sc_network::NetworkService;
@@ -245,6 +245,8 @@ where
 		+ sp_block_builder::BlockBuilder<Block>
 		+ frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Index>
 		+ pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance>
+		+ pallet_omnipool_rpc::OmnipoolRuntimeApi<Block, AssetId, Balance>
+		+ pallet_ema_oracle_rpc::EmaOracleRuntimeApi<Block, AssetId, Balance, BlockNumber>
 		+ sp_api::Metadata<Block>
 		+ sp_offchain::OffchainWorkerApi<Block>
 		+ sp_session::SessionKeys<Block>
@@ -618,6 +618,16 @@ impl<T: Config> Pallet<T> {
 		Ok(T::WeightInfo::ensure_remove_liquidity_limit())
 	}
 
+	/// Remove all limits and volumes of an asset, e.g. when the asset is removed from a pool.
+	pub fn remove_asset_limits(asset_id: T::AssetId) {
+		<TradeVolumeLimitPerAsset<T>>::remove(asset_id);
+		<AllowedTradeVolumeLimitPerAsset<T>>::remove(asset_id);
+		<LiquidityAddLimitPerAsset<T>>::remove(asset_id);
+		<AllowedAddLiquidityAmountPerAsset<T>>::remove(asset_id);
+		<LiquidityRemoveLimitPerAsset<T>>::remove(asset_id);
+		<AllowedRemoveLiquidityAmountPerAsset<T>>::remove(asset_id);
+	}
+
 	pub(crate) fn is_origin_whitelisted_or_root(origin: OriginFor<T>) -> Result<bool, DispatchError> {
 		let who = ensure_signed_or_root(origin)?;
 		match who {
@@ -24,7 +24,7 @@ use frame_system::EnsureRoot;
 use hydra_dx_math::omnipool::types::BalanceUpdate;
 use orml_traits::{parameter_type_with_key, GetByKey};
 use sp_core::H256;
-use sp_runtime::traits::{ConstU128, ConstU32};
+use sp_runtime::traits::{ConstU128, ConstU32, ConstU64};
 use sp_runtime::DispatchResult;
 use sp_runtime::FixedU128;
 use sp_runtime::Permill;
@@ -201,6 +201,8 @@ parameter_types! {
 	pub MaxOutRatio: Balance = MAX_OUT_RATIO.with(|v| *v.borrow());
 	pub const TVLCap: Balance = Balance::MAX;
 	pub MinWithdrawFee: Permill = Permill::from_percent(0);
+	pub const ImbalanceBurnRatio: Permill = Permill::from_percent(10);
+	pub const MaxAddLiquidityAssets: u32 = 5;
 }
 
 impl pallet_omnipool::Config for Test {
@@ -227,6 +229,10 @@ impl pallet_omnipool::Config for Test {
 	type MinWithdrawalFee = MinWithdrawFee;
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
+	type ProtocolFeeCollector = ();
+	type FeeLedgerPeriod = ConstU64<10>;
+	type ImbalanceBurnRatio = ImbalanceBurnRatio;
+	type MaxAddLiquidityAssets = MaxAddLiquidityAssets;
 }
 
 pub struct CircuitBreakerHooks<T>(PhantomData<T>);
@@ -298,6 +304,12 @@ where
 		Ok(Weight::zero())
 	}
 
+	fn on_asset_removed(asset_id: AssetId) -> Result<Weight, Self::Error> {
+		pallet_circuit_breaker::Pallet::<T>::remove_asset_limits(asset_id.into());
+
+		Ok(Weight::zero())
+	}
+
 	fn on_liquidity_changed_weight() -> Weight {
 		todo!()
 	}
@@ -305,6 +317,10 @@ where
 	fn on_trade_weight() -> Weight {
 		todo!()
 	}
+
+	fn on_asset_removed_weight() -> Weight {
+		todo!()
+	}
 }
 
 use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
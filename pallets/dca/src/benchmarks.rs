@@ -437,6 +437,81 @@ benchmarks! {
 		assert!(<Schedules<T>>::get::<ScheduleId>(schedule_id).is_none());
 	}
 
+	pause {
+		initialize_omnipool::<T>()?;
+		let caller: T::AccountId = create_account_with_native_balance::<T>()?;
+		<T as pallet_omnipool::Config>::Currency::update_balance(HDX.into(), &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake::<T>(caller.clone(), HDX.into(), DAI.into(), amount_sell);
+		let schedule_id : ScheduleId = 0;
+
+		set_period::<T>(99);
+		let execution_block = 100u32;
+		assert_ok!(crate::Pallet::<T>::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1, Option::Some(execution_block.into())));
+
+	}: _(RawOrigin::Signed(caller), schedule_id, Option::Some(execution_block.into()))
+	verify {
+		assert!(<PausedSchedules<T>>::get::<ScheduleId>(schedule_id).is_some());
+		assert_eq!(<ScheduleIdsPerBlock<T>>::get::<BlockNumberFor<T>>(execution_block.into()).len(), 0);
+	}
+
+	resume {
+		initialize_omnipool::<T>()?;
+		let caller: T::AccountId = create_account_with_native_balance::<T>()?;
+		<T as pallet_omnipool::Config>::Currency::update_balance(HDX.into(), &caller, 100_000_000_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake::<T>(caller.clone(), HDX.into(), DAI.into(), amount_sell);
+		let execution_block = 100u32;
+
+		set_period::<T>(99);
+		assert_ok!(crate::Pallet::<T>::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1.clone(), Option::Some(execution_block.into())));
+		let schedule_id : ScheduleId = 0;
+		assert_ok!(crate::Pallet::<T>::pause(RawOrigin::Signed(caller.clone()).into(), schedule_id, Option::Some(execution_block.into())));
+
+		//We fill blocks with schedules leaving only one place
+		let number_of_all_schedules = T::MaxSchedulePerBlock::get() + T::MaxSchedulePerBlock::get() * RETRY_TO_SEARCH_FOR_FREE_BLOCK - 1;
+		for i in 0..number_of_all_schedules {
+			assert_ok!(crate::Pallet::<T>::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1.clone(), Option::Some(execution_block.into())));
+		}
+
+		assert_eq!((T::MaxSchedulePerBlock::get() - 1) as usize, <ScheduleIdsPerBlock<T>>::get::<BlockNumberFor<T>>((execution_block + DELAY_AFTER_LAST_RADIUS).into()).len());
+	}: _(RawOrigin::Signed(caller), schedule_id, Option::Some(execution_block.into()))
+	verify {
+		assert!(<PausedSchedules<T>>::get::<ScheduleId>(schedule_id).is_none());
+		assert_eq!((T::MaxSchedulePerBlock::get()) as usize, <ScheduleIdsPerBlock<T>>::get::<BlockNumberFor<T>>((execution_block + DELAY_AFTER_LAST_RADIUS).into()).len());
+	}
+
+	update_schedule {
+		initialize_omnipool::<T>()?;
+		let caller: T::AccountId = create_account_with_native_balance::<T>()?;
+		<T as pallet_omnipool::Config>::Currency::update_balance(HDX.into(), &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake::<T>(caller.clone(), HDX.into(), DAI.into(), amount_sell);
+		let schedule_id : ScheduleId = 0;
+		let total_amount = schedule1.total_amount;
+
+		set_period::<T>(99);
+		let execution_block = 100u32;
+		assert_ok!(crate::Pallet::<T>::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1, Option::Some(execution_block.into())));
+
+		let update = ScheduleUpdate {
+			period: Some(10u32.into()),
+			total_amount: Some(2 * total_amount),
+			slippage: Some(Some(Permill::from_percent(5))),
+			route: Some(create_bounded_vec::<T>(vec![Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX.into(),
+				asset_out: DAI.into()
+			}])),
+		};
+	}: _(RawOrigin::Signed(caller), schedule_id, update)
+	verify {
+		assert_eq!(<Schedules<T>>::get::<ScheduleId>(schedule_id).unwrap().total_amount, 2 * total_amount);
+	}
+
 }
 
 #[cfg(test)]
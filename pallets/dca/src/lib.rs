@@ -42,8 +42,8 @@
 //!
 //! A trade can fail due to two main reasons:
 //!
-//! 1. Price Stability Error: If the price difference between the short oracle price and the current price
-//! exceeds the specified threshold. The user can customize this threshold,
+//! 1. Price Stability Error: If the price difference between the median of the last block, short and ten minutes
+//! oracle prices and the current price exceeds the specified threshold. The user can customize this threshold,
 //! or the default value from the pallet configuration will be used.
 //! 2. Slippage Error: If the minimum amount out (sell) or maximum amount in (buy) slippage limits are not reached.
 //! These limits are calculated based on the last block's oracle price and the user-specified slippage.
@@ -55,6 +55,31 @@
 //!
 //! If a trade fails due to other types of errors, the order is terminated without any retry logic.
 //!
+//! ### Limit orders
+//!
+//! A limit order (`Order::Limit`) is a sell order with a price trigger. It is evaluated every `period` blocks
+//! and executed only when the spot price of the bought asset denominated in the sold asset meets the trigger:
+//!
+//! * `PriceTrigger::Limit` - the price is at or below the trigger price,
+//! * `PriceTrigger::Stop` - the price is at or above the trigger price.
+//!
+//! The execution fee is charged on every evaluation, so a waiting limit order pays for the block space it takes.
+//! While the trigger is not met, the order is planned again after `period` blocks.
+//! Once triggered, the order is executed the same way as a sell order, including the price stability
+//! and slippage checks and the retry logic. After a successful trade, the order is planned after `period` blocks
+//! as long as there is remaining budget.
+//!
+//! The `expiry` block can be at most `MaxLimitOrderDuration` blocks in the future.
+//! A limit order which is not completed by its `expiry` block is terminated and the remaining budget is unreserved.
+//!
+//! ## Pausing, resuming and updating a Schedule
+//!
+//! The owner can pause a schedule, which removes it from its planned execution block while keeping
+//! the reserved budget and the retry counter. A paused schedule is planned again by resuming it.
+//!
+//! The owner can also update the period, the slippage, the total amount (budget) and the route of a schedule.
+//! When the total amount changes, the difference is reserved or unreserved and the remaining budget is adjusted accordingly.
+//!
 //! ## Terminating a Schedule
 //!
 //! Both users and technical origin can terminate a DCA schedule. However, users can only terminate schedules that they own.
@@ -73,6 +98,7 @@ use frame_support::{
 	weights::WeightToFee as FrameSupportWeight,
 };
 use frame_system::{ensure_signed, pallet_prelude::OriginFor, Origin};
+use hydra_dx_math::ema::{median_price, EmaPrice};
 use hydradx_traits::pools::SpotPriceProvider;
 use hydradx_traits::{OraclePeriod, PriceOracle};
 use orml_traits::arithmetic::CheckedAdd;
@@ -109,6 +135,9 @@ use crate::types::*;
 type BlockNumberFor<T> = <T as frame_system::Config>::BlockNumber;
 
 pub const SHORT_ORACLE_BLOCK_PERIOD: u32 = 10;
+/// Oracle periods whose median price is compared with the spot price to check the price stability.
+pub const PRICE_STABILITY_ORACLE_PERIODS: [OraclePeriod; 3] =
+	[OraclePeriod::LastBlock, OraclePeriod::Short, OraclePeriod::TenMinutes];
 pub const MAX_NUMBER_OF_RETRY_FOR_RESCHEDULING: u32 = 10;
 pub const FEE_MULTIPLIER_FOR_MIN_TRADE_LIMIT: Balance = 20;
 
@@ -119,7 +148,6 @@ pub mod pallet {
 	use frame_support::weights::WeightToFee;
 
 	use frame_system::pallet_prelude::OriginFor;
-	use hydra_dx_math::ema::EmaPrice;
 	use hydradx_traits::pools::SpotPriceProvider;
 	use hydradx_traits::PriceOracle;
 	use orml_traits::NamedMultiReservableCurrency;
@@ -163,7 +191,7 @@ pub mod pallet {
 					&schedule,
 					&mut randomness_generator,
 				) {
-					if e != Error::<T>::PriceUnstable.into() {
+					if e != Error::<T>::PriceUnstable.into() && e != Error::<T>::PriceTriggerNotMet.into() {
 						Self::terminate_schedule(schedule_id, &schedule, e);
 					};
 					continue;
@@ -249,6 +277,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinimumTradingLimit: Get<Balance>;
 
+		/// Max number of blocks between scheduling a limit order and its expiry
+		#[pallet::constant]
+		type MaxLimitOrderDuration: Get<Self::BlockNumber>;
+
 		/// Native Asset Id
 		#[pallet::constant]
 		type NativeAssetId: Get<Self::AssetId>;
@@ -306,6 +338,12 @@ pub mod pallet {
 		},
 		///The DCA is completed and completely removed from the chain
 		Completed { id: ScheduleId, who: T::AccountId },
+		///The DCA is paused and removed from its planned execution block
+		Paused { id: ScheduleId, who: T::AccountId },
+		///The paused DCA is resumed
+		Resumed { id: ScheduleId, who: T::AccountId },
+		///The DCA is updated
+		Updated { id: ScheduleId, who: T::AccountId },
 		///Randomness generation failed possibly coming from missing data about relay chain
 		RandomnessGenerationFailed {
 			block: BlockNumberFor<T>,
@@ -347,6 +385,18 @@ pub mod pallet {
 		NoParentHashFound,
 		///Error that should not really happen only in case of invalid state of the schedule storage entries
 		InvalidState,
+		///The limit order has not been executed before its expiry block
+		OrderExpired,
+		///The spot price does not meet the price trigger of the limit order, leading to waiting for the next block
+		PriceTriggerNotMet,
+		///The schedule is already paused
+		AlreadyPaused,
+		///The schedule is not paused
+		NotPaused,
+		///The route does not start with the sold asset or does not end with the bought asset of the order
+		InvalidRoute,
+		///The expiry of the limit order is further in the future than allowed
+		ExpiryTooFar,
 	}
 
 	/// Id sequencer for schedules
@@ -382,6 +432,11 @@ pub mod pallet {
 	pub type ScheduleIdsPerBlock<T: Config> =
 		StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, BoundedVec<ScheduleId, T::MaxSchedulePerBlock>, ValueQuery>;
 
+	/// Keep tracking of the paused DCA schedules
+	#[pallet::storage]
+	#[pallet::getter(fn paused_schedules)]
+	pub type PausedSchedules<T: Config> = StorageMap<_, Blake2_128Concat, ScheduleId, (), OptionQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T>
 	where
@@ -405,6 +460,10 @@ pub mod pallet {
 		/// the schedule will be terminated permanently.
 		/// In the case of a successful trade, the retry counter is reset.
 		///
+		/// Limit orders are checked every `period` blocks, paying the execution fee for every check,
+		/// and executed only when their price trigger is met.
+		/// Their `expiry` block has to be in the future, at most `MaxLimitOrderDuration` blocks from now.
+		///
 		/// Parameters:
 		/// - `origin`: schedule owner
 		/// - `schedule`: schedule details
@@ -425,40 +484,16 @@ pub mod pallet {
 
 			ensure!(schedule.order.get_route_length() > 0, Error::<T>::RouteNotSpecified);
 
-			let min_budget = Self::convert_native_amount_to_currency(
-				schedule.order.get_asset_in(),
-				T::MinBudgetInNativeCurrency::get(),
-			)?;
-			ensure!(
-				schedule.total_amount >= min_budget,
-				Error::<T>::TotalAmountIsSmallerThanMinBudget
-			);
-
-			let transaction_fee = Self::get_transaction_fee(&schedule.order)?;
-
-			let amount_in = match schedule.order {
-				Order::Sell { amount_in, .. } => amount_in,
-				Order::Buy {
-					amount_out, ref route, ..
-				} => Self::get_amount_in_for_buy(&amount_out, route)?,
-			};
-			let min_trade_amount_in_from_fee = transaction_fee.saturating_mul(FEE_MULTIPLIER_FOR_MIN_TRADE_LIMIT);
-			ensure!(
-				amount_in >= min_trade_amount_in_from_fee,
-				Error::<T>::MinTradeAmountNotReached
-			);
-			ensure!(
-				amount_in >= T::MinimumTradingLimit::get(),
-				Error::<T>::MinTradeAmountNotReached
-			);
+			if let Order::Limit { expiry, .. } = schedule.order {
+				let current_block_number = frame_system::Pallet::<T>::current_block_number();
+				ensure!(expiry > current_block_number, Error::<T>::BlockNumberIsNotInFuture);
+				ensure!(
+					expiry.saturating_sub(current_block_number) <= T::MaxLimitOrderDuration::get(),
+					Error::<T>::ExpiryTooFar
+				);
+			}
 
-			let amount_in_with_transaction_fee = amount_in
-				.checked_add(transaction_fee)
-				.ok_or(ArithmeticError::Overflow)?;
-			ensure!(
-				amount_in_with_transaction_fee <= schedule.total_amount,
-				Error::<T>::BudgetTooLow
-			);
+			Self::validate_budget(&schedule)?;
 
 			let next_schedule_id =
 				ScheduleIdSequencer::<T>::try_mutate(|current_id| -> Result<ScheduleId, DispatchError> {
@@ -528,26 +563,11 @@ pub mod pallet {
 
 			Self::try_unreserve_all(schedule_id, &schedule);
 
-			let next_execution_block = Self::get_next_execution_block(next_execution_block)?;
-
-			//Remove schedule id from next execution block
-			ScheduleIdsPerBlock::<T>::try_mutate_exists(
-				next_execution_block,
-				|maybe_schedule_ids| -> DispatchResult {
-					let schedule_ids = maybe_schedule_ids.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
-
-					let index = schedule_ids
-						.binary_search(&schedule_id)
-						.map_err(|_| Error::<T>::ScheduleNotFound)?;
-
-					schedule_ids.remove(index);
-
-					if schedule_ids.is_empty() {
-						*maybe_schedule_ids = None;
-					}
-					Ok(())
-				},
-			)?;
+			//Paused schedules are not planned in any block
+			if !PausedSchedules::<T>::contains_key(schedule_id) {
+				let next_execution_block = Self::get_next_execution_block(next_execution_block)?;
+				Self::remove_schedule_id_from_block(next_execution_block, schedule_id)?;
+			}
 
 			Self::remove_schedule_from_storages(&schedule.owner, schedule_id);
 
@@ -559,6 +579,159 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Pauses a DCA schedule by removing it from its planned execution block.
+		///
+		/// The reserved budget and the retry counter of the schedule are kept.
+		/// The schedule can be planned again by `resume`.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `next_execution_block`: block number where the schedule is planned.
+		///
+		/// Emits `Paused` event when successful.
+		///
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::pause())]
+		#[transactional]
+		pub fn pause(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			next_execution_block: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let schedule = Schedules::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+			ensure!(who == schedule.owner, Error::<T>::Forbidden);
+			ensure!(
+				!PausedSchedules::<T>::contains_key(schedule_id),
+				Error::<T>::AlreadyPaused
+			);
+
+			let next_execution_block = Self::get_next_execution_block(next_execution_block)?;
+			Self::remove_schedule_id_from_block(next_execution_block, schedule_id)?;
+
+			PausedSchedules::<T>::insert(schedule_id, ());
+
+			Self::deposit_event(Event::Paused { id: schedule_id, who });
+
+			Ok(())
+		}
+
+		/// Resumes a paused DCA schedule and plans the next execution for the specified block.
+		///
+		/// If the block is not specified, the execution is planned for the next block.
+		/// If the given block is full, the execution will be planned in the subsequent block.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `next_execution_block`: block number where the schedule should be executed
+		///
+		/// Emits `Resumed` and `ExecutionPlanned` event when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::resume())]
+		#[transactional]
+		pub fn resume(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			next_execution_block: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let schedule = Schedules::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+			ensure!(who == schedule.owner, Error::<T>::Forbidden);
+			ensure!(PausedSchedules::<T>::take(schedule_id).is_some(), Error::<T>::NotPaused);
+
+			let next_execution_block = Self::get_next_execution_block(next_execution_block)?;
+
+			let mut randomness_generator =
+				Self::get_randomness_generator(frame_system::Pallet::<T>::current_block_number(), Some(schedule_id));
+			Self::plan_schedule_for_block(&who, next_execution_block, schedule_id, &mut randomness_generator)?;
+
+			Self::deposit_event(Event::Resumed { id: schedule_id, who });
+
+			Ok(())
+		}
+
+		/// Updates the period, the slippage, the total amount (budget) or the route of a DCA schedule.
+		///
+		/// When the total amount is increased, the difference is reserved and added to the remaining budget.
+		/// When it is decreased, the difference is unreserved and deducted from the remaining budget.
+		///
+		/// The updated schedule has to pass the same budget checks as a new schedule.
+		/// When the total amount is changed, the remaining budget has to cover at least one more trade
+		/// including the transaction fee.
+		///
+		/// The planned execution of the schedule is not changed.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `update`: changes of the schedule
+		///
+		/// Emits `Updated` event when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_schedule())]
+		#[transactional]
+		pub fn update_schedule(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			update: ScheduleUpdate<T::AssetId, BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Schedules::<T>::try_mutate(schedule_id, |maybe_schedule| -> DispatchResult {
+				let schedule = maybe_schedule.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+				ensure!(who == schedule.owner, Error::<T>::Forbidden);
+
+				if let Some(period) = update.period {
+					schedule.period = period;
+				}
+
+				if let Some(slippage) = update.slippage {
+					schedule.slippage = slippage;
+				}
+
+				if let Some(route) = update.route {
+					ensure!(
+						route.first().map(|trade| trade.asset_in) == Some(schedule.order.get_asset_in()),
+						Error::<T>::InvalidRoute
+					);
+					ensure!(
+						route.last().map(|trade| trade.asset_out) == Some(schedule.order.get_asset_out()),
+						Error::<T>::InvalidRoute
+					);
+					schedule.order.set_route(route);
+				}
+
+				if let Some(total_amount) = update.total_amount {
+					Self::update_budget(schedule_id, schedule, total_amount)?;
+					schedule.total_amount = total_amount;
+				}
+
+				let amount_in_with_transaction_fee = Self::validate_budget(schedule)?;
+
+				// Updated budget has to leave enough for at least one more trade
+				if update.total_amount.is_some() {
+					let remaining_amount =
+						RemainingAmounts::<T>::get(schedule_id).defensive_ok_or(Error::<T>::InvalidState)?;
+					ensure!(
+						remaining_amount >= amount_in_with_transaction_fee,
+						Error::<T>::BudgetTooLow
+					);
+				}
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::Updated { id: schedule_id, who });
+
+			Ok(())
+		}
 	}
 }
 
@@ -605,8 +778,29 @@ where
 		schedule: &Schedule<T::AccountId, T::AssetId, T::BlockNumber>,
 		randomness_generator: &mut StdRng,
 	) -> DispatchResult {
+		if let Order::Limit { expiry, .. } = &schedule.order {
+			ensure!(current_blocknumber <= *expiry, Error::<T>::OrderExpired);
+		}
+
 		Self::take_transaction_fee_from_user(schedule_id, schedule, weight_for_dca_execution)?;
 
+		if let Order::Limit { trigger, .. } = &schedule.order {
+			if !Self::is_price_trigger_met(schedule, trigger) {
+				let next_execution_block = current_blocknumber
+					.checked_add(&schedule.period.max(T::BlockNumber::one()))
+					.ok_or(ArithmeticError::Overflow)?;
+
+				Self::plan_schedule_for_block(
+					&schedule.owner,
+					next_execution_block,
+					schedule_id,
+					randomness_generator,
+				)?;
+
+				return Err(Error::<T>::PriceTriggerNotMet.into());
+			}
+		}
+
 		if Self::is_price_unstable(schedule) {
 			Self::deposit_event(Event::TradeFailed {
 				id: schedule_id,
@@ -635,6 +829,14 @@ where
 				amount_in,
 				min_amount_out,
 				route,
+			}
+			| Order::Limit {
+				asset_in,
+				asset_out,
+				amount_in,
+				min_amount_out,
+				route,
+				..
 			} => {
 				let remaining_amount =
 					RemainingAmounts::<T>::get(schedule_id).defensive_ok_or(Error::<T>::InvalidState)?;
@@ -806,7 +1008,7 @@ where
 			return true;
 		};
 
-		let Ok(price_from_short_oracle) = Self::get_price_from_short_oracle(asset_a, asset_b) else {
+		let Ok(oracle_price) = Self::get_median_oracle_price(asset_a, asset_b) else {
    			return true;
 		};
 
@@ -817,7 +1019,7 @@ where
 		let max_allowed = FixedU128::from(max_allowed_diff);
 
 		let Some(price_sum) = current_price
-			.checked_add(&price_from_short_oracle) else {
+			.checked_add(&oracle_price) else {
 			return true;
 		};
 
@@ -830,10 +1032,10 @@ where
 				return true;
 		};
 
-		let diff = if current_price > price_from_short_oracle {
-			current_price.saturating_sub(price_from_short_oracle)
+		let diff = if current_price > oracle_price {
+			current_price.saturating_sub(oracle_price)
 		} else {
-			price_from_short_oracle.saturating_sub(current_price)
+			oracle_price.saturating_sub(current_price)
 		};
 
 		let Some(diff) = diff.checked_mul(&FixedU128::from(2)) else {
@@ -843,6 +1045,16 @@ where
 		diff > max_allowed_difference
 	}
 
+	fn is_price_trigger_met(
+		schedule: &Schedule<T::AccountId, T::AssetId, T::BlockNumber>,
+		trigger: &PriceTrigger,
+	) -> bool {
+		let asset_in = schedule.order.get_asset_in();
+		let asset_out = schedule.order.get_asset_out();
+
+		T::SpotPriceProvider::spot_price(asset_in, asset_out).map_or(false, |price| trigger.is_met(price))
+	}
+
 	fn get_amount_in_for_buy(
 		amount_out: &Balance,
 		route: &BoundedVec<Trade<T::AssetId>, ConstU32<5>>,
@@ -855,7 +1067,7 @@ where
 		Ok(first_trade.amount_in.into())
 	}
 
-	fn get_transaction_fee(order: &Order<T::AssetId>) -> Result<Balance, DispatchError> {
+	fn get_transaction_fee(order: &Order<T::AssetId, T::BlockNumber>) -> Result<Balance, DispatchError> {
 		Self::convert_weight_to_fee(Self::get_trade_weight(order), order.get_asset_in())
 	}
 
@@ -1033,9 +1245,9 @@ where
 		Ok(fee_amount_in_sold_asset)
 	}
 
-	fn get_trade_weight(order: &Order<T::AssetId>) -> Weight {
+	fn get_trade_weight(order: &Order<T::AssetId, T::BlockNumber>) -> Weight {
 		match order {
-			Order::Sell { .. } => <T as Config>::WeightInfo::on_initialize_with_sell_trade(),
+			Order::Sell { .. } | Order::Limit { .. } => <T as Config>::WeightInfo::on_initialize_with_sell_trade(),
 			Order::Buy { .. } => <T as Config>::WeightInfo::on_initialize_with_buy_trade(),
 		}
 	}
@@ -1065,9 +1277,13 @@ where
 		Ok(price_from_rational)
 	}
 
-	fn get_price_from_short_oracle(asset_a: T::AssetId, asset_b: T::AssetId) -> Result<FixedU128, DispatchError> {
-		let price = T::OraclePriceProvider::price(asset_a, asset_b, OraclePeriod::Short)
+	fn get_median_oracle_price(asset_a: T::AssetId, asset_b: T::AssetId) -> Result<FixedU128, DispatchError> {
+		let mut prices = PRICE_STABILITY_ORACLE_PERIODS
+			.iter()
+			.map(|period| T::OraclePriceProvider::price(asset_a, asset_b, *period))
+			.collect::<Option<Vec<EmaPrice>>>()
 			.ok_or(Error::<T>::CalculatingPriceError)?;
+		let price = median_price(&mut prices).ok_or(Error::<T>::CalculatingPriceError)?;
 
 		let price_from_rational =
 			FixedU128::checked_from_rational(price.n, price.d).ok_or(ArithmeticError::Overflow)?;
@@ -1075,11 +1291,120 @@ where
 		Ok(price_from_rational)
 	}
 
+	fn remove_schedule_id_from_block(blocknumber: T::BlockNumber, schedule_id: ScheduleId) -> DispatchResult {
+		ScheduleIdsPerBlock::<T>::try_mutate_exists(blocknumber, |maybe_schedule_ids| -> DispatchResult {
+			let schedule_ids = maybe_schedule_ids.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+
+			let index = schedule_ids
+				.binary_search(&schedule_id)
+				.map_err(|_| Error::<T>::ScheduleNotFound)?;
+
+			schedule_ids.remove(index);
+
+			if schedule_ids.is_empty() {
+				*maybe_schedule_ids = None;
+			}
+			Ok(())
+		})
+	}
+
+	/// Ensures the budget of the schedule is at least the minimum budget and covers one trade
+	/// including the transaction fee, and the traded amount is not below the minimum trade limits.
+	///
+	/// Returns the amount in of one trade including the transaction fee.
+	fn validate_budget(
+		schedule: &Schedule<T::AccountId, T::AssetId, T::BlockNumber>,
+	) -> Result<Balance, DispatchError> {
+		let min_budget = Self::convert_native_amount_to_currency(
+			schedule.order.get_asset_in(),
+			T::MinBudgetInNativeCurrency::get(),
+		)?;
+		ensure!(
+			schedule.total_amount >= min_budget,
+			Error::<T>::TotalAmountIsSmallerThanMinBudget
+		);
+
+		let transaction_fee = Self::get_transaction_fee(&schedule.order)?;
+
+		let amount_in = match schedule.order {
+			Order::Sell { amount_in, .. } | Order::Limit { amount_in, .. } => amount_in,
+			Order::Buy {
+				amount_out, ref route, ..
+			} => Self::get_amount_in_for_buy(&amount_out, route)?,
+		};
+		let min_trade_amount_in_from_fee = transaction_fee.saturating_mul(FEE_MULTIPLIER_FOR_MIN_TRADE_LIMIT);
+		ensure!(
+			amount_in >= min_trade_amount_in_from_fee,
+			Error::<T>::MinTradeAmountNotReached
+		);
+		ensure!(
+			amount_in >= T::MinimumTradingLimit::get(),
+			Error::<T>::MinTradeAmountNotReached
+		);
+
+		let amount_in_with_transaction_fee = amount_in
+			.checked_add(transaction_fee)
+			.ok_or(ArithmeticError::Overflow)?;
+		ensure!(
+			amount_in_with_transaction_fee <= schedule.total_amount,
+			Error::<T>::BudgetTooLow
+		);
+
+		Ok(amount_in_with_transaction_fee)
+	}
+
+	fn update_budget(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, T::BlockNumber>,
+		new_total_amount: Balance,
+	) -> DispatchResult {
+		let sold_currency = schedule.order.get_asset_in();
+
+		if new_total_amount > schedule.total_amount {
+			let extra_amount = new_total_amount.saturating_sub(schedule.total_amount);
+
+			RemainingAmounts::<T>::try_mutate(schedule_id, |maybe_remaining_amount| -> DispatchResult {
+				let remaining_amount = maybe_remaining_amount
+					.as_mut()
+					.defensive_ok_or(Error::<T>::InvalidState)?;
+				*remaining_amount = remaining_amount
+					.checked_add(extra_amount)
+					.ok_or(ArithmeticError::Overflow)?;
+				Ok(())
+			})?;
+
+			T::Currencies::reserve_named(&T::NamedReserveId::get(), sold_currency, &schedule.owner, extra_amount)?;
+		} else {
+			let released_amount = schedule.total_amount.saturating_sub(new_total_amount);
+
+			RemainingAmounts::<T>::try_mutate(schedule_id, |maybe_remaining_amount| -> DispatchResult {
+				let remaining_amount = maybe_remaining_amount
+					.as_mut()
+					.defensive_ok_or(Error::<T>::InvalidState)?;
+				*remaining_amount = remaining_amount
+					.checked_sub(released_amount)
+					.ok_or(Error::<T>::BudgetTooLow)?;
+				Ok(())
+			})?;
+
+			let remaining_amount_if_insufficient_balance = T::Currencies::unreserve_named(
+				&T::NamedReserveId::get(),
+				sold_currency,
+				&schedule.owner,
+				released_amount,
+			);
+			ensure!(remaining_amount_if_insufficient_balance == 0, Error::<T>::InvalidState);
+		}
+
+		Ok(())
+	}
+
 	fn remove_schedule_from_storages(owner: &T::AccountId, schedule_id: ScheduleId) {
 		Schedules::<T>::remove(schedule_id);
 		ScheduleOwnership::<T>::remove(owner, schedule_id);
 		RemainingAmounts::<T>::remove(schedule_id);
 		RetriesOnError::<T>::remove(schedule_id);
+		PausedSchedules::<T>::remove(schedule_id);
 	}
 }
 
@@ -0,0 +1,318 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::MaxLimitOrderDuration;
+use crate::tests::on_initialize::{proceed_to_blocknumber, set_to_blocknumber};
+use crate::tests::*;
+use crate::{
+	assert_balance, assert_number_of_executed_sell_trades, assert_scheduled_ids,
+	assert_that_schedule_has_been_removed_from_storages, Error, Event as DcaEvent, Order, PriceTrigger,
+};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::PoolType;
+use orml_traits::MultiReservableCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+fn limit_order(trigger: PriceTrigger, expiry: BlockNumber) -> Order<AssetId, BlockNumber> {
+	Order::Limit {
+		asset_in: HDX,
+		asset_out: BTC,
+		amount_in: ONE,
+		min_amount_out: Balance::MIN,
+		trigger,
+		expiry,
+		route: create_bounded_vec(vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: HDX,
+			asset_out: BTC,
+		}]),
+	}
+}
+
+#[test]
+fn schedule_should_fail_when_limit_order_expiry_is_not_in_future() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_order(limit_order(PriceTrigger::Limit(FixedU128::from_rational(85, 100)), 500))
+				.build();
+
+			//Act and assert
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None),
+				Error::<Test>::BlockNumberIsNotInFuture
+			);
+		});
+}
+
+#[test]
+fn limit_order_should_not_be_executed_when_price_trigger_is_not_met() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 5 * ONE;
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_order(limit_order(
+					PriceTrigger::Limit(FixedU128::from_rational(70, 100)),
+					1000,
+				))
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(0);
+			assert_balance!(TreasuryAccount::get(), HDX, SELL_DCA_FEE_IN_NATIVE);
+			assert_eq!(
+				total_amount - SELL_DCA_FEE_IN_NATIVE,
+				Currencies::reserved_balance(HDX, &ALICE)
+			);
+			assert_scheduled_ids!(601, vec![0]);
+		});
+}
+
+#[test]
+fn limit_order_should_be_executed_when_spot_price_drops_to_limit_price() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 5 * ONE;
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(limit_order(
+					PriceTrigger::Limit(FixedU128::from_rational(85, 100)),
+					1000,
+				))
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			set_spot_price(FixedU128::from_rational(90, 100));
+			set_to_blocknumber(501);
+			assert_number_of_executed_sell_trades!(0);
+
+			//Act
+			set_spot_price(FixedU128::from_rational(80, 100));
+			set_to_blocknumber(601);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(1);
+			assert_balance!(TreasuryAccount::get(), HDX, 2 * SELL_DCA_FEE_IN_NATIVE);
+			assert_eq!(
+				total_amount - ONE - 2 * SELL_DCA_FEE_IN_NATIVE,
+				Currencies::reserved_balance(HDX, &ALICE)
+			);
+
+			let schedule_id = 0;
+			expect_events(vec![
+				DcaEvent::TradeExecuted {
+					id: schedule_id,
+					who: ALICE,
+					amount_in: ONE,
+					amount_out: *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+				}
+				.into(),
+				DcaEvent::ExecutionPlanned {
+					id: schedule_id,
+					who: ALICE,
+					block: 701,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn stop_order_should_be_executed_when_spot_price_rises_to_stop_price() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(5 * ONE)
+				.with_order(limit_order(PriceTrigger::Stop(FixedU128::from_rational(85, 100)), 1000))
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			set_to_blocknumber(501);
+			assert_number_of_executed_sell_trades!(0);
+
+			//Act
+			set_spot_price(FixedU128::from_rational(90, 100));
+			set_to_blocknumber(601);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(1);
+			assert_balance!(TreasuryAccount::get(), HDX, 2 * SELL_DCA_FEE_IN_NATIVE);
+		});
+}
+
+#[test]
+fn limit_order_should_be_terminated_when_expired() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(5 * ONE)
+				.with_order(limit_order(PriceTrigger::Limit(FixedU128::from_rational(70, 100)), 505))
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			proceed_to_blocknumber(501, 601);
+
+			//Assert
+			let schedule_id = 0;
+			assert_number_of_executed_sell_trades!(0);
+			assert_eq!(0, Currencies::reserved_balance(HDX, &ALICE));
+			assert_that_schedule_has_been_removed_from_storages!(ALICE, schedule_id);
+
+			expect_events(vec![DcaEvent::Terminated {
+				id: schedule_id,
+				who: ALICE,
+				error: Error::<Test>::OrderExpired.into(),
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn schedule_should_fail_when_limit_order_expiry_is_too_far_in_future() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_order(limit_order(
+					PriceTrigger::Limit(FixedU128::from_rational(85, 100)),
+					500 + MaxLimitOrderDuration::get() + 1,
+				))
+				.build();
+
+			//Act and assert
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule.clone(), Option::None),
+				Error::<Test>::ExpiryTooFar
+			);
+
+			let schedule = ScheduleBuilder::new()
+				.with_order(limit_order(
+					PriceTrigger::Limit(FixedU128::from_rational(85, 100)),
+					500 + MaxLimitOrderDuration::get(),
+				))
+				.build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+		});
+}
+
+#[test]
+fn limit_order_should_be_checked_every_period() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 5 * ONE;
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(10)
+				.with_order(limit_order(
+					PriceTrigger::Limit(FixedU128::from_rational(70, 100)),
+					1000,
+				))
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			proceed_to_blocknumber(501, 530);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(0);
+			assert_balance!(TreasuryAccount::get(), HDX, 3 * SELL_DCA_FEE_IN_NATIVE);
+			assert_eq!(
+				total_amount - 3 * SELL_DCA_FEE_IN_NATIVE,
+				Currencies::reserved_balance(HDX, &ALICE)
+			);
+			assert_scheduled_ids!(531, vec![0]);
+		});
+}
+
+#[test]
+fn limit_order_should_be_terminated_when_budget_is_spent_on_checks() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = ONE + SELL_DCA_FEE_IN_NATIVE;
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(1)
+				.with_order(limit_order(
+					PriceTrigger::Limit(FixedU128::from_rational(70, 100)),
+					1000,
+				))
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			proceed_to_blocknumber(501, 1000);
+
+			//Assert
+			let schedule_id = 0;
+			assert_number_of_executed_sell_trades!(0);
+			assert_eq!(0, Currencies::reserved_balance(HDX, &ALICE));
+			assert_that_schedule_has_been_removed_from_storages!(ALICE, schedule_id);
+		});
+}
@@ -18,7 +18,7 @@
 use crate as dca;
 use crate::{Config, Error, RandomnessProvider, RelayChainBlockHashProvider};
 use cumulus_primitives_core::relay_chain::Hash;
-use frame_support::traits::{Everything, GenesisBuild, Nothing};
+use frame_support::traits::{ConstU64, Everything, GenesisBuild, Nothing};
 use frame_support::weights::constants::ExtrinsicBaseWeight;
 use frame_support::weights::WeightToFeeCoefficient;
 use frame_support::weights::{IdentityFee, Weight};
@@ -118,6 +118,8 @@ thread_local! {
 	pub static WITHDRAWAL_ADJUSTMENT: RefCell<(u32,u32, bool)> = RefCell::new((0u32,0u32, false));
 	pub static CALCULATED_AMOUNT_OUT_FOR_SELL: RefCell<Balance> = RefCell::new(*AMOUNT_OUT_FOR_OMNIPOOL_SELL);
 	pub static USE_PROD_RANDOMNESS: RefCell<bool> = RefCell::new(false);
+	pub static SPOT_PRICE: RefCell<FixedU128> = RefCell::new(FixedU128::from_rational(80, 100));
+	pub static ORACLE_PRICES: RefCell<Vec<(OraclePeriod, Ratio)>> = RefCell::new(vec![]);
 	pub static PARENT_HASH: RefCell<Option<Hash>> = RefCell::new(Some([
 			14, 87, 81, 192, 38, 229, 67, 178, 232, 171, 46, 176, 96, 153, 218, 161, 209, 229, 223, 71, 119, 143, 119,
 			135, 250, 171, 69, 205, 241, 47, 227, 168,
@@ -154,9 +156,12 @@ parameter_types! {
 impl pallet_ema_oracle::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
 	type BlockNumberProvider = MockBlockNumberProvider;
 	type SupportedPeriods = SupportedPeriods;
 	type MaxUniqueEntries = ConstU32<20>;
+	type MaxUniqueExternalEntries = ConstU32<20>;
+	type LocalSources = Nothing;
 }
 
 impl BlockNumberProvider for MockBlockNumberProvider {
@@ -236,6 +241,8 @@ parameter_types! {
 	pub MinTradeAmount: Balance = MIN_TRADE_AMOUNT.with(|v| *v.borrow());
 	pub MaxInRatio: Balance = MAX_IN_RATIO.with(|v| *v.borrow());
 	pub MaxOutRatio: Balance = MAX_OUT_RATIO.with(|v| *v.borrow());
+	pub const ImbalanceBurnRatio: Permill = Permill::from_percent(10);
+	pub const MaxAddLiquidityAssets: u32 = 5;
 	pub const TVLCap: Balance = Balance::MAX;
 
 	pub const TransactionByteFee: Balance = 10 * ONE / 100_000;
@@ -268,6 +275,10 @@ impl pallet_omnipool::Config for Test {
 	type MinWithdrawalFee = ();
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
+	type ProtocolFeeCollector = ();
+	type FeeLedgerPeriod = ConstU64<10>;
+	type ImbalanceBurnRatio = ImbalanceBurnRatio;
+	type MaxAddLiquidityAssets = MaxAddLiquidityAssets;
 }
 
 pub struct WithdrawFeePriceOracle;
@@ -350,6 +361,7 @@ pub const ASSET_PAIR_ACCOUNT: AccountId = 12;
 
 parameter_types! {
 	pub MaxNumberOfTrades: u8 = 3;
+	pub ExistentialDepositMultiplier: u8 = 5;
 }
 
 type Pools = (OmniPool, Xyk);
@@ -359,8 +371,11 @@ impl pallet_route_executor::Config for Test {
 	type AssetId = AssetId;
 	type Balance = Balance;
 	type MaxNumberOfTrades = MaxNumberOfTrades;
+	type ExistentialDepositMultiplier = ExistentialDepositMultiplier;
+	type MinimumTradingLimit = MinTradeAmount;
 	type Currency = MultiInspectAdapter<AccountId, AssetId, Balance, Balances, Tokens, NativeCurrencyId>;
 	type AMM = Pools;
+	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
 	type WeightInfo = ();
 }
 
@@ -586,8 +601,9 @@ pub struct PriceProviderMock {}
 impl PriceOracle<AssetId> for PriceProviderMock {
 	type Price = Ratio;
 
-	fn price(_: AssetId, _: AssetId, _: OraclePeriod) -> Option<Ratio> {
-		Some(Ratio::new(88, 100))
+	fn price(_: AssetId, _: AssetId, period: OraclePeriod) -> Option<Ratio> {
+		let price = ORACLE_PRICES.with(|v| v.borrow().iter().find(|(p, _)| *p == period).map(|(_, price)| *price));
+		Some(price.unwrap_or_else(|| Ratio::new(88, 100)))
 	}
 }
 
@@ -601,7 +617,7 @@ impl SpotPriceProvider<AssetId> for SpotPriceProviderMock {
 	}
 
 	fn spot_price(_: AssetId, _: AssetId) -> Option<Self::Price> {
-		Some(FixedU128::from_rational(80, 100))
+		Some(SPOT_PRICE.with(|v| *v.borrow()))
 	}
 }
 
@@ -612,6 +628,7 @@ parameter_types! {
 	pub OmnipoolMaxAllowedPriceDifference: Permill = MAX_PRICE_DIFFERENCE.with(|v| *v.borrow());
 	pub NamedReserveId: NamedReserveIdentifier = *b"dcaorder";
 	pub MaxNumberOfRetriesOnError: u8 = 3;
+	pub MaxLimitOrderDuration: BlockNumber = 1_000;
 }
 
 pub struct RandomnessProviderMock {}
@@ -646,6 +663,7 @@ impl Config for Test {
 	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
 	type RelayChainBlockHashProvider = ParentHashGetterMock;
 	type MinimumTradingLimit = MinTradeAmount;
+	type MaxLimitOrderDuration = MaxLimitOrderDuration;
 }
 
 pub struct ParentHashGetterMock {}
@@ -819,6 +837,14 @@ impl ExtBuilder {
 			*v.borrow_mut() = self.min_trading_limit;
 		});
 
+		SPOT_PRICE.with(|v| {
+			*v.borrow_mut() = FixedU128::from_rational(80, 100);
+		});
+
+		ORACLE_PRICES.with(|v| {
+			v.borrow_mut().clear();
+		});
+
 		let mut initial_native_accounts: Vec<(AccountId, Balance)> = vec![(ASSET_PAIR_ACCOUNT, 10000 * ONE)];
 		let additional_accounts: Vec<(AccountId, Balance)> = self
 			.endowed_accounts
@@ -897,6 +923,20 @@ pub fn set_sell_amount_out(balance: Balance) {
 	});
 }
 
+pub fn set_spot_price(price: FixedU128) {
+	SPOT_PRICE.with(|v| {
+		*v.borrow_mut() = price;
+	});
+}
+
+pub fn set_oracle_price(period: OraclePeriod, price: Ratio) {
+	ORACLE_PRICES.with(|v| {
+		let mut prices = v.borrow_mut();
+		prices.retain(|(p, _)| *p != period);
+		prices.push((period, price));
+	});
+}
+
 pub fn use_prod_randomness() {
 	USE_PROD_RANDOMNESS.with(|v| {
 		*v.borrow_mut() = true;
@@ -23,8 +23,10 @@ use crate::{
 };
 use frame_support::assert_ok;
 use frame_support::traits::OnInitialize;
+use hydra_dx_math::ema::EmaPrice;
 use hydradx_traits::router::PoolType;
 use hydradx_traits::router::PoolType::Omnipool;
+use hydradx_traits::OraclePeriod;
 use orml_traits::MultiCurrency;
 use orml_traits::MultiReservableCurrency;
 use orml_traits::NamedMultiReservableCurrency;
@@ -1824,6 +1826,121 @@ fn one_sell_dca_execution_should_be_rescheduled_when_price_diff_is_more_than_use
 		});
 }
 
+#[test]
+fn one_sell_dca_execution_should_be_rescheduled_when_only_last_block_oracle_price_is_close_to_spot_price() {
+	let initial_alice_hdx_balance = 10000 * ONE;
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, initial_alice_hdx_balance)])
+		.with_max_price_difference(Permill::from_percent(9))
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+			set_oracle_price(OraclePeriod::LastBlock, EmaPrice::new(80, 100));
+
+			let total_amount = 5 * ONE;
+			let amount_to_sell = ONE;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(Order::Sell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: amount_to_sell,
+					min_amount_out: Balance::MIN,
+					route: create_bounded_vec(vec![Trade {
+						pool: Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(501)));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			assert_executed_sell_trades!(vec![]);
+
+			let schedule_id = 0;
+			assert_scheduled_ids!(511, vec![schedule_id]);
+			expect_dca_events(vec![
+				DcaEvent::TradeFailed {
+					id: schedule_id,
+					who: ALICE,
+					error: Error::<Test>::PriceUnstable.into(),
+				}
+				.into(),
+				DcaEvent::ExecutionPlanned {
+					id: schedule_id,
+					who: ALICE,
+					block: 511,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn one_sell_dca_execution_should_be_executed_when_median_oracle_price_is_close_to_spot_price() {
+	let initial_alice_hdx_balance = 10000 * ONE;
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, initial_alice_hdx_balance)])
+		.with_max_price_difference(Permill::from_percent(9))
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+			set_oracle_price(OraclePeriod::Short, EmaPrice::new(80, 100));
+			set_oracle_price(OraclePeriod::TenMinutes, EmaPrice::new(81, 100));
+
+			let total_amount = 5 * ONE;
+			let amount_to_sell = ONE;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(Order::Sell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: amount_to_sell,
+					min_amount_out: Balance::MIN,
+					route: create_bounded_vec(vec![Trade {
+						pool: Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(501)));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			let schedule_id = 0;
+			expect_events(vec![
+				DcaEvent::TradeExecuted {
+					id: schedule_id,
+					who: ALICE,
+					amount_in: amount_to_sell,
+					amount_out: *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+				}
+				.into(),
+				DcaEvent::ExecutionPlanned {
+					id: schedule_id,
+					who: ALICE,
+					block: 601,
+				}
+				.into(),
+			]);
+		});
+}
+
 #[test]
 fn one_buy_dca_execution_should_be_rescheduled_when_price_diff_is_more_than_max_allowed() {
 	ExtBuilder::default()
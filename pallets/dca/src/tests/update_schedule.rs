@@ -0,0 +1,385 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::tests::terminate::set_block_number;
+use crate::tests::*;
+use crate::{Error, Event, ScheduleUpdate};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::PoolType;
+use orml_traits::NamedMultiReservableCurrency;
+use pretty_assertions::assert_eq;
+
+fn sell_order(amount_in: Balance) -> Order<AssetId, BlockNumber> {
+	Order::Sell {
+		asset_in: HDX,
+		asset_out: BTC,
+		amount_in,
+		min_amount_out: Balance::MIN,
+		route: create_bounded_vec(vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: HDX,
+			asset_out: BTC,
+		}]),
+	}
+}
+
+fn no_update() -> ScheduleUpdate<AssetId, BlockNumber> {
+	ScheduleUpdate {
+		period: None,
+		total_amount: None,
+		slippage: None,
+		route: None,
+	}
+}
+
+#[test]
+fn update_schedule_should_update_period_and_slippage() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act
+			assert_ok!(DCA::update_schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				ScheduleUpdate {
+					period: Some(10),
+					slippage: Some(Some(Permill::from_percent(5))),
+					..no_update()
+				}
+			));
+
+			//Assert
+			let schedule = DCA::schedules(schedule_id).unwrap();
+			assert_eq!(schedule.period, 10);
+			assert_eq!(schedule.slippage, Some(Permill::from_percent(5)));
+
+			expect_events(vec![Event::Updated {
+				id: schedule_id,
+				who: ALICE,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn update_schedule_should_reserve_extra_budget_when_total_amount_increased() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let total_amount = 100 * ONE;
+			let schedule = ScheduleBuilder::new().with_total_amount(total_amount).build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act
+			assert_ok!(DCA::update_schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				ScheduleUpdate {
+					total_amount: Some(150 * ONE),
+					..no_update()
+				}
+			));
+
+			//Assert
+			assert_eq!(DCA::schedules(schedule_id).unwrap().total_amount, 150 * ONE);
+			assert_eq!(DCA::remaining_amounts(schedule_id), Some(150 * ONE));
+			assert_eq!(
+				150 * ONE,
+				Currencies::reserved_balance_named(&NamedReserveId::get(), HDX, &ALICE)
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_unreserve_budget_when_total_amount_decreased() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let total_amount = 100 * ONE;
+			let schedule = ScheduleBuilder::new().with_total_amount(total_amount).build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act
+			assert_ok!(DCA::update_schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				ScheduleUpdate {
+					total_amount: Some(60 * ONE),
+					..no_update()
+				}
+			));
+
+			//Assert
+			assert_eq!(DCA::remaining_amounts(schedule_id), Some(60 * ONE));
+			assert_eq!(
+				60 * ONE,
+				Currencies::reserved_balance_named(&NamedReserveId::get(), HDX, &ALICE)
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_decreased_total_amount_exceeds_remaining_budget() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let total_amount = 100 * ONE;
+			let schedule = ScheduleBuilder::new().with_total_amount(total_amount).build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			crate::RemainingAmounts::<Test>::insert(0, 10 * ONE);
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleUpdate {
+						total_amount: Some(50 * ONE),
+						..no_update()
+					}
+				),
+				Error::<Test>::BudgetTooLow
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_total_amount_is_decreased_below_min_budget() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().with_total_amount(100 * ONE).build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleUpdate {
+						total_amount: Some(*ORIGINAL_MIN_BUDGET_IN_NATIVE - 1),
+						..no_update()
+					}
+				),
+				Error::<Test>::TotalAmountIsSmallerThanMinBudget
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_total_amount_is_decreased_below_one_trade() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(100 * ONE)
+				.with_order(sell_order(10 * ONE))
+				.build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleUpdate {
+						total_amount: Some(10 * ONE),
+						..no_update()
+					}
+				),
+				Error::<Test>::BudgetTooLow
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_remaining_budget_does_not_cover_one_trade() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(100 * ONE)
+				.with_order(sell_order(10 * ONE))
+				.build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			crate::RemainingAmounts::<Test>::insert(0, 20 * ONE);
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleUpdate {
+						total_amount: Some(90 * ONE),
+						..no_update()
+					}
+				),
+				Error::<Test>::BudgetTooLow
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_work_when_remaining_budget_covers_one_trade() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(100 * ONE)
+				.with_order(sell_order(10 * ONE))
+				.build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			crate::RemainingAmounts::<Test>::insert(0, 50 * ONE);
+
+			//Act
+			assert_ok!(DCA::update_schedule(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				ScheduleUpdate {
+					total_amount: Some(70 * ONE),
+					..no_update()
+				}
+			));
+
+			//Assert
+			assert_eq!(DCA::remaining_amounts(0), Some(20 * ONE));
+		});
+}
+
+#[test]
+fn update_schedule_should_update_route() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			let route = create_bounded_vec(vec![
+				Trade {
+					pool: PoolType::XYK,
+					asset_in: HDX,
+					asset_out: DAI,
+				},
+				Trade {
+					pool: PoolType::XYK,
+					asset_in: DAI,
+					asset_out: BTC,
+				},
+			]);
+
+			//Act
+			assert_ok!(DCA::update_schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				ScheduleUpdate {
+					route: Some(route.clone()),
+					..no_update()
+				}
+			));
+
+			//Assert
+			let Order::Buy { route: updated_route, .. } = DCA::schedules(schedule_id).unwrap().order else {
+				panic!("Order should be a buy order");
+			};
+			assert_eq!(updated_route, route);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_route_does_not_match_order_assets() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(
+					RuntimeOrigin::signed(ALICE),
+					0,
+					ScheduleUpdate {
+						route: Some(create_bounded_vec(vec![Trade {
+							pool: PoolType::Omnipool,
+							asset_in: HDX,
+							asset_out: DAI,
+						}])),
+						..no_update()
+					}
+				),
+				Error::<Test>::InvalidRoute
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_called_by_not_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(RuntimeOrigin::signed(BOB), 0, no_update()),
+				Error::<Test>::Forbidden
+			);
+		});
+}
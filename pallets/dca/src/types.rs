@@ -2,7 +2,7 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use pallet_route_executor::Trade;
 use scale_info::TypeInfo;
 use sp_runtime::traits::ConstU32;
-use sp_runtime::{BoundedVec, Permill};
+use sp_runtime::{BoundedVec, FixedU128, Permill};
 
 pub type Balance = u128;
 pub type ScheduleId = u32;
@@ -30,11 +30,11 @@ pub struct Schedule<AccountId, AssetId, BlockNumber> {
 	/// The slippage limit used to calculate the `min_amount_out` and `max_amount_in` trade limits.
 	pub slippage: Option<Permill>,
 	/// The order containing information to execute a specific trade by the router.
-	pub order: Order<AssetId>,
+	pub order: Order<AssetId, BlockNumber>,
 }
 
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
-pub enum Order<AssetId> {
+pub enum Order<AssetId, BlockNumber> {
 	Sell {
 		asset_in: AssetId,
 		asset_out: AssetId,
@@ -49,9 +49,56 @@ pub enum Order<AssetId> {
 		max_amount_in: Balance,
 		route: BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>,
 	},
+	/// Sell order which is evaluated in every block and executed only when the spot price
+	/// of `asset_out` denominated in `asset_in` meets the `trigger`.
+	/// The order is terminated if it is not fully executed by the `expiry` block.
+	Limit {
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		trigger: PriceTrigger,
+		expiry: BlockNumber,
+		route: BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>,
+	},
+}
+
+/// Changes of an existing DCA schedule. Fields which are `None` are left unchanged.
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
+pub struct ScheduleUpdate<AssetId, BlockNumber> {
+	/// New time period (in blocks) between two schedule executions.
+	pub period: Option<BlockNumber>,
+	/// New total amount (budget) of the schedule.
+	/// The difference to the current total amount is reserved or unreserved from the remaining budget.
+	pub total_amount: Option<Balance>,
+	/// New slippage limit.
+	pub slippage: Option<Option<Permill>>,
+	/// New route of the order. It has to start with `asset_in` and end with `asset_out` of the order.
+	pub route: Option<BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>>,
+}
+
+/// Spot price condition of a limit order.
+///
+/// The price is the spot price of `asset_out` denominated in `asset_in` of the order,
+/// i.e. the amount of `asset_in` paid for one unit of `asset_out`.
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, Copy, TypeInfo, MaxEncodedLen)]
+pub enum PriceTrigger {
+	/// Limit order - executed when the price drops to or below the given price.
+	Limit(FixedU128),
+	/// Stop order - executed when the price rises to or above the given price.
+	Stop(FixedU128),
+}
+
+impl PriceTrigger {
+	pub fn is_met(&self, price: FixedU128) -> bool {
+		match self {
+			PriceTrigger::Limit(limit_price) => price <= *limit_price,
+			PriceTrigger::Stop(stop_price) => price >= *stop_price,
+		}
+	}
 }
 
-impl<AssetId> Order<AssetId>
+impl<AssetId, BlockNumber> Order<AssetId, BlockNumber>
 where
 	AssetId: Copy,
 {
@@ -59,6 +106,7 @@ where
 		let asset_in = match &self {
 			Order::Sell { asset_in, .. } => asset_in,
 			Order::Buy { asset_in, .. } => asset_in,
+			Order::Limit { asset_in, .. } => asset_in,
 		};
 		*asset_in
 	}
@@ -67,14 +115,24 @@ where
 		let asset_out = match &self {
 			Order::Sell { asset_out, .. } => asset_out,
 			Order::Buy { asset_out, .. } => asset_out,
+			Order::Limit { asset_out, .. } => asset_out,
 		};
 		*asset_out
 	}
 
+	pub fn set_route(&mut self, new_route: BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>) {
+		match self {
+			Order::Sell { route, .. } => *route = new_route,
+			Order::Buy { route, .. } => *route = new_route,
+			Order::Limit { route, .. } => *route = new_route,
+		}
+	}
+
 	pub fn get_route_length(&self) -> usize {
 		let route = match &self {
 			Order::Sell { route, .. } => route,
 			Order::Buy { route, .. } => route,
+			Order::Limit { route, .. } => route,
 		};
 
 		route.len()
@@ -55,6 +55,9 @@ pub trait WeightInfo {
 	fn on_initialize_with_empty_block() -> Weight;
 	fn schedule() -> Weight;
 	fn terminate() -> Weight;
+	fn pause() -> Weight;
+	fn resume() -> Weight;
+	fn update_schedule() -> Weight;
 }
 
 /// Weights for pallet_dca using the hydraDX node and recommended hardware.
@@ -183,13 +186,61 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: DCA ScheduleIdsPerBlock (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
 	// Storage: DCA RetriesOnError (r:0 w:1)
 	// Proof: DCA RetriesOnError (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	// Storage: DCA PausedSchedules (r:1 w:1)
+	// Proof: DCA PausedSchedules (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
 	// Storage: DCA ScheduleOwnership (r:0 w:1)
 	// Proof: DCA ScheduleOwnership (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn terminate() -> Weight {
 		// Minimum execution time: 59_201 nanoseconds.
 		Weight::from_ref_time(59_909_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(5 as u64))
-			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Storage: DCA Schedules (r:1 w:0)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	// Storage: DCA PausedSchedules (r:1 w:1)
+	// Proof: DCA PausedSchedules (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	// Storage: DCA ScheduleIdsPerBlock (r:1 w:1)
+	// Proof: DCA ScheduleIdsPerBlock (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	fn pause() -> Weight {
+		// Minimum execution time: 30_845 nanoseconds.
+		Weight::from_ref_time(31_517_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: DCA Schedules (r:1 w:0)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	// Storage: DCA PausedSchedules (r:1 w:1)
+	// Proof: DCA PausedSchedules (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Proof Skipped: ParachainSystem ValidationData (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: DCA ScheduleIdsPerBlock (r:11 w:1)
+	// Proof: DCA ScheduleIdsPerBlock (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	fn resume() -> Weight {
+		// Minimum execution time: 53_566 nanoseconds.
+		Weight::from_ref_time(54_208_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(14 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: DCA Schedules (r:1 w:1)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	// Storage: DCA RemainingAmounts (r:1 w:1)
+	// Proof: DCA RemainingAmounts (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	// Storage: Omnipool Assets (r:2 w:0)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:1 w:0)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Omnipool HubAssetImbalance (r:1 w:0)
+	// Proof: Omnipool HubAssetImbalance (max_values: Some(1), max_size: Some(17), added: 512, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn update_schedule() -> Weight {
+		// Minimum execution time: 89_264 nanoseconds.
+		Weight::from_ref_time(90_517_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
 }
 
@@ -317,12 +368,60 @@ impl WeightInfo for () {
 	// Proof: DCA ScheduleIdsPerBlock (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
 	// Storage: DCA RetriesOnError (r:0 w:1)
 	// Proof: DCA RetriesOnError (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	// Storage: DCA PausedSchedules (r:1 w:1)
+	// Proof: DCA PausedSchedules (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
 	// Storage: DCA ScheduleOwnership (r:0 w:1)
 	// Proof: DCA ScheduleOwnership (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn terminate() -> Weight {
 		// Minimum execution time: 59_201 nanoseconds.
 		Weight::from_ref_time(59_909_000)
-			.saturating_add(RocksDbWeight::get().reads(5))
-			.saturating_add(RocksDbWeight::get().writes(7))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+	// Storage: DCA Schedules (r:1 w:0)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	// Storage: DCA PausedSchedules (r:1 w:1)
+	// Proof: DCA PausedSchedules (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	// Storage: DCA ScheduleIdsPerBlock (r:1 w:1)
+	// Proof: DCA ScheduleIdsPerBlock (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	fn pause() -> Weight {
+		// Minimum execution time: 30_845 nanoseconds.
+		Weight::from_ref_time(31_517_000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: DCA Schedules (r:1 w:0)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	// Storage: DCA PausedSchedules (r:1 w:1)
+	// Proof: DCA PausedSchedules (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Proof Skipped: ParachainSystem ValidationData (max_values: Some(1), max_size: None, mode: Measured)
+	// Storage: DCA ScheduleIdsPerBlock (r:11 w:1)
+	// Proof: DCA ScheduleIdsPerBlock (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	fn resume() -> Weight {
+		// Minimum execution time: 53_566 nanoseconds.
+		Weight::from_ref_time(54_208_000)
+			.saturating_add(RocksDbWeight::get().reads(14))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: DCA Schedules (r:1 w:1)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	// Storage: DCA RemainingAmounts (r:1 w:1)
+	// Proof: DCA RemainingAmounts (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	// Storage: Omnipool Assets (r:2 w:0)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:1 w:0)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Omnipool HubAssetImbalance (r:1 w:0)
+	// Proof: Omnipool HubAssetImbalance (max_values: Some(1), max_size: Some(17), added: 512, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn update_schedule() -> Weight {
+		// Minimum execution time: 89_264 nanoseconds.
+		Weight::from_ref_time(90_517_000)
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(4))
 	}
 }
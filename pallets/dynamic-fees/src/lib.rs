@@ -151,6 +151,13 @@ pub mod pallet {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// Remove stored fee entry of an asset, e.g. when the asset is removed from a pool.
+	pub fn remove_asset_fee(asset_id: T::AssetId) {
+		AssetFee::<T>::remove(asset_id);
+	}
+}
+
 impl<T: Config> Pallet<T>
 where
 	<T::Fee as PerThing>::Inner: FixedPointOperand,
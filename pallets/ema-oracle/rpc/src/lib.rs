@@ -0,0 +1,150 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the EMA oracle pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_ema_oracle_rpc_runtime_api::{
+	AggregatedEntry, EmaOracleApi as EmaOracleRuntimeApi, OracleError, OraclePeriod, Price, Source,
+};
+
+#[rpc(client, server)]
+pub trait EmaOracleApi<BlockHash, AssetId, Balance, BlockNumber> {
+	/// Get oracle entry of `asset_a/asset_b` pair from `source` aggregated over `period`.
+	#[method(name = "emaOracle_getEntry")]
+	fn get_entry(
+		&self,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		source: Source,
+		at: Option<BlockHash>,
+	) -> RpcResult<AggregatedEntry<Balance, BlockNumber, Price>>;
+
+	/// Get oracle entries of all `pairs` from `source` aggregated over `period`.
+	#[method(name = "emaOracle_getEntries")]
+	fn get_entries(
+		&self,
+		pairs: Vec<(AssetId, AssetId)>,
+		period: OraclePeriod,
+		source: Source,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Result<AggregatedEntry<Balance, BlockNumber, Price>, OracleError>>>;
+}
+
+/// Provides RPC methods to read EMA oracle entries.
+pub struct EmaOracle<C, Block> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> EmaOracle<C, Block> {
+	/// Create new `EmaOracle` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The oracle entry is not available.
+	OracleError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::OracleError => 2,
+		}
+	}
+}
+
+impl<C, Block, AssetId, Balance, BlockNumber> EmaOracleApiServer<<Block as BlockT>::Hash, AssetId, Balance, BlockNumber>
+	for EmaOracle<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: EmaOracleRuntimeApi<Block, AssetId, Balance, BlockNumber>,
+	AssetId: Codec,
+	Balance: Codec,
+	BlockNumber: Codec,
+{
+	fn get_entry(
+		&self,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		source: Source,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<AggregatedEntry<Balance, BlockNumber, Price>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_entry(at, asset_a, asset_b, period, source)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(oracle_error_into_rpc_err)
+	}
+
+	fn get_entries(
+		&self,
+		pairs: Vec<(AssetId, AssetId)>,
+		period: OraclePeriod,
+		source: Source,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<Result<AggregatedEntry<Balance, BlockNumber, Price>, OracleError>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_entries(at, pairs, period, source)
+			.map_err(runtime_error_into_rpc_err)
+	}
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query EMA oracle entry.",
+		Some(format!("{err:?}")),
+	))
+	.into()
+}
+
+fn oracle_error_into_rpc_err(err: OracleError) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::OracleError.into(),
+		"Oracle entry is not available.",
+		Some(format!("{err:?}")),
+	))
+	.into()
+}
@@ -0,0 +1,50 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the EMA oracle pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use hydradx_traits::{AggregatedEntry, OraclePeriod, Source};
+pub use pallet_ema_oracle::{OracleError, Price};
+
+sp_api::decl_runtime_apis! {
+	/// Reads EMA oracle entries updated to the current block.
+	pub trait EmaOracleApi<AssetId, Balance, BlockNumber> where
+		AssetId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Oracle entry of `asset_a/asset_b` pair from `source` aggregated over `period`.
+		fn get_entry(
+			asset_a: AssetId,
+			asset_b: AssetId,
+			period: OraclePeriod,
+			source: Source,
+		) -> Result<AggregatedEntry<Balance, BlockNumber, Price>, OracleError>;
+
+		/// Oracle entries of all `pairs` from `source` aggregated over `period`, in the order of `pairs`.
+		fn get_entries(
+			pairs: Vec<(AssetId, AssetId)>,
+			period: OraclePeriod,
+			source: Source,
+		) -> Vec<Result<AggregatedEntry<Balance, BlockNumber, Price>, OracleError>>;
+	}
+}
@@ -22,8 +22,9 @@ use super::*;
 pub const HDX: AssetId = 1_000;
 pub const DOT: AssetId = 2_000;
 
-use frame_benchmarking::benchmarks;
+use frame_benchmarking::{account, benchmarks};
 use frame_support::{assert_ok, traits::Hooks};
+use frame_system::RawOrigin;
 
 #[cfg(test)]
 use pretty_assertions::assert_eq;
@@ -33,6 +34,9 @@ use crate::Pallet as EmaOracle;
 /// Default oracle source.
 const SOURCE: Source = *b"dummysrc";
 
+/// Source of externally fed oracles.
+const EXTERNAL_SOURCE: Source = *b"external";
+
 benchmarks! {
 	on_finalize_no_entry {
 		let block_num: u32 = 5;
@@ -280,5 +284,48 @@ benchmarks! {
 		}));
 	}
 
+	add_feeder {
+		let feeder: T::AccountId = account("feeder", 0, 0);
+	}: _(RawOrigin::Root, SOURCE, feeder.clone())
+	verify {
+		assert!(WhitelistedFeeders::<T>::contains_key(SOURCE, feeder));
+	}
+
+	remove_feeder {
+		let feeder: T::AccountId = account("feeder", 0, 0);
+		assert_ok!(EmaOracle::<T>::add_feeder(RawOrigin::Root.into(), SOURCE, feeder.clone()));
+	}: _(RawOrigin::Root, SOURCE, feeder.clone())
+	verify {
+		assert!(!WhitelistedFeeders::<T>::contains_key(SOURCE, feeder));
+	}
+
+	update_external_oracle {
+		let feeder: T::AccountId = account("feeder", 0, 0);
+		assert_ok!(EmaOracle::<T>::add_feeder(RawOrigin::Root.into(), EXTERNAL_SOURCE, feeder.clone()));
+
+		let block_num: T::BlockNumber = 5u32.into();
+		frame_system::Pallet::<T>::set_block_number(block_num);
+		EmaOracle::<T>::on_initialize(block_num);
+
+		// fill the external accumulator so that the new entry is inserted into the largest map possible
+		let (liquidity_asset_a, liquidity_asset_b) = (1_000_000_000_000_000, 2_000_000_000_000_000);
+		for i in 0 .. (T::MaxUniqueExternalEntries::get() - 1) {
+			let asset_a = i * 1_000;
+			let asset_b = asset_a + 500;
+			assert_ok!(EmaOracle::<T>::update_external_oracle(RawOrigin::Signed(feeder.clone()).into(), EXTERNAL_SOURCE, asset_a, asset_b, (liquidity_asset_a, liquidity_asset_b), (liquidity_asset_a, liquidity_asset_b)));
+		}
+		let asset_a = T::MaxUniqueExternalEntries::get() * 1_000;
+		let asset_b = asset_a + 500;
+	}: _(RawOrigin::Signed(feeder), EXTERNAL_SOURCE, asset_a, asset_b, (liquidity_asset_a, liquidity_asset_b), (liquidity_asset_a, liquidity_asset_b))
+	verify {
+		let entry = OracleEntry {
+			price: Price::from((liquidity_asset_a, liquidity_asset_b)),
+			volume: Volume::default(),
+			liquidity: Liquidity::new(liquidity_asset_a, liquidity_asset_b),
+			updated_at: block_num,
+		};
+		assert_eq!(ExternalAccumulator::<T>::get().get(&(EXTERNAL_SOURCE, ordered_pair(asset_a, asset_b))), Some(&entry));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::new_test_ext(), crate::tests::Test);
 }
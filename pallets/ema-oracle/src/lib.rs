@@ -28,13 +28,25 @@
 //! Data is ingested by plugging the provided `OnActivityHandler` into callbacks provided by other
 //! pallets (e.g. xyk pallet).
 //!
+//! Data of pairs that are not traded locally (e.g. assets arriving over XCM) can be fed via
+//! `update_external_oracle` by accounts whitelisted for a source by `AuthorityOrigin`. Fed data is
+//! aggregated the same way as data of local activity. Sources fed by local activity (`LocalSources`)
+//! can't be fed externally.
+//!
 //! It is meant to be used by other pallets via the `AggregatedOracle` and `AggregatedPriceOracle`
 //! traits.
 //!
+//! Consumers sensitive to price manipulation can use `get_checked_entry`, which rejects oracles
+//! younger than a minimum age or with EMA liquidity below a minimum, and `get_median_price`,
+//! which combines the prices of several periods into their median.
+//!
 //! When integrating with this pallet take care to use the `on_trade_weight`,
 //! `on_liquidity_changed_weight` and `get_entry_weight` into account when calculating the weight
 //! for your extrinsics (that either feed data into or take data from this pallet).
 //!
+//! Off-chain consumers can read oracle entries via the `EmaOracleApi` runtime API and the
+//! corresponding RPC methods, which return the entries fast-forwarded to the current block.
+//!
 //! ### Concepts
 //!
 //! - *EMA*: Averaging via exponential decay with a smoothing factor; meaning each new value to
@@ -53,6 +65,11 @@
 //! this aggregation is NOT based on EMA, yet, it just sums the volume and replaces price and
 //! liquidity with the most recent value.
 //!
+//! At most `MaxUniqueEntries` unique entries are accumulated per block. Updates of further entries
+//! are dropped and `OracleUpdateDropped` event is emitted, the activity feeding the data is not affected.
+//! Externally fed entries are accumulated separately, at most `MaxUniqueExternalEntries` per block,
+//! so they never take the place of local entries. Updates of further external entries are rejected.
+//!
 //! At the end of the block, all the entries are merged into permanent storage via the exponential
 //! moving average logic defined in the math package this pallet depens on. There is one oracle
 //! entry for each combination of `(source, asset_pair, period)` in storage.
@@ -68,6 +85,9 @@
 
 use frame_support::pallet_prelude::*;
 use frame_support::sp_runtime::traits::{BlockNumberProvider, One, Zero};
+use frame_support::traits::Contains;
+use frame_support::BoundedBTreeMap;
+use hydra_dx_math::ema::median_price;
 use hydradx_traits::{
 	AggregatedEntry, AggregatedOracle, AggregatedPriceOracle, Liquidity, OnCreatePoolHandler,
 	OnLiquidityChangedHandler, OnTradeHandler,
@@ -78,6 +98,9 @@ use sp_arithmetic::traits::Saturating;
 use sp_std::marker::PhantomData;
 use sp_std::prelude::*;
 
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
 #[cfg(test)]
 mod tests;
 
@@ -102,8 +125,8 @@ pub use pallet::*;
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::BoundedBTreeMap;
-	use frame_system::pallet_prelude::BlockNumberFor;
+	use frame_system::ensure_signed;
+	use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
@@ -115,6 +138,9 @@ pub mod pallet {
 		/// Weight information for the extrinsics.
 		type WeightInfo: WeightInfo;
 
+		/// Origin that can whitelist and remove external oracle feeders.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Provider for the current block number.
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = Self::BlockNumber>;
 
@@ -122,18 +148,52 @@ pub mod pallet {
 		type SupportedPeriods: Get<BoundedVec<OraclePeriod, ConstU32<MAX_PERIODS>>>;
 
 		/// Maximum number of unique oracle entries expected in one block.
+		/// Updates of further entries in the same block are dropped.
 		#[pallet::constant]
 		type MaxUniqueEntries: Get<u32>;
+
+		/// Maximum number of unique externally fed oracle entries in one block.
+		/// External updates of further entries in the same block are rejected.
+		#[pallet::constant]
+		type MaxUniqueExternalEntries: Get<u32>;
+
+		/// Sources fed by local activity. Their oracles can't be fed via `update_external_oracle`.
+		type LocalSources: Contains<Source>;
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
+		/// Too many unique external oracle entries in the current block.
 		TooManyUniqueEntries,
 		OnTradeValueZero,
+		/// Account is already whitelisted as a feeder of the source.
+		FeederAlreadyWhitelisted,
+		/// Account is not whitelisted as a feeder of the source.
+		FeederNotWhitelisted,
+		/// Oracle is not defined if the asset ids are the same.
+		SameAsset,
+		/// Price fed to the oracle must not be zero.
+		PriceIsZero,
+		/// Oracle could not be found.
+		OracleNotPresent,
+		/// Oracle was initialized too recently to be considered reliable.
+		OracleTooYoung,
+		/// Liquidity tracked by the oracle is too low for the oracle to be considered reliable.
+		InsufficientOracleLiquidity,
+		/// Oracles of sources fed by local activity can't be fed externally.
+		NotExternalSource,
 	}
 
 	#[pallet::event]
-	pub enum Event<T: Config> {}
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Oracle update was dropped because the accumulator is full.
+		OracleUpdateDropped { source: Source, assets: (AssetId, AssetId) },
+		/// Account was whitelisted as an oracle feeder of the source.
+		FeederAdded { source: Source, feeder: T::AccountId },
+		/// Account was removed from oracle feeders of the source.
+		FeederRemoved { source: Source, feeder: T::AccountId },
+	}
 
 	/// Accumulator for oracle data in current block that will be recorded at the end of the block.
 	#[pallet::storage]
@@ -144,6 +204,15 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Accumulator for externally fed oracle data in current block that will be recorded at the end
+	/// of the block.
+	#[pallet::storage]
+	pub type ExternalAccumulator<T: Config> = StorageValue<
+		_,
+		BoundedBTreeMap<(Source, (AssetId, AssetId)), OracleEntry<T::BlockNumber>, T::MaxUniqueExternalEntries>,
+		ValueQuery,
+	>;
+
 	/// Orace storage keyed by data source, involved asset ids and the period length of the oracle.
 	///
 	/// Stores the data entry as well as the block number when the oracle was first initialized.
@@ -160,6 +229,11 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Accounts allowed to feed oracle data of a source via `update_external_oracle`.
+	#[pallet::storage]
+	pub type WhitelistedFeeders<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, Source, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(Default)]
 	pub struct GenesisConfig {
@@ -211,28 +285,141 @@ pub mod pallet {
 	}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {}
+	impl<T: Config> Pallet<T> {
+		/// Whitelist `feeder` to feed oracle data of `source` via `update_external_oracle`.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// Emits `FeederAdded` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::add_feeder())]
+		pub fn add_feeder(origin: OriginFor<T>, source: Source, feeder: T::AccountId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				!WhitelistedFeeders::<T>::contains_key(source, &feeder),
+				Error::<T>::FeederAlreadyWhitelisted
+			);
+			WhitelistedFeeders::<T>::insert(source, &feeder, ());
+
+			Self::deposit_event(Event::FeederAdded { source, feeder });
+			Ok(())
+		}
+
+		/// Remove `feeder` from oracle feeders of `source`.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// Emits `FeederRemoved` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::remove_feeder())]
+		pub fn remove_feeder(origin: OriginFor<T>, source: Source, feeder: T::AccountId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				WhitelistedFeeders::<T>::contains_key(source, &feeder),
+				Error::<T>::FeederNotWhitelisted
+			);
+			WhitelistedFeeders::<T>::remove(source, &feeder);
+
+			Self::deposit_event(Event::FeederRemoved { source, feeder });
+			Ok(())
+		}
+
+		/// Feed price and liquidity of `asset_a/asset_b` pair to the oracles of `source`.
+		///
+		/// Meant for pairs that are not traded locally, e.g. assets arriving over XCM. The data is
+		/// accumulated like data of local trades and integrated into oracles of all supported periods
+		/// at the end of the block.
+		///
+		/// Parameters:
+		/// - `origin`: whitelisted feeder of `source`
+		/// - `source`: oracle source to feed
+		/// - `asset_a`: first asset of the pair
+		/// - `asset_b`: second asset of the pair
+		/// - `price`: amounts of `asset_a` and `asset_b` of equal value
+		/// - `liquidity`: liquidity of `asset_a` and `asset_b` in the source, zero if not known
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::update_external_oracle()
+			.saturating_add(fractional_on_finalize_weight::<T>(T::MaxUniqueExternalEntries::get())))]
+		pub fn update_external_oracle(
+			origin: OriginFor<T>,
+			source: Source,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			price: (Balance, Balance),
+			liquidity: (Balance, Balance),
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				WhitelistedFeeders::<T>::contains_key(source, &who),
+				Error::<T>::FeederNotWhitelisted
+			);
+			ensure!(!T::LocalSources::contains(&source), Error::<T>::NotExternalSource);
+			ensure!(asset_a != asset_b, Error::<T>::SameAsset);
+			ensure!(!price.0.is_zero() && !price.1.is_zero(), Error::<T>::PriceIsZero);
+
+			let entry = OracleEntry {
+				price: determine_normalized_price(asset_a, asset_b, price.0, price.1),
+				// externally fed data does not count as trade volume
+				volume: Volume::default(),
+				liquidity: determine_normalized_liquidity(asset_a, asset_b, liquidity.0, liquidity.1),
+				updated_at: T::BlockNumberProvider::current_block_number(),
+			};
+			Self::on_external_entry(source, ordered_pair(asset_a, asset_b), entry)
+		}
+	}
 }
 
 impl<T: Config> Pallet<T> {
 	/// Insert or update data in the accumulator from received entry. Aggregates volume and
 	/// takes the most recent data for the rest.
-	pub(crate) fn on_entry(
+	///
+	/// If the accumulator is full and does not contain the entry yet, the oracle update is dropped
+	/// and `OracleUpdateDropped` event is emitted. The activity feeding the data is not affected.
+	pub(crate) fn on_entry(src: Source, assets: (AssetId, AssetId), oracle_entry: OracleEntry<T::BlockNumber>) {
+		let inserted = Accumulator::<T>::mutate(|accumulator| Self::accumulate(accumulator, src, assets, oracle_entry));
+
+		if !inserted {
+			log::warn!(
+				target: LOG_TARGET,
+				"Accumulator is full, dropping oracle update. Source: {src:?}, assets: {assets:?}"
+			);
+			Self::deposit_event(Event::OracleUpdateDropped { source: src, assets });
+		}
+	}
+
+	/// Insert `oracle_entry` into `accumulator` or update the existing entry of the same source and
+	/// assets.
+	///
+	/// Returns `false` if the accumulator is full and does not contain the entry yet.
+	fn accumulate<Limit: Get<u32>>(
+		accumulator: &mut BoundedBTreeMap<(Source, (AssetId, AssetId)), OracleEntry<T::BlockNumber>, Limit>,
 		src: Source,
 		assets: (AssetId, AssetId),
 		oracle_entry: OracleEntry<T::BlockNumber>,
-	) -> Result<(), ()> {
-		Accumulator::<T>::mutate(|accumulator| {
-			if let Some(entry) = accumulator.get_mut(&(src, assets)) {
-				entry.accumulate_volume_and_update_from(&oracle_entry);
-				Ok(())
-			} else {
-				accumulator
-					.try_insert((src, assets), oracle_entry)
-					.map(|_| ())
-					.map_err(|_| ())
-			}
-		})
+	) -> bool {
+		if let Some(entry) = accumulator.get_mut(&(src, assets)) {
+			entry.accumulate_volume_and_update_from(&oracle_entry);
+			true
+		} else {
+			accumulator.try_insert((src, assets), oracle_entry).is_ok()
+		}
+	}
+
+	/// Insert or update data in the external accumulator from externally fed entry.
+	///
+	/// Fails if the external accumulator is full and does not contain the entry yet.
+	pub(crate) fn on_external_entry(
+		src: Source,
+		assets: (AssetId, AssetId),
+		oracle_entry: OracleEntry<T::BlockNumber>,
+	) -> DispatchResult {
+		let inserted =
+			ExternalAccumulator::<T>::mutate(|accumulator| Self::accumulate(accumulator, src, assets, oracle_entry));
+		ensure!(inserted, Error::<T>::TooManyUniqueEntries);
+		Ok(())
 	}
 
 	/// Insert or update data in the accumulator from received entry. Aggregates volume and
@@ -242,10 +429,8 @@ impl<T: Config> Pallet<T> {
 		assets: (AssetId, AssetId),
 		oracle_entry: OracleEntry<T::BlockNumber>,
 	) -> Result<Weight, (Weight, DispatchError)> {
-		let weight = OnActivityHandler::<T>::on_trade_weight();
-		Self::on_entry(src, assets, oracle_entry)
-			.map(|_| weight)
-			.map_err(|_| (weight, Error::<T>::TooManyUniqueEntries.into()))
+		Self::on_entry(src, assets, oracle_entry);
+		Ok(OnActivityHandler::<T>::on_trade_weight())
 	}
 
 	/// Insert or update data in the accumulator from received entry. Aggregates volume and
@@ -255,10 +440,8 @@ impl<T: Config> Pallet<T> {
 		assets: (AssetId, AssetId),
 		oracle_entry: OracleEntry<T::BlockNumber>,
 	) -> Result<Weight, (Weight, DispatchError)> {
-		let weight = OnActivityHandler::<T>::on_liquidity_changed_weight();
-		Self::on_entry(src, assets, oracle_entry)
-			.map(|_| weight)
-			.map_err(|_| (weight, Error::<T>::TooManyUniqueEntries.into()))
+		Self::on_entry(src, assets, oracle_entry);
+		Ok(OnActivityHandler::<T>::on_liquidity_changed_weight())
 	}
 
 	/// Return the current value of the `LastBlock` oracle for the given `source` and `assets`.
@@ -279,7 +462,9 @@ impl<T: Config> Pallet<T> {
 
 	/// Update oracles based on data accumulated during the block.
 	fn update_oracles_from_accumulator() {
-		for ((src, assets), oracle_entry) in Accumulator::<T>::take().into_iter() {
+		let local_entries = Accumulator::<T>::take().into_iter();
+		let external_entries = ExternalAccumulator::<T>::take().into_iter();
+		for ((src, assets), oracle_entry) in local_entries.chain(external_entries) {
 			// First we update the non-immediate oracles with the value of the `LastBlock` oracle.
 			for period in T::SupportedPeriods::get().into_iter().filter(|p| *p != LastBlock) {
 				Self::update_oracle(src, assets, period, oracle_entry.clone());
@@ -332,31 +517,138 @@ impl<T: Config> Pallet<T> {
 		});
 	}
 
-	/// Return the updated oracle entry for the given source, assets and period.
+	/// Return the oracle entry for the given source, assets and period updated to `block`.
 	///
-	/// The value will be up to date until the parent block, thus excluding trading data from the
-	/// current block. Note: It does not update the values in storage.
-	fn get_updated_entry(
+	/// Called with the parent block the value excludes trading data from the current block.
+	/// Note: It does not update the values in storage.
+	fn get_entry_updated_to(
 		src: Source,
 		assets: (AssetId, AssetId),
 		period: OraclePeriod,
+		block: T::BlockNumber,
 	) -> Option<(OracleEntry<T::BlockNumber>, T::BlockNumber)> {
-		let parent = T::BlockNumberProvider::current_block_number().saturating_sub(One::one());
 		// First get the `LastBlock` oracle to calculate the updated values for the others.
-		let (last_block, last_block_init) = Self::last_block_oracle(src, assets, parent)?;
+		let (last_block, last_block_init) = Self::last_block_oracle(src, assets, block)?;
 		// If it was requested return it directly.
 		if period == LastBlock {
 			return Some((last_block, last_block_init));
 		}
 
 		let (entry, init) = Self::oracle((src, assets, period))?;
-		if entry.updated_at < parent {
+		if entry.updated_at < block {
 			entry.calculate_current_from_outdated(period, &last_block)
 		} else {
 			Some(entry)
 		}
 		.map(|return_entry| (return_entry, init))
 	}
+
+	/// Return the aggregated entry for the given assets, period and source updated to `block` and
+	/// adjusted to the order of the given assets.
+	fn aggregated_entry_at(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		source: Source,
+		block: T::BlockNumber,
+	) -> Result<AggregatedEntry<Balance, T::BlockNumber, Price>, OracleError> {
+		if asset_a == asset_b {
+			return Err(OracleError::SameAsset);
+		};
+		Self::get_entry_updated_to(source, ordered_pair(asset_a, asset_b), period, block)
+			.ok_or(OracleError::NotPresent)
+			.map(|(entry, initialized)| {
+				let entry = if (asset_a, asset_b) != ordered_pair(asset_a, asset_b) {
+					entry.inverted()
+				} else {
+					entry
+				};
+				entry.into_aggregated(initialized)
+			})
+	}
+
+	/// Return the entry corresponding to the given assets, period and source as seen from outside
+	/// of block execution.
+	///
+	/// Unlike `AggregatedOracle::get_entry`, which is meant to be called during block execution and
+	/// thus excludes the current block, the entry includes the data of the current block as the
+	/// oracles were already updated on finalize. Meant to be used by runtime APIs.
+	pub fn get_current_entry(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		source: Source,
+	) -> Result<AggregatedEntry<Balance, T::BlockNumber, Price>, OracleError> {
+		Self::aggregated_entry_at(
+			asset_a,
+			asset_b,
+			period,
+			source,
+			T::BlockNumberProvider::current_block_number(),
+		)
+	}
+
+	/// Check whether the given entry can be considered reliable.
+	///
+	/// Returns `OracleError::TooYoung` if the oracle was initialized less than `min_oracle_age`
+	/// blocks ago and `OracleError::InsufficientLiquidity` if the EMA liquidity of any of the
+	/// assets is below `min_liquidity`. Meant to flag entries returned by the unchecked queries.
+	pub fn check_entry(
+		entry: &AggregatedEntry<Balance, T::BlockNumber, Price>,
+		min_oracle_age: T::BlockNumber,
+		min_liquidity: Balance,
+	) -> Result<(), OracleError> {
+		ensure!(entry.oracle_age >= min_oracle_age, OracleError::TooYoung);
+		ensure!(
+			entry.liquidity.a >= min_liquidity && entry.liquidity.b >= min_liquidity,
+			OracleError::InsufficientLiquidity
+		);
+		Ok(())
+	}
+
+	/// Return the entry corresponding to the given assets, period and source like
+	/// `AggregatedOracle::get_entry`, but reject it if it does not pass `check_entry`.
+	pub fn get_checked_entry(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		source: Source,
+		min_oracle_age: T::BlockNumber,
+		min_liquidity: Balance,
+	) -> Result<AggregatedEntry<Balance, T::BlockNumber, Price>, OracleError> {
+		let entry = Self::get_entry(asset_a, asset_b, period, source)?;
+		Self::check_entry(&entry, min_oracle_age, min_liquidity)?;
+		Ok(entry)
+	}
+
+	/// Return the median of the prices of the given periods for the given assets and source.
+	///
+	/// The entries of all periods are checked via `get_checked_entry`, so the query fails if any of
+	/// them is missing or not reliable. A manipulation of a single period (e.g. `LastBlock`) does
+	/// not move the median as long as the other periods are not affected.
+	/// Returns `OracleError::NotPresent` if no periods are given.
+	pub fn get_median_price(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		periods: &[OraclePeriod],
+		source: Source,
+		min_oracle_age: T::BlockNumber,
+		min_liquidity: Balance,
+	) -> Result<Price, OracleError> {
+		let mut prices = periods
+			.iter()
+			.map(|period| {
+				Self::get_checked_entry(asset_a, asset_b, *period, source, min_oracle_age, min_liquidity)
+					.map(|entry| entry.price)
+			})
+			.collect::<Result<Vec<Price>, OracleError>>()?;
+		median_price(&mut prices).ok_or(OracleError::NotPresent)
+	}
+
+	/// Return the weight of `get_median_price` for `periods` number of periods.
+	pub fn get_median_price_weight(periods: u32) -> Weight {
+		T::WeightInfo::get_entry().saturating_mul(periods.into())
+	}
 }
 
 /// A callback handler for trading and liquidity activity that schedules oracle updates.
@@ -508,12 +800,28 @@ pub fn ordered_pair(asset_a: AssetId, asset_b: AssetId) -> (AssetId, AssetId) {
 }
 
 /// Possible errors when requesting an oracle value.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo)]
 pub enum OracleError {
 	/// The oracle could not be found
 	NotPresent,
 	/// The oracle is not defined if the asset ids are the same.
 	SameAsset,
+	/// The oracle was initialized too recently to be considered reliable.
+	TooYoung,
+	/// The liquidity tracked by the oracle is too low for the oracle to be considered reliable.
+	InsufficientLiquidity,
+}
+
+impl<T: Config> From<OracleError> for Error<T> {
+	fn from(e: OracleError) -> Self {
+		match e {
+			OracleError::NotPresent => Error::<T>::OracleNotPresent,
+			OracleError::SameAsset => Error::<T>::SameAsset,
+			OracleError::TooYoung => Error::<T>::OracleTooYoung,
+			OracleError::InsufficientLiquidity => Error::<T>::InsufficientOracleLiquidity,
+		}
+	}
 }
 
 impl<T: Config> AggregatedOracle<AssetId, Balance, T::BlockNumber, Price> for Pallet<T> {
@@ -530,19 +838,8 @@ impl<T: Config> AggregatedOracle<AssetId, Balance, T::BlockNumber, Price> for Pa
 		period: OraclePeriod,
 		source: Source,
 	) -> Result<AggregatedEntry<Balance, T::BlockNumber, Price>, OracleError> {
-		if asset_a == asset_b {
-			return Err(OracleError::SameAsset);
-		};
-		Self::get_updated_entry(source, ordered_pair(asset_a, asset_b), period)
-			.ok_or(OracleError::NotPresent)
-			.map(|(entry, initialized)| {
-				let entry = if (asset_a, asset_b) != ordered_pair(asset_a, asset_b) {
-					entry.inverted()
-				} else {
-					entry
-				};
-				entry.into_aggregated(initialized)
-			})
+		let parent = T::BlockNumberProvider::current_block_number().saturating_sub(One::one());
+		Self::aggregated_entry_at(asset_a, asset_b, period, source, parent)
 	}
 
 	fn get_entry_weight() -> Weight {
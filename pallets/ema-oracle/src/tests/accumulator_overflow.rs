@@ -0,0 +1,153 @@
+// This file is part of pallet-ema-oracle.
+
+// Copyright (C) 2022-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn max_entries() -> u32 {
+	<<Test as crate::Config>::MaxUniqueEntries as Get<u32>>::get()
+}
+
+fn trade(asset_a: AssetId, asset_b: AssetId) -> Result<Weight, (Weight, DispatchError)> {
+	OnActivityHandler::<Test>::on_trade(SOURCE, asset_a, asset_b, 1_000, 1_000, 2_000, 2_000)
+}
+
+fn fill_accumulator() {
+	for i in 0..max_entries() {
+		assert_ok!(trade(i, i + 1));
+	}
+	assert_eq!(Accumulator::<Test>::get().len() as u32, max_entries());
+}
+
+fn dropped_updates() -> Vec<(Source, (AssetId, AssetId))> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			TestEvent::EmaOracle(Event::OracleUpdateDropped { source, assets }) => Some((source, assets)),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn on_trade_should_not_fail_when_accumulator_is_full() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		fill_accumulator();
+
+		let first_overflow = 2 * max_entries();
+		for i in first_overflow..first_overflow + 5 {
+			assert_ok!(trade(i, i + 1));
+		}
+
+		assert_eq!(Accumulator::<Test>::get().len() as u32, max_entries());
+		assert_eq!(
+			dropped_updates(),
+			(first_overflow..first_overflow + 5)
+				.map(|i| (SOURCE, (i, i + 1)))
+				.collect::<Vec<_>>()
+		);
+	});
+}
+
+#[test]
+fn on_liquidity_changed_should_not_fail_when_accumulator_is_full() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		fill_accumulator();
+
+		let overflow = 2 * max_entries();
+		assert_ok!(OnActivityHandler::<Test>::on_liquidity_changed(
+			SOURCE,
+			overflow,
+			overflow + 1,
+			1_000,
+			1_000,
+			2_000,
+			2_000
+		));
+
+		assert_eq!(Accumulator::<Test>::get().len() as u32, max_entries());
+		assert_eq!(dropped_updates(), vec![(SOURCE, (overflow, overflow + 1))]);
+	});
+}
+
+#[test]
+fn on_trade_should_aggregate_existing_entry_when_accumulator_is_full() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		fill_accumulator();
+
+		assert_ok!(trade(0, 1));
+
+		assert_eq!(
+			get_accumulator_entry(SOURCE, (0, 1)).map(|entry| entry.volume),
+			Some(Volume::from_a_in_b_out(2_000, 2_000))
+		);
+		assert!(dropped_updates().is_empty());
+	});
+}
+
+#[test]
+fn only_accumulated_pairs_should_be_updated_in_block_with_many_distinct_pairs() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let first_overflow = 2 * max_entries();
+		fill_accumulator();
+		for i in first_overflow..first_overflow + 5 {
+			assert_ok!(trade(i, i + 1));
+		}
+
+		EmaOracle::on_finalize(1);
+
+		for i in 0..max_entries() {
+			for period in supported_periods() {
+				assert!(get_oracle_entry(i, i + 1, period).is_some());
+			}
+		}
+		for i in first_overflow..first_overflow + 5 {
+			for period in supported_periods() {
+				assert_eq!(get_oracle_entry(i, i + 1, period), None);
+			}
+		}
+		assert!(Accumulator::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn dropped_pair_should_be_recorded_in_next_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let overflow = 2 * max_entries();
+		fill_accumulator();
+		assert_ok!(trade(overflow, overflow + 1));
+		EmaOracle::on_finalize(1);
+
+		System::set_block_number(2);
+		assert_ok!(trade(overflow, overflow + 1));
+		EmaOracle::on_finalize(2);
+
+		assert_eq!(
+			get_oracle_entry(overflow, overflow + 1, LastBlock),
+			Some(OracleEntry {
+				price: Price::new(2_000, 2_000),
+				volume: Volume::from_a_in_b_out(1_000, 1_000),
+				liquidity: Liquidity::new(2_000, 2_000),
+				updated_at: 2,
+			})
+		);
+	});
+}
@@ -0,0 +1,187 @@
+// This file is part of pallet-ema-oracle.
+
+// Copyright (C) 2022-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+fn trade_at_blocks_1_and_50() {
+	System::set_block_number(1);
+	assert_ok!(OnActivityHandler::<Test>::on_trade(
+		SOURCE, HDX, DOT, 1_000, 500, 2_000, 1_000
+	));
+	EmaOracle::on_finalize(1);
+	System::set_block_number(50);
+	assert_ok!(OnActivityHandler::<Test>::on_trade(
+		SOURCE, HDX, DOT, 1_000, 600, 3_000, 1_200
+	));
+	EmaOracle::on_finalize(50);
+}
+
+#[test]
+fn get_checked_entry_should_return_entry_when_requirements_are_met() {
+	new_test_ext().execute_with(|| {
+		trade_at_blocks_1_and_50();
+		System::set_block_number(100);
+
+		let entry = EmaOracle::get_entry(HDX, DOT, TenMinutes, SOURCE).unwrap();
+		assert_eq!(
+			EmaOracle::get_checked_entry(HDX, DOT, TenMinutes, SOURCE, entry.oracle_age, 1_000),
+			Ok(entry)
+		);
+	});
+}
+
+#[test]
+fn get_checked_entry_should_fail_when_oracle_is_too_young() {
+	new_test_ext().execute_with(|| {
+		trade_at_blocks_1_and_50();
+		System::set_block_number(100);
+
+		let entry = EmaOracle::get_entry(HDX, DOT, TenMinutes, SOURCE).unwrap();
+		assert_eq!(
+			EmaOracle::get_checked_entry(HDX, DOT, TenMinutes, SOURCE, entry.oracle_age + 1, 0),
+			Err(OracleError::TooYoung)
+		);
+	});
+}
+
+#[test]
+fn get_checked_entry_should_fail_when_liquidity_is_too_low() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(OnActivityHandler::<Test>::on_trade(
+			SOURCE, HDX, DOT, 1_000, 500, 2_000, 1_000
+		));
+		EmaOracle::on_finalize(1);
+		System::set_block_number(2);
+
+		assert_ok!(EmaOracle::get_checked_entry(HDX, DOT, LastBlock, SOURCE, 0, 1_000));
+		// liquidity of both assets is checked regardless of the asset order
+		assert_eq!(
+			EmaOracle::get_checked_entry(HDX, DOT, LastBlock, SOURCE, 0, 1_001),
+			Err(OracleError::InsufficientLiquidity)
+		);
+		assert_eq!(
+			EmaOracle::get_checked_entry(DOT, HDX, LastBlock, SOURCE, 0, 1_001),
+			Err(OracleError::InsufficientLiquidity)
+		);
+	});
+}
+
+#[test]
+fn get_checked_entry_should_fail_when_oracle_is_not_present() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			EmaOracle::get_checked_entry(HDX, ACA, TenMinutes, SOURCE, 0, 0),
+			Err(OracleError::NotPresent)
+		);
+	});
+}
+
+#[test]
+fn check_entry_should_flag_entry_without_rejecting_query() {
+	new_test_ext().execute_with(|| {
+		let entry = AggregatedEntry {
+			price: Price::new(2_000, 1_000),
+			volume: Volume::default(),
+			liquidity: Liquidity::new(2_000, 1_000),
+			oracle_age: 5,
+		};
+
+		assert_ok!(EmaOracle::check_entry(&entry, 5, 1_000));
+		assert_eq!(EmaOracle::check_entry(&entry, 6, 1_000), Err(OracleError::TooYoung));
+		assert_eq!(
+			EmaOracle::check_entry(&entry, 5, 1_001),
+			Err(OracleError::InsufficientLiquidity)
+		);
+	});
+}
+
+#[test]
+fn get_median_price_should_return_median_of_prices_of_given_periods() {
+	new_test_ext().execute_with(|| {
+		trade_at_blocks_1_and_50();
+		System::set_block_number(100);
+
+		let last_block = EmaOracle::get_entry(HDX, DOT, LastBlock, SOURCE).unwrap().price;
+		let ten_minutes = EmaOracle::get_entry(HDX, DOT, TenMinutes, SOURCE).unwrap().price;
+		let day = EmaOracle::get_entry(HDX, DOT, Day, SOURCE).unwrap().price;
+		// sanity check that the median is not trivially the first or last period
+		assert!(day < ten_minutes && ten_minutes < last_block);
+
+		assert_eq!(
+			EmaOracle::get_median_price(HDX, DOT, &[LastBlock, TenMinutes, Day], SOURCE, 0, 0),
+			Ok(ten_minutes)
+		);
+		assert_eq!(
+			EmaOracle::get_median_price(HDX, DOT, &[Day, LastBlock, TenMinutes], SOURCE, 0, 0),
+			Ok(ten_minutes)
+		);
+	});
+}
+
+#[test]
+fn get_median_price_should_fail_when_any_period_fails_the_check() {
+	new_test_ext().execute_with(|| {
+		trade_at_blocks_1_and_50();
+		System::set_block_number(100);
+
+		// `Short` is not supported by the mock runtime
+		assert_eq!(
+			EmaOracle::get_median_price(HDX, DOT, &[LastBlock, Short, Day], SOURCE, 0, 0),
+			Err(OracleError::NotPresent)
+		);
+		assert_eq!(
+			EmaOracle::get_median_price(HDX, DOT, &[LastBlock, TenMinutes, Day], SOURCE, 0, 1_000_000),
+			Err(OracleError::InsufficientLiquidity)
+		);
+	});
+}
+
+#[test]
+fn get_median_price_should_fail_when_no_periods_are_given() {
+	new_test_ext().execute_with(|| {
+		trade_at_blocks_1_and_50();
+		System::set_block_number(100);
+
+		assert_eq!(
+			EmaOracle::get_median_price(HDX, DOT, &[], SOURCE, 0, 0),
+			Err(OracleError::NotPresent)
+		);
+	});
+}
+
+#[test]
+fn oracle_errors_should_convert_to_distinct_pallet_errors() {
+	let into_dispatch_error = |e: OracleError| -> DispatchError { Error::<Test>::from(e).into() };
+
+	assert_eq!(
+		into_dispatch_error(OracleError::NotPresent),
+		Error::<Test>::OracleNotPresent.into()
+	);
+	assert_eq!(
+		into_dispatch_error(OracleError::SameAsset),
+		Error::<Test>::SameAsset.into()
+	);
+	assert_eq!(
+		into_dispatch_error(OracleError::TooYoung),
+		Error::<Test>::OracleTooYoung.into()
+	);
+	assert_eq!(
+		into_dispatch_error(OracleError::InsufficientLiquidity),
+		Error::<Test>::InsufficientOracleLiquidity.into()
+	);
+}
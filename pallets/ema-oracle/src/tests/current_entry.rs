@@ -0,0 +1,98 @@
+// This file is part of pallet-ema-oracle.
+
+// Copyright (C) 2022-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn get_current_entry_should_include_data_of_current_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(OnActivityHandler::<Test>::on_trade(
+			SOURCE, HDX, DOT, 1_000, 500, 2_000, 1_000
+		));
+		EmaOracle::on_finalize(1);
+
+		let expected = AggregatedEntry {
+			price: Price::new(2_000, 1_000),
+			volume: Volume::from_a_in_b_out(1_000, 500),
+			liquidity: Liquidity::new(2_000, 1_000),
+			oracle_age: 0,
+		};
+		assert_eq!(EmaOracle::get_current_entry(HDX, DOT, LastBlock, SOURCE), Ok(expected));
+	});
+}
+
+#[test]
+fn get_current_entry_should_equal_entry_seen_from_next_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(OnActivityHandler::<Test>::on_trade(
+			SOURCE, HDX, DOT, 1_000, 500, 2_000, 1_000
+		));
+		EmaOracle::on_finalize(1);
+		System::set_block_number(50);
+		assert_ok!(OnActivityHandler::<Test>::on_trade(
+			SOURCE, HDX, DOT, 1_000, 600, 3_000, 1_200
+		));
+		EmaOracle::on_finalize(50);
+		System::set_block_number(100);
+
+		let current: Vec<_> = supported_periods()
+			.into_iter()
+			.flat_map(|period| {
+				[
+					EmaOracle::get_current_entry(HDX, DOT, period, SOURCE),
+					EmaOracle::get_current_entry(DOT, HDX, period, SOURCE),
+				]
+			})
+			.collect();
+
+		System::set_block_number(101);
+		let next_block: Vec<_> = supported_periods()
+			.into_iter()
+			.flat_map(|period| {
+				[
+					EmaOracle::get_entry(HDX, DOT, period, SOURCE),
+					EmaOracle::get_entry(DOT, HDX, period, SOURCE),
+				]
+			})
+			.collect();
+
+		assert!(current.iter().all(|entry| entry.is_ok()));
+		assert_eq!(current, next_block);
+	});
+}
+
+#[test]
+fn get_current_entry_should_fail_when_oracle_is_not_present() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			EmaOracle::get_current_entry(HDX, ACA, TenMinutes, SOURCE),
+			Err(OracleError::NotPresent)
+		);
+	});
+}
+
+#[test]
+fn get_current_entry_should_fail_for_same_asset() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			EmaOracle::get_current_entry(HDX, HDX, LastBlock, SOURCE),
+			Err(OracleError::SameAsset)
+		);
+	});
+}
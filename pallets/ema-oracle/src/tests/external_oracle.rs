@@ -0,0 +1,373 @@
+// This file is part of pallet-ema-oracle.
+
+// Copyright (C) 2022-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::sp_runtime::traits::BadOrigin;
+
+const FEEDER: u64 = 1;
+const OTHER: u64 = 2;
+const EXTERNAL_SOURCE: Source = *b"external";
+
+fn whitelisted() -> sp_io::TestExternalities {
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EmaOracle::add_feeder(RuntimeOrigin::root(), EXTERNAL_SOURCE, FEEDER));
+	});
+	ext
+}
+
+#[test]
+fn add_feeder_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(EmaOracle::add_feeder(RuntimeOrigin::root(), EXTERNAL_SOURCE, FEEDER));
+
+		assert!(WhitelistedFeeders::<Test>::contains_key(EXTERNAL_SOURCE, FEEDER));
+		System::assert_last_event(
+			Event::FeederAdded {
+				source: EXTERNAL_SOURCE,
+				feeder: FEEDER,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn add_feeder_should_fail_when_called_by_non_authority() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmaOracle::add_feeder(RuntimeOrigin::signed(FEEDER), EXTERNAL_SOURCE, FEEDER),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn add_feeder_should_fail_when_already_whitelisted() {
+	whitelisted().execute_with(|| {
+		assert_noop!(
+			EmaOracle::add_feeder(RuntimeOrigin::root(), EXTERNAL_SOURCE, FEEDER),
+			Error::<Test>::FeederAlreadyWhitelisted
+		);
+	});
+}
+
+#[test]
+fn remove_feeder_should_work() {
+	whitelisted().execute_with(|| {
+		assert_ok!(EmaOracle::remove_feeder(RuntimeOrigin::root(), EXTERNAL_SOURCE, FEEDER));
+
+		assert!(!WhitelistedFeeders::<Test>::contains_key(EXTERNAL_SOURCE, FEEDER));
+		System::assert_last_event(
+			Event::FeederRemoved {
+				source: EXTERNAL_SOURCE,
+				feeder: FEEDER,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn remove_feeder_should_fail_when_called_by_non_authority() {
+	whitelisted().execute_with(|| {
+		assert_noop!(
+			EmaOracle::remove_feeder(RuntimeOrigin::signed(FEEDER), EXTERNAL_SOURCE, FEEDER),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn remove_feeder_should_fail_when_not_whitelisted() {
+	whitelisted().execute_with(|| {
+		assert_noop!(
+			EmaOracle::remove_feeder(RuntimeOrigin::root(), EXTERNAL_SOURCE, OTHER),
+			Error::<Test>::FeederNotWhitelisted
+		);
+	});
+}
+
+#[test]
+fn update_external_oracle_should_fail_when_feeder_is_not_whitelisted() {
+	whitelisted().execute_with(|| {
+		assert_noop!(
+			EmaOracle::update_external_oracle(
+				RuntimeOrigin::signed(OTHER),
+				EXTERNAL_SOURCE,
+				HDX,
+				DOT,
+				(2_000, 1_000),
+				(2_000, 1_000)
+			),
+			Error::<Test>::FeederNotWhitelisted
+		);
+	});
+}
+
+#[test]
+fn update_external_oracle_should_fail_when_feeder_is_whitelisted_for_other_source() {
+	whitelisted().execute_with(|| {
+		assert_noop!(
+			EmaOracle::update_external_oracle(
+				RuntimeOrigin::signed(FEEDER),
+				SOURCE,
+				HDX,
+				DOT,
+				(2_000, 1_000),
+				(2_000, 1_000)
+			),
+			Error::<Test>::FeederNotWhitelisted
+		);
+	});
+}
+
+#[test]
+fn update_external_oracle_should_fail_when_feeder_was_removed() {
+	whitelisted().execute_with(|| {
+		assert_ok!(EmaOracle::remove_feeder(RuntimeOrigin::root(), EXTERNAL_SOURCE, FEEDER));
+
+		assert_noop!(
+			EmaOracle::update_external_oracle(
+				RuntimeOrigin::signed(FEEDER),
+				EXTERNAL_SOURCE,
+				HDX,
+				DOT,
+				(2_000, 1_000),
+				(2_000, 1_000)
+			),
+			Error::<Test>::FeederNotWhitelisted
+		);
+	});
+}
+
+#[test]
+fn update_external_oracle_should_fail_when_assets_are_same() {
+	whitelisted().execute_with(|| {
+		assert_noop!(
+			EmaOracle::update_external_oracle(
+				RuntimeOrigin::signed(FEEDER),
+				EXTERNAL_SOURCE,
+				HDX,
+				HDX,
+				(2_000, 1_000),
+				(2_000, 1_000)
+			),
+			Error::<Test>::SameAsset
+		);
+	});
+}
+
+#[test]
+fn update_external_oracle_should_fail_when_price_is_zero() {
+	whitelisted().execute_with(|| {
+		assert_noop!(
+			EmaOracle::update_external_oracle(
+				RuntimeOrigin::signed(FEEDER),
+				EXTERNAL_SOURCE,
+				HDX,
+				DOT,
+				(0, 1_000),
+				(2_000, 1_000)
+			),
+			Error::<Test>::PriceIsZero
+		);
+		assert_noop!(
+			EmaOracle::update_external_oracle(
+				RuntimeOrigin::signed(FEEDER),
+				EXTERNAL_SOURCE,
+				HDX,
+				DOT,
+				(2_000, 0),
+				(2_000, 1_000)
+			),
+			Error::<Test>::PriceIsZero
+		);
+	});
+}
+
+#[test]
+fn update_external_oracle_should_update_oracles_of_all_periods() {
+	whitelisted().execute_with(|| {
+		assert_ok!(EmaOracle::update_external_oracle(
+			RuntimeOrigin::signed(FEEDER),
+			EXTERNAL_SOURCE,
+			DOT,
+			HDX,
+			(1_000, 2_000),
+			(0, 0)
+		));
+		EmaOracle::on_finalize(1);
+
+		let expected = OracleEntry {
+			price: Price::new(2_000, 1_000),
+			volume: Volume::default(),
+			liquidity: Liquidity::new(0, 0),
+			updated_at: 1,
+		};
+		for period in supported_periods() {
+			assert_eq!(
+				Oracles::<Test>::get((EXTERNAL_SOURCE, ordered_pair(HDX, DOT), period)),
+				Some((expected.clone(), 1))
+			);
+		}
+
+		System::set_block_number(2);
+		assert_eq!(
+			EmaOracle::get_price(DOT, HDX, LastBlock, EXTERNAL_SOURCE),
+			Ok((Price::new(1_000, 2_000), 0))
+		);
+	});
+}
+
+#[test]
+fn update_external_oracle_should_aggregate_like_local_liquidity_changes() {
+	whitelisted().execute_with(|| {
+		let feed = |block: BlockNumber, price: (Balance, Balance)| {
+			System::set_block_number(block);
+			assert_ok!(EmaOracle::update_external_oracle(
+				RuntimeOrigin::signed(FEEDER),
+				EXTERNAL_SOURCE,
+				HDX,
+				DOT,
+				price,
+				price
+			));
+			assert_ok!(OnActivityHandler::<Test>::on_liquidity_changed(
+				SOURCE, HDX, DOT, 0, 0, price.0, price.1
+			));
+			EmaOracle::on_finalize(block);
+		};
+		feed(1, (2_000, 1_000));
+		feed(50, (3_000, 1_000));
+		feed(80, (2_500, 1_000));
+
+		System::set_block_number(100);
+		for period in supported_periods() {
+			assert_eq!(
+				EmaOracle::get_entry(HDX, DOT, period, EXTERNAL_SOURCE),
+				EmaOracle::get_entry(HDX, DOT, period, SOURCE)
+			);
+		}
+	});
+}
+
+#[test]
+fn update_external_oracle_should_fail_when_source_is_local() {
+	whitelisted().execute_with(|| {
+		assert_ok!(EmaOracle::add_feeder(RuntimeOrigin::root(), SOURCE, FEEDER));
+
+		assert_noop!(
+			EmaOracle::update_external_oracle(
+				RuntimeOrigin::signed(FEEDER),
+				SOURCE,
+				HDX,
+				DOT,
+				(2_000, 1_000),
+				(2_000, 1_000)
+			),
+			Error::<Test>::NotExternalSource
+		);
+	});
+}
+
+#[test]
+fn update_external_oracle_should_fail_when_too_many_external_entries_are_fed() {
+	whitelisted().execute_with(|| {
+		let max_entries = <Test as Config>::MaxUniqueExternalEntries::get();
+		for asset in 0..max_entries {
+			assert_ok!(EmaOracle::update_external_oracle(
+				RuntimeOrigin::signed(FEEDER),
+				EXTERNAL_SOURCE,
+				HDX,
+				DOT + 1 + asset,
+				(2_000, 1_000),
+				(2_000, 1_000)
+			));
+		}
+
+		assert_noop!(
+			EmaOracle::update_external_oracle(
+				RuntimeOrigin::signed(FEEDER),
+				EXTERNAL_SOURCE,
+				HDX,
+				DOT,
+				(2_000, 1_000),
+				(2_000, 1_000)
+			),
+			Error::<Test>::TooManyUniqueEntries
+		);
+		// already accumulated entry can still be updated
+		assert_ok!(EmaOracle::update_external_oracle(
+			RuntimeOrigin::signed(FEEDER),
+			EXTERNAL_SOURCE,
+			HDX,
+			DOT + 1,
+			(3_000, 1_000),
+			(3_000, 1_000)
+		));
+	});
+}
+
+#[test]
+fn external_entries_should_not_take_place_of_local_entries() {
+	whitelisted().execute_with(|| {
+		let max_entries = <Test as Config>::MaxUniqueExternalEntries::get();
+		for asset in 0..max_entries {
+			assert_ok!(EmaOracle::update_external_oracle(
+				RuntimeOrigin::signed(FEEDER),
+				EXTERNAL_SOURCE,
+				HDX,
+				DOT + 1 + asset,
+				(2_000, 1_000),
+				(2_000, 1_000)
+			));
+		}
+
+		let max_local_entries = <Test as Config>::MaxUniqueEntries::get();
+		for asset in 0..max_local_entries {
+			assert_ok!(OnActivityHandler::<Test>::on_trade(
+				SOURCE,
+				HDX,
+				DOT + 1 + asset,
+				1_000,
+				500,
+				2_000,
+				1_000
+			));
+		}
+
+		assert_eq!(Accumulator::<Test>::get().len(), max_local_entries as usize);
+		assert_eq!(ExternalAccumulator::<Test>::get().len(), max_entries as usize);
+
+		EmaOracle::on_finalize(1);
+
+		assert!(Oracles::<Test>::contains_key((
+			SOURCE,
+			ordered_pair(HDX, DOT + 1),
+			LastBlock
+		)));
+		assert!(Oracles::<Test>::contains_key((
+			EXTERNAL_SOURCE,
+			ordered_pair(HDX, DOT + 1),
+			LastBlock
+		)));
+	});
+}
@@ -25,8 +25,9 @@ use frame_support::sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
 };
-use frame_support::traits::{Everything, GenesisBuild};
+use frame_support::traits::{Everything, GenesisBuild, IsInVec};
 use frame_support::BoundedVec;
+use frame_system::EnsureRoot;
 use hydradx_traits::OraclePeriod::{self, *};
 use hydradx_traits::{AssetPairAccountIdFor, Liquidity, Volume};
 use sp_core::H256;
@@ -126,14 +127,18 @@ impl AssetPairAccountIdFor<AssetId, u64> for AssetPairAccountIdTest {
 
 parameter_types! {
 	pub SupportedPeriods: BoundedVec<OraclePeriod, ConstU32<MAX_PERIODS>> = bounded_vec![LastBlock, TenMinutes, Day, Week];
+	pub LocalSources: Vec<Source> = vec![super::SOURCE];
 }
 
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
 	type BlockNumberProvider = System;
 	type SupportedPeriods = SupportedPeriods;
 	type MaxUniqueEntries = ConstU32<45>;
+	type MaxUniqueExternalEntries = ConstU32<5>;
+	type LocalSources = IsInVec<LocalSources>;
 }
 
 pub type InitialDataEntry = (Source, (AssetId, AssetId), Price, Liquidity<Balance>);
@@ -15,6 +15,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod accumulator_overflow;
+mod checked_queries;
+mod current_entry;
+mod external_oracle;
 mod invariants;
 mod mock;
 
@@ -280,39 +284,6 @@ fn on_trade_should_exclude_zero_values() {
 	});
 }
 
-#[test]
-fn on_entry_should_error_on_accumulator_overflow() {
-	new_test_ext().execute_with(|| {
-		let max_entries = <<Test as crate::Config>::MaxUniqueEntries as Get<u32>>::get();
-		// let's fill the accumulator
-		for i in 0..max_entries {
-			assert_ok!(OnActivityHandler::<Test>::on_trade(
-				SOURCE,
-				i,
-				i + 1,
-				1_000,
-				1_000,
-				2_000,
-				2_000,
-			));
-		}
-		// on_trade should fail once the accumulator is full
-		assert_noop!(
-			OnActivityHandler::<Test>::on_trade(
-				SOURCE,
-				2 * max_entries,
-				2 * max_entries + 1,
-				1_000,
-				1_000,
-				2_000,
-				2_000
-			)
-			.map_err(|(_w, e)| e),
-			Error::<Test>::TooManyUniqueEntries
-		);
-	});
-}
-
 #[test]
 fn volume_normalization_should_factor_in_asset_order() {
 	assert_ne!(
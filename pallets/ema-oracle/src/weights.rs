@@ -52,6 +52,9 @@ pub trait WeightInfo {
 	fn on_trade_multiple_tokens(b: u32) -> Weight;
 	fn on_liquidity_changed_multiple_tokens(b: u32) -> Weight;
 	fn get_entry() -> Weight;
+	fn add_feeder() -> Weight;
+	fn remove_feeder() -> Weight;
+	fn update_external_oracle() -> Weight;
 }
 
 pub struct BasiliskWeight<T>(PhantomData<T>);
@@ -83,6 +86,21 @@ impl<T: frame_system::Config> WeightInfo for BasiliskWeight<T> {
 	fn get_entry() -> Weight {
 		Weight::from_ref_time(23_575_000 as u64).saturating_add(T::DbWeight::get().reads(2 as u64))
 	}
+	fn add_feeder() -> Weight {
+		Weight::from_ref_time(19_812_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn remove_feeder() -> Weight {
+		Weight::from_ref_time(20_346_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn update_external_oracle() -> Weight {
+		Weight::from_ref_time(38_924_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -113,4 +131,19 @@ impl WeightInfo for () {
 	fn get_entry() -> Weight {
 		Weight::from_ref_time(23_575_000 as u64).saturating_add(RocksDbWeight::get().reads(2 as u64))
 	}
+	fn add_feeder() -> Weight {
+		Weight::from_ref_time(19_812_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn remove_feeder() -> Weight {
+		Weight::from_ref_time(20_346_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn update_external_oracle() -> Weight {
+		Weight::from_ref_time(38_924_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }
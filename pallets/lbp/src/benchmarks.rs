@@ -0,0 +1,182 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+use sp_std::vec;
+
+const SEED: u32 = 1;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+const INITIAL_WEIGHT: LBPWeight = MAX_WEIGHT / 5 * 4;
+const FINAL_WEIGHT: LBPWeight = MAX_WEIGHT / 5;
+const FEE: (u32, u32) = (2, 1_000);
+
+fn funded_account<T: Config>(name: &'static str, index: u32, assets: &[AssetId]) -> T::AccountId {
+	let caller: T::AccountId = account(name, index, SEED);
+
+	for asset in assets.iter() {
+		T::Currency::update_balance(*asset, &caller, 1_000_000_000_000_000i128).unwrap();
+	}
+
+	caller
+}
+
+fn register_assets<T: Config>() -> Result<(AssetId, AssetId), DispatchError> {
+	let asset_a = T::AssetRegistry::create_asset(&b"TKNA".to_vec(), 1u128)?;
+	let asset_b = T::AssetRegistry::create_asset(&b"TKNB".to_vec(), 1u128)?;
+
+	Ok((asset_a, asset_b))
+}
+
+fn create_pool<T: Config>(
+	owner: T::AccountId,
+	asset_a: AssetId,
+	asset_b: AssetId,
+	fee_collector: T::AccountId,
+) -> Result<T::AccountId, DispatchError> {
+	let successful_origin = T::CreatePoolOrigin::try_successful_origin().unwrap();
+	crate::Pallet::<T>::create_pool(
+		successful_origin,
+		owner,
+		asset_a,
+		100 * ONE,
+		asset_b,
+		200 * ONE,
+		INITIAL_WEIGHT,
+		FINAL_WEIGHT,
+		WeightCurveType::Linear,
+		FEE,
+		fee_collector,
+		0,
+	)?;
+
+	Ok(crate::Pallet::<T>::get_pair_id(asset_a, asset_b))
+}
+
+fn start_sale<T: Config>(owner: T::AccountId, pool_id: T::AccountId) -> DispatchResult {
+	crate::Pallet::<T>::update_pool_data(
+		RawOrigin::Signed(owner).into(),
+		pool_id,
+		None,
+		Some(10u32.into()),
+		Some(40u32.into()),
+		None,
+		None,
+		None,
+		None,
+		None,
+	)?;
+
+	frame_system::Pallet::<T>::set_block_number(15u32.into());
+
+	Ok(())
+}
+
+benchmarks! {
+	create_pool {
+		let (asset_a, asset_b) = register_assets::<T>()?;
+
+		let owner = funded_account::<T>("owner", 0, &[asset_a, asset_b]);
+		let fee_collector: T::AccountId = account("collector", 1, SEED);
+
+		let successful_origin = T::CreatePoolOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, owner.clone(), asset_a, 100 * ONE, asset_b, 200 * ONE, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, FEE, fee_collector, 0)
+	verify {
+		assert!(PoolData::<T>::contains_key(crate::Pallet::<T>::get_pair_id(asset_a, asset_b)));
+	}
+
+	update_pool_data {
+		let (asset_a, asset_b) = register_assets::<T>()?;
+
+		let owner = funded_account::<T>("owner", 0, &[asset_a, asset_b]);
+		let new_owner: T::AccountId = account("new_owner", 2, SEED);
+		let fee_collector: T::AccountId = account("collector", 1, SEED);
+
+		let pool_id = create_pool::<T>(owner.clone(), asset_a, asset_b, fee_collector.clone())?;
+	}: _(RawOrigin::Signed(owner), pool_id.clone(), Some(new_owner.clone()), Some(10u32.into()), Some(40u32.into()), Some(INITIAL_WEIGHT), Some(FINAL_WEIGHT), Some(FEE), Some(fee_collector), Some(ONE))
+	verify {
+		assert_eq!(PoolData::<T>::get(&pool_id).unwrap().owner, new_owner);
+	}
+
+	add_liquidity {
+		let (asset_a, asset_b) = register_assets::<T>()?;
+
+		let owner = funded_account::<T>("owner", 0, &[asset_a, asset_b]);
+		let fee_collector: T::AccountId = account("collector", 1, SEED);
+
+		let pool_id = create_pool::<T>(owner.clone(), asset_a, asset_b, fee_collector)?;
+	}: _(RawOrigin::Signed(owner), (asset_a, 10 * ONE), (asset_b, 20 * ONE))
+	verify {
+		assert_eq!(T::Currency::free_balance(asset_a, &pool_id), 110 * ONE);
+		assert_eq!(T::Currency::free_balance(asset_b, &pool_id), 220 * ONE);
+	}
+
+	remove_liquidity {
+		let (asset_a, asset_b) = register_assets::<T>()?;
+
+		let owner = funded_account::<T>("owner", 0, &[asset_a, asset_b]);
+		let fee_collector: T::AccountId = account("collector", 1, SEED);
+
+		let pool_id = create_pool::<T>(owner.clone(), asset_a, asset_b, fee_collector)?;
+	}: _(RawOrigin::Signed(owner.clone()), pool_id.clone())
+	verify {
+		assert!(!PoolData::<T>::contains_key(&pool_id));
+		assert_eq!(T::Currency::free_balance(asset_a, &owner), 1_000 * ONE);
+	}
+
+	sell {
+		let (asset_a, asset_b) = register_assets::<T>()?;
+
+		let owner = funded_account::<T>("owner", 0, &[asset_a, asset_b]);
+		let caller = funded_account::<T>("caller", 2, &[asset_a, asset_b]);
+		let fee_collector = funded_account::<T>("collector", 1, &[asset_a]);
+
+		let pool_id = create_pool::<T>(owner.clone(), asset_a, asset_b, fee_collector.clone())?;
+		start_sale::<T>(owner, pool_id)?;
+
+		let amount: Balance = ONE;
+		let min_bought: Balance = 1_000;
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, amount, min_bought)
+	verify {
+		assert_eq!(T::Currency::free_balance(asset_a, &caller), 999 * ONE);
+		assert!(T::Currency::free_balance(asset_a, &fee_collector) > 1_000 * ONE);
+	}
+
+	buy {
+		let (asset_a, asset_b) = register_assets::<T>()?;
+
+		let owner = funded_account::<T>("owner", 0, &[asset_a, asset_b]);
+		let caller = funded_account::<T>("caller", 2, &[asset_a, asset_b]);
+		let fee_collector = funded_account::<T>("collector", 1, &[asset_a]);
+
+		let pool_id = create_pool::<T>(owner.clone(), asset_a, asset_b, fee_collector.clone())?;
+		start_sale::<T>(owner, pool_id)?;
+
+		let amount: Balance = ONE;
+		let max_sold: Balance = 10 * ONE;
+	}: _(RawOrigin::Signed(caller.clone()), asset_b, asset_a, amount, max_sold)
+	verify {
+		assert_eq!(T::Currency::free_balance(asset_b, &caller), 1_001 * ONE);
+		assert!(T::Currency::free_balance(asset_a, &fee_collector) > 1_000 * ONE);
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
+}
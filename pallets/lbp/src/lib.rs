@@ -0,0 +1,960 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # LBP pallet
+//!
+//! Liquidity Bootstrapping Pool implementation.
+//!
+//! ## Overview
+//!
+//! LBP is a time-bounded pool of two assets used for token launches. Weights of the pool assets change
+//! linearly between the `start` and `end` block of the sale, which gradually lowers the price of the sold asset
+//! unless there is demand for it.
+//!
+//! Pool is created by `CreatePoolOrigin` on behalf of the pool owner, who provides the initial liquidity.
+//! Only the owner can update the pool parameters and add or remove liquidity, and only while the sale is not running.
+//!
+//! The first asset of the pool is the accumulated asset. Trade fees are always paid in the accumulated asset
+//! and transferred to the `fee_collector`. Until the pool pays `repay_target` of the accumulated asset in fees,
+//! the higher `RepayFee` is charged instead of the pool fee.
+//!
+//! Trades are reported to `AMMHandler` (e.g. the EMA oracle).
+//!
+//! ## Interface
+//!
+//! ### Dispatchable functions
+//!
+//! * `create_pool` - create new pool with initial liquidity.
+//! * `update_pool_data` - update pool parameters before the sale starts.
+//! * `add_liquidity` - add liquidity to a pool before the sale starts.
+//! * `remove_liquidity` - remove all liquidity and destroy the pool.
+//! * `sell` - sell an asset to a pool.
+//! * `buy` - buy an asset from a pool.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+#![allow(clippy::too_many_arguments)]
+
+use frame_support::sp_runtime::{
+	traits::{BlockNumberProvider, Zero},
+	DispatchError, SaturatedConversion,
+};
+use frame_support::{dispatch::DispatchResult, ensure, traits::Get, transactional};
+use frame_system::ensure_signed;
+use hydra_dx_math::lbp;
+use hydradx_traits::{AssetPairAccountIdFor, OnLiquidityChangedHandler, OnTradeHandler, Registry, Source};
+use orml_traits::{MultiCurrency, MultiCurrencyExtended};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarks;
+
+mod trade_execution;
+pub mod types;
+pub mod weights;
+
+pub use trade_execution::*;
+pub use types::*;
+use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+/// LBP pool account id identifier.
+pub const POOL_IDENTIFIER: &str = "lbp";
+
+type PoolOf<T> = Pool<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber>;
+
+/// Result of a trade calculation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TradeAmounts {
+	/// Amount transferred from the trader to the pool.
+	pub amount_in: Balance,
+	/// Amount transferred from the pool to the trader.
+	pub amount_out: Balance,
+	/// Fee paid in the accumulated asset to the fee collector.
+	pub fee: Balance,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(crate) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Multi currency for transfers.
+		type Currency: MultiCurrencyExtended<Self::AccountId, CurrencyId = AssetId, Balance = Balance, Amount = Amount>;
+
+		/// Asset registry - pool assets have to be registered.
+		type AssetRegistry: Registry<AssetId, Vec<u8>, Balance, DispatchError>;
+
+		/// Origin which can create pools.
+		type CreatePoolOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Account id constructor - pool accounts are derived from pool assets.
+		type AssetPairAccountId: AssetPairAccountIdFor<AssetId, Self::AccountId>;
+
+		/// Block number provider.
+		type BlockNumberProvider: BlockNumberProvider<BlockNumber = Self::BlockNumber>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+
+		/// Minimum trading limit.
+		#[pallet::constant]
+		type MinTradingLimit: Get<Balance>;
+
+		/// Minimum pool liquidity.
+		#[pallet::constant]
+		type MinPoolLiquidity: Get<Balance>;
+
+		/// Max fraction of pool to sell in single transaction.
+		#[pallet::constant]
+		type MaxInRatio: Get<u128>;
+
+		/// Max fraction of pool to buy in single transaction.
+		#[pallet::constant]
+		type MaxOutRatio: Get<u128>;
+
+		/// Trade fee charged until fees paid by a pool reach the repay target of the pool.
+		#[pallet::constant]
+		type RepayFee: Get<(u32, u32)>;
+
+		/// AMM handlers - notified about trades and liquidity changes.
+		type AMMHandler: OnTradeHandler<AssetId, Balance> + OnLiquidityChangedHandler<AssetId, Balance>;
+
+		/// Source identifier of the pallet used when reporting to `AMMHandler`.
+		#[pallet::constant]
+		type OracleSource: Get<Source>;
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// It is not allowed to create a pool between same assets.
+		CannotCreatePoolWithSameAssets,
+
+		/// Asset is not registered in the asset registry.
+		AssetNotRegistered,
+
+		/// Account is not the owner of the pool.
+		NotOwner,
+
+		/// Sale has already started.
+		SaleStarted,
+
+		/// Sale is still in progress.
+		SaleNotEnded,
+
+		/// Sale is not running.
+		SaleIsNotRunning,
+
+		/// Liquidity being added should not be zero.
+		CannotAddZeroLiquidity,
+
+		/// Asset balance is not sufficient.
+		InsufficientAssetBalance,
+
+		/// Pool does not exist.
+		PoolNotFound,
+
+		/// Pool for given assets already exists.
+		PoolAlreadyExists,
+
+		/// Invalid block range - start has to be in the future and before the end.
+		InvalidBlockRange,
+
+		/// Calculation of the weights failed.
+		WeightCalculationError,
+
+		/// Weights have to be in the range of `MIN_WEIGHT` and `MAX_WEIGHT`.
+		InvalidWeight,
+
+		/// Trade amount is zero.
+		ZeroAmount,
+
+		/// Amount is less than min trading limit.
+		InsufficientTradingAmount,
+
+		/// Initial liquidity has not reached the required minimum.
+		InsufficientLiquidity,
+
+		/// Max fraction of pool to sell in single transaction has been exceeded.
+		MaxInRatioExceeded,
+
+		/// Max fraction of pool to buy in single transaction has been exceeded.
+		MaxOutRatioExceeded,
+
+		/// Invalid fee amount.
+		FeeAmountInvalid,
+
+		/// Trading limit has been reached.
+		TradingLimitReached,
+
+		/// Asset is not in the pool.
+		AssetNotInPool,
+
+		/// Nothing to update.
+		NothingToUpdate,
+
+		/// An unexpected integer overflow occurred.
+		Overflow,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Pool was created.
+		PoolCreated { pool: T::AccountId, data: PoolOf<T> },
+
+		/// Pool data were updated.
+		PoolUpdated { pool: T::AccountId, data: PoolOf<T> },
+
+		/// New liquidity was provided to the pool.
+		LiquidityAdded {
+			who: T::AccountId,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			amount_a: Balance,
+			amount_b: Balance,
+		},
+
+		/// Liquidity was removed from the pool and the pool was destroyed.
+		LiquidityRemoved {
+			who: T::AccountId,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			amount_a: Balance,
+			amount_b: Balance,
+		},
+
+		/// Sale executed.
+		SellExecuted {
+			who: T::AccountId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+			sale_price: Balance,
+			fee_asset: AssetId,
+			fee_amount: Balance,
+		},
+
+		/// Purchase executed.
+		BuyExecuted {
+			who: T::AccountId,
+			asset_out: AssetId,
+			asset_in: AssetId,
+			amount: Balance,
+			buy_price: Balance,
+			fee_asset: AssetId,
+			fee_amount: Balance,
+		},
+	}
+
+	/// Details of a pool.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_data)]
+	pub type PoolData<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, PoolOf<T>, OptionQuery>;
+
+	/// Fees paid by a pool to its fee collector. Repay fee is charged until the repay target is reached.
+	#[pallet::storage]
+	#[pallet::getter(fn repaid_fees)]
+	pub type RepaidFees<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a new liquidity bootstrapping pool for given asset pair.
+		///
+		/// Initial liquidity is transferred from `pool_owner`. The sale does not start until the pool owner
+		/// sets the `start` and `end` blocks via `update_pool_data`.
+		///
+		/// Parameters:
+		/// - `origin`: `CreatePoolOrigin`
+		/// - `pool_owner`: owner of the pool, provides the initial liquidity
+		/// - `asset_a`: accumulated asset
+		/// - `asset_a_amount`: initial liquidity of `asset_a`
+		/// - `asset_b`: sold asset
+		/// - `asset_b_amount`: initial liquidity of `asset_b`
+		/// - `initial_weight`: weight of `asset_a` at the start of the sale
+		/// - `final_weight`: weight of `asset_a` at the end of the sale
+		/// - `weight_curve`: curve along which the weights change
+		/// - `fee`: trade fee charged once the repay target is reached
+		/// - `fee_collector`: account which receives the fees
+		/// - `repay_target`: amount of `asset_a` to be collected before `fee` applies
+		///
+		/// Emits `PoolCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool())]
+		#[transactional]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			pool_owner: T::AccountId,
+			asset_a: AssetId,
+			asset_a_amount: Balance,
+			asset_b: AssetId,
+			asset_b_amount: Balance,
+			initial_weight: LBPWeight,
+			final_weight: LBPWeight,
+			weight_curve: WeightCurveType,
+			fee: (u32, u32),
+			fee_collector: T::AccountId,
+			repay_target: Balance,
+		) -> DispatchResult {
+			T::CreatePoolOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_a != asset_b, Error::<T>::CannotCreatePoolWithSameAssets);
+
+			ensure!(
+				T::AssetRegistry::exists(asset_a) && T::AssetRegistry::exists(asset_b),
+				Error::<T>::AssetNotRegistered
+			);
+
+			ensure!(
+				!asset_a_amount.is_zero() && !asset_b_amount.is_zero(),
+				Error::<T>::CannotAddZeroLiquidity
+			);
+
+			ensure!(
+				asset_a_amount >= T::MinPoolLiquidity::get() && asset_b_amount >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientLiquidity
+			);
+
+			let pool_id = Self::get_pair_id(asset_a, asset_b);
+			ensure!(!PoolData::<T>::contains_key(&pool_id), Error::<T>::PoolAlreadyExists);
+
+			let pool_data = Pool {
+				owner: pool_owner.clone(),
+				start: None,
+				end: None,
+				assets: (asset_a, asset_b),
+				initial_weight,
+				final_weight,
+				weight_curve,
+				fee,
+				fee_collector,
+				repay_target,
+			};
+
+			Self::validate_pool_data(&pool_data)?;
+
+			ensure!(
+				T::Currency::free_balance(asset_a, &pool_owner) >= asset_a_amount,
+				Error::<T>::InsufficientAssetBalance
+			);
+			ensure!(
+				T::Currency::free_balance(asset_b, &pool_owner) >= asset_b_amount,
+				Error::<T>::InsufficientAssetBalance
+			);
+
+			PoolData::<T>::insert(&pool_id, &pool_data);
+
+			T::Currency::transfer(asset_a, &pool_owner, &pool_id, asset_a_amount)?;
+			T::Currency::transfer(asset_b, &pool_owner, &pool_id, asset_b_amount)?;
+
+			Self::deposit_event(Event::PoolCreated {
+				pool: pool_id,
+				data: pool_data,
+			});
+
+			Ok(())
+		}
+
+		/// Update pool data of a pool.
+		///
+		/// Only the pool owner can update the pool and only before the sale starts.
+		/// Sale starts once both `start` and `end` are set and `start` block is reached.
+		///
+		/// Parameters:
+		/// - `origin`: pool owner
+		/// - `pool_id`: pool account
+		/// - `pool_owner`: new owner of the pool
+		/// - `start`: block at which the sale starts, has to be in the future
+		/// - `end`: block at which the sale ends, has to be after `start`
+		/// - `initial_weight`: weight of the accumulated asset at the start of the sale
+		/// - `final_weight`: weight of the accumulated asset at the end of the sale
+		/// - `fee`: trade fee charged once the repay target is reached
+		/// - `fee_collector`: account which receives the fees
+		/// - `repay_target`: amount of accumulated asset to be collected before `fee` applies
+		///
+		/// Emits `PoolUpdated` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_pool_data())]
+		#[transactional]
+		pub fn update_pool_data(
+			origin: OriginFor<T>,
+			pool_id: T::AccountId,
+			pool_owner: Option<T::AccountId>,
+			start: Option<T::BlockNumber>,
+			end: Option<T::BlockNumber>,
+			initial_weight: Option<LBPWeight>,
+			final_weight: Option<LBPWeight>,
+			fee: Option<(u32, u32)>,
+			fee_collector: Option<T::AccountId>,
+			repay_target: Option<Balance>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				pool_owner.is_some()
+					|| start.is_some() || end.is_some()
+					|| initial_weight.is_some()
+					|| final_weight.is_some()
+					|| fee.is_some() || fee_collector.is_some()
+					|| repay_target.is_some(),
+				Error::<T>::NothingToUpdate
+			);
+
+			PoolData::<T>::try_mutate(&pool_id, |maybe_pool| -> DispatchResult {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+
+				ensure!(who == pool.owner, Error::<T>::NotOwner);
+
+				ensure!(!Self::has_pool_started(pool), Error::<T>::SaleStarted);
+
+				pool.owner = pool_owner.unwrap_or_else(|| pool.owner.clone());
+				pool.start = start.or(pool.start);
+				pool.end = end.or(pool.end);
+				pool.initial_weight = initial_weight.unwrap_or(pool.initial_weight);
+				pool.final_weight = final_weight.unwrap_or(pool.final_weight);
+				pool.fee = fee.unwrap_or(pool.fee);
+				pool.fee_collector = fee_collector.unwrap_or_else(|| pool.fee_collector.clone());
+				pool.repay_target = repay_target.unwrap_or(pool.repay_target);
+
+				Self::validate_pool_data(pool)?;
+
+				Self::deposit_event(Event::PoolUpdated {
+					pool: pool_id.clone(),
+					data: pool.clone(),
+				});
+
+				Ok(())
+			})
+		}
+
+		/// Add liquidity to a pool.
+		///
+		/// Only the pool owner can add liquidity and only before the sale starts.
+		/// Liquidity can be added in one or both assets of the pool.
+		///
+		/// Parameters:
+		/// - `origin`: pool owner
+		/// - `amount_a`: asset id and amount of the first asset
+		/// - `amount_b`: asset id and amount of the second asset
+		///
+		/// Emits `LiquidityAdded` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
+			.saturating_add(T::AMMHandler::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn add_liquidity(
+			origin: OriginFor<T>,
+			amount_a: (AssetId, Balance),
+			amount_b: (AssetId, Balance),
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (asset_a, amount_a) = amount_a;
+			let (asset_b, amount_b) = amount_b;
+
+			let pool_id = Self::get_pair_id(asset_a, asset_b);
+			let pool = Self::pool_data(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			ensure!(who == pool.owner, Error::<T>::NotOwner);
+
+			ensure!(!Self::has_pool_started(&pool), Error::<T>::SaleStarted);
+
+			ensure!(
+				!amount_a.is_zero() || !amount_b.is_zero(),
+				Error::<T>::CannotAddZeroLiquidity
+			);
+
+			ensure!(
+				T::Currency::free_balance(asset_a, &who) >= amount_a,
+				Error::<T>::InsufficientAssetBalance
+			);
+			ensure!(
+				T::Currency::free_balance(asset_b, &who) >= amount_b,
+				Error::<T>::InsufficientAssetBalance
+			);
+
+			T::Currency::transfer(asset_a, &who, &pool_id, amount_a)?;
+			T::Currency::transfer(asset_b, &who, &pool_id, amount_b)?;
+
+			let liquidity_a = T::Currency::total_balance(asset_a, &pool_id);
+			let liquidity_b = T::Currency::total_balance(asset_b, &pool_id);
+			T::AMMHandler::on_liquidity_changed(
+				T::OracleSource::get(),
+				asset_a,
+				asset_b,
+				amount_a,
+				amount_b,
+				liquidity_a,
+				liquidity_b,
+			)
+			.map_err(|(_w, e)| e)?;
+
+			Self::deposit_event(Event::LiquidityAdded {
+				who,
+				asset_a,
+				asset_b,
+				amount_a,
+				amount_b,
+			});
+
+			Ok(())
+		}
+
+		/// Remove all liquidity from a pool and destroy it.
+		///
+		/// Only the pool owner can remove liquidity. Liquidity can be removed before the sale starts
+		/// or after the sale ends.
+		///
+		/// Parameters:
+		/// - `origin`: pool owner
+		/// - `pool_id`: pool account
+		///
+		/// Emits `LiquidityRemoved` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity()
+			.saturating_add(T::AMMHandler::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn remove_liquidity(origin: OriginFor<T>, pool_id: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool = Self::pool_data(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			ensure!(who == pool.owner, Error::<T>::NotOwner);
+
+			ensure!(
+				!Self::has_pool_started(&pool) || Self::has_pool_ended(&pool),
+				Error::<T>::SaleNotEnded
+			);
+
+			let (asset_a, asset_b) = pool.assets;
+
+			let amount_a = T::Currency::free_balance(asset_a, &pool_id);
+			let amount_b = T::Currency::free_balance(asset_b, &pool_id);
+
+			T::Currency::transfer(asset_a, &pool_id, &who, amount_a)?;
+			T::Currency::transfer(asset_b, &pool_id, &who, amount_b)?;
+
+			PoolData::<T>::remove(&pool_id);
+			RepaidFees::<T>::remove(&pool_id);
+
+			T::AMMHandler::on_liquidity_changed(
+				T::OracleSource::get(),
+				asset_a,
+				asset_b,
+				amount_a,
+				amount_b,
+				Balance::zero(),
+				Balance::zero(),
+			)
+			.map_err(|(_w, e)| e)?;
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				who,
+				asset_a,
+				asset_b,
+				amount_a,
+				amount_b,
+			});
+
+			Ok(())
+		}
+
+		/// Trade `asset_in` for `asset_out`.
+		///
+		/// Executes a swap of `asset_in` for `asset_out`. Price is determined by the pool reserves
+		/// and the current weights. Trading is allowed only while the sale is running.
+		///
+		/// Parameters:
+		/// - `origin`: trader
+		/// - `asset_in`: asset to sell
+		/// - `asset_out`: asset to buy
+		/// - `amount`: amount of `asset_in` to sell
+		/// - `min_bought`: minimum amount of `asset_out` to receive
+		///
+		/// Emits `SellExecuted` when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::sell()
+			.saturating_add(T::AMMHandler::on_trade_weight()))]
+		#[transactional]
+		pub fn sell(
+			origin: OriginFor<T>,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+			min_bought: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (pool_id, pool) = Self::get_running_pool(asset_in, asset_out)?;
+
+			ensure!(
+				T::Currency::free_balance(asset_in, &who) >= amount,
+				Error::<T>::InsufficientAssetBalance
+			);
+
+			let trade = Self::calculate_sell_amounts(&pool_id, &pool, asset_in, asset_out, amount)?;
+
+			ensure!(trade.amount_out >= min_bought, Error::<T>::TradingLimitReached);
+
+			Self::execute_trade(&who, &pool_id, &pool, asset_in, asset_out, trade)?;
+
+			Self::deposit_event(Event::SellExecuted {
+				who,
+				asset_in,
+				asset_out,
+				amount,
+				sale_price: trade.amount_out,
+				fee_asset: pool.assets.0,
+				fee_amount: trade.fee,
+			});
+
+			Ok(())
+		}
+
+		/// Trade `asset_in` for exact amount of `asset_out`.
+		///
+		/// Executes a swap of `asset_in` for `asset_out`. Price is determined by the pool reserves
+		/// and the current weights. Trading is allowed only while the sale is running.
+		///
+		/// Parameters:
+		/// - `origin`: trader
+		/// - `asset_out`: asset to buy
+		/// - `asset_in`: asset to sell
+		/// - `amount`: amount of `asset_out` to buy
+		/// - `max_sold`: maximum amount of `asset_in` to pay
+		///
+		/// Emits `BuyExecuted` when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy()
+			.saturating_add(T::AMMHandler::on_trade_weight()))]
+		#[transactional]
+		pub fn buy(
+			origin: OriginFor<T>,
+			asset_out: AssetId,
+			asset_in: AssetId,
+			amount: Balance,
+			max_sold: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (pool_id, pool) = Self::get_running_pool(asset_in, asset_out)?;
+
+			let trade = Self::calculate_buy_amounts(&pool_id, &pool, asset_in, asset_out, amount)?;
+
+			ensure!(trade.amount_in <= max_sold, Error::<T>::TradingLimitReached);
+
+			ensure!(
+				T::Currency::free_balance(asset_in, &who) >= trade.amount_in,
+				Error::<T>::InsufficientAssetBalance
+			);
+
+			Self::execute_trade(&who, &pool_id, &pool, asset_in, asset_out, trade)?;
+
+			Self::deposit_event(Event::BuyExecuted {
+				who,
+				asset_out,
+				asset_in,
+				amount,
+				buy_price: trade.amount_in,
+				fee_asset: pool.assets.0,
+				fee_amount: trade.fee,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Return account of the pool of given asset pair.
+	pub fn get_pair_id(asset_a: AssetId, asset_b: AssetId) -> T::AccountId {
+		T::AssetPairAccountId::from_assets(asset_a, asset_b, POOL_IDENTIFIER)
+	}
+
+	/// Return true if both `start` and `end` are set and the `start` block has been reached.
+	pub fn has_pool_started(pool: &PoolOf<T>) -> bool {
+		match (pool.start, pool.end) {
+			(Some(start), Some(_)) => start <= T::BlockNumberProvider::current_block_number(),
+			_ => false,
+		}
+	}
+
+	/// Return true if the `end` block of the sale has passed.
+	pub fn has_pool_ended(pool: &PoolOf<T>) -> bool {
+		match pool.end {
+			Some(end) => end < T::BlockNumberProvider::current_block_number(),
+			None => false,
+		}
+	}
+
+	/// Return true if trading is allowed in the pool.
+	pub fn is_pool_running(pool: &PoolOf<T>) -> bool {
+		Self::has_pool_started(pool) && !Self::has_pool_ended(pool)
+	}
+
+	fn validate_pool_data(pool: &PoolOf<T>) -> DispatchResult {
+		let now = T::BlockNumberProvider::current_block_number();
+
+		match (pool.start, pool.end) {
+			(Some(start), Some(end)) => {
+				ensure!(now < start && start < end, Error::<T>::InvalidBlockRange);
+			}
+			(None, None) => {}
+			_ => return Err(Error::<T>::InvalidBlockRange.into()),
+		}
+
+		ensure!(
+			(MIN_WEIGHT..MAX_WEIGHT).contains(&pool.initial_weight)
+				&& (MIN_WEIGHT..MAX_WEIGHT).contains(&pool.final_weight),
+			Error::<T>::InvalidWeight
+		);
+
+		ensure!(!pool.fee.1.is_zero(), Error::<T>::FeeAmountInvalid);
+
+		Ok(())
+	}
+
+	pub(crate) fn get_running_pool(
+		asset_in: AssetId,
+		asset_out: AssetId,
+	) -> Result<(T::AccountId, PoolOf<T>), DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::AssetNotInPool);
+
+		let pool_id = Self::get_pair_id(asset_in, asset_out);
+		let pool = Self::pool_data(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+		ensure!(Self::is_pool_running(&pool), Error::<T>::SaleIsNotRunning);
+
+		Ok((pool_id, pool))
+	}
+
+	/// Return weights of the pool assets at the current block.
+	pub(crate) fn get_weights(pool: &PoolOf<T>) -> Result<(LBPWeight, LBPWeight), DispatchError> {
+		let (start, end) = match (pool.start, pool.end) {
+			(Some(start), Some(end)) => (start.saturated_into::<u32>(), end.saturated_into::<u32>()),
+			_ => return Err(Error::<T>::SaleIsNotRunning.into()),
+		};
+		let now = T::BlockNumberProvider::current_block_number().saturated_into::<u32>();
+
+		let weight_a = match pool.weight_curve {
+			WeightCurveType::Linear => {
+				lbp::calculate_linear_weights(start, end, pool.initial_weight, pool.final_weight, now)
+					.map_err(|_| Error::<T>::WeightCalculationError)?
+			}
+		};
+
+		let weight_b = MAX_WEIGHT.saturating_sub(weight_a);
+
+		Ok((weight_a, weight_b))
+	}
+
+	/// Return fee rate which applies to the pool. Repay fee applies until the repay target is reached.
+	fn get_fee_rate(pool_id: &T::AccountId, pool: &PoolOf<T>) -> (u32, u32) {
+		if Self::repaid_fees(pool_id) < pool.repay_target {
+			T::RepayFee::get()
+		} else {
+			pool.fee
+		}
+	}
+
+	fn calculate_fee(pool_id: &T::AccountId, pool: &PoolOf<T>, amount: Balance) -> Result<Balance, DispatchError> {
+		let fee = hydra_dx_math::fee::calculate_pool_trade_fee(amount, Self::get_fee_rate(pool_id, pool))
+			.ok_or(Error::<T>::FeeAmountInvalid)?;
+
+		Ok(fee)
+	}
+
+	fn get_asset_weights(
+		pool: &PoolOf<T>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+	) -> Result<(LBPWeight, LBPWeight), DispatchError> {
+		let (weight_a, weight_b) = Self::get_weights(pool)?;
+
+		if (asset_in, asset_out) == pool.assets {
+			Ok((weight_a, weight_b))
+		} else if (asset_out, asset_in) == pool.assets {
+			Ok((weight_b, weight_a))
+		} else {
+			Err(Error::<T>::AssetNotInPool.into())
+		}
+	}
+
+	/// Calculate amounts of a sell of `amount` of `asset_in`.
+	pub(crate) fn calculate_sell_amounts(
+		pool_id: &T::AccountId,
+		pool: &PoolOf<T>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount: Balance,
+	) -> Result<TradeAmounts, DispatchError> {
+		ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+		ensure!(
+			amount >= T::MinTradingLimit::get(),
+			Error::<T>::InsufficientTradingAmount
+		);
+
+		let (weight_in, weight_out) = Self::get_asset_weights(pool, asset_in, asset_out)?;
+
+		let reserve_in = T::Currency::free_balance(asset_in, pool_id);
+		let reserve_out = T::Currency::free_balance(asset_out, pool_id);
+
+		ensure!(
+			amount <= reserve_in / T::MaxInRatio::get(),
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		let is_accumulated_in = asset_in == pool.assets.0;
+
+		let (amount_to_pool, fee) = if is_accumulated_in {
+			let fee = Self::calculate_fee(pool_id, pool, amount)?;
+			(amount.checked_sub(fee).ok_or(Error::<T>::Overflow)?, fee)
+		} else {
+			(amount, Balance::zero())
+		};
+
+		let amount_out = lbp::calculate_out_given_in(reserve_in, reserve_out, weight_in, weight_out, amount_to_pool)
+			.map_err(|_| Error::<T>::Overflow)?;
+
+		ensure!(
+			amount_out <= reserve_out / T::MaxOutRatio::get(),
+			Error::<T>::MaxOutRatioExceeded
+		);
+
+		let (amount_out, fee) = if is_accumulated_in {
+			(amount_out, fee)
+		} else {
+			let fee = Self::calculate_fee(pool_id, pool, amount_out)?;
+			(amount_out.checked_sub(fee).ok_or(Error::<T>::Overflow)?, fee)
+		};
+
+		Ok(TradeAmounts {
+			amount_in: amount,
+			amount_out,
+			fee,
+		})
+	}
+
+	/// Calculate amounts of a buy of `amount` of `asset_out`.
+	pub(crate) fn calculate_buy_amounts(
+		pool_id: &T::AccountId,
+		pool: &PoolOf<T>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount: Balance,
+	) -> Result<TradeAmounts, DispatchError> {
+		ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+		ensure!(
+			amount >= T::MinTradingLimit::get(),
+			Error::<T>::InsufficientTradingAmount
+		);
+
+		let (weight_in, weight_out) = Self::get_asset_weights(pool, asset_in, asset_out)?;
+
+		let reserve_in = T::Currency::free_balance(asset_in, pool_id);
+		let reserve_out = T::Currency::free_balance(asset_out, pool_id);
+
+		let is_accumulated_out = asset_out == pool.assets.0;
+
+		// Fee in the accumulated asset leaving the pool is paid on top of the bought amount.
+		let (amount_from_pool, fee) = if is_accumulated_out {
+			let fee = Self::calculate_fee(pool_id, pool, amount)?;
+			(amount.checked_add(fee).ok_or(Error::<T>::Overflow)?, fee)
+		} else {
+			(amount, Balance::zero())
+		};
+
+		ensure!(
+			amount_from_pool <= reserve_out / T::MaxOutRatio::get(),
+			Error::<T>::MaxOutRatioExceeded
+		);
+
+		let amount_in = lbp::calculate_in_given_out(reserve_in, reserve_out, weight_in, weight_out, amount_from_pool)
+			.map_err(|_| Error::<T>::Overflow)?;
+
+		ensure!(
+			amount_in <= reserve_in / T::MaxInRatio::get(),
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		let (amount_in, fee) = if is_accumulated_out {
+			(amount_in, fee)
+		} else {
+			let fee = Self::calculate_fee(pool_id, pool, amount_in)?;
+			(amount_in.checked_add(fee).ok_or(Error::<T>::Overflow)?, fee)
+		};
+
+		Ok(TradeAmounts {
+			amount_in,
+			amount_out: amount,
+			fee,
+		})
+	}
+
+	/// Transfer trade amounts and the fee and notify `AMMHandler`.
+	fn execute_trade(
+		who: &T::AccountId,
+		pool_id: &T::AccountId,
+		pool: &PoolOf<T>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		trade: TradeAmounts,
+	) -> DispatchResult {
+		T::Currency::transfer(asset_in, who, pool_id, trade.amount_in)?;
+		T::Currency::transfer(asset_out, pool_id, who, trade.amount_out)?;
+
+		// Fee is always paid by the pool in the accumulated asset.
+		T::Currency::transfer(pool.assets.0, pool_id, &pool.fee_collector, trade.fee)?;
+		RepaidFees::<T>::mutate(pool_id, |repaid| *repaid = repaid.saturating_add(trade.fee));
+
+		let liquidity_in = T::Currency::total_balance(asset_in, pool_id);
+		let liquidity_out = T::Currency::total_balance(asset_out, pool_id);
+
+		let (amount_in, amount_out) = if asset_in == pool.assets.0 {
+			(trade.amount_in.saturating_sub(trade.fee), trade.amount_out)
+		} else {
+			(trade.amount_in, trade.amount_out.saturating_add(trade.fee))
+		};
+
+		T::AMMHandler::on_trade(
+			T::OracleSource::get(),
+			asset_in,
+			asset_out,
+			amount_in,
+			amount_out,
+			liquidity_in,
+			liquidity_out,
+		)
+		.map_err(|(_w, e)| e)?;
+
+		Ok(())
+	}
+}
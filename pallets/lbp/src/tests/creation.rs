@@ -0,0 +1,299 @@
+use super::mock::*;
+use crate::{Error, Event, Pool, PoolData, WeightCurveType};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn default_pool_data() -> Pool<AccountId, u64> {
+	Pool {
+		owner: ALICE,
+		start: None,
+		end: None,
+		assets: (DAI, TKN),
+		initial_weight: INITIAL_WEIGHT,
+		final_weight: FINAL_WEIGHT,
+		weight_curve: WeightCurveType::Linear,
+		fee: DEFAULT_FEE,
+		fee_collector: CHARLIE,
+		repay_target: 0,
+	}
+}
+
+#[test]
+fn create_pool_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool();
+
+		assert_eq!(LBP::get_pair_id(DAI, TKN), POOL);
+		assert_eq!(LBP::get_pair_id(TKN, DAI), POOL);
+		assert_eq!(PoolData::<Test>::get(POOL), Some(default_pool_data()));
+
+		assert_eq!(Tokens::free_balance(DAI, &POOL), 100 * ONE);
+		assert_eq!(Tokens::free_balance(TKN, &POOL), 200 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 900 * ONE);
+		assert_eq!(Tokens::free_balance(TKN, &ALICE), 800 * ONE);
+
+		expect_events(vec![Event::PoolCreated {
+			pool: POOL,
+			data: default_pool_data(),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn create_pool_should_transfer_liquidity_from_pool_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(LBP::create_pool(
+			RuntimeOrigin::root(),
+			BOB,
+			DAI,
+			100 * ONE,
+			TKN,
+			200 * ONE,
+			INITIAL_WEIGHT,
+			FINAL_WEIGHT,
+			WeightCurveType::Linear,
+			DEFAULT_FEE,
+			CHARLIE,
+			0,
+		));
+
+		assert_eq!(LBP::pool_data(POOL).unwrap().owner, BOB);
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 900 * ONE);
+		assert_eq!(Tokens::free_balance(TKN, &BOB), 800 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 1_000 * ONE);
+		assert_eq!(Tokens::free_balance(TKN, &ALICE), 1_000 * ONE);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_origin_is_not_allowed() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LBP::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				ALICE,
+				DAI,
+				100 * ONE,
+				TKN,
+				200 * ONE,
+				INITIAL_WEIGHT,
+				FINAL_WEIGHT,
+				WeightCurveType::Linear,
+				DEFAULT_FEE,
+				CHARLIE,
+				0,
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_assets_are_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LBP::create_pool(
+				RuntimeOrigin::root(),
+				ALICE,
+				DAI,
+				100 * ONE,
+				DAI,
+				200 * ONE,
+				INITIAL_WEIGHT,
+				FINAL_WEIGHT,
+				WeightCurveType::Linear,
+				DEFAULT_FEE,
+				CHARLIE,
+				0,
+			),
+			Error::<Test>::CannotCreatePoolWithSameAssets
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_asset_is_not_registered() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, DAI, 1_000 * ONE), (ALICE, UNREGISTERED, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				LBP::create_pool(
+					RuntimeOrigin::root(),
+					ALICE,
+					DAI,
+					100 * ONE,
+					UNREGISTERED,
+					200 * ONE,
+					INITIAL_WEIGHT,
+					FINAL_WEIGHT,
+					WeightCurveType::Linear,
+					DEFAULT_FEE,
+					CHARLIE,
+					0,
+				),
+				Error::<Test>::AssetNotRegistered
+			);
+		});
+}
+
+#[test]
+fn create_pool_should_fail_when_liquidity_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LBP::create_pool(
+				RuntimeOrigin::root(),
+				ALICE,
+				DAI,
+				0,
+				TKN,
+				200 * ONE,
+				INITIAL_WEIGHT,
+				FINAL_WEIGHT,
+				WeightCurveType::Linear,
+				DEFAULT_FEE,
+				CHARLIE,
+				0,
+			),
+			Error::<Test>::CannotAddZeroLiquidity
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_liquidity_is_below_minimum() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LBP::create_pool(
+				RuntimeOrigin::root(),
+				ALICE,
+				DAI,
+				999,
+				TKN,
+				200 * ONE,
+				INITIAL_WEIGHT,
+				FINAL_WEIGHT,
+				WeightCurveType::Linear,
+				DEFAULT_FEE,
+				CHARLIE,
+				0,
+			),
+			Error::<Test>::InsufficientLiquidity
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_pool_already_exists() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool();
+
+		assert_noop!(
+			LBP::create_pool(
+				RuntimeOrigin::root(),
+				ALICE,
+				TKN,
+				100 * ONE,
+				DAI,
+				200 * ONE,
+				INITIAL_WEIGHT,
+				FINAL_WEIGHT,
+				WeightCurveType::Linear,
+				DEFAULT_FEE,
+				CHARLIE,
+				0,
+			),
+			Error::<Test>::PoolAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_weight_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LBP::create_pool(
+				RuntimeOrigin::root(),
+				ALICE,
+				DAI,
+				100 * ONE,
+				TKN,
+				200 * ONE,
+				0,
+				FINAL_WEIGHT,
+				WeightCurveType::Linear,
+				DEFAULT_FEE,
+				CHARLIE,
+				0,
+			),
+			Error::<Test>::InvalidWeight
+		);
+
+		assert_noop!(
+			LBP::create_pool(
+				RuntimeOrigin::root(),
+				ALICE,
+				DAI,
+				100 * ONE,
+				TKN,
+				200 * ONE,
+				INITIAL_WEIGHT,
+				crate::MAX_WEIGHT,
+				WeightCurveType::Linear,
+				DEFAULT_FEE,
+				CHARLIE,
+				0,
+			),
+			Error::<Test>::InvalidWeight
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_fee_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LBP::create_pool(
+				RuntimeOrigin::root(),
+				ALICE,
+				DAI,
+				100 * ONE,
+				TKN,
+				200 * ONE,
+				INITIAL_WEIGHT,
+				FINAL_WEIGHT,
+				WeightCurveType::Linear,
+				(2, 0),
+				CHARLIE,
+				0,
+			),
+			Error::<Test>::FeeAmountInvalid
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_owner_has_insufficient_balance() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LBP::create_pool(
+				RuntimeOrigin::root(),
+				ALICE,
+				DAI,
+				1_001 * ONE,
+				TKN,
+				200 * ONE,
+				INITIAL_WEIGHT,
+				FINAL_WEIGHT,
+				WeightCurveType::Linear,
+				DEFAULT_FEE,
+				CHARLIE,
+				0,
+			),
+			Error::<Test>::InsufficientAssetBalance
+		);
+	});
+}
@@ -0,0 +1,226 @@
+use super::mock::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn add_liquidity_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool();
+
+		assert_ok!(LBP::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			(DAI, 10 * ONE),
+			(TKN, 20 * ONE)
+		));
+
+		assert_eq!(Tokens::free_balance(DAI, &POOL), 110 * ONE);
+		assert_eq!(Tokens::free_balance(TKN, &POOL), 220 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 890 * ONE);
+		assert_eq!(Tokens::free_balance(TKN, &ALICE), 780 * ONE);
+
+		assert_eq!(
+			liquidity_changes(),
+			vec![Activity {
+				source: LBP_SOURCE,
+				asset_a: DAI,
+				asset_b: TKN,
+				amount_a: 10 * ONE,
+				amount_b: 20 * ONE,
+				liquidity_a: 110 * ONE,
+				liquidity_b: 220 * ONE,
+			}]
+		);
+
+		expect_events(vec![Event::LiquidityAdded {
+			who: ALICE,
+			asset_a: DAI,
+			asset_b: TKN,
+			amount_a: 10 * ONE,
+			amount_b: 20 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn add_liquidity_should_work_when_only_one_asset_is_provided() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool();
+
+		assert_ok!(LBP::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			(TKN, 50 * ONE),
+			(DAI, 0)
+		));
+
+		assert_eq!(Tokens::free_balance(DAI, &POOL), 100 * ONE);
+		assert_eq!(Tokens::free_balance(TKN, &POOL), 250 * ONE);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_amounts_are_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool();
+
+		assert_noop!(
+			LBP::add_liquidity(RuntimeOrigin::signed(ALICE), (DAI, 0), (TKN, 0)),
+			Error::<Test>::CannotAddZeroLiquidity
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_called_by_not_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool();
+
+		assert_noop!(
+			LBP::add_liquidity(RuntimeOrigin::signed(BOB), (DAI, 10 * ONE), (TKN, 20 * ONE)),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_pool_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LBP::add_liquidity(RuntimeOrigin::signed(ALICE), (DAI, 10 * ONE), (TKN, 20 * ONE)),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_balance_is_insufficient() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool();
+
+		assert_noop!(
+			LBP::add_liquidity(RuntimeOrigin::signed(ALICE), (DAI, 901 * ONE), (TKN, 20 * ONE)),
+			Error::<Test>::InsufficientAssetBalance
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_sale_started() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool_with_sale();
+		run_to_block(SALE_START);
+
+		assert_noop!(
+			LBP::add_liquidity(RuntimeOrigin::signed(ALICE), (DAI, 10 * ONE), (TKN, 20 * ONE)),
+			Error::<Test>::SaleStarted
+		);
+
+		run_to_block(SALE_END + 1);
+
+		assert_noop!(
+			LBP::add_liquidity(RuntimeOrigin::signed(ALICE), (DAI, 10 * ONE), (TKN, 20 * ONE)),
+			Error::<Test>::SaleStarted
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_work_before_sale_starts() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool_with_sale();
+
+		assert_ok!(LBP::remove_liquidity(RuntimeOrigin::signed(ALICE), POOL));
+
+		assert_eq!(LBP::pool_data(POOL), None);
+		assert_eq!(Tokens::free_balance(DAI, &POOL), 0);
+		assert_eq!(Tokens::free_balance(TKN, &POOL), 0);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 1_000 * ONE);
+		assert_eq!(Tokens::free_balance(TKN, &ALICE), 1_000 * ONE);
+
+		assert_eq!(
+			liquidity_changes(),
+			vec![Activity {
+				source: LBP_SOURCE,
+				asset_a: DAI,
+				asset_b: TKN,
+				amount_a: 100 * ONE,
+				amount_b: 200 * ONE,
+				liquidity_a: 0,
+				liquidity_b: 0,
+			}]
+		);
+
+		expect_events(vec![Event::LiquidityRemoved {
+			who: ALICE,
+			asset_a: DAI,
+			asset_b: TKN,
+			amount_a: 100 * ONE,
+			amount_b: 200 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_work_after_sale_ends() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool_with_sale();
+		run_to_block(SALE_START + 1);
+
+		assert_ok!(LBP::sell(RuntimeOrigin::signed(BOB), DAI, TKN, 10 * ONE, 0));
+
+		let pool_dai = Tokens::free_balance(DAI, &POOL);
+		let pool_tkn = Tokens::free_balance(TKN, &POOL);
+
+		run_to_block(SALE_END + 1);
+
+		assert_ok!(LBP::remove_liquidity(RuntimeOrigin::signed(ALICE), POOL));
+
+		assert_eq!(LBP::pool_data(POOL), None);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 900 * ONE + pool_dai);
+		assert_eq!(Tokens::free_balance(TKN, &ALICE), 800 * ONE + pool_tkn);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_sale_is_running() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool_with_sale();
+
+		run_to_block(SALE_START);
+		assert_noop!(
+			LBP::remove_liquidity(RuntimeOrigin::signed(ALICE), POOL),
+			Error::<Test>::SaleNotEnded
+		);
+
+		run_to_block(SALE_END);
+		assert_noop!(
+			LBP::remove_liquidity(RuntimeOrigin::signed(ALICE), POOL),
+			Error::<Test>::SaleNotEnded
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_called_by_not_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool();
+
+		assert_noop!(
+			LBP::remove_liquidity(RuntimeOrigin::signed(BOB), POOL),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_pool_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LBP::remove_liquidity(RuntimeOrigin::signed(ALICE), POOL),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
@@ -0,0 +1,365 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as lbp;
+use crate::Config;
+use frame_support::{
+	parameter_types,
+	traits::{Everything, Nothing},
+	weights::Weight,
+};
+use frame_system as system;
+use frame_system::EnsureRoot;
+use hydradx_traits::{AssetPairAccountIdFor, OnLiquidityChangedHandler, OnTradeHandler, Registry, Source};
+use orml_traits::parameter_type_with_key;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	DispatchError,
+};
+use std::{cell::RefCell, collections::HashSet};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+pub type Amount = i128;
+pub type AssetId = u32;
+pub type Balance = u128;
+
+pub const HDX: AssetId = 0;
+pub const DAI: AssetId = 2_000;
+pub const TKN: AssetId = 3_000;
+pub const UNREGISTERED: AssetId = 4_000;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const LBP_SOURCE: Source = *b"hydralbp";
+
+/// Pool account of the DAI/TKN pool.
+pub const POOL: AccountId = 2_003_000;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+	 Block = Block,
+	 NodeBlock = Block,
+	 UncheckedExtrinsic = UncheckedExtrinsic,
+	 {
+		 System: frame_system,
+		 LBP: lbp,
+		 Tokens: orml_tokens,
+	 }
+);
+
+/// Trade or liquidity change reported to the AMM handler.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Activity {
+	pub source: Source,
+	pub asset_a: AssetId,
+	pub asset_b: AssetId,
+	pub amount_a: Balance,
+	pub amount_b: Balance,
+	pub liquidity_a: Balance,
+	pub liquidity_b: Balance,
+}
+
+thread_local! {
+	pub static REGISTERED_ASSETS: RefCell<HashSet<AssetId>> = RefCell::new(HashSet::default());
+	pub static TRADES: RefCell<Vec<Activity>> = RefCell::new(Vec::new());
+	pub static LIQUIDITY_CHANGES: RefCell<Vec<Activity>> = RefCell::new(Vec::new());
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+	pub const MaxReserves: u32 = 50;
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Nothing;
+	type ReserveIdentifier = [u8; 8];
+	type MaxReserves = MaxReserves;
+	type CurrencyHooks = ();
+}
+
+pub struct DummyRegistry;
+
+impl Registry<AssetId, Vec<u8>, Balance, DispatchError> for DummyRegistry {
+	fn exists(asset_id: AssetId) -> bool {
+		REGISTERED_ASSETS.with(|v| v.borrow().contains(&asset_id))
+	}
+
+	fn retrieve_asset(_name: &Vec<u8>) -> Result<AssetId, DispatchError> {
+		Err(DispatchError::Other("NotImplemented"))
+	}
+
+	fn create_asset(_name: &Vec<u8>, _existential_deposit: Balance) -> Result<AssetId, DispatchError> {
+		let assigned = REGISTERED_ASSETS.with(|v| {
+			let asset_id = 1_000_000 + v.borrow().len() as AssetId;
+			v.borrow_mut().insert(asset_id);
+			asset_id
+		});
+		Ok(assigned)
+	}
+}
+
+pub struct AssetPairAccountIdTest;
+
+impl AssetPairAccountIdFor<AssetId, AccountId> for AssetPairAccountIdTest {
+	fn from_assets(asset_a: AssetId, asset_b: AssetId, _: &str) -> AccountId {
+		let mut a = asset_a as u128;
+		let mut b = asset_b as u128;
+		if a > b {
+			std::mem::swap(&mut a, &mut b)
+		}
+		(a * 1000 + b) as u64
+	}
+}
+
+pub struct AMMHandlerMock;
+
+impl OnTradeHandler<AssetId, Balance> for AMMHandlerMock {
+	fn on_trade(
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		amount_b: Balance,
+		liquidity_a: Balance,
+		liquidity_b: Balance,
+	) -> Result<Weight, (Weight, DispatchError)> {
+		TRADES.with(|v| {
+			v.borrow_mut().push(Activity {
+				source,
+				asset_a,
+				asset_b,
+				amount_a,
+				amount_b,
+				liquidity_a,
+				liquidity_b,
+			})
+		});
+		Ok(Weight::zero())
+	}
+
+	fn on_trade_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+impl OnLiquidityChangedHandler<AssetId, Balance> for AMMHandlerMock {
+	fn on_liquidity_changed(
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		amount_b: Balance,
+		liquidity_a: Balance,
+		liquidity_b: Balance,
+	) -> Result<Weight, (Weight, DispatchError)> {
+		LIQUIDITY_CHANGES.with(|v| {
+			v.borrow_mut().push(Activity {
+				source,
+				asset_a,
+				asset_b,
+				amount_a,
+				amount_b,
+				liquidity_a,
+				liquidity_b,
+			})
+		});
+		Ok(Weight::zero())
+	}
+
+	fn on_liquidity_changed_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+parameter_types! {
+	pub const MinTradingLimit: Balance = 1_000;
+	pub const MinPoolLiquidity: Balance = 1_000;
+	pub const MaxInRatio: u128 = 3;
+	pub const MaxOutRatio: u128 = 3;
+	pub const RepayFee: (u32, u32) = (2, 10);
+	pub const OracleSource: Source = LBP_SOURCE;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Tokens;
+	type AssetRegistry = DummyRegistry;
+	type CreatePoolOrigin = EnsureRoot<AccountId>;
+	type AssetPairAccountId = AssetPairAccountIdTest;
+	type BlockNumberProvider = System;
+	type WeightInfo = ();
+	type MinTradingLimit = MinTradingLimit;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type MaxInRatio = MaxInRatio;
+	type MaxOutRatio = MaxOutRatio;
+	type RepayFee = RepayFee;
+	type AMMHandler = AMMHandlerMock;
+	type OracleSource = OracleSource;
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each  test case
+		REGISTERED_ASSETS.with(|v| {
+			let mut v = v.borrow_mut();
+			v.clear();
+			v.extend([HDX, DAI, TKN]);
+		});
+		TRADES.with(|v| v.borrow_mut().clear());
+		LIQUIDITY_CHANGES.with(|v| v.borrow_mut().clear());
+
+		Self {
+			endowed_accounts: vec![
+				(ALICE, DAI, 1_000 * ONE),
+				(ALICE, TKN, 1_000 * ONE),
+				(BOB, DAI, 1_000 * ONE),
+				(BOB, TKN, 1_000 * ONE),
+			],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+		r.execute_with(|| System::set_block_number(1));
+		r
+	}
+}
+
+pub const INITIAL_WEIGHT: u32 = 80_000_000;
+pub const FINAL_WEIGHT: u32 = 20_000_000;
+pub const SALE_START: u64 = 10;
+pub const SALE_END: u64 = 40;
+pub const DEFAULT_FEE: (u32, u32) = (2, 1_000);
+
+/// Create DAI/TKN pool owned by ALICE with CHARLIE as the fee collector.
+pub(crate) fn create_default_pool() {
+	frame_support::assert_ok!(LBP::create_pool(
+		RuntimeOrigin::root(),
+		ALICE,
+		DAI,
+		100 * ONE,
+		TKN,
+		200 * ONE,
+		INITIAL_WEIGHT,
+		FINAL_WEIGHT,
+		crate::WeightCurveType::Linear,
+		DEFAULT_FEE,
+		CHARLIE,
+		0,
+	));
+}
+
+/// Create default pool and schedule the sale from `SALE_START` to `SALE_END`.
+pub(crate) fn create_default_pool_with_sale() {
+	create_default_pool();
+	frame_support::assert_ok!(LBP::update_pool_data(
+		RuntimeOrigin::signed(ALICE),
+		POOL,
+		None,
+		Some(SALE_START),
+		Some(SALE_END),
+		None,
+		None,
+		None,
+		None,
+		None,
+	));
+}
+
+pub(crate) fn run_to_block(n: u64) {
+	System::set_block_number(n);
+}
+
+pub(crate) fn expect_events(e: Vec<RuntimeEvent>) {
+	test_utils::expect_events::<RuntimeEvent, Test>(e);
+}
+
+pub(crate) fn trades() -> Vec<Activity> {
+	TRADES.with(|v| v.borrow().clone())
+}
+
+pub(crate) fn liquidity_changes() -> Vec<Activity> {
+	LIQUIDITY_CHANGES.with(|v| v.borrow().clone())
+}
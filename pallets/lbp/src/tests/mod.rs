@@ -0,0 +1,6 @@
+mod creation;
+mod liquidity;
+pub(crate) mod mock;
+mod trade_execution;
+mod trades;
+mod update_pool;
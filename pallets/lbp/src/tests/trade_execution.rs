@@ -0,0 +1,83 @@
+use super::mock::*;
+use crate::Error;
+use frame_support::assert_ok;
+use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+fn with_running_sale() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		create_default_pool_with_sale();
+		run_to_block(SALE_START + 1);
+	});
+	ext
+}
+
+#[test]
+fn calculate_sell_should_return_amount_received_by_execute_sell() {
+	with_running_sale().execute_with(|| {
+		let amount_out = LBP::calculate_sell(PoolType::LBP, DAI, TKN, 10 * ONE).unwrap();
+
+		assert_ok!(<LBP as TradeExecution<_, _, _, _>>::execute_sell(
+			RuntimeOrigin::signed(BOB),
+			PoolType::LBP,
+			DAI,
+			TKN,
+			10 * ONE,
+			amount_out
+		));
+
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 990 * ONE);
+		assert_eq!(Tokens::free_balance(TKN, &BOB), 1_000 * ONE + amount_out);
+	});
+}
+
+#[test]
+fn calculate_buy_should_return_amount_paid_by_execute_buy() {
+	with_running_sale().execute_with(|| {
+		let amount_in = LBP::calculate_buy(PoolType::LBP, DAI, TKN, 10 * ONE).unwrap();
+
+		assert_ok!(<LBP as TradeExecution<_, _, _, _>>::execute_buy(
+			RuntimeOrigin::signed(BOB),
+			PoolType::LBP,
+			DAI,
+			TKN,
+			10 * ONE,
+			amount_in
+		));
+
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 1_000 * ONE - amount_in);
+		assert_eq!(Tokens::free_balance(TKN, &BOB), 1_010 * ONE);
+	});
+}
+
+#[test]
+fn calculate_sell_should_fail_when_sale_is_not_running() {
+	with_running_sale().execute_with(|| {
+		run_to_block(SALE_END + 1);
+
+		assert_eq!(
+			LBP::calculate_sell(PoolType::LBP, DAI, TKN, 10 * ONE),
+			Err(ExecutorError::Error(Error::<Test>::SaleIsNotRunning.into()))
+		);
+		assert_eq!(
+			LBP::calculate_buy(PoolType::LBP, DAI, TKN, 10 * ONE),
+			Err(ExecutorError::Error(Error::<Test>::SaleIsNotRunning.into()))
+		);
+	});
+}
+
+#[test]
+fn trade_execution_should_not_support_other_pool_types() {
+	with_running_sale().execute_with(|| {
+		assert_eq!(
+			LBP::calculate_sell(PoolType::XYK, DAI, TKN, 10 * ONE),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			LBP::calculate_buy(PoolType::Omnipool, DAI, TKN, 10 * ONE),
+			Err(ExecutorError::NotSupported)
+		);
+	});
+}
@@ -0,0 +1,510 @@
+use super::mock::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::lbp::{calculate_in_given_out, calculate_out_given_in};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+/// Weights of DAI and TKN in the default pool one block after the sale starts.
+const WEIGHT_DAI: u32 = 78_000_000;
+const WEIGHT_TKN: u32 = 22_000_000;
+
+fn fee(amount: Balance, fee: (u32, u32)) -> Balance {
+	hydra_dx_math::fee::calculate_pool_trade_fee(amount, fee).unwrap()
+}
+
+#[test]
+fn weights_should_change_linearly_during_sale() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool_with_sale();
+		let pool = LBP::pool_data(POOL).unwrap();
+
+		run_to_block(SALE_START);
+		assert_eq!(LBP::get_weights(&pool).unwrap(), (INITIAL_WEIGHT, 20_000_000));
+
+		run_to_block(SALE_START + 1);
+		assert_eq!(LBP::get_weights(&pool).unwrap(), (WEIGHT_DAI, WEIGHT_TKN));
+
+		run_to_block(25);
+		assert_eq!(LBP::get_weights(&pool).unwrap(), (50_000_000, 50_000_000));
+
+		run_to_block(SALE_END);
+		assert_eq!(LBP::get_weights(&pool).unwrap(), (FINAL_WEIGHT, 80_000_000));
+	});
+}
+
+#[test]
+fn sell_accumulated_asset_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool_with_sale();
+		run_to_block(SALE_START + 1);
+
+		let amount = 10 * ONE;
+		let fee = fee(amount, DEFAULT_FEE);
+		let expected_out = calculate_out_given_in(100 * ONE, 200 * ONE, WEIGHT_DAI, WEIGHT_TKN, amount - fee).unwrap();
+
+		assert_ok!(LBP::sell(RuntimeOrigin::signed(BOB), DAI, TKN, amount, expected_out));
+
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 990 * ONE);
+		assert_eq!(Tokens::free_balance(TKN, &BOB), 1_000 * ONE + expected_out);
+		assert_eq!(Tokens::free_balance(DAI, &POOL), 110 * ONE - fee);
+		assert_eq!(Tokens::free_balance(TKN, &POOL), 200 * ONE - expected_out);
+		assert_eq!(Tokens::free_balance(DAI, &CHARLIE), fee);
+
+		assert_eq!(
+			trades(),
+			vec![Activity {
+				source: LBP_SOURCE,
+				asset_a: DAI,
+				asset_b: TKN,
+				amount_a: amount - fee,
+				amount_b: expected_out,
+				liquidity_a: 110 * ONE - fee,
+				liquidity_b: 200 * ONE - expected_out,
+			}]
+		);
+
+		expect_events(vec![Event::SellExecuted {
+			who: BOB,
+			asset_in: DAI,
+			asset_out: TKN,
+			amount,
+			sale_price: expected_out,
+			fee_asset: DAI,
+			fee_amount: fee,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_sold_asset_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool_with_sale();
+		run_to_block(SALE_START + 1);
+
+		let amount = 10 * ONE;
+		let amount_out = calculate_out_given_in(200 * ONE, 100 * ONE, WEIGHT_TKN, WEIGHT_DAI, amount).unwrap();
+		let fee = fee(amount_out, DEFAULT_FEE);
+		let expected_out = amount_out - fee;
+
+		assert_ok!(LBP::sell(RuntimeOrigin::signed(BOB), TKN, DAI, amount, expected_out));
+
+		assert_eq!(Tokens::free_balance(TKN, &BOB), 990 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 1_000 * ONE + expected_out);
+		assert_eq!(Tokens::free_balance(TKN, &POOL), 210 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &POOL), 100 * ONE - amount_out);
+		assert_eq!(Tokens::free_balance(DAI, &CHARLIE), fee);
+
+		assert_eq!(
+			trades(),
+			vec![Activity {
+				source: LBP_SOURCE,
+				asset_a: TKN,
+				asset_b: DAI,
+				amount_a: amount,
+				amount_b: amount_out,
+				liquidity_a: 210 * ONE,
+				liquidity_b: 100 * ONE - amount_out,
+			}]
+		);
+
+		expect_events(vec![Event::SellExecuted {
+			who: BOB,
+			asset_in: TKN,
+			asset_out: DAI,
+			amount,
+			sale_price: expected_out,
+			fee_asset: DAI,
+			fee_amount: fee,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_accumulated_asset_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool_with_sale();
+		run_to_block(SALE_START + 1);
+
+		let amount = 5 * ONE;
+		let fee = fee(amount, DEFAULT_FEE);
+		let expected_in = calculate_in_given_out(200 * ONE, 100 * ONE, WEIGHT_TKN, WEIGHT_DAI, amount + fee).unwrap();
+
+		assert_ok!(LBP::buy(RuntimeOrigin::signed(BOB), DAI, TKN, amount, expected_in));
+
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 1_005 * ONE);
+		assert_eq!(Tokens::free_balance(TKN, &BOB), 1_000 * ONE - expected_in);
+		assert_eq!(Tokens::free_balance(DAI, &POOL), 95 * ONE - fee);
+		assert_eq!(Tokens::free_balance(TKN, &POOL), 200 * ONE + expected_in);
+		assert_eq!(Tokens::free_balance(DAI, &CHARLIE), fee);
+
+		assert_eq!(
+			trades(),
+			vec![Activity {
+				source: LBP_SOURCE,
+				asset_a: TKN,
+				asset_b: DAI,
+				amount_a: expected_in,
+				amount_b: amount + fee,
+				liquidity_a: 200 * ONE + expected_in,
+				liquidity_b: 95 * ONE - fee,
+			}]
+		);
+
+		expect_events(vec![Event::BuyExecuted {
+			who: BOB,
+			asset_out: DAI,
+			asset_in: TKN,
+			amount,
+			buy_price: expected_in,
+			fee_asset: DAI,
+			fee_amount: fee,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_sold_asset_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool_with_sale();
+		run_to_block(SALE_START + 1);
+
+		let amount = 10 * ONE;
+		let amount_in = calculate_in_given_out(100 * ONE, 200 * ONE, WEIGHT_DAI, WEIGHT_TKN, amount).unwrap();
+		let fee = fee(amount_in, DEFAULT_FEE);
+		let expected_in = amount_in + fee;
+
+		assert_ok!(LBP::buy(RuntimeOrigin::signed(BOB), TKN, DAI, amount, expected_in));
+
+		assert_eq!(Tokens::free_balance(TKN, &BOB), 1_010 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 1_000 * ONE - expected_in);
+		assert_eq!(Tokens::free_balance(TKN, &POOL), 190 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &POOL), 100 * ONE + amount_in);
+		assert_eq!(Tokens::free_balance(DAI, &CHARLIE), fee);
+
+		assert_eq!(
+			trades(),
+			vec![Activity {
+				source: LBP_SOURCE,
+				asset_a: DAI,
+				asset_b: TKN,
+				amount_a: amount_in,
+				amount_b: amount,
+				liquidity_a: 100 * ONE + amount_in,
+				liquidity_b: 190 * ONE,
+			}]
+		);
+
+		expect_events(vec![Event::BuyExecuted {
+			who: BOB,
+			asset_out: TKN,
+			asset_in: DAI,
+			amount,
+			buy_price: expected_in,
+			fee_asset: DAI,
+			fee_amount: fee,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn price_of_sold_asset_should_decrease_during_sale() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool_with_sale();
+
+		run_to_block(SALE_START + 1);
+		let (pool_id, pool) = LBP::get_running_pool(DAI, TKN).unwrap();
+		let early = LBP::calculate_sell_amounts(&pool_id, &pool, DAI, TKN, ONE).unwrap();
+
+		run_to_block(SALE_END);
+		let late = LBP::calculate_sell_amounts(&pool_id, &pool, DAI, TKN, ONE).unwrap();
+
+		assert!(late.amount_out > early.amount_out);
+		assert_eq!(late.fee, early.fee);
+	});
+}
+
+#[test]
+fn repay_fee_should_apply_until_repay_target_is_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool_with_sale();
+		assert_ok!(LBP::update_pool_data(
+			RuntimeOrigin::signed(ALICE),
+			POOL,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			Some(ONE),
+		));
+		run_to_block(SALE_START + 1);
+
+		let repay_fee = fee(10 * ONE, RepayFee::get());
+		assert_ok!(LBP::sell(RuntimeOrigin::signed(BOB), DAI, TKN, 10 * ONE, 0));
+		assert_eq!(Tokens::free_balance(DAI, &CHARLIE), repay_fee);
+		assert!(repay_fee >= ONE);
+
+		let regular_fee = fee(10 * ONE, DEFAULT_FEE);
+		assert_ok!(LBP::sell(RuntimeOrigin::signed(BOB), DAI, TKN, 10 * ONE, 0));
+		assert_eq!(Tokens::free_balance(DAI, &CHARLIE), repay_fee + regular_fee);
+	});
+}
+
+#[test]
+fn repay_fee_should_apply_when_fee_collector_has_balance_above_repay_target() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, DAI, 1_000 * ONE),
+			(ALICE, TKN, 1_000 * ONE),
+			(BOB, DAI, 1_000 * ONE),
+			(CHARLIE, DAI, 10 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			create_default_pool_with_sale();
+			assert_ok!(LBP::update_pool_data(
+				RuntimeOrigin::signed(ALICE),
+				POOL,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				Some(ONE),
+			));
+			run_to_block(SALE_START + 1);
+
+			let repay_fee = fee(10 * ONE, RepayFee::get());
+			assert_ok!(LBP::sell(RuntimeOrigin::signed(BOB), DAI, TKN, 10 * ONE, 0));
+			assert_eq!(Tokens::free_balance(DAI, &CHARLIE), 10 * ONE + repay_fee);
+			assert_eq!(LBP::repaid_fees(POOL), repay_fee);
+		});
+}
+
+#[test]
+fn repay_fee_should_apply_per_pool_when_fee_collector_is_shared() {
+	const HDX_POOL: AccountId = 2_000;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, DAI, 1_000 * ONE),
+			(ALICE, TKN, 1_000 * ONE),
+			(ALICE, HDX, 1_000 * ONE),
+			(BOB, DAI, 1_000 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			create_default_pool_with_sale();
+			assert_ok!(LBP::create_pool(
+				RuntimeOrigin::root(),
+				ALICE,
+				DAI,
+				100 * ONE,
+				HDX,
+				200 * ONE,
+				INITIAL_WEIGHT,
+				FINAL_WEIGHT,
+				crate::WeightCurveType::Linear,
+				DEFAULT_FEE,
+				CHARLIE,
+				ONE,
+			));
+			assert_ok!(LBP::update_pool_data(
+				RuntimeOrigin::signed(ALICE),
+				HDX_POOL,
+				None,
+				Some(SALE_START),
+				Some(SALE_END),
+				None,
+				None,
+				None,
+				None,
+				None,
+			));
+			assert_ok!(LBP::update_pool_data(
+				RuntimeOrigin::signed(ALICE),
+				POOL,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				Some(ONE),
+			));
+			run_to_block(SALE_START + 1);
+
+			let repay_fee = fee(10 * ONE, RepayFee::get());
+			assert_ok!(LBP::sell(RuntimeOrigin::signed(BOB), DAI, TKN, 10 * ONE, 0));
+			assert!(repay_fee >= ONE);
+
+			// Repay target of the other pool has not been reached yet.
+			assert_ok!(LBP::sell(RuntimeOrigin::signed(BOB), DAI, HDX, 10 * ONE, 0));
+			assert_eq!(Tokens::free_balance(DAI, &CHARLIE), 2 * repay_fee);
+			assert_eq!(LBP::repaid_fees(HDX_POOL), repay_fee);
+
+			let regular_fee = fee(10 * ONE, DEFAULT_FEE);
+			assert_ok!(LBP::sell(RuntimeOrigin::signed(BOB), DAI, TKN, 10 * ONE, 0));
+			assert_eq!(Tokens::free_balance(DAI, &CHARLIE), 2 * repay_fee + regular_fee);
+			assert_eq!(LBP::repaid_fees(POOL), repay_fee + regular_fee);
+		});
+}
+
+#[test]
+fn trade_should_fail_when_sale_is_not_running() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool();
+
+		// sale is not scheduled
+		assert_noop!(
+			LBP::sell(RuntimeOrigin::signed(BOB), DAI, TKN, ONE, 0),
+			Error::<Test>::SaleIsNotRunning
+		);
+
+		assert_ok!(LBP::update_pool_data(
+			RuntimeOrigin::signed(ALICE),
+			POOL,
+			None,
+			Some(SALE_START),
+			Some(SALE_END),
+			None,
+			None,
+			None,
+			None,
+			None,
+		));
+
+		// sale has not started yet
+		run_to_block(SALE_START - 1);
+		assert_noop!(
+			LBP::sell(RuntimeOrigin::signed(BOB), DAI, TKN, ONE, 0),
+			Error::<Test>::SaleIsNotRunning
+		);
+		assert_noop!(
+			LBP::buy(RuntimeOrigin::signed(BOB), TKN, DAI, ONE, u128::MAX),
+			Error::<Test>::SaleIsNotRunning
+		);
+
+		// sale has ended
+		run_to_block(SALE_END + 1);
+		assert_noop!(
+			LBP::sell(RuntimeOrigin::signed(BOB), DAI, TKN, ONE, 0),
+			Error::<Test>::SaleIsNotRunning
+		);
+		assert_noop!(
+			LBP::buy(RuntimeOrigin::signed(BOB), TKN, DAI, ONE, u128::MAX),
+			Error::<Test>::SaleIsNotRunning
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_pool_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LBP::sell(RuntimeOrigin::signed(BOB), DAI, TKN, ONE, 0),
+			Error::<Test>::PoolNotFound
+		);
+		assert_noop!(
+			LBP::buy(RuntimeOrigin::signed(BOB), TKN, DAI, ONE, u128::MAX),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_limit_is_not_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool_with_sale();
+		run_to_block(SALE_START + 1);
+
+		let (pool_id, pool) = LBP::get_running_pool(DAI, TKN).unwrap();
+		let trade = LBP::calculate_sell_amounts(&pool_id, &pool, DAI, TKN, 10 * ONE).unwrap();
+
+		assert_noop!(
+			LBP::sell(RuntimeOrigin::signed(BOB), DAI, TKN, 10 * ONE, trade.amount_out + 1),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_limit_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool_with_sale();
+		run_to_block(SALE_START + 1);
+
+		let (pool_id, pool) = LBP::get_running_pool(DAI, TKN).unwrap();
+		let trade = LBP::calculate_buy_amounts(&pool_id, &pool, DAI, TKN, 10 * ONE).unwrap();
+
+		assert_noop!(
+			LBP::buy(RuntimeOrigin::signed(BOB), TKN, DAI, 10 * ONE, trade.amount_in - 1),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_amount_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool_with_sale();
+		run_to_block(SALE_START + 1);
+
+		assert_noop!(
+			LBP::sell(RuntimeOrigin::signed(BOB), DAI, TKN, 0, 0),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			LBP::sell(RuntimeOrigin::signed(BOB), DAI, TKN, 999, 0),
+			Error::<Test>::InsufficientTradingAmount
+		);
+		assert_noop!(
+			LBP::buy(RuntimeOrigin::signed(BOB), TKN, DAI, 999, u128::MAX),
+			Error::<Test>::InsufficientTradingAmount
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_max_ratio_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool_with_sale();
+		run_to_block(SALE_START + 1);
+
+		assert_noop!(
+			LBP::sell(RuntimeOrigin::signed(BOB), DAI, TKN, 34 * ONE, 0),
+			Error::<Test>::MaxInRatioExceeded
+		);
+		assert_noop!(
+			LBP::buy(RuntimeOrigin::signed(BOB), TKN, DAI, 67 * ONE, u128::MAX),
+			Error::<Test>::MaxOutRatioExceeded
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_balance_is_insufficient() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool_with_sale();
+		run_to_block(SALE_START + 1);
+
+		assert_noop!(
+			LBP::sell(RuntimeOrigin::signed(CHARLIE), DAI, TKN, ONE, 0),
+			Error::<Test>::InsufficientAssetBalance
+		);
+		assert_noop!(
+			LBP::buy(RuntimeOrigin::signed(CHARLIE), TKN, DAI, ONE, u128::MAX),
+			Error::<Test>::InsufficientAssetBalance
+		);
+	});
+}
@@ -0,0 +1,290 @@
+use super::mock::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn update_pool_data_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool();
+
+		assert_ok!(LBP::update_pool_data(
+			RuntimeOrigin::signed(ALICE),
+			POOL,
+			Some(BOB),
+			Some(SALE_START),
+			Some(SALE_END),
+			Some(70_000_000),
+			Some(30_000_000),
+			Some((5, 1_000)),
+			Some(BOB),
+			Some(10 * ONE),
+		));
+
+		let pool = LBP::pool_data(POOL).unwrap();
+		assert_eq!(pool.owner, BOB);
+		assert_eq!(pool.start, Some(SALE_START));
+		assert_eq!(pool.end, Some(SALE_END));
+		assert_eq!(pool.initial_weight, 70_000_000);
+		assert_eq!(pool.final_weight, 30_000_000);
+		assert_eq!(pool.fee, (5, 1_000));
+		assert_eq!(pool.fee_collector, BOB);
+		assert_eq!(pool.repay_target, 10 * ONE);
+		assert_eq!(pool.assets, (DAI, TKN));
+
+		expect_events(vec![Event::PoolUpdated { pool: POOL, data: pool }.into()]);
+	});
+}
+
+#[test]
+fn update_pool_data_should_keep_values_which_are_not_updated() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool();
+		let before = LBP::pool_data(POOL).unwrap();
+
+		assert_ok!(LBP::update_pool_data(
+			RuntimeOrigin::signed(ALICE),
+			POOL,
+			None,
+			None,
+			None,
+			None,
+			None,
+			Some((1, 100)),
+			None,
+			None,
+		));
+
+		let after = LBP::pool_data(POOL).unwrap();
+		assert_eq!(after.fee, (1, 100));
+		assert_eq!(after.owner, before.owner);
+		assert_eq!(after.start, before.start);
+		assert_eq!(after.end, before.end);
+		assert_eq!(after.initial_weight, before.initial_weight);
+		assert_eq!(after.final_weight, before.final_weight);
+		assert_eq!(after.fee_collector, before.fee_collector);
+		assert_eq!(after.repay_target, before.repay_target);
+	});
+}
+
+#[test]
+fn update_pool_data_should_fail_when_nothing_to_update() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool();
+
+		assert_noop!(
+			LBP::update_pool_data(
+				RuntimeOrigin::signed(ALICE),
+				POOL,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::NothingToUpdate
+		);
+	});
+}
+
+#[test]
+fn update_pool_data_should_fail_when_pool_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			LBP::update_pool_data(
+				RuntimeOrigin::signed(ALICE),
+				POOL,
+				None,
+				Some(SALE_START),
+				Some(SALE_END),
+				None,
+				None,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn update_pool_data_should_fail_when_called_by_not_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool();
+
+		assert_noop!(
+			LBP::update_pool_data(
+				RuntimeOrigin::signed(BOB),
+				POOL,
+				None,
+				Some(SALE_START),
+				Some(SALE_END),
+				None,
+				None,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn update_pool_data_should_fail_when_block_range_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool();
+
+		// end before start
+		assert_noop!(
+			LBP::update_pool_data(
+				RuntimeOrigin::signed(ALICE),
+				POOL,
+				None,
+				Some(SALE_END),
+				Some(SALE_START),
+				None,
+				None,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::InvalidBlockRange
+		);
+
+		// start in the past
+		run_to_block(20);
+		assert_noop!(
+			LBP::update_pool_data(
+				RuntimeOrigin::signed(ALICE),
+				POOL,
+				None,
+				Some(SALE_START),
+				Some(SALE_END),
+				None,
+				None,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::InvalidBlockRange
+		);
+
+		// only start is set
+		assert_noop!(
+			LBP::update_pool_data(
+				RuntimeOrigin::signed(ALICE),
+				POOL,
+				None,
+				Some(30),
+				None,
+				None,
+				None,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::InvalidBlockRange
+		);
+	});
+}
+
+#[test]
+fn update_pool_data_should_fail_when_weight_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool();
+
+		assert_noop!(
+			LBP::update_pool_data(
+				RuntimeOrigin::signed(ALICE),
+				POOL,
+				None,
+				None,
+				None,
+				Some(0),
+				None,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::InvalidWeight
+		);
+	});
+}
+
+#[test]
+fn update_pool_data_should_fail_when_sale_started() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool_with_sale();
+		run_to_block(SALE_START);
+
+		assert_noop!(
+			LBP::update_pool_data(
+				RuntimeOrigin::signed(ALICE),
+				POOL,
+				None,
+				None,
+				None,
+				None,
+				None,
+				Some((1, 100)),
+				None,
+				None,
+			),
+			Error::<Test>::SaleStarted
+		);
+	});
+}
+
+#[test]
+fn update_pool_data_should_allow_new_owner_to_update_pool() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_default_pool();
+
+		assert_ok!(LBP::update_pool_data(
+			RuntimeOrigin::signed(ALICE),
+			POOL,
+			Some(BOB),
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+			None,
+		));
+
+		assert_noop!(
+			LBP::update_pool_data(
+				RuntimeOrigin::signed(ALICE),
+				POOL,
+				None,
+				Some(SALE_START),
+				Some(SALE_END),
+				None,
+				None,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::NotOwner
+		);
+
+		assert_ok!(LBP::update_pool_data(
+			RuntimeOrigin::signed(BOB),
+			POOL,
+			None,
+			Some(SALE_START),
+			Some(SALE_END),
+			None,
+			None,
+			None,
+			None,
+			None,
+		));
+	});
+}
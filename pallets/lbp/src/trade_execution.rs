@@ -0,0 +1,88 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{AssetId, Balance, Config, Pallet};
+use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use sp_runtime::DispatchError;
+
+impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance> for Pallet<T> {
+	type Error = DispatchError;
+
+	fn calculate_sell(
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::LBP => {
+				let (pool_id, pool) = Self::get_running_pool(asset_in, asset_out).map_err(ExecutorError::Error)?;
+
+				let trade = Self::calculate_sell_amounts(&pool_id, &pool, asset_in, asset_out, amount_in)
+					.map_err(ExecutorError::Error)?;
+
+				Ok(trade.amount_out)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn calculate_buy(
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::LBP => {
+				let (pool_id, pool) = Self::get_running_pool(asset_in, asset_out).map_err(ExecutorError::Error)?;
+
+				let trade = Self::calculate_buy_amounts(&pool_id, &pool, asset_in, asset_out, amount_out)
+					.map_err(ExecutorError::Error)?;
+
+				Ok(trade.amount_in)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn execute_sell(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::LBP => Self::sell(who, asset_in, asset_out, amount_in, min_limit).map_err(ExecutorError::Error),
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn execute_buy(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		max_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::LBP => Self::buy(who, asset_out, asset_in, amount_out, max_limit).map_err(ExecutorError::Error),
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+}
@@ -0,0 +1,77 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+pub use hydra_dx_math::types::LBPWeight;
+pub use primitives::{Amount, AssetId, Balance};
+
+/// Max weight corresponds to 100%.
+pub const MAX_WEIGHT: LBPWeight = 100_000_000;
+
+/// Min weight corresponds to 1 / MAX_WEIGHT.
+pub const MIN_WEIGHT: LBPWeight = 1;
+
+/// Shape of the curve along which the weights change during the sale.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen)]
+pub enum WeightCurveType {
+	#[default]
+	Linear,
+}
+
+/// Liquidity bootstrapping pool.
+///
+/// `assets.0` is the accumulated asset - the asset in which the fees are paid and which is collected by
+/// the `fee_collector` during the sale.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Pool<AccountId, BlockNumber> {
+	/// Owner of the pool. Only the owner can update the pool and manage its liquidity.
+	pub owner: AccountId,
+
+	/// Block at which the sale starts. Trading is not allowed until both `start` and `end` are set.
+	pub start: Option<BlockNumber>,
+
+	/// Block at which the sale ends.
+	pub end: Option<BlockNumber>,
+
+	/// Pool assets. First asset is the accumulated asset.
+	pub assets: (AssetId, AssetId),
+
+	/// Weight of the accumulated asset at the start of the sale.
+	pub initial_weight: LBPWeight,
+
+	/// Weight of the accumulated asset at the end of the sale.
+	pub final_weight: LBPWeight,
+
+	/// Curve along which the weights change between `start` and `end`.
+	pub weight_curve: WeightCurveType,
+
+	/// Trade fee paid in the accumulated asset once the repay target is reached.
+	pub fee: (u32, u32),
+
+	/// Account which receives the fees.
+	pub fee_collector: AccountId,
+
+	/// Amount of the accumulated asset `fee_collector` has to collect before the regular `fee` applies.
+	/// Higher repay fee is charged until the target is reached.
+	pub repay_target: Balance,
+}
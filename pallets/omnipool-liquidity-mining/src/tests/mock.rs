@@ -26,7 +26,7 @@ use frame_support::BoundedVec;
 use hydradx_traits::liquidity_mining::PriceAdjustment;
 use pallet_omnipool;
 
-use frame_support::traits::{ConstU128, Contains, Everything, GenesisBuild};
+use frame_support::traits::{ConstU128, Contains, Everything, GenesisBuild, Nothing};
 use frame_support::{
 	assert_ok, construct_runtime, parameter_types,
 	traits::{ConstU32, ConstU64},
@@ -248,9 +248,12 @@ parameter_types! {
 impl pallet_ema_oracle::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
 	type BlockNumberProvider = MockBlockNumberProvider;
 	type SupportedPeriods = SupportedPeriods;
 	type MaxUniqueEntries = ConstU32<20>;
+	type MaxUniqueExternalEntries = ConstU32<20>;
+	type LocalSources = Nothing;
 }
 
 parameter_types! {
@@ -267,6 +270,8 @@ parameter_types! {
 	pub MaxInRatio: Balance = MAX_IN_RATIO.with(|v| *v.borrow());
 	pub MaxOutRatio: Balance = MAX_OUT_RATIO.with(|v| *v.borrow());
 	pub MinWithdrawFee: Permill = Permill::from_percent(0);
+	pub const ImbalanceBurnRatio: Permill = Permill::from_percent(10);
+	pub const MaxAddLiquidityAssets: u32 = 5;
 }
 
 impl pallet_omnipool::Config for Test {
@@ -293,6 +298,10 @@ impl pallet_omnipool::Config for Test {
 	type MinWithdrawalFee = MinWithdrawFee;
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
+	type ProtocolFeeCollector = ();
+	type FeeLedgerPeriod = ConstU64<10>;
+	type ImbalanceBurnRatio = ImbalanceBurnRatio;
+	type MaxAddLiquidityAssets = MaxAddLiquidityAssets;
 }
 
 pub struct ExtBuilder {
@@ -0,0 +1,196 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+use hydradx_traits::Registry;
+use orml_traits::MultiCurrencyExtended;
+use sp_runtime::{traits::One, FixedU128, Permill};
+use sp_std::vec::Vec;
+
+const TVL_CAP: Balance = 222_222_000_000_000_000_000_000;
+const ONE: Balance = 1_000_000_000_000;
+
+fn fund<T: Config>(to: &T::AccountId, asset: AssetIdOf<T>, amount: Balance) -> DispatchResult
+where
+	CurrencyOf<T>: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	CurrencyOf::<T>::update_balance(asset, to, amount as i128)
+}
+
+fn initialize_omnipool<T: Config>() -> DispatchResult
+where
+	CurrencyOf<T>: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	let stable_amount: Balance = 1_000_000_000_000_000_u128;
+	let native_amount: Balance = 1_000_000_000_000_000_u128;
+	let stable_price: FixedU128 = FixedU128::from((1, 2));
+	let native_price: FixedU128 = FixedU128::from(1);
+	let acc = OmnipoolPallet::<T>::protocol_account();
+
+	OmnipoolPallet::<T>::set_tvl_cap(RawOrigin::Root.into(), TVL_CAP)?;
+
+	fund::<T>(
+		&acc,
+		<T as pallet_omnipool::Config>::StableCoinAssetId::get(),
+		stable_amount,
+	)?;
+	fund::<T>(&acc, <T as pallet_omnipool::Config>::HdxAssetId::get(), native_amount)?;
+
+	OmnipoolPallet::<T>::initialize_pool(
+		RawOrigin::Root.into(),
+		stable_price,
+		native_price,
+		Permill::from_percent(100),
+		Permill::from_percent(100),
+	)
+}
+
+/// Register and add tokens to Omnipool. Returns ids of added tokens.
+fn add_omnipool_tokens<T: Config>(names: &[&[u8]]) -> Result<Vec<AssetIdOf<T>>, DispatchError>
+where
+	CurrencyOf<T>: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	let owner: T::AccountId = account("owner", 0, 1);
+	let acc = OmnipoolPallet::<T>::protocol_account();
+
+	let mut assets = Vec::new();
+	for name in names.iter() {
+		let asset_id = <T as pallet_omnipool::Config>::AssetRegistry::create_asset(&name.to_vec(), Balance::one())?;
+
+		fund::<T>(&acc, asset_id, 1_000_000 * ONE)?;
+
+		OmnipoolPallet::<T>::add_token(
+			RawOrigin::Root.into(),
+			asset_id,
+			FixedU128::from((1, 2)),
+			Permill::from_percent(100),
+			owner.clone(),
+		)?;
+		assets.push(asset_id);
+	}
+
+	Ok(assets)
+}
+
+fn create_subpool<T: Config>(assets: &[AssetIdOf<T>]) -> Result<AssetIdOf<T>, DispatchError> {
+	let share_asset =
+		<T as pallet_omnipool::Config>::AssetRegistry::create_asset(&b"SUBPOOL".to_vec(), Balance::one())?;
+
+	Pallet::<T>::create_subpool(
+		RawOrigin::Root.into(),
+		share_asset,
+		assets[0],
+		assets[1],
+		Permill::from_percent(100),
+		100u16,
+		Permill::from_percent(1),
+		Permill::from_percent(1),
+	)?;
+
+	Ok(share_asset)
+}
+
+benchmarks! {
+	where_clause { where
+		CurrencyOf<T>: MultiCurrencyExtended<T::AccountId, Amount=i128>,
+	}
+
+	create_subpool {
+		initialize_omnipool::<T>()?;
+		let assets = add_omnipool_tokens::<T>(&[b"A", b"B"])?;
+		let share_asset = <T as pallet_omnipool::Config>::AssetRegistry::create_asset(&b"SUBPOOL".to_vec(), Balance::one())?;
+	}: _(RawOrigin::Root, share_asset, assets[0], assets[1], Permill::from_percent(100), 100u16, Permill::from_percent(1), Permill::from_percent(1))
+	verify {
+		assert!(OmnipoolPallet::<T>::exists(share_asset));
+		assert_eq!(Pallet::<T>::subpool_of(assets[0]), Some(share_asset));
+	}
+
+	migrate_asset_to_subpool {
+		initialize_omnipool::<T>()?;
+		let assets = add_omnipool_tokens::<T>(&[b"A", b"B", b"C"])?;
+		let pool_id = create_subpool::<T>(&assets)?;
+	}: _(RawOrigin::Root, pool_id, assets[2])
+	verify {
+		assert!(!OmnipoolPallet::<T>::exists(assets[2]));
+		assert_eq!(Pallet::<T>::subpool_of(assets[2]), Some(pool_id));
+	}
+
+	migrate_position {
+		initialize_omnipool::<T>()?;
+		let position_id = OmnipoolPallet::<T>::next_position_id();
+		let assets = add_omnipool_tokens::<T>(&[b"A", b"B"])?;
+		let pool_id = create_subpool::<T>(&assets)?;
+		let owner: T::AccountId = account("owner", 0, 1);
+	}: _(RawOrigin::Signed(owner), position_id)
+	verify {
+		assert_eq!(OmnipoolPallet::<T>::positions(position_id).unwrap().asset_id, pool_id);
+	}
+
+	sell {
+		initialize_omnipool::<T>()?;
+		let assets = add_omnipool_tokens::<T>(&[b"A", b"B", b"C", b"D"])?;
+		create_subpool::<T>(&assets[0..2])?;
+
+		let share_asset = <T as pallet_omnipool::Config>::AssetRegistry::create_asset(&b"SUBPOOL2".to_vec(), Balance::one())?;
+		Pallet::<T>::create_subpool(
+			RawOrigin::Root.into(),
+			share_asset,
+			assets[2],
+			assets[3],
+			Permill::from_percent(100),
+			100u16,
+			Permill::from_percent(1),
+			Permill::from_percent(1),
+		)?;
+
+		let seller: T::AccountId = account("seller", 0, 1);
+		let amount = 1_000 * ONE;
+		fund::<T>(&seller, assets[0], amount)?;
+	}: _(RawOrigin::Signed(seller.clone()), assets[0], assets[2], amount, Balance::zero())
+	verify {
+		assert!(CurrencyOf::<T>::free_balance(assets[2], &seller) > Balance::zero());
+	}
+
+	buy {
+		initialize_omnipool::<T>()?;
+		let assets = add_omnipool_tokens::<T>(&[b"A", b"B", b"C", b"D"])?;
+		create_subpool::<T>(&assets[0..2])?;
+
+		let share_asset = <T as pallet_omnipool::Config>::AssetRegistry::create_asset(&b"SUBPOOL2".to_vec(), Balance::one())?;
+		Pallet::<T>::create_subpool(
+			RawOrigin::Root.into(),
+			share_asset,
+			assets[2],
+			assets[3],
+			Permill::from_percent(100),
+			100u16,
+			Permill::from_percent(1),
+			Permill::from_percent(1),
+		)?;
+
+		let buyer: T::AccountId = account("buyer", 0, 1);
+		let amount = 1_000 * ONE;
+		fund::<T>(&buyer, assets[0], 10 * amount)?;
+	}: _(RawOrigin::Signed(buyer.clone()), assets[2], assets[0], amount, 10 * amount)
+	verify {
+		assert!(CurrencyOf::<T>::free_balance(assets[2], &buyer) >= amount);
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
+}
@@ -0,0 +1,630 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Omnipool subpools pallet
+//!
+//! ## Overview
+//!
+//! Governance driven migration of Omnipool assets to Stableswap subpools.
+//!
+//! A subpool is a Stableswap pool whose share token is listed in the Omnipool. Assets of the subpool are no longer
+//! traded directly in the Omnipool, their liquidity is held by the subpool and the Omnipool holds the subpool share
+//! tokens instead.
+//!
+//! ### Terminology
+//!
+//! * **Subpool:** Stableswap pool whose share asset is an Omnipool asset.
+//! * **Migrated asset:** asset which was moved from the Omnipool to a subpool.
+//!
+//! ## Migration
+//!
+//! `create_subpool` moves two Omnipool assets to a new subpool. Hub asset reserves of both assets are combined and
+//! the share asset is added to the Omnipool. Share tokens are minted to the Omnipool account 1:1 with the combined
+//! hub reserve.
+//!
+//! `migrate_asset_to_subpool` moves another Omnipool asset to an existing subpool. Hub reserve, shares and protocol
+//! shares of the asset are added to the subpool share asset state and corresponding amount of share tokens is minted
+//! to the Omnipool account.
+//!
+//! Both migrations call the Omnipool hooks - liquidity change of the share asset and removal of the migrated assets -
+//! so that the oracle and the circuit breaker follow the change.
+//!
+//! Migration details of each asset are stored so that Omnipool positions of a migrated asset can be converted to
+//! positions of the subpool share asset by their owners (`migrate_position`). Conversion preserves the value of the
+//! position.
+//!
+//! ## Trading
+//!
+//! `sell` and `buy` route trades across the Omnipool and subpool boundary. Trades between assets of the same subpool
+//! are executed in the subpool, trades between Omnipool assets in the Omnipool. Otherwise, migrated asset is first
+//! exchanged for the subpool share asset (by adding or removing single asset liquidity) which is then traded in the
+//! Omnipool.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarks;
+
+#[cfg(test)]
+mod tests;
+
+pub mod types;
+pub mod weights;
+
+use frame_support::{
+	ensure,
+	pallet_prelude::{DispatchError, DispatchResult},
+	traits::Get,
+	transactional,
+};
+use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+use hydra_dx_math::omnipool::types::{AssetReserveState as MathReserveState, AssetStateChange, BalanceUpdate};
+use hydra_dx_math::omnipool_subpools::{
+	calculate_asset_migration_details, convert_position, create_subpool_initial_state,
+};
+use orml_traits::MultiCurrency;
+use pallet_omnipool::traits::{AssetInfo, OmnipoolHooks};
+use pallet_omnipool::types::{AssetReserveState, Position, Tradability};
+use pallet_stableswap::traits::StableswapHooks;
+use pallet_stableswap::types::AssetLiquidity;
+use pallet_stableswap::MAX_ASSETS_IN_POOL;
+use sp_runtime::traits::Zero;
+use sp_runtime::{ArithmeticError, Permill};
+use sp_std::num::NonZeroU16;
+use sp_std::vec;
+
+use crate::types::{AssetDetail, Balance};
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+type OmnipoolPallet<T> = pallet_omnipool::Pallet<T>;
+type StableswapPallet<T> = pallet_stableswap::Pallet<T>;
+type CurrencyOf<T> = <T as pallet_omnipool::Config>::Currency;
+
+pub type AssetIdOf<T> = <T as pallet_omnipool::Config>::AssetId;
+
+#[frame_support::pallet]
+#[allow(clippy::too_many_arguments)]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(crate) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config
+		+ pallet_omnipool::Config
+		+ pallet_stableswap::Config<AssetId = <Self as pallet_omnipool::Config>::AssetId>
+	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The origin which can create subpools and migrate assets.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Assets migrated from Omnipool to subpools.
+	/// Maps migrated asset to the subpool id and details of the migration.
+	#[pallet::storage]
+	#[pallet::getter(fn migrated_assets)]
+	pub type MigratedAssets<T: Config> = StorageMap<_, Blake2_128Concat, AssetIdOf<T>, (AssetIdOf<T>, AssetDetail)>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A subpool was created from two Omnipool assets.
+		SubpoolCreated {
+			pool_id: AssetIdOf<T>,
+			assets: (AssetIdOf<T>, AssetIdOf<T>),
+		},
+		/// An Omnipool asset was migrated to an existing subpool.
+		AssetMigrated {
+			asset_id: AssetIdOf<T>,
+			pool_id: AssetIdOf<T>,
+		},
+		/// Omnipool position of a migrated asset was converted to a position of the subpool share asset.
+		PositionMigrated {
+			position_id: T::PositionItemId,
+			owner: T::AccountId,
+			pool_id: AssetIdOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Subpool does not exist or its share asset is not in Omnipool.
+		SubpoolNotFound,
+
+		/// Asset is not allowed to be migrated to a subpool.
+		NotAllowed,
+
+		/// Asset has not been migrated to any subpool.
+		AssetNotMigrated,
+
+		/// Minimum limit has not been reached during trade.
+		BuyLimitNotReached,
+
+		/// Maximum limit has been exceeded during trade.
+		SellLimitExceeded,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a subpool from two Omnipool assets.
+		///
+		/// Reserves of both assets are moved from Omnipool to a newly created Stableswap pool. The pool share asset
+		/// is added to Omnipool with combined hub reserve of both assets. Share tokens matching the combined hub
+		/// reserve are minted to the Omnipool account.
+		///
+		/// Omnipool hooks are called for the added share asset and for both removed assets, so that the oracle
+		/// and the circuit breaker are aware of the change.
+		///
+		/// Positions of both assets have to be converted by their owners by `migrate_position`.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `share_asset`: Preregistered share asset identifier. Used as subpool id.
+		/// - `asset_a`: Omnipool asset to migrate
+		/// - `asset_b`: Omnipool asset to migrate
+		/// - `share_asset_weight_cap`: weight cap of the share asset in Omnipool
+		/// - `amplification`: subpool amplification
+		/// - `trade_fee`: subpool trade fee
+		/// - `withdraw_fee`: subpool withdraw fee
+		///
+		/// Emits `SubpoolCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_subpool()
+			.saturating_add(<T as pallet_omnipool::Config>::OmnipoolHooks::on_liquidity_changed_weight())
+			.saturating_add(<T as pallet_omnipool::Config>::OmnipoolHooks::on_asset_removed_weight().saturating_mul(2))
+		)]
+		#[transactional]
+		pub fn create_subpool(
+			origin: OriginFor<T>,
+			share_asset: AssetIdOf<T>,
+			asset_a: AssetIdOf<T>,
+			asset_b: AssetIdOf<T>,
+			share_asset_weight_cap: Permill,
+			amplification: u16,
+			trade_fee: Permill,
+			withdraw_fee: Permill,
+		) -> DispatchResult {
+			<T as Config>::AuthorityOrigin::ensure_origin(origin.clone())?;
+
+			Self::ensure_asset_can_be_migrated(asset_a)?;
+			Self::ensure_asset_can_be_migrated(asset_b)?;
+
+			let amplification =
+				NonZeroU16::new(amplification).ok_or(pallet_stableswap::Error::<T>::InvalidAmplification)?;
+
+			let asset_state_a = OmnipoolPallet::<T>::load_asset_state(asset_a)?;
+			let asset_state_b = OmnipoolPallet::<T>::load_asset_state(asset_b)?;
+
+			let state_a: MathReserveState<Balance> = (&asset_state_a).into();
+			let state_b: MathReserveState<Balance> = (&asset_state_b).into();
+
+			let subpool_state = create_subpool_initial_state(&state_a, &state_b).ok_or(ArithmeticError::Overflow)?;
+
+			let pool_id = StableswapPallet::<T>::do_create_pool(
+				share_asset,
+				&[asset_a, asset_b],
+				amplification,
+				trade_fee,
+				withdraw_fee,
+			)?;
+
+			let omnipool_account = OmnipoolPallet::<T>::protocol_account();
+
+			StableswapPallet::<T>::move_liquidity_to_pool(
+				&omnipool_account,
+				pool_id,
+				&[
+					AssetLiquidity {
+						asset_id: asset_a,
+						amount: state_a.reserve,
+					},
+					AssetLiquidity {
+						asset_id: asset_b,
+						amount: state_b.reserve,
+					},
+				],
+			)?;
+
+			CurrencyOf::<T>::deposit(pool_id, &omnipool_account, subpool_state.reserve)?;
+
+			for (asset_id, asset_state) in [(asset_a, &state_a), (asset_b, &state_b)] {
+				let (details, _) = calculate_asset_migration_details(asset_state, None, Balance::zero())
+					.ok_or(ArithmeticError::Overflow)?;
+
+				OmnipoolPallet::<T>::remove_asset(asset_id)?;
+				<T as pallet_omnipool::Config>::OmnipoolHooks::on_asset_removed(asset_id)?;
+				MigratedAssets::<T>::insert(asset_id, (pool_id, AssetDetail::from(details)));
+			}
+
+			let changes = AssetStateChange {
+				delta_reserve: BalanceUpdate::Increase(subpool_state.reserve),
+				delta_hub_reserve: BalanceUpdate::Increase(subpool_state.hub_reserve),
+				delta_shares: BalanceUpdate::Increase(subpool_state.shares),
+				delta_protocol_shares: BalanceUpdate::Increase(subpool_state.protocol_shares),
+			};
+
+			OmnipoolPallet::<T>::add_asset(
+				pool_id,
+				(subpool_state, share_asset_weight_cap, Tradability::default()).into(),
+			)?;
+
+			let share_asset_state = OmnipoolPallet::<T>::load_asset_state(pool_id)?;
+			<T as pallet_omnipool::Config>::OmnipoolHooks::on_liquidity_changed(
+				origin,
+				AssetInfo::new(pool_id, &AssetReserveState::default(), &share_asset_state, &changes),
+			)?;
+
+			Self::deposit_event(Event::SubpoolCreated {
+				pool_id,
+				assets: (asset_a, asset_b),
+			});
+
+			Ok(())
+		}
+
+		/// Migrate an Omnipool asset to an existing subpool.
+		///
+		/// Reserve of the asset is moved to the subpool. Hub reserve, shares and protocol shares of the asset are
+		/// added to the state of the subpool share asset in Omnipool and corresponding amount of share tokens is
+		/// minted to the Omnipool account.
+		///
+		/// Omnipool hooks are called for the share asset and for the removed asset, so that the oracle and the
+		/// circuit breaker are aware of the change.
+		///
+		/// Positions of the asset have to be converted by their owners by `migrate_position`.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: subpool id
+		/// - `asset_id`: Omnipool asset to migrate
+		///
+		/// Emits `AssetMigrated` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::migrate_asset_to_subpool()
+			.saturating_add(<T as pallet_omnipool::Config>::OmnipoolHooks::on_liquidity_changed_weight())
+			.saturating_add(<T as pallet_omnipool::Config>::OmnipoolHooks::on_asset_removed_weight())
+		)]
+		#[transactional]
+		pub fn migrate_asset_to_subpool(
+			origin: OriginFor<T>,
+			pool_id: AssetIdOf<T>,
+			asset_id: AssetIdOf<T>,
+		) -> DispatchResult {
+			<T as Config>::AuthorityOrigin::ensure_origin(origin.clone())?;
+
+			Self::ensure_asset_can_be_migrated(asset_id)?;
+			ensure!(
+				pallet_stableswap::Pools::<T>::contains_key(pool_id) && OmnipoolPallet::<T>::exists(pool_id),
+				Error::<T>::SubpoolNotFound
+			);
+
+			let asset_state: MathReserveState<Balance> = (&OmnipoolPallet::<T>::load_asset_state(asset_id)?).into();
+			let share_asset_state = OmnipoolPallet::<T>::load_asset_state(pool_id)?;
+			let subpool_state: MathReserveState<Balance> = (&share_asset_state).into();
+			let share_issuance = CurrencyOf::<T>::total_issuance(pool_id);
+
+			let (details, delta) =
+				calculate_asset_migration_details(&asset_state, Some(&subpool_state), share_issuance)
+					.ok_or(ArithmeticError::Overflow)?;
+			let delta = delta.ok_or(ArithmeticError::Overflow)?;
+
+			let omnipool_account = OmnipoolPallet::<T>::protocol_account();
+
+			StableswapPallet::<T>::add_asset_to_pool(pool_id, asset_id)?;
+			StableswapPallet::<T>::move_liquidity_to_pool(
+				&omnipool_account,
+				pool_id,
+				&[AssetLiquidity {
+					asset_id,
+					amount: asset_state.reserve,
+				}],
+			)?;
+
+			CurrencyOf::<T>::deposit(pool_id, &omnipool_account, details.share_tokens)?;
+
+			OmnipoolPallet::<T>::update_asset_state(pool_id, delta.clone())?;
+			OmnipoolPallet::<T>::remove_asset(asset_id)?;
+
+			let updated_share_asset_state = OmnipoolPallet::<T>::load_asset_state(pool_id)?;
+			<T as pallet_omnipool::Config>::OmnipoolHooks::on_liquidity_changed(
+				origin,
+				AssetInfo::new(pool_id, &share_asset_state, &updated_share_asset_state, &delta),
+			)?;
+			<T as pallet_omnipool::Config>::OmnipoolHooks::on_asset_removed(asset_id)?;
+
+			MigratedAssets::<T>::insert(asset_id, (pool_id, AssetDetail::from(details)));
+
+			Self::deposit_event(Event::AssetMigrated { asset_id, pool_id });
+
+			Ok(())
+		}
+
+		/// Convert Omnipool position of a migrated asset to a position of the subpool share asset.
+		///
+		/// Position NFT stays the same. Amount, shares and price of the position are converted using details
+		/// of the asset migration, so the value of the position is preserved.
+		///
+		/// Parameters:
+		/// - `origin`: Owner of the position
+		/// - `position_id`: Omnipool position id
+		///
+		/// Emits `PositionMigrated` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::migrate_position())]
+		#[transactional]
+		pub fn migrate_position(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let position = OmnipoolPallet::<T>::load_position(position_id, who.clone())?;
+
+			let (pool_id, detail) = MigratedAssets::<T>::get(position.asset_id).ok_or(Error::<T>::AssetNotMigrated)?;
+
+			let converted = convert_position((&position).into(), detail.into()).ok_or(ArithmeticError::Overflow)?;
+
+			let updated_position = Position {
+				asset_id: pool_id,
+				amount: converted.amount,
+				shares: converted.shares,
+				price: converted.price,
+			};
+
+			OmnipoolPallet::<T>::set_position(position_id, &updated_position)?;
+
+			Self::deposit_event(Event::PositionMigrated {
+				position_id,
+				owner: who,
+				pool_id,
+			});
+
+			Ok(())
+		}
+
+		/// Execute a swap of `asset_in` for `asset_out` where either of the assets can be a migrated asset.
+		///
+		/// Trade is executed in the subpool if both assets are in the same subpool and in Omnipool if none of the
+		/// assets has been migrated. Otherwise, migrated `asset_in` is added as liquidity to its subpool, received
+		/// share asset is sold in Omnipool and, if `asset_out` is a migrated asset, its liquidity is removed from
+		/// its subpool.
+		///
+		/// Parameters:
+		/// - `origin`: Trader
+		/// - `asset_in`: ID of asset sold
+		/// - `asset_out`: ID of asset bought
+		/// - `amount`: Amount of asset sold
+		/// - `min_buy_amount`: Minimum amount required to receive
+		///
+		/// Emits events of the underlying Omnipool and Stableswap operations.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::sell()
+			.saturating_add(<T as pallet_omnipool::Config>::OmnipoolHooks::on_trade_weight())
+			.saturating_add(<T as pallet_omnipool::Config>::OmnipoolHooks::on_liquidity_changed_weight())
+			.saturating_add(<T as pallet_stableswap::Config>::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize).saturating_mul(2))
+		)]
+		#[transactional]
+		pub fn sell(
+			origin: OriginFor<T>,
+			asset_in: AssetIdOf<T>,
+			asset_out: AssetIdOf<T>,
+			amount: Balance,
+			min_buy_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			let subpool_in = Self::subpool_of(asset_in);
+			let subpool_out = Self::subpool_of(asset_out);
+
+			match (subpool_in, subpool_out) {
+				(None, None) => OmnipoolPallet::<T>::sell(origin, asset_in, asset_out, amount, min_buy_amount),
+				(Some(pool_in), Some(pool_out)) if pool_in == pool_out => {
+					StableswapPallet::<T>::sell(origin, pool_in, asset_in, asset_out, amount, min_buy_amount)
+				}
+				_ => {
+					let initial_balance = CurrencyOf::<T>::free_balance(asset_out, &who);
+
+					let (omnipool_asset_in, omnipool_amount_in) = if let Some(pool_id) = subpool_in {
+						let shares = Self::received_amount(pool_id, &who, || {
+							StableswapPallet::<T>::add_liquidity(
+								origin.clone(),
+								pool_id,
+								vec![AssetLiquidity {
+									asset_id: asset_in,
+									amount,
+								}],
+								Balance::zero(),
+							)
+						})?;
+						(pool_id, shares)
+					} else {
+						(asset_in, amount)
+					};
+
+					let omnipool_asset_out = subpool_out.unwrap_or(asset_out);
+
+					let omnipool_amount_out = if omnipool_asset_in != omnipool_asset_out {
+						Self::received_amount(omnipool_asset_out, &who, || {
+							OmnipoolPallet::<T>::sell(
+								origin.clone(),
+								omnipool_asset_in,
+								omnipool_asset_out,
+								omnipool_amount_in,
+								Balance::zero(),
+							)
+						})?
+					} else {
+						omnipool_amount_in
+					};
+
+					if let Some(pool_id) = subpool_out {
+						StableswapPallet::<T>::remove_liquidity_one_asset(
+							origin,
+							pool_id,
+							asset_out,
+							omnipool_amount_out,
+						)?;
+					}
+
+					let received = CurrencyOf::<T>::free_balance(asset_out, &who).saturating_sub(initial_balance);
+					ensure!(received >= min_buy_amount, Error::<T>::BuyLimitNotReached);
+
+					Ok(())
+				}
+			}
+		}
+
+		/// Execute a swap of `asset_in` for `asset_out` by specifying how much to receive, where either of the assets
+		/// can be a migrated asset.
+		///
+		/// Trade is executed in the subpool if both assets are in the same subpool and in Omnipool if none of the
+		/// assets has been migrated. Otherwise, amount of `asset_out` is converted to amount of its subpool share
+		/// asset (if migrated), which is bought in Omnipool for share asset of `asset_in` subpool obtained by adding
+		/// liquidity of `asset_in` (if migrated).
+		///
+		/// Parameters:
+		/// - `origin`: Trader
+		/// - `asset_out`: ID of asset bought
+		/// - `asset_in`: ID of asset sold
+		/// - `amount`: Amount of asset bought
+		/// - `max_sell_amount`: Maximum amount to be sold
+		///
+		/// Emits events of the underlying Omnipool and Stableswap operations.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy()
+			.saturating_add(<T as pallet_omnipool::Config>::OmnipoolHooks::on_trade_weight())
+			.saturating_add(<T as pallet_omnipool::Config>::OmnipoolHooks::on_liquidity_changed_weight())
+			.saturating_add(<T as pallet_stableswap::Config>::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize).saturating_mul(2))
+		)]
+		#[transactional]
+		pub fn buy(
+			origin: OriginFor<T>,
+			asset_out: AssetIdOf<T>,
+			asset_in: AssetIdOf<T>,
+			amount: Balance,
+			max_sell_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			let subpool_in = Self::subpool_of(asset_in);
+			let subpool_out = Self::subpool_of(asset_out);
+
+			match (subpool_in, subpool_out) {
+				(None, None) => OmnipoolPallet::<T>::buy(origin, asset_out, asset_in, amount, max_sell_amount),
+				(Some(pool_in), Some(pool_out)) if pool_in == pool_out => {
+					StableswapPallet::<T>::buy(origin, pool_in, asset_out, asset_in, amount, max_sell_amount)
+				}
+				_ => {
+					let initial_balance = CurrencyOf::<T>::free_balance(asset_in, &who);
+
+					let (omnipool_asset_out, omnipool_amount_out) = if let Some(pool_id) = subpool_out {
+						let shares = StableswapPallet::<T>::calculate_shares_for_amount(pool_id, asset_out, amount)?;
+						(pool_id, shares)
+					} else {
+						(asset_out, amount)
+					};
+
+					let omnipool_asset_in = subpool_in.unwrap_or(asset_in);
+
+					if let Some(pool_id) = subpool_in {
+						let shares = if omnipool_asset_in != omnipool_asset_out {
+							OmnipoolPallet::<T>::calculate_buy_quote(
+								omnipool_asset_in,
+								omnipool_asset_out,
+								omnipool_amount_out,
+							)?
+							.amount_in
+						} else {
+							omnipool_amount_out
+						};
+
+						StableswapPallet::<T>::add_liquidity_shares(
+							origin.clone(),
+							pool_id,
+							shares,
+							asset_in,
+							max_sell_amount,
+						)?;
+					}
+
+					if omnipool_asset_in != omnipool_asset_out {
+						OmnipoolPallet::<T>::buy(
+							origin.clone(),
+							omnipool_asset_out,
+							omnipool_asset_in,
+							omnipool_amount_out,
+							Balance::MAX,
+						)?;
+					}
+
+					if let Some(pool_id) = subpool_out {
+						StableswapPallet::<T>::remove_liquidity_one_asset(
+							origin,
+							pool_id,
+							asset_out,
+							omnipool_amount_out,
+						)?;
+					}
+
+					let spent = initial_balance.saturating_sub(CurrencyOf::<T>::free_balance(asset_in, &who));
+					ensure!(spent <= max_sell_amount, Error::<T>::SellLimitExceeded);
+
+					Ok(())
+				}
+			}
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns id of the subpool the asset has been migrated to.
+	pub fn subpool_of(asset_id: AssetIdOf<T>) -> Option<AssetIdOf<T>> {
+		MigratedAssets::<T>::get(asset_id).map(|(pool_id, _)| pool_id)
+	}
+
+	/// Hub asset, native asset and stable asset are required by Omnipool and cannot be migrated.
+	/// Share assets of existing subpools cannot be migrated either.
+	fn ensure_asset_can_be_migrated(asset_id: AssetIdOf<T>) -> DispatchResult {
+		ensure!(
+			asset_id != <T as pallet_omnipool::Config>::HubAssetId::get()
+				&& asset_id != <T as pallet_omnipool::Config>::HdxAssetId::get()
+				&& asset_id != <T as pallet_omnipool::Config>::StableCoinAssetId::get()
+				&& !pallet_stableswap::Pools::<T>::contains_key(asset_id),
+			Error::<T>::NotAllowed
+		);
+		Ok(())
+	}
+
+	/// Execute `f` and return amount of `asset_id` received by `who`.
+	fn received_amount(
+		asset_id: AssetIdOf<T>,
+		who: &T::AccountId,
+		f: impl FnOnce() -> DispatchResult,
+	) -> Result<Balance, DispatchError> {
+		let initial_balance = CurrencyOf::<T>::free_balance(asset_id, who);
+		f()?;
+		Ok(CurrencyOf::<T>::free_balance(asset_id, who).saturating_sub(initial_balance))
+	}
+}
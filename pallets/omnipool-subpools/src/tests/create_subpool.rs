@@ -0,0 +1,195 @@
+use super::mock::*;
+use crate::types::AssetDetail;
+use crate::{Error, Event, MigratedAssets};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::{DispatchError::BadOrigin, Permill};
+
+#[test]
+fn create_subpool_should_work() {
+	omnipool_with_tokens().build().execute_with(|| {
+		let state_a = Omnipool::load_asset_state(ASSET_A).unwrap();
+		let state_b = Omnipool::load_asset_state(ASSET_B).unwrap();
+		let hub_reserve = state_a.hub_reserve + state_b.hub_reserve;
+
+		assert_ok!(OmnipoolSubpools::create_subpool(
+			RuntimeOrigin::root(),
+			SHARE_ASSET,
+			ASSET_A,
+			ASSET_B,
+			Permill::from_percent(50),
+			100u16,
+			Permill::from_percent(1),
+			Permill::from_percent(2),
+		));
+
+		assert!(!Omnipool::exists(ASSET_A));
+		assert!(!Omnipool::exists(ASSET_B));
+
+		let subpool_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+		assert_eq!(subpool_state.hub_reserve, hub_reserve);
+		assert_eq!(subpool_state.shares, hub_reserve);
+		assert_eq!(subpool_state.reserve, hub_reserve);
+		assert_eq!(Tokens::total_issuance(SHARE_ASSET), hub_reserve);
+
+		let pool = Stableswap::pools(SHARE_ASSET).unwrap();
+		assert_eq!(pool.assets.to_vec(), vec![ASSET_A, ASSET_B]);
+		assert_eq!(pool.trade_fee, Permill::from_percent(1));
+		assert_eq!(pool.withdraw_fee, Permill::from_percent(2));
+
+		let pool_account = stableswap_pool_account(SHARE_ASSET);
+		assert_eq!(Tokens::free_balance(ASSET_A, &pool_account), state_a.reserve);
+		assert_eq!(Tokens::free_balance(ASSET_B, &pool_account), state_b.reserve);
+		assert_eq!(Tokens::free_balance(ASSET_A, &Omnipool::protocol_account()), 0);
+		assert_eq!(Tokens::free_balance(ASSET_B, &Omnipool::protocol_account()), 0);
+
+		assert_eq!(
+			MigratedAssets::<Test>::get(ASSET_A),
+			Some((
+				SHARE_ASSET,
+				AssetDetail {
+					price: (state_a.hub_reserve, state_a.reserve),
+					shares: state_a.shares,
+					hub_reserve: state_a.hub_reserve,
+					subpool_shares: state_a.hub_reserve,
+					share_tokens: state_a.hub_reserve,
+				}
+			))
+		);
+		assert_eq!(
+			MigratedAssets::<Test>::get(ASSET_B),
+			Some((
+				SHARE_ASSET,
+				AssetDetail {
+					price: (state_b.hub_reserve, state_b.reserve),
+					shares: state_b.shares,
+					hub_reserve: state_b.hub_reserve,
+					subpool_shares: state_b.hub_reserve,
+					share_tokens: state_b.hub_reserve,
+				}
+			))
+		);
+
+		expect_events(vec![Event::SubpoolCreated {
+			pool_id: SHARE_ASSET,
+			assets: (ASSET_A, ASSET_B),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn create_subpool_should_not_change_total_hub_reserve() {
+	omnipool_with_tokens().build().execute_with(|| {
+		let hub_balance = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+		let imbalance = Omnipool::current_imbalance();
+
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		assert_eq!(Tokens::free_balance(LRNA, &Omnipool::protocol_account()), hub_balance);
+		assert_eq!(Omnipool::current_imbalance(), imbalance);
+	});
+}
+
+#[test]
+fn create_subpool_should_fail_when_called_by_non_authority_origin() {
+	omnipool_with_tokens().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::signed(LP1),
+				SHARE_ASSET,
+				ASSET_A,
+				ASSET_B,
+				Permill::from_percent(100),
+				100u16,
+				Permill::zero(),
+				Permill::zero(),
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_subpool_should_fail_when_asset_is_required_by_omnipool() {
+	omnipool_with_tokens().build().execute_with(|| {
+		for asset_id in [HDX, LRNA, DAI] {
+			assert_noop!(
+				OmnipoolSubpools::create_subpool(
+					RuntimeOrigin::root(),
+					SHARE_ASSET,
+					asset_id,
+					ASSET_B,
+					Permill::from_percent(100),
+					100u16,
+					Permill::zero(),
+					Permill::zero(),
+				),
+				Error::<Test>::NotAllowed
+			);
+		}
+	});
+}
+
+#[test]
+fn create_subpool_should_fail_when_asset_is_not_in_omnipool() {
+	omnipool_with_tokens()
+		.with_registered_asset(500)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				OmnipoolSubpools::create_subpool(
+					RuntimeOrigin::root(),
+					SHARE_ASSET,
+					ASSET_A,
+					500,
+					Permill::from_percent(100),
+					100u16,
+					Permill::zero(),
+					Permill::zero(),
+				),
+				pallet_omnipool::Error::<Test>::AssetNotFound
+			);
+		});
+}
+
+#[test]
+fn create_subpool_should_fail_when_share_asset_is_not_registered() {
+	omnipool_with_tokens().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::root(),
+				5_000,
+				ASSET_A,
+				ASSET_B,
+				Permill::from_percent(100),
+				100u16,
+				Permill::zero(),
+				Permill::zero(),
+			),
+			pallet_stableswap::Error::<Test>::ShareAssetNotRegistered
+		);
+	});
+}
+
+#[test]
+fn create_subpool_should_fail_when_asset_was_already_migrated() {
+	omnipool_with_tokens().build().execute_with(|| {
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		assert_noop!(
+			OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::root(),
+				SHARE_ASSET_2,
+				ASSET_A,
+				ASSET_C,
+				Permill::from_percent(100),
+				100u16,
+				Permill::zero(),
+				Permill::zero(),
+			),
+			pallet_omnipool::Error::<Test>::AssetNotFound
+		);
+	});
+}
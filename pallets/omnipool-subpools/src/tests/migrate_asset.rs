@@ -0,0 +1,129 @@
+use super::mock::*;
+use crate::{Error, Event, MigratedAssets};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+#[test]
+fn migrate_asset_to_subpool_should_work() {
+	omnipool_with_tokens().build().execute_with(|| {
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		let asset_state = Omnipool::load_asset_state(ASSET_C).unwrap();
+		let subpool_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+		let share_issuance = Tokens::total_issuance(SHARE_ASSET);
+
+		assert_ok!(OmnipoolSubpools::migrate_asset_to_subpool(
+			RuntimeOrigin::root(),
+			SHARE_ASSET,
+			ASSET_C
+		));
+
+		assert!(!Omnipool::exists(ASSET_C));
+
+		let pool = Stableswap::pools(SHARE_ASSET).unwrap();
+		assert_eq!(pool.assets.to_vec(), vec![ASSET_A, ASSET_B, ASSET_C]);
+		assert_eq!(
+			Tokens::free_balance(ASSET_C, &stableswap_pool_account(SHARE_ASSET)),
+			asset_state.reserve
+		);
+		assert_eq!(Tokens::free_balance(ASSET_C, &Omnipool::protocol_account()), 0);
+
+		let share_tokens = asset_state.hub_reserve * share_issuance / subpool_state.hub_reserve;
+		let delta_shares = asset_state.hub_reserve * subpool_state.shares / subpool_state.hub_reserve;
+
+		let updated_subpool_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+		assert_eq!(
+			updated_subpool_state.hub_reserve,
+			subpool_state.hub_reserve + asset_state.hub_reserve
+		);
+		assert_eq!(updated_subpool_state.shares, subpool_state.shares + delta_shares);
+		assert_eq!(updated_subpool_state.reserve, subpool_state.reserve + share_tokens);
+		assert_eq!(Tokens::total_issuance(SHARE_ASSET), share_issuance + share_tokens);
+
+		let (pool_id, detail) = MigratedAssets::<Test>::get(ASSET_C).unwrap();
+		assert_eq!(pool_id, SHARE_ASSET);
+		assert_eq!(detail.shares, asset_state.shares);
+		assert_eq!(detail.hub_reserve, asset_state.hub_reserve);
+		assert_eq!(detail.subpool_shares, delta_shares);
+		assert_eq!(detail.share_tokens, share_tokens);
+
+		expect_events(vec![Event::AssetMigrated {
+			asset_id: ASSET_C,
+			pool_id: SHARE_ASSET,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn migrate_asset_to_subpool_should_keep_share_asset_price() {
+	omnipool_with_tokens().build().execute_with(|| {
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		let subpool_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+
+		assert_ok!(OmnipoolSubpools::migrate_asset_to_subpool(
+			RuntimeOrigin::root(),
+			SHARE_ASSET,
+			ASSET_C
+		));
+
+		let updated_subpool_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+
+		assert_eq!(
+			subpool_state.hub_reserve * updated_subpool_state.reserve,
+			updated_subpool_state.hub_reserve * subpool_state.reserve
+		);
+	});
+}
+
+#[test]
+fn migrate_asset_to_subpool_should_fail_when_called_by_non_authority_origin() {
+	omnipool_with_tokens().build().execute_with(|| {
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		assert_noop!(
+			OmnipoolSubpools::migrate_asset_to_subpool(RuntimeOrigin::signed(LP1), SHARE_ASSET, ASSET_C),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn migrate_asset_to_subpool_should_fail_when_subpool_does_not_exist() {
+	omnipool_with_tokens().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::migrate_asset_to_subpool(RuntimeOrigin::root(), SHARE_ASSET, ASSET_C),
+			Error::<Test>::SubpoolNotFound
+		);
+	});
+}
+
+#[test]
+fn migrate_asset_to_subpool_should_fail_when_asset_is_required_by_omnipool() {
+	omnipool_with_tokens().build().execute_with(|| {
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		for asset_id in [HDX, LRNA, DAI] {
+			assert_noop!(
+				OmnipoolSubpools::migrate_asset_to_subpool(RuntimeOrigin::root(), SHARE_ASSET, asset_id),
+				Error::<Test>::NotAllowed
+			);
+		}
+	});
+}
+
+#[test]
+fn migrate_asset_to_subpool_should_fail_when_asset_is_share_asset_of_another_subpool() {
+	omnipool_with_tokens().build().execute_with(|| {
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+		create_subpool(SHARE_ASSET_2, ASSET_C, ASSET_D);
+
+		assert_noop!(
+			OmnipoolSubpools::migrate_asset_to_subpool(RuntimeOrigin::root(), SHARE_ASSET, SHARE_ASSET_2),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
@@ -0,0 +1,153 @@
+use super::mock::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn migrate_position_should_work_when_subpool_is_created() {
+	omnipool_with_tokens().build().execute_with(|| {
+		let position_id = Omnipool::next_position_id();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP2), ASSET_A, 100 * ONE));
+
+		let position = Omnipool::positions(position_id).unwrap();
+		let asset_state = Omnipool::load_asset_state(ASSET_A).unwrap();
+
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		assert_ok!(OmnipoolSubpools::migrate_position(
+			RuntimeOrigin::signed(LP2),
+			position_id
+		));
+
+		let migrated_position = Omnipool::positions(position_id).unwrap();
+		assert_eq!(migrated_position.asset_id, SHARE_ASSET);
+		assert_eq!(
+			migrated_position.shares,
+			position.shares * asset_state.hub_reserve / asset_state.shares
+		);
+
+		expect_events(vec![Event::PositionMigrated {
+			position_id,
+			owner: LP2,
+			pool_id: SHARE_ASSET,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn migrated_position_should_preserve_value_when_liquidity_is_removed() {
+	omnipool_with_tokens().build().execute_with(|| {
+		let position_id = Omnipool::next_position_id();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP2), ASSET_A, 100 * ONE));
+
+		let asset_state = Omnipool::load_asset_state(ASSET_A).unwrap();
+		// value of the position in hub asset
+		let position_value = 100 * ONE * asset_state.hub_reserve / asset_state.reserve;
+
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		assert_ok!(OmnipoolSubpools::migrate_position(
+			RuntimeOrigin::signed(LP2),
+			position_id
+		));
+
+		let position = Omnipool::positions(position_id).unwrap();
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP2),
+			position_id,
+			position.shares
+		));
+
+		// share asset price is 1 as long as no trades were executed
+		let received_shares = Tokens::free_balance(SHARE_ASSET, &LP2);
+		assert!(received_shares.abs_diff(position_value) <= 1_000);
+		assert!(Tokens::free_balance(LRNA, &LP2) <= 1_000);
+	});
+}
+
+#[test]
+fn migrate_position_should_work_when_asset_is_migrated_to_existing_subpool() {
+	omnipool_with_tokens().build().execute_with(|| {
+		let position_id = Omnipool::next_position_id();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP2), ASSET_C, 100 * ONE));
+
+		let position = Omnipool::positions(position_id).unwrap();
+
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		let asset_state = Omnipool::load_asset_state(ASSET_C).unwrap();
+		let subpool_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+
+		assert_ok!(OmnipoolSubpools::migrate_asset_to_subpool(
+			RuntimeOrigin::root(),
+			SHARE_ASSET,
+			ASSET_C
+		));
+
+		assert_ok!(OmnipoolSubpools::migrate_position(
+			RuntimeOrigin::signed(LP2),
+			position_id
+		));
+
+		let migrated_position = Omnipool::positions(position_id).unwrap();
+		assert_eq!(migrated_position.asset_id, SHARE_ASSET);
+
+		let delta_shares = asset_state.hub_reserve * subpool_state.shares / subpool_state.hub_reserve;
+		assert_eq!(
+			migrated_position.shares,
+			position.shares * delta_shares / asset_state.shares
+		);
+	});
+}
+
+#[test]
+fn migrate_position_should_fail_when_caller_is_not_position_owner() {
+	omnipool_with_tokens().build().execute_with(|| {
+		let position_id = Omnipool::next_position_id();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP2), ASSET_A, 100 * ONE));
+
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		assert_noop!(
+			OmnipoolSubpools::migrate_position(RuntimeOrigin::signed(LP3), position_id),
+			pallet_omnipool::Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn migrate_position_should_fail_when_asset_was_not_migrated() {
+	omnipool_with_tokens().build().execute_with(|| {
+		let position_id = Omnipool::next_position_id();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP2), ASSET_C, 100 * ONE));
+
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		assert_noop!(
+			OmnipoolSubpools::migrate_position(RuntimeOrigin::signed(LP2), position_id),
+			Error::<Test>::AssetNotMigrated
+		);
+	});
+}
+
+#[test]
+fn migrate_position_should_fail_when_position_was_already_migrated() {
+	omnipool_with_tokens().build().execute_with(|| {
+		let position_id = Omnipool::next_position_id();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP2), ASSET_A, 100 * ONE));
+
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		assert_ok!(OmnipoolSubpools::migrate_position(
+			RuntimeOrigin::signed(LP2),
+			position_id
+		));
+
+		assert_noop!(
+			OmnipoolSubpools::migrate_position(RuntimeOrigin::signed(LP2), position_id),
+			Error::<Test>::AssetNotMigrated
+		);
+	});
+}
@@ -0,0 +1,507 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for Omnipool subpools pallet.
+
+use crate::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate as pallet_omnipool_subpools;
+
+use core::ops::RangeInclusive;
+use frame_support::dispatch::Weight;
+use frame_support::traits::{Contains, Everything, GenesisBuild};
+use frame_support::{
+	assert_ok, construct_runtime, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::{AccountIdFor, Registry};
+use orml_traits::{parameter_type_with_key, GetByKey};
+use pallet_omnipool::traits::ExternalPriceProvider;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	DispatchError, FixedU128,
+};
+use std::num::NonZeroU16;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+pub type AssetId = u32;
+
+pub const HDX: AssetId = 0;
+pub const LRNA: AssetId = 1;
+pub const DAI: AssetId = 2;
+
+pub const ASSET_A: AssetId = 100;
+pub const ASSET_B: AssetId = 200;
+pub const ASSET_C: AssetId = 300;
+pub const ASSET_D: AssetId = 400;
+
+pub const SHARE_ASSET: AssetId = 1_000;
+pub const SHARE_ASSET_2: AssetId = 2_000;
+
+pub const LP1: AccountId = 1;
+pub const LP2: AccountId = 2;
+pub const LP3: AccountId = 3;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const NATIVE_AMOUNT: Balance = 10_000 * ONE;
+
+thread_local! {
+	pub static POSITIONS: RefCell<HashMap<u32, u64>> = RefCell::new(HashMap::default());
+	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, u32>> = RefCell::new(HashMap::default());
+	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = RefCell::new(Vec::new());
+}
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Tokens: orml_tokens,
+		Omnipool: pallet_omnipool,
+		Stableswap: pallet_stableswap,
+		OmnipoolSubpools: pallet_omnipool_subpools,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Everything;
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type CurrencyHooks = ();
+}
+
+parameter_types! {
+	pub const HDXAssetId: AssetId = HDX;
+	pub const LRNAAssetId: AssetId = LRNA;
+	pub const DAIAssetId: AssetId = DAI;
+	pub const PositionCollectionId: u32 = 1000;
+	pub const MinAddedLiquidity: Balance = 1000;
+	pub const MinTradeAmount: Balance = 1000;
+	pub const MaxInRatio: Balance = 1;
+	pub const MaxOutRatio: Balance = 1;
+	pub const MinWithdrawFee: Permill = Permill::from_percent(0);
+	pub const ImbalanceBurnRatio: Permill = Permill::from_percent(10);
+	pub const MaxAddLiquidityAssets: u32 = 5;
+	pub AmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
+}
+
+impl pallet_omnipool::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type PositionItemId = u32;
+	type Currency = Tokens;
+	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
+	type HubAssetId = LRNAAssetId;
+	type StableCoinAssetId = DAIAssetId;
+	type WeightInfo = ();
+	type HdxAssetId = HDXAssetId;
+	type NFTCollectionId = PositionCollectionId;
+	type NFTHandler = DummyNFT;
+	type AssetRegistry = DummyRegistry;
+	type MinimumTradingLimit = MinTradeAmount;
+	type MinimumPoolLiquidity = MinAddedLiquidity;
+	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
+	type MaxInRatio = MaxInRatio;
+	type MaxOutRatio = MaxOutRatio;
+	type CollectionId = u32;
+	type OmnipoolHooks = ();
+	type PriceBarrier = ();
+	type MinWithdrawalFee = MinWithdrawFee;
+	type ExternalPriceOracle = SpotPriceOracle;
+	type Fee = FeeProvider;
+	type ProtocolFeeCollector = ();
+	type FeeLedgerPeriod = ConstU64<10>;
+	type ImbalanceBurnRatio = ImbalanceBurnRatio;
+	type MaxAddLiquidityAssets = MaxAddLiquidityAssets;
+}
+
+impl pallet_stableswap::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type ShareAccountId = AccountIdConstructor;
+	type AssetRegistry = DummyRegistry;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type MinPoolLiquidity = MinAddedLiquidity;
+	type AmplificationRange = AmplificationRange;
+	type MinTradingLimit = MinTradeAmount;
+	type WeightInfo = ();
+	type BlockNumberProvider = System;
+	type Hooks = ();
+	type DustAccountHandler = Whitelist;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	registered_assets: Vec<AssetId>,
+	init_pool: Option<(FixedU128, FixedU128)>,
+	pool_tokens: Vec<(AssetId, FixedU128, AccountId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each  test case
+		// or set to original default value
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		POSITIONS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		DUSTER_WHITELIST.with(|v| {
+			v.borrow_mut().clear();
+		});
+
+		Self {
+			endowed_accounts: vec![
+				(Omnipool::protocol_account(), DAI, 1000 * ONE),
+				(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			],
+			registered_assets: vec![],
+			init_pool: None,
+			pool_tokens: vec![],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn add_endowed_accounts(mut self, account: (AccountId, AssetId, Balance)) -> Self {
+		self.endowed_accounts.push(account);
+		self
+	}
+
+	pub fn with_registered_asset(mut self, asset: AssetId) -> Self {
+		self.registered_assets.push(asset);
+		self
+	}
+
+	pub fn with_initial_pool(mut self, stable_price: FixedU128, native_price: FixedU128) -> Self {
+		self.init_pool = Some((stable_price, native_price));
+		self
+	}
+
+	pub fn with_token(
+		mut self,
+		asset_id: AssetId,
+		price: FixedU128,
+		position_owner: AccountId,
+		amount: Balance,
+	) -> Self {
+		self.registered_assets.push(asset_id);
+		self.endowed_accounts.push((position_owner, asset_id, amount));
+		self.pool_tokens.push((asset_id, price, position_owner, amount));
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().insert(HDX, HDX);
+			v.borrow_mut().insert(DAI, DAI);
+			self.registered_assets.iter().for_each(|asset| {
+				v.borrow_mut().insert(*asset, *asset);
+			});
+		});
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Omnipool::set_tvl_cap(RuntimeOrigin::root(), Balance::MAX));
+		});
+
+		if let Some((stable_price, native_price)) = self.init_pool {
+			r.execute_with(|| {
+				assert_ok!(Omnipool::initialize_pool(
+					RuntimeOrigin::root(),
+					stable_price,
+					native_price,
+					Permill::from_percent(100),
+					Permill::from_percent(100)
+				));
+
+				for (asset_id, price, owner, amount) in self.pool_tokens {
+					assert_ok!(Tokens::transfer(
+						RuntimeOrigin::signed(owner),
+						Omnipool::protocol_account(),
+						asset_id,
+						amount
+					));
+					assert_ok!(Omnipool::add_token(
+						RuntimeOrigin::root(),
+						asset_id,
+						price,
+						Permill::from_percent(100),
+						owner
+					));
+				}
+			});
+		}
+
+		r
+	}
+}
+
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
+
+pub struct DummyNFT;
+
+impl<AccountId: From<u64>> Inspect<AccountId> for DummyNFT {
+	type ItemId = u32;
+	type CollectionId = u32;
+
+	fn owner(_class: &Self::CollectionId, instance: &Self::ItemId) -> Option<AccountId> {
+		let mut owner: Option<AccountId> = None;
+
+		POSITIONS.with(|v| {
+			if let Some(o) = v.borrow().get(instance) {
+				owner = Some((*o).into());
+			}
+		});
+		owner
+	}
+}
+
+impl<AccountId: From<u64>> Create<AccountId> for DummyNFT {
+	fn create_collection(_class: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+impl<AccountId: From<u64> + Into<u64> + Copy> Mutate<AccountId> for DummyNFT {
+	fn mint_into(_class: &Self::CollectionId, _instance: &Self::ItemId, _who: &AccountId) -> DispatchResult {
+		POSITIONS.with(|v| {
+			let mut m = v.borrow_mut();
+			m.insert(*_instance, (*_who).into());
+		});
+		Ok(())
+	}
+
+	fn burn(
+		_class: &Self::CollectionId,
+		instance: &Self::ItemId,
+		_maybe_check_owner: Option<&AccountId>,
+	) -> DispatchResult {
+		POSITIONS.with(|v| {
+			let mut m = v.borrow_mut();
+			m.remove(instance);
+		});
+		Ok(())
+	}
+}
+
+pub struct DummyRegistry;
+
+impl Registry<AssetId, Vec<u8>, Balance, DispatchError> for DummyRegistry {
+	fn exists(asset_id: AssetId) -> bool {
+		REGISTERED_ASSETS.with(|v| v.borrow().contains_key(&asset_id))
+	}
+
+	fn retrieve_asset(_name: &Vec<u8>) -> Result<AssetId, DispatchError> {
+		Ok(AssetId::default())
+	}
+
+	fn create_asset(_name: &Vec<u8>, _existential_deposit: Balance) -> Result<AssetId, DispatchError> {
+		let assigned = REGISTERED_ASSETS.with(|v| {
+			let l = 10_000 + v.borrow().len() as u32;
+			v.borrow_mut().insert(l, l);
+			l
+		});
+		Ok(assigned)
+	}
+}
+
+pub struct AccountIdConstructor;
+
+impl AccountIdFor<u32> for AccountIdConstructor {
+	type AccountId = AccountId;
+
+	fn from_assets(asset: &u32, _identifier: Option<&[u8]>) -> Self::AccountId {
+		(asset * 1000) as u64
+	}
+
+	fn name(asset: &u32, identifier: Option<&[u8]>) -> Vec<u8> {
+		let mut buf: Vec<u8> = if let Some(ident) = identifier {
+			ident.to_vec()
+		} else {
+			vec![]
+		};
+		buf.extend_from_slice(&(asset).to_le_bytes());
+
+		buf
+	}
+}
+
+pub struct Whitelist;
+
+impl Contains<AccountId> for Whitelist {
+	fn contains(account: &AccountId) -> bool {
+		DUSTER_WHITELIST.with(|v| v.borrow().contains(account))
+	}
+}
+
+impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
+	type Error = DispatchError;
+
+	fn add_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().push(*account));
+		Ok(())
+	}
+
+	fn remove_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| {
+			let mut v = v.borrow_mut();
+
+			let idx = v.iter().position(|x| *x == *account).unwrap();
+			v.remove(idx);
+
+			Ok(())
+		})
+	}
+}
+
+pub struct SpotPriceOracle;
+
+impl ExternalPriceProvider<AssetId, EmaPrice> for SpotPriceOracle {
+	type Error = DispatchError;
+
+	fn get_price(asset_a: AssetId, asset_b: AssetId) -> Result<EmaPrice, Self::Error> {
+		assert_eq!(asset_a, LRNA);
+		let asset_state = Omnipool::load_asset_state(asset_b)?;
+		Ok(EmaPrice::new(asset_state.hub_reserve, asset_state.reserve))
+	}
+
+	fn get_price_weight() -> Weight {
+		todo!()
+	}
+}
+
+pub struct FeeProvider;
+
+impl GetByKey<AssetId, (Permill, Permill)> for FeeProvider {
+	fn get(_: &AssetId) -> (Permill, Permill) {
+		(Permill::zero(), Permill::zero())
+	}
+}
+
+pub(crate) fn stableswap_pool_account(pool_id: AssetId) -> AccountId {
+	AccountIdConstructor::from_assets(&pool_id, None)
+}
+
+pub(crate) fn expect_events(e: Vec<RuntimeEvent>) {
+	test_utils::expect_events::<RuntimeEvent, Test>(e);
+}
+
+/// Omnipool with tokens A, B, C and D. LP2 and LP3 are funded with all the tokens.
+pub(crate) fn omnipool_with_tokens() -> ExtBuilder {
+	let mut builder = ExtBuilder::default()
+		.with_registered_asset(SHARE_ASSET)
+		.with_registered_asset(SHARE_ASSET_2)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET_A, FixedU128::from_float(0.65), LP1, 2_000 * ONE)
+		.with_token(ASSET_B, FixedU128::from_float(0.65), LP1, 2_000 * ONE)
+		.with_token(ASSET_C, FixedU128::from_float(0.65), LP1, 2_000 * ONE)
+		.with_token(ASSET_D, FixedU128::from_float(0.5), LP1, 2_000 * ONE);
+
+	for asset_id in [ASSET_A, ASSET_B, ASSET_C, ASSET_D] {
+		builder = builder
+			.add_endowed_accounts((LP2, asset_id, 1_000 * ONE))
+			.add_endowed_accounts((LP3, asset_id, 1_000 * ONE));
+	}
+
+	builder
+}
+
+pub(crate) fn create_subpool(pool_id: AssetId, asset_a: AssetId, asset_b: AssetId) {
+	assert_ok!(OmnipoolSubpools::create_subpool(
+		RuntimeOrigin::root(),
+		pool_id,
+		asset_a,
+		asset_b,
+		Permill::from_percent(100),
+		100u16,
+		Permill::zero(),
+		Permill::zero(),
+	));
+}
@@ -0,0 +1,5 @@
+mod create_subpool;
+mod migrate_asset;
+mod migrate_position;
+pub(crate) mod mock;
+mod trades;
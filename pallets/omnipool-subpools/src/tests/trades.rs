@@ -0,0 +1,248 @@
+use super::mock::*;
+use crate::Error;
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn sell_should_work_when_both_assets_are_in_same_subpool() {
+	omnipool_with_tokens().build().execute_with(|| {
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		let subpool_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+
+		assert_ok!(OmnipoolSubpools::sell(
+			RuntimeOrigin::signed(LP3),
+			ASSET_A,
+			ASSET_B,
+			10 * ONE,
+			0
+		));
+
+		assert_eq!(Tokens::free_balance(ASSET_A, &LP3), 990 * ONE);
+		assert!(Tokens::free_balance(ASSET_B, &LP3) > 1_000 * ONE);
+		assert_eq!(Tokens::free_balance(SHARE_ASSET, &LP3), 0);
+		assert_eq!(Omnipool::load_asset_state(SHARE_ASSET).unwrap(), subpool_state);
+	});
+}
+
+#[test]
+fn sell_should_work_when_selling_migrated_asset_for_omnipool_asset() {
+	omnipool_with_tokens().build().execute_with(|| {
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		let subpool_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+
+		assert_ok!(OmnipoolSubpools::sell(
+			RuntimeOrigin::signed(LP3),
+			ASSET_A,
+			ASSET_C,
+			10 * ONE,
+			0
+		));
+
+		assert_eq!(Tokens::free_balance(ASSET_A, &LP3), 990 * ONE);
+		assert!(Tokens::free_balance(ASSET_C, &LP3) > 1_000 * ONE);
+		assert_eq!(Tokens::free_balance(SHARE_ASSET, &LP3), 0);
+
+		let updated_subpool_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+		assert!(updated_subpool_state.reserve > subpool_state.reserve);
+		assert!(updated_subpool_state.hub_reserve < subpool_state.hub_reserve);
+	});
+}
+
+#[test]
+fn sell_should_work_when_selling_omnipool_asset_for_migrated_asset() {
+	omnipool_with_tokens().build().execute_with(|| {
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		let subpool_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+
+		assert_ok!(OmnipoolSubpools::sell(
+			RuntimeOrigin::signed(LP3),
+			ASSET_C,
+			ASSET_A,
+			10 * ONE,
+			0
+		));
+
+		assert_eq!(Tokens::free_balance(ASSET_C, &LP3), 990 * ONE);
+		assert!(Tokens::free_balance(ASSET_A, &LP3) > 1_000 * ONE);
+		assert_eq!(Tokens::free_balance(SHARE_ASSET, &LP3), 0);
+
+		let updated_subpool_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+		assert!(updated_subpool_state.reserve < subpool_state.reserve);
+		assert!(updated_subpool_state.hub_reserve > subpool_state.hub_reserve);
+	});
+}
+
+#[test]
+fn sell_should_work_when_assets_are_in_different_subpools() {
+	omnipool_with_tokens().build().execute_with(|| {
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+		create_subpool(SHARE_ASSET_2, ASSET_C, ASSET_D);
+
+		assert_ok!(OmnipoolSubpools::sell(
+			RuntimeOrigin::signed(LP3),
+			ASSET_A,
+			ASSET_C,
+			10 * ONE,
+			0
+		));
+
+		assert_eq!(Tokens::free_balance(ASSET_A, &LP3), 990 * ONE);
+		assert!(Tokens::free_balance(ASSET_C, &LP3) > 1_000 * ONE);
+		assert_eq!(Tokens::free_balance(SHARE_ASSET, &LP3), 0);
+		assert_eq!(Tokens::free_balance(SHARE_ASSET_2, &LP3), 0);
+	});
+}
+
+#[test]
+fn sell_should_work_when_selling_migrated_asset_for_its_share_asset() {
+	omnipool_with_tokens().build().execute_with(|| {
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		let subpool_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+
+		assert_ok!(OmnipoolSubpools::sell(
+			RuntimeOrigin::signed(LP3),
+			ASSET_A,
+			SHARE_ASSET,
+			10 * ONE,
+			0
+		));
+
+		assert_eq!(Tokens::free_balance(ASSET_A, &LP3), 990 * ONE);
+		assert!(Tokens::free_balance(SHARE_ASSET, &LP3) > 0);
+		assert_eq!(Omnipool::load_asset_state(SHARE_ASSET).unwrap(), subpool_state);
+	});
+}
+
+#[test]
+fn sell_should_work_when_none_of_the_assets_was_migrated() {
+	omnipool_with_tokens().build().execute_with(|| {
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		let expected = Omnipool::calculate_sell_quote(ASSET_C, ASSET_D, 10 * ONE).unwrap();
+
+		assert_ok!(OmnipoolSubpools::sell(
+			RuntimeOrigin::signed(LP3),
+			ASSET_C,
+			ASSET_D,
+			10 * ONE,
+			0
+		));
+
+		assert_eq!(Tokens::free_balance(ASSET_C, &LP3), 990 * ONE);
+		assert_eq!(Tokens::free_balance(ASSET_D, &LP3), 1_000 * ONE + expected.amount_out);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_buy_limit_is_not_reached() {
+	omnipool_with_tokens().build().execute_with(|| {
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		assert_noop!(
+			OmnipoolSubpools::sell(RuntimeOrigin::signed(LP3), ASSET_A, ASSET_C, 10 * ONE, 100 * ONE),
+			Error::<Test>::BuyLimitNotReached
+		);
+	});
+}
+
+#[test]
+fn buy_should_work_when_both_assets_are_in_same_subpool() {
+	omnipool_with_tokens().build().execute_with(|| {
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		let subpool_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+
+		assert_ok!(OmnipoolSubpools::buy(
+			RuntimeOrigin::signed(LP3),
+			ASSET_B,
+			ASSET_A,
+			10 * ONE,
+			20 * ONE
+		));
+
+		assert_eq!(Tokens::free_balance(ASSET_B, &LP3), 1_010 * ONE);
+		assert!(Tokens::free_balance(ASSET_A, &LP3) < 990 * ONE);
+		assert_eq!(Omnipool::load_asset_state(SHARE_ASSET).unwrap(), subpool_state);
+	});
+}
+
+#[test]
+fn buy_should_work_when_buying_omnipool_asset_with_migrated_asset() {
+	omnipool_with_tokens().build().execute_with(|| {
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		assert_ok!(OmnipoolSubpools::buy(
+			RuntimeOrigin::signed(LP3),
+			ASSET_C,
+			ASSET_A,
+			10 * ONE,
+			20 * ONE
+		));
+
+		assert_eq!(Tokens::free_balance(ASSET_C, &LP3), 1_010 * ONE);
+		assert!(Tokens::free_balance(ASSET_A, &LP3) < 990 * ONE);
+		assert!(Tokens::free_balance(ASSET_A, &LP3) >= 980 * ONE);
+		assert_eq!(Tokens::free_balance(SHARE_ASSET, &LP3), 0);
+	});
+}
+
+#[test]
+fn buy_should_work_when_buying_migrated_asset_with_omnipool_asset() {
+	omnipool_with_tokens().build().execute_with(|| {
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		assert_ok!(OmnipoolSubpools::buy(
+			RuntimeOrigin::signed(LP3),
+			ASSET_A,
+			ASSET_C,
+			10 * ONE,
+			20 * ONE
+		));
+
+		let received = Tokens::free_balance(ASSET_A, &LP3) - 1_000 * ONE;
+		assert!(received >= 10 * ONE);
+		assert!(received - 10 * ONE <= ONE / 1_000);
+		assert!(Tokens::free_balance(ASSET_C, &LP3) < 990 * ONE);
+		assert_eq!(Tokens::free_balance(SHARE_ASSET, &LP3), 0);
+	});
+}
+
+#[test]
+fn buy_should_work_when_assets_are_in_different_subpools() {
+	omnipool_with_tokens().build().execute_with(|| {
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+		create_subpool(SHARE_ASSET_2, ASSET_C, ASSET_D);
+
+		assert_ok!(OmnipoolSubpools::buy(
+			RuntimeOrigin::signed(LP3),
+			ASSET_C,
+			ASSET_A,
+			10 * ONE,
+			20 * ONE
+		));
+
+		let received = Tokens::free_balance(ASSET_C, &LP3) - 1_000 * ONE;
+		assert!(received >= 10 * ONE);
+		assert!(received - 10 * ONE <= ONE / 1_000);
+		assert!(Tokens::free_balance(ASSET_A, &LP3) < 990 * ONE);
+		assert_eq!(Tokens::free_balance(SHARE_ASSET, &LP3), 0);
+		assert_eq!(Tokens::free_balance(SHARE_ASSET_2, &LP3), 0);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_sell_limit_is_exceeded() {
+	omnipool_with_tokens().build().execute_with(|| {
+		create_subpool(SHARE_ASSET, ASSET_A, ASSET_B);
+
+		assert_noop!(
+			OmnipoolSubpools::buy(RuntimeOrigin::signed(LP3), ASSET_A, ASSET_C, 10 * ONE, ONE),
+			Error::<Test>::SellLimitExceeded
+		);
+	});
+}
@@ -0,0 +1,63 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use hydra_dx_math::omnipool_subpools::types::MigrationDetails;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+pub type Balance = u128;
+
+/// Details of an asset migrated from Omnipool to a subpool.
+///
+/// Snapshot of the asset state at the time of migration. Used to convert Omnipool positions of the asset
+/// to positions of the subpool share asset.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct AssetDetail {
+	/// Price of the asset at the time of migration.
+	pub price: (Balance, Balance),
+	/// Total amount of Omnipool shares of the asset.
+	pub shares: Balance,
+	/// Hub reserve of the asset moved to the subpool share asset.
+	pub hub_reserve: Balance,
+	/// Amount of subpool asset shares the asset shares have been converted to.
+	pub subpool_shares: Balance,
+	/// Amount of subpool share tokens issued for the asset reserve.
+	pub share_tokens: Balance,
+}
+
+impl From<MigrationDetails> for AssetDetail {
+	fn from(details: MigrationDetails) -> Self {
+		Self {
+			price: details.price,
+			shares: details.shares,
+			hub_reserve: details.hub_reserve,
+			subpool_shares: details.subpool_shares,
+			share_tokens: details.share_tokens,
+		}
+	}
+}
+
+impl From<AssetDetail> for MigrationDetails {
+	fn from(detail: AssetDetail) -> Self {
+		Self {
+			price: detail.price,
+			shares: detail.shares,
+			hub_reserve: detail.hub_reserve,
+			subpool_shares: detail.subpool_shares,
+			share_tokens: detail.share_tokens,
+		}
+	}
+}
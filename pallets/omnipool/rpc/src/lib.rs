@@ -0,0 +1,145 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the Omnipool pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{traits::Block as BlockT, DispatchError};
+
+pub use pallet_omnipool_rpc_runtime_api::{OmnipoolApi as OmnipoolRuntimeApi, TradeQuote};
+
+#[rpc(client, server)]
+pub trait OmnipoolApi<BlockHash, AssetId, Balance> {
+	/// Quote selling `amount_in` of `asset_in` for `asset_out`.
+	#[method(name = "omnipool_sellQuote")]
+	fn sell_quote(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<TradeQuote<Balance>>;
+
+	/// Quote buying `amount_out` of `asset_out` for `asset_in`.
+	#[method(name = "omnipool_buyQuote")]
+	fn buy_quote(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<TradeQuote<Balance>>;
+}
+
+/// Provides RPC methods to quote Omnipool trades.
+pub struct Omnipool<C, Block> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> Omnipool<C, Block> {
+	/// Create new `Omnipool` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The trade cannot be quoted.
+	QuoteError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::QuoteError => 2,
+		}
+	}
+}
+
+impl<C, Block, AssetId, Balance> OmnipoolApiServer<<Block as BlockT>::Hash, AssetId, Balance> for Omnipool<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: OmnipoolRuntimeApi<Block, AssetId, Balance>,
+	AssetId: Codec,
+	Balance: Codec,
+{
+	fn sell_quote(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<TradeQuote<Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.sell_quote(at, asset_in, asset_out, amount_in)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(quote_error_into_rpc_err)
+	}
+
+	fn buy_quote(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<TradeQuote<Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.buy_quote(at, asset_in, asset_out, amount_out)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(quote_error_into_rpc_err)
+	}
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query Omnipool quote.",
+		Some(format!("{err:?}")),
+	))
+	.into()
+}
+
+fn quote_error_into_rpc_err(err: DispatchError) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::QuoteError.into(),
+		"Trade cannot be quoted.",
+		Some(format!("{err:?}")),
+	))
+	.into()
+}
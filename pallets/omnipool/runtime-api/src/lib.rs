@@ -0,0 +1,49 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the Omnipool pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_runtime::DispatchError;
+
+pub use pallet_omnipool::types::{FeeLedgerEntry, TradeQuote};
+
+sp_api::decl_runtime_apis! {
+	/// Quotes Omnipool trades without executing them.
+	pub trait OmnipoolApi<AssetId, Balance> where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Result of selling `amount_in` of `asset_in` for `asset_out`.
+		fn sell_quote(asset_in: AssetId, asset_out: AssetId, amount_in: Balance) -> Result<TradeQuote<Balance>, DispatchError>;
+
+		/// Result of buying `amount_out` of `asset_out` for `asset_in`.
+		fn buy_quote(asset_in: AssetId, asset_out: AssetId, amount_out: Balance) -> Result<TradeQuote<Balance>, DispatchError>;
+	}
+
+	/// Reads trade fees accumulated in Omnipool fee ledger.
+	pub trait OmnipoolFeeApi<AssetId, Balance, BlockNumber> where
+		AssetId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Fees of `asset_id` accumulated in current and previous fee ledger period.
+		fn fee_ledger(asset_id: AssetId) -> FeeLedgerEntry<Balance, BlockNumber>;
+	}
+}
@@ -65,6 +65,26 @@
 //! * `buy` - Trades an asset in for asset out by buying given amount of asset out.
 //! * `set_asset_tradable_state` - Updates asset's tradable asset with new flags. This allows/forbids asset operation such SELL,BUY,ADD or  REMOVE liquidtityy.
 //! * `refund_refused_asset` - Refunds the initial liquidity amount sent to pool account prior to add_token if the token has been refused to be added.
+//! * `remove_token` - Removes frozen token with only protocol owned shares from the pool. Remaining reserve is transferred to beneficiary.
+//! * `add_liquidity_with_limit` - Same as `add_liquidity` but fails if minted shares are less than given minimum.
+//! * `remove_liquidity_with_limit` - Same as `remove_liquidity` but fails if received asset amount is less than given minimum.
+//! * `split_position` - Splits LP position into two positions with the same price.
+//! * `merge_positions` - Merges two LP positions of the same asset into one.
+//! * `add_liquidity_multiple` - Adds liquidity of multiple assets at once, creating one LP position per asset.
+//!
+//! ### Fees
+//!
+//! Asset and protocol fees of trades are accumulated per asset in `FeeLedger` for current and previous period
+//! of `FeeLedgerPeriod` blocks.
+//!
+//! Hub asset amount of protocol fee is burned unless `ProtocolFeeCollector` account is set, in which case it is
+//! transferred to the collector. Note that the `protocol_fee` recorded in `FeeLedger` is not the amount burned or
+//! received by the collector - only `min(protocol_fee, imbalance)` is burned or collected, the rest of the protocol fee
+//! is transferred to HDX subpool.
+//!
+//! Hub asset collected this way is tracked in `CollectedProtocolFees` and used in `on_idle` to reduce negative hub
+//! asset imbalance. At most `ImbalanceBurnRatio` of current imbalance is burned in a single block. Other hub asset
+//! held by the collector is never burned.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -73,16 +93,20 @@ use frame_support::require_transactional;
 use frame_support::PalletId;
 use frame_support::{ensure, transactional};
 use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned, One};
-use sp_runtime::traits::{CheckedAdd, CheckedSub, Zero};
+use sp_runtime::traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Saturating, Zero};
 use sp_std::ops::{Add, Sub};
 use sp_std::prelude::*;
 
 use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
-use hydra_dx_math::omnipool::types::{AssetStateChange, BalanceUpdate, I129};
+use frame_support::traits::DefensiveOption;
+use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+use hydra_dx_math::ema::EmaPrice;
+use hydra_dx_math::omnipool::types::{AssetStateChange, BalanceUpdate, HubTradeStateChange, TradeStateChange, I129};
 use hydradx_traits::Registry;
 use orml_traits::{GetByKey, MultiCurrency};
 use scale_info::TypeInfo;
-use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill};
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill, Rounding};
 
 #[cfg(test)]
 mod tests;
@@ -93,8 +117,11 @@ pub mod traits;
 pub mod types;
 pub mod weights;
 
-use crate::traits::{AssetInfo, OmnipoolHooks};
-use crate::types::{AssetReserveState, AssetState, Balance, Position, SimpleImbalance, Tradability};
+use crate::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks, PriceBarrierError, ShouldAllow};
+use crate::types::{
+	AssetReserveState, AssetState, Balance, FeeAccumulator, FeeLedgerEntry, Position, SimpleImbalance, Tradability,
+	TradeQuote,
+};
 pub use pallet::*;
 pub use weights::WeightInfo;
 
@@ -109,7 +136,6 @@ pub mod pallet {
 	use crate::types::{Position, Price, Tradability};
 	use codec::HasCompact;
 	use frame_support::pallet_prelude::*;
-	use frame_support::traits::DefensiveOption;
 	use frame_system::pallet_prelude::*;
 	use hydra_dx_math::ema::EmaPrice;
 	use hydra_dx_math::omnipool::types::{BalanceUpdate, I129};
@@ -206,6 +232,23 @@ pub mod pallet {
 		type PriceBarrier: ShouldAllow<Self::AccountId, Self::AssetId, EmaPrice>;
 
 		type ExternalPriceOracle: ExternalPriceProvider<Self::AssetId, EmaPrice, Error = DispatchError>;
+
+		/// Account which receives hub asset amount of protocol fee instead of burning it.
+		/// If not set, the amount is burned and hub asset imbalance is updated.
+		type ProtocolFeeCollector: Get<Option<Self::AccountId>>;
+
+		/// Length of fee ledger period in blocks.
+		#[pallet::constant]
+		type FeeLedgerPeriod: Get<Self::BlockNumber>;
+
+		/// Maximum fraction of negative hub asset imbalance which can be burned in a single block
+		/// from protocol fees collected by `ProtocolFeeCollector`.
+		#[pallet::constant]
+		type ImbalanceBurnRatio: Get<Permill>;
+
+		/// Maximum number of assets in single `add_liquidity_multiple` call.
+		#[pallet::constant]
+		type MaxAddLiquidityAssets: Get<u32>;
 	}
 
 	#[pallet::storage]
@@ -218,6 +261,11 @@ pub mod pallet {
 	#[pallet::getter(fn current_imbalance)]
 	pub(super) type HubAssetImbalance<T: Config> = StorageValue<_, SimpleImbalance<Balance>, ValueQuery>;
 
+	#[pallet::storage]
+	/// Hub asset amount of protocol fees transferred to `ProtocolFeeCollector` and not burned yet.
+	#[pallet::getter(fn collected_protocol_fees)]
+	pub type CollectedProtocolFees<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
 	#[pallet::storage]
 	/// Tradable state of hub asset.
 	pub(super) type HubAssetTradability<T: Config> = StorageValue<_, Tradability, ValueQuery>;
@@ -237,6 +285,11 @@ pub mod pallet {
 	/// TVL cap
 	pub(super) type TvlCap<T: Config> = StorageValue<_, Balance, ValueQuery>;
 
+	#[pallet::storage]
+	/// Trade fees of an asset accumulated in current and previous fee ledger period.
+	pub(super) type FeeLedger<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, FeeLedgerEntry<Balance, T::BlockNumber>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -319,6 +372,23 @@ pub mod pallet {
 
 		/// TVL cap has been updated.
 		TVLCapUpdated { cap: Balance },
+
+		/// Asset has been removed from Omnipool.
+		TokenRemoved {
+			asset_id: T::AssetId,
+			amount: Balance,
+			hub_withdrawn: Balance,
+		},
+
+		/// Collected hub asset has been burned to reduce negative hub asset imbalance.
+		HubAssetImbalanceReduced { amount: Balance, imbalance: Balance },
+
+		/// Liquidity of multiple assets was added to Omnipool.
+		/// `positions` contains asset id, added amount and id of created position for each asset.
+		LiquidityAddedMultiple {
+			who: T::AccountId,
+			positions: Vec<(T::AssetId, Balance, T::PositionItemId)>,
+		},
 	}
 
 	#[pallet::error]
@@ -382,6 +452,20 @@ pub mod pallet {
 		InvalidOraclePrice,
 		/// Failed to calculate withdrawal fee.
 		InvalidWithdrawalFee,
+		/// Asset is not frozen.
+		AssetNotFrozen,
+		/// Asset still has LP shares not owned by protocol.
+		SharesRemaining,
+		/// Minted shares or received amount is less than the provided limit.
+		SlippageLimit,
+		/// Positions cannot be merged - same position or positions of different assets.
+		InvalidPositionMerge,
+		/// Positions cannot be merged because their prices are on different sides of current spot price.
+		UnfairPositionMerge,
+		/// Asset list is empty or contains the same asset more than once.
+		InvalidAssetList,
+		/// Asset list contains more than `MaxAddLiquidityAssets` assets.
+		TooManyAssets,
 	}
 
 	#[pallet::call]
@@ -647,127 +731,7 @@ pub mod pallet {
 		)]
 		#[transactional]
 		pub fn add_liquidity(origin: OriginFor<T>, asset: T::AssetId, amount: Balance) -> DispatchResult {
-			//
-			// Preconditions
-			//
-			let who = ensure_signed(origin.clone())?;
-
-			ensure!(
-				amount >= T::MinimumPoolLiquidity::get(),
-				Error::<T>::InsufficientLiquidity
-			);
-
-			ensure!(
-				T::Currency::ensure_can_withdraw(asset, &who, amount).is_ok(),
-				Error::<T>::InsufficientBalance
-			);
-
-			let asset_state = Self::load_asset_state(asset)?;
-
-			ensure!(
-				asset_state.tradable.contains(Tradability::ADD_LIQUIDITY),
-				Error::<T>::NotAllowed
-			);
-
-			T::PriceBarrier::ensure_price(
-				&who,
-				T::HubAssetId::get(),
-				asset,
-				EmaPrice::new(asset_state.hub_reserve, asset_state.reserve),
-			)
-			.map_err(|_| Error::<T>::PriceDifferenceTooHigh)?;
-
-			let current_imbalance = <HubAssetImbalance<T>>::get();
-			let current_hub_asset_liquidity =
-				T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
-
-			//
-			// Calculate add liquidity state changes
-			//
-			let state_changes = hydra_dx_math::omnipool::calculate_add_liquidity_state_changes(
-				&(&asset_state).into(),
-				amount,
-				I129 {
-					value: current_imbalance.value,
-					negative: current_imbalance.negative,
-				},
-				current_hub_asset_liquidity,
-			)
-			.ok_or(ArithmeticError::Overflow)?;
-
-			let new_asset_state = asset_state
-				.clone()
-				.delta_update(&state_changes.asset)
-				.ok_or(ArithmeticError::Overflow)?;
-
-			let hub_reserve_ratio = FixedU128::checked_from_rational(
-				new_asset_state.hub_reserve,
-				T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account())
-					.checked_add(*state_changes.asset.delta_hub_reserve)
-					.ok_or(ArithmeticError::Overflow)?,
-			)
-			.ok_or(ArithmeticError::DivisionByZero)?;
-
-			ensure!(
-				hub_reserve_ratio <= new_asset_state.weight_cap(),
-				Error::<T>::AssetWeightCapExceeded
-			);
-
-			//
-			// Post - update states
-			//
-
-			// Create LP position with given shares
-			let lp_position = Position::<Balance, T::AssetId> {
-				asset_id: asset,
-				amount,
-				shares: *state_changes.asset.delta_shares,
-				// Note: position needs price after asset state is updated.
-				price: (new_asset_state.hub_reserve, new_asset_state.reserve),
-			};
-
-			let instance_id = Self::create_and_mint_position_instance(&who)?;
-
-			<Positions<T>>::insert(instance_id, lp_position);
-
-			Self::deposit_event(Event::PositionCreated {
-				position_id: instance_id,
-				owner: who.clone(),
-				asset,
-				amount,
-				shares: *state_changes.asset.delta_shares,
-				price: new_asset_state.price().ok_or(ArithmeticError::DivisionByZero)?,
-			});
-
-			T::Currency::transfer(
-				asset,
-				&who,
-				&Self::protocol_account(),
-				*state_changes.asset.delta_reserve,
-			)?;
-
-			debug_assert_eq!(*state_changes.asset.delta_reserve, amount);
-
-			// Callback hook info
-			let info: AssetInfo<T::AssetId, Balance> =
-				AssetInfo::new(asset, &asset_state, &new_asset_state, &state_changes.asset);
-
-			Self::update_imbalance(state_changes.delta_imbalance)?;
-
-			Self::update_hub_asset_liquidity(&state_changes.asset.delta_hub_reserve)?;
-
-			Self::set_asset_state(asset, new_asset_state);
-
-			Self::ensure_tvl_cap()?;
-
-			Self::deposit_event(Event::LiquidityAdded {
-				who,
-				asset_id: asset,
-				amount,
-				position_id: instance_id,
-			});
-
-			T::OmnipoolHooks::on_liquidity_changed(origin, info)?;
+			Self::do_add_liquidity(origin, asset, amount)?;
 
 			Ok(())
 		}
@@ -794,157 +758,7 @@ pub mod pallet {
 			position_id: T::PositionItemId,
 			amount: Balance,
 		) -> DispatchResult {
-			//
-			// Preconditions
-			//
-			let who = ensure_signed(origin.clone())?;
-
-			ensure!(amount > Balance::zero(), Error::<T>::InvalidSharesAmount);
-
-			ensure!(
-				T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id) == Some(who.clone()),
-				Error::<T>::Forbidden
-			);
-
-			let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
-
-			ensure!(position.shares >= amount, Error::<T>::InsufficientShares);
-
-			let asset_id = position.asset_id;
-
-			let asset_state = Self::load_asset_state(asset_id)?;
-
-			ensure!(
-				asset_state.tradable.contains(Tradability::REMOVE_LIQUIDITY),
-				Error::<T>::NotAllowed
-			);
-
-			T::PriceBarrier::ensure_price(
-				&who,
-				T::HubAssetId::get(),
-				asset_id,
-				EmaPrice::new(asset_state.hub_reserve, asset_state.reserve),
-			)
-			.map_err(|_| Error::<T>::PriceDifferenceTooHigh)?;
-
-			let current_imbalance = <HubAssetImbalance<T>>::get();
-			let current_hub_asset_liquidity =
-				T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
-
-			let ext_asset_price = T::ExternalPriceOracle::get_price(T::HubAssetId::get(), asset_id)?;
-
-			if ext_asset_price.is_zero() {
-				return Err(Error::<T>::InvalidOraclePrice.into());
-			}
-
-			let withdrawal_fee = hydra_dx_math::omnipool::calculate_withdrawal_fee(
-				asset_state.price().ok_or(ArithmeticError::DivisionByZero)?,
-				FixedU128::checked_from_rational(ext_asset_price.n, ext_asset_price.d)
-					.defensive_ok_or(Error::<T>::InvalidOraclePrice)?,
-				T::MinWithdrawalFee::get(),
-			);
-
-			//
-			// calculate state changes of remove liquidity
-			//
-			let state_changes = hydra_dx_math::omnipool::calculate_remove_liquidity_state_changes(
-				&(&asset_state).into(),
-				amount,
-				&(&position).into(),
-				I129 {
-					value: current_imbalance.value,
-					negative: current_imbalance.negative,
-				},
-				current_hub_asset_liquidity,
-				withdrawal_fee,
-			)
-			.ok_or(ArithmeticError::Overflow)?;
-
-			let new_asset_state = asset_state
-				.clone()
-				.delta_update(&state_changes.asset)
-				.ok_or(ArithmeticError::Overflow)?;
-
-			// Update position state
-			let updated_position = position
-				.delta_update(
-					&state_changes.delta_position_reserve,
-					&state_changes.delta_position_shares,
-				)
-				.ok_or(ArithmeticError::Overflow)?;
-
-			//
-			// Post - update states
-			//
-
-			T::Currency::transfer(
-				asset_id,
-				&Self::protocol_account(),
-				&who,
-				*state_changes.asset.delta_reserve,
-			)?;
-
-			Self::update_imbalance(state_changes.delta_imbalance)?;
-
-			// burn only difference between delta hub and lp hub amount.
-			Self::update_hub_asset_liquidity(
-				&state_changes
-					.asset
-					.delta_hub_reserve
-					.merge(BalanceUpdate::Increase(state_changes.lp_hub_amount))
-					.ok_or(ArithmeticError::Overflow)?,
-			)?;
-
-			// LP receives some hub asset
-			if state_changes.lp_hub_amount > Balance::zero() {
-				T::Currency::transfer(
-					T::HubAssetId::get(),
-					&Self::protocol_account(),
-					&who,
-					state_changes.lp_hub_amount,
-				)?;
-			}
-
-			if updated_position.shares == Balance::zero() {
-				// All liquidity removed, remove position and burn NFT instance
-
-				<Positions<T>>::remove(position_id);
-				T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
-
-				Self::deposit_event(Event::PositionDestroyed {
-					position_id,
-					owner: who.clone(),
-				});
-			} else {
-				Self::deposit_event(Event::PositionUpdated {
-					position_id,
-					owner: who.clone(),
-					asset: asset_id,
-					amount: updated_position.amount,
-					shares: updated_position.shares,
-					price: updated_position
-						.price_from_rational()
-						.ok_or(ArithmeticError::DivisionByZero)?,
-				});
-
-				<Positions<T>>::insert(position_id, updated_position);
-			}
-
-			// Callback hook info
-			let info: AssetInfo<T::AssetId, Balance> =
-				AssetInfo::new(asset_id, &asset_state, &new_asset_state, &state_changes.asset);
-
-			Self::set_asset_state(asset_id, new_asset_state);
-
-			Self::deposit_event(Event::LiquidityRemoved {
-				who,
-				position_id,
-				asset_id,
-				shares_removed: amount,
-				fee: withdrawal_fee,
-			});
-
-			T::OmnipoolHooks::on_liquidity_changed(origin, info)?;
+			Self::do_remove_liquidity(origin, position_id, amount)?;
 
 			Ok(())
 		}
@@ -1045,36 +859,8 @@ pub mod pallet {
 				return Self::sell_asset_for_hub_asset(&who, asset_in, amount, min_buy_amount);
 			}
 
-			let asset_in_state = Self::load_asset_state(asset_in)?;
-			let asset_out_state = Self::load_asset_state(asset_out)?;
-
-			ensure!(
-				Self::allow_assets(&asset_in_state, &asset_out_state),
-				Error::<T>::NotAllowed
-			);
-
-			ensure!(
-				amount
-					<= asset_in_state
-						.reserve
-						.checked_div(T::MaxInRatio::get())
-						.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
-				Error::<T>::MaxInRatioExceeded
-			);
-
-			let current_imbalance = <HubAssetImbalance<T>>::get();
-
-			let (asset_fee, protocol_fee) = T::Fee::get(&asset_out);
-
-			let state_changes = hydra_dx_math::omnipool::calculate_sell_state_changes(
-				&(&asset_in_state).into(),
-				&(&asset_out_state).into(),
-				amount,
-				asset_fee,
-				protocol_fee,
-				current_imbalance.value,
-			)
-			.ok_or(ArithmeticError::Overflow)?;
+			let (asset_in_state, asset_out_state, state_changes) =
+				Self::calculate_sell_trade(asset_in, asset_out, amount)?;
 
 			ensure!(
 				*state_changes.asset_out.delta_reserve >= min_buy_amount,
@@ -1130,17 +916,16 @@ pub mod pallet {
 				)
 				.ok_or(ArithmeticError::Overflow)?;
 
-			match delta_hub_asset {
+			let hub_asset_to_burn = match delta_hub_asset {
 				BalanceUpdate::Increase(val) if val == Balance::zero() => {
 					// nothing to do if zero.
+					Balance::zero()
 				}
 				BalanceUpdate::Increase(_) => {
 					// trade can only burn some.
 					return Err(Error::<T>::HubAssetUpdateError.into());
 				}
-				BalanceUpdate::Decrease(amount) => {
-					T::Currency::withdraw(T::HubAssetId::get(), &Self::protocol_account(), amount)?;
-				}
+				BalanceUpdate::Decrease(amount) => amount,
 			};
 
 			// Callback hook info
@@ -1154,7 +939,7 @@ pub mod pallet {
 				&state_changes.asset_out,
 			);
 
-			Self::update_imbalance(state_changes.delta_imbalance)?;
+			Self::burn_or_collect_hub_asset(hub_asset_to_burn, state_changes.delta_imbalance)?;
 
 			Self::set_asset_state(asset_in, new_asset_in_state);
 			Self::set_asset_state(asset_out, new_asset_out_state);
@@ -1163,6 +948,8 @@ pub mod pallet {
 
 			Self::update_hdx_subpool_hub_asset(origin, state_changes.hdx_hub_amount)?;
 
+			Self::record_fees(asset_out, state_changes.fee.asset_fee, state_changes.fee.protocol_fee)?;
+
 			Self::deposit_event(Event::SellExecuted {
 				who,
 				asset_in,
@@ -1223,37 +1010,8 @@ pub mod pallet {
 				return Self::buy_asset_for_hub_asset(origin, &who, asset_out, amount, max_sell_amount);
 			}
 
-			let asset_in_state = Self::load_asset_state(asset_in)?;
-			let asset_out_state = Self::load_asset_state(asset_out)?;
-
-			ensure!(
-				Self::allow_assets(&asset_in_state, &asset_out_state),
-				Error::<T>::NotAllowed
-			);
-
-			ensure!(asset_out_state.reserve >= amount, Error::<T>::InsufficientLiquidity);
-
-			ensure!(
-				amount
-					<= asset_out_state
-						.reserve
-						.checked_div(T::MaxOutRatio::get())
-						.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxOutRatio is zero.
-				Error::<T>::MaxOutRatioExceeded
-			);
-
-			let current_imbalance = <HubAssetImbalance<T>>::get();
-
-			let (asset_fee, protocol_fee) = T::Fee::get(&asset_in);
-			let state_changes = hydra_dx_math::omnipool::calculate_buy_state_changes(
-				&(&asset_in_state).into(),
-				&(&asset_out_state).into(),
-				amount,
-				asset_fee,
-				protocol_fee,
-				current_imbalance.value,
-			)
-			.ok_or(ArithmeticError::Overflow)?;
+			let (asset_in_state, asset_out_state, state_changes) =
+				Self::calculate_buy_trade(asset_in, asset_out, amount)?;
 
 			ensure!(
 				T::Currency::ensure_can_withdraw(asset_in, &who, *state_changes.asset_in.delta_reserve).is_ok(),
@@ -1314,17 +1072,16 @@ pub mod pallet {
 				)
 				.ok_or(ArithmeticError::Overflow)?;
 
-			match delta_hub_asset {
+			let hub_asset_to_burn = match delta_hub_asset {
 				BalanceUpdate::Increase(val) if val == Balance::zero() => {
 					// nothing to do if zero.
+					Balance::zero()
 				}
 				BalanceUpdate::Increase(_) => {
 					// trade can only burn some.
 					return Err(Error::<T>::HubAssetUpdateError.into());
 				}
-				BalanceUpdate::Decrease(amount) => {
-					T::Currency::withdraw(T::HubAssetId::get(), &Self::protocol_account(), amount)?;
-				}
+				BalanceUpdate::Decrease(amount) => amount,
 			};
 
 			// Callback hook info
@@ -1338,7 +1095,7 @@ pub mod pallet {
 				&state_changes.asset_out,
 			);
 
-			Self::update_imbalance(state_changes.delta_imbalance)?;
+			Self::burn_or_collect_hub_asset(hub_asset_to_burn, state_changes.delta_imbalance)?;
 
 			Self::set_asset_state(asset_in, new_asset_in_state);
 			Self::set_asset_state(asset_out, new_asset_out_state);
@@ -1347,6 +1104,8 @@ pub mod pallet {
 
 			Self::update_hdx_subpool_hub_asset(origin, state_changes.hdx_hub_amount)?;
 
+			Self::record_fees(asset_in, state_changes.fee.asset_fee, state_changes.fee.protocol_fee)?;
+
 			Self::deposit_event(Event::BuyExecuted {
 				who,
 				asset_in,
@@ -1484,31 +1243,423 @@ pub mod pallet {
 			Self::deposit_event(Event::TVLCapUpdated { cap });
 			Ok(())
 		}
-	}
 
-	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn integrity_test() {
-			assert_ne!(
-				T::MinimumPoolLiquidity::get(),
-				Balance::zero(),
-				"Minimum pool liquidity is 0."
-			);
-			assert_ne!(
-				T::MinimumTradingLimit::get(),
-				Balance::zero(),
-				"Minimum trading limit is 0."
-			);
-			assert_ne!(
-				T::HdxAssetId::get(),
-				T::StableCoinAssetId::get(),
-				"Same Hdx asset id and stable asset id."
+		/// Remove asset from Omnipool.
+		///
+		/// Asset's tradable state must be `FROZEN` and all shares of the asset must be owned by protocol
+		/// ( all LP positions have been either removed or sacrificed ).
+		///
+		/// Hub asset reserve of the asset is burned and the imbalance is settled accordingly.
+		/// Remaining asset reserve is transferred to the beneficiary.
+		///
+		/// Only `AuthorityOrigin` can perform this operation.
+		///
+		/// Parameters:
+		/// - `asset_id`: asset id
+		/// - `beneficiary`: account which receives remaining asset reserve
+		///
+		/// Emits `TokenRemoved` event when successful.
+		///
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_token().saturating_add(T::OmnipoolHooks::on_asset_removed_weight()))]
+		#[transactional]
+		pub fn remove_token(origin: OriginFor<T>, asset_id: T::AssetId, beneficiary: T::AccountId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let asset_state = Self::load_asset_state(asset_id)?;
+
+			ensure!(asset_state.tradable == Tradability::FROZEN, Error::<T>::AssetNotFrozen);
+			ensure!(
+				asset_state.shares == asset_state.protocol_shares,
+				Error::<T>::SharesRemaining
 			);
-			assert_ne!(T::MaxInRatio::get(), Balance::zero(), "MaxInRatio is 0.");
-			assert_ne!(T::MaxOutRatio::get(), Balance::zero(), "MaxOutRatio is 0.");
-		}
-	}
-}
+
+			let current_imbalance = <HubAssetImbalance<T>>::get();
+			let current_hub_asset_liquidity = Self::get_hub_asset_balance_of_protocol_account();
+
+			let delta_imbalance = hydra_dx_math::omnipool::calculate_delta_imbalance(
+				asset_state.hub_reserve,
+				I129 {
+					value: current_imbalance.value,
+					negative: current_imbalance.negative,
+				},
+				current_hub_asset_liquidity,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+			Self::update_imbalance(BalanceUpdate::Increase(delta_imbalance))?;
+
+			Self::update_hub_asset_liquidity(&BalanceUpdate::Decrease(asset_state.hub_reserve))?;
+
+			T::Currency::transfer(asset_id, &Self::protocol_account(), &beneficiary, asset_state.reserve)?;
+
+			<Assets<T>>::remove(asset_id);
+
+			T::OmnipoolHooks::on_asset_removed(asset_id)?;
+
+			Self::deposit_event(Event::TokenRemoved {
+				asset_id,
+				amount: asset_state.reserve,
+				hub_withdrawn: asset_state.hub_reserve,
+			});
+
+			Ok(())
+		}
+
+		/// Add liquidity of asset `asset` in quantity `amount` to Omnipool.
+		///
+		/// Same as `add_liquidity` but fails with `SlippageLimit` error if amount of shares
+		/// minted for the new position is less than `min_shares_limit`.
+		///
+		/// Parameters:
+		/// - `asset`: The identifier of the new asset added to the pool. Must be already in the pool
+		/// - `amount`: Amount of asset added to omnipool
+		/// - `min_shares_limit`: Minimum amount of shares to receive
+		///
+		/// Emits `LiquidityAdded` event when successful.
+		///
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
+			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight()
+			.saturating_add(T::ExternalPriceOracle::get_price_weight()))
+		)]
+		#[transactional]
+		pub fn add_liquidity_with_limit(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			amount: Balance,
+			min_shares_limit: Balance,
+		) -> DispatchResult {
+			let shares = Self::do_add_liquidity(origin, asset, amount)?;
+
+			ensure!(shares >= min_shares_limit, Error::<T>::SlippageLimit);
+
+			Ok(())
+		}
+
+		/// Remove liquidity of asset `asset` in quantity `amount` from Omnipool.
+		///
+		/// Same as `remove_liquidity` but fails with `SlippageLimit` error if amount of asset
+		/// received is less than `min_limit`. Hub asset possibly received is not taken into account.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of position which liquidity is removed from.
+		/// - `amount`: Amount of shares removed from omnipool
+		/// - `min_limit`: Minimum amount of asset to receive
+		///
+		/// Emits `LiquidityRemoved` event when successful.
+		///
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity().saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn remove_liquidity_with_limit(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			amount: Balance,
+			min_limit: Balance,
+		) -> DispatchResult {
+			let received = Self::do_remove_liquidity(origin, position_id, amount)?;
+
+			ensure!(received >= min_limit, Error::<T>::SlippageLimit);
+
+			Ok(())
+		}
+
+		/// Split LP position into two positions.
+		///
+		/// Given amount of shares is moved from the position to a newly created position.
+		/// Position amount is split proportionally, both positions keep the original price.
+		///
+		/// Only owner of position can perform this action. Positions locked in liquidity mining
+		/// are owned by liquidity mining pallet and cannot be split.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of position to split.
+		/// - `shares`: Amount of shares moved to the new position. Must be less than position's shares.
+		///
+		/// Emits `PositionUpdated` and `PositionCreated` events when successful.
+		///
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::split_position())]
+		#[transactional]
+		pub fn split_position(origin: OriginFor<T>, position_id: T::PositionItemId, shares: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+
+			ensure!(
+				T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id) == Some(who.clone()),
+				Error::<T>::Forbidden
+			);
+
+			ensure!(
+				shares > Balance::zero() && shares < position.shares,
+				Error::<T>::InvalidSharesAmount
+			);
+
+			let new_amount =
+				multiply_by_rational_with_rounding(position.amount, shares, position.shares, Rounding::Down)
+					.ok_or(ArithmeticError::Overflow)?;
+
+			let new_position = Position::<Balance, T::AssetId> {
+				asset_id: position.asset_id,
+				amount: new_amount,
+				shares,
+				price: position.price,
+			};
+
+			let updated_position = Position::<Balance, T::AssetId> {
+				asset_id: position.asset_id,
+				amount: position
+					.amount
+					.checked_sub(new_amount)
+					.ok_or(ArithmeticError::Underflow)?,
+				shares: position.shares.checked_sub(shares).ok_or(ArithmeticError::Underflow)?,
+				price: position.price,
+			};
+
+			let price = position.price_from_rational().ok_or(ArithmeticError::DivisionByZero)?;
+
+			Self::deposit_event(Event::PositionUpdated {
+				position_id,
+				owner: who.clone(),
+				asset: updated_position.asset_id,
+				amount: updated_position.amount,
+				shares: updated_position.shares,
+				price,
+			});
+
+			<Positions<T>>::insert(position_id, updated_position);
+
+			let instance_id = Self::create_and_mint_position_instance(&who)?;
+
+			Self::deposit_event(Event::PositionCreated {
+				position_id: instance_id,
+				owner: who,
+				asset: new_position.asset_id,
+				amount: new_position.amount,
+				shares: new_position.shares,
+				price,
+			});
+
+			<Positions<T>>::insert(instance_id, new_position);
+
+			Ok(())
+		}
+
+		/// Merge two LP positions of the same asset into one.
+		///
+		/// Amount and shares of `other_position_id` are added to `position_id` and `other_position_id` is destroyed.
+		/// Price of resulting position is the amount-weighted price of both positions.
+		///
+		/// Merge is not allowed if prices of the positions are on different sides of current spot price of the asset,
+		/// as the resulting position would not be treated same way as the original positions on liquidity removal.
+		///
+		/// Only owner of both positions can perform this action. Positions locked in liquidity mining
+		/// are owned by liquidity mining pallet and cannot be merged.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of position which is kept.
+		/// - `other_position_id`: The identifier of position which is merged into `position_id`.
+		///
+		/// Emits `PositionUpdated` and `PositionDestroyed` events when successful.
+		///
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::merge_positions())]
+		#[transactional]
+		pub fn merge_positions(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			other_position_id: T::PositionItemId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(position_id != other_position_id, Error::<T>::InvalidPositionMerge);
+
+			let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+			let other_position = Positions::<T>::get(other_position_id).ok_or(Error::<T>::PositionNotFound)?;
+
+			ensure!(
+				T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id) == Some(who.clone())
+					&& T::NFTHandler::owner(&T::NFTCollectionId::get(), &other_position_id) == Some(who.clone()),
+				Error::<T>::Forbidden
+			);
+
+			ensure!(
+				position.asset_id == other_position.asset_id,
+				Error::<T>::InvalidPositionMerge
+			);
+
+			let merged_price = if position.price == other_position.price {
+				position.price
+			} else {
+				let asset_state = Self::load_asset_state(position.asset_id)?;
+				let spot_price = asset_state.price().ok_or(ArithmeticError::DivisionByZero)?;
+				let price = position.price_from_rational().ok_or(ArithmeticError::DivisionByZero)?;
+				let other_price = other_position
+					.price_from_rational()
+					.ok_or(ArithmeticError::DivisionByZero)?;
+
+				ensure!(
+					(price <= spot_price && other_price <= spot_price)
+						|| (price >= spot_price && other_price >= spot_price),
+					Error::<T>::UnfairPositionMerge
+				);
+
+				let hub_value = |p: &Position<Balance, T::AssetId>| {
+					multiply_by_rational_with_rounding(p.amount, p.price.0, p.price.1, Rounding::Down)
+				};
+
+				(
+					hub_value(&position)
+						.zip(hub_value(&other_position))
+						.and_then(|(a, b)| a.checked_add(b))
+						.ok_or(ArithmeticError::Overflow)?,
+					position
+						.amount
+						.checked_add(other_position.amount)
+						.ok_or(ArithmeticError::Overflow)?,
+				)
+			};
+
+			let merged_position = Position::<Balance, T::AssetId> {
+				asset_id: position.asset_id,
+				amount: position
+					.amount
+					.checked_add(other_position.amount)
+					.ok_or(ArithmeticError::Overflow)?,
+				shares: position
+					.shares
+					.checked_add(other_position.shares)
+					.ok_or(ArithmeticError::Overflow)?,
+				price: merged_price,
+			};
+
+			<Positions<T>>::remove(other_position_id);
+			T::NFTHandler::burn(&T::NFTCollectionId::get(), &other_position_id, Some(&who))?;
+
+			Self::deposit_event(Event::PositionDestroyed {
+				position_id: other_position_id,
+				owner: who.clone(),
+			});
+
+			Self::deposit_event(Event::PositionUpdated {
+				position_id,
+				owner: who,
+				asset: merged_position.asset_id,
+				amount: merged_position.amount,
+				shares: merged_position.shares,
+				price: merged_position
+					.price_from_rational()
+					.ok_or(ArithmeticError::DivisionByZero)?,
+			});
+
+			<Positions<T>>::insert(position_id, merged_position);
+
+			Ok(())
+		}
+
+		/// Add liquidity of multiple assets to Omnipool in single call.
+		///
+		/// One LP position is created for each asset. All additions either succeed or fail together.
+		///
+		/// Asset weight caps and TVL cap are checked once all liquidity is added.
+		///
+		/// Parameters:
+		/// - `assets`: list of asset ids and amounts to add. Each asset can be included only once.
+		///
+		/// Emits `LiquidityAddedMultiple` event when successful.
+		///
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity_multiple(assets.len() as u32)
+			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight()
+			.saturating_add(T::ExternalPriceOracle::get_price_weight())
+			.saturating_mul(assets.len() as u64))
+		)]
+		#[transactional]
+		pub fn add_liquidity_multiple(origin: OriginFor<T>, assets: Vec<(T::AssetId, Balance)>) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			ensure!(!assets.is_empty(), Error::<T>::InvalidAssetList);
+			ensure!(
+				assets.len() <= T::MaxAddLiquidityAssets::get() as usize,
+				Error::<T>::TooManyAssets
+			);
+
+			let mut positions = Vec::with_capacity(assets.len());
+			let mut asset_infos = Vec::with_capacity(assets.len());
+
+			for (asset, amount) in assets.into_iter() {
+				ensure!(
+					!positions.iter().any(|(asset_id, _, _)| *asset_id == asset),
+					Error::<T>::InvalidAssetList
+				);
+
+				let (position_id, _, info) = Self::add_liquidity_position(&who, asset, amount)?;
+
+				positions.push((asset, amount, position_id));
+				asset_infos.push(info);
+			}
+
+			for (asset, _, _) in positions.iter() {
+				Self::ensure_asset_weight_cap(*asset)?;
+			}
+
+			Self::ensure_tvl_cap()?;
+
+			Self::deposit_event(Event::LiquidityAddedMultiple { who, positions });
+
+			for info in asset_infos.into_iter() {
+				T::OmnipoolHooks::on_liquidity_changed(origin.clone(), info)?;
+			}
+
+			Ok(())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let collector = match T::ProtocolFeeCollector::get() {
+				Some(collector) => collector,
+				None => return Weight::zero(),
+			};
+
+			let weight = <T as Config>::WeightInfo::reduce_hub_asset_imbalance();
+			if remaining_weight.any_lt(weight) {
+				return Weight::zero();
+			}
+
+			if let Err(error) = Self::reduce_hub_asset_imbalance(&collector) {
+				log::error!(target: "runtime::omnipool", "Failed to reduce hub asset imbalance: {:?}", error);
+			}
+
+			weight
+		}
+
+		fn integrity_test() {
+			assert_ne!(
+				T::MinimumPoolLiquidity::get(),
+				Balance::zero(),
+				"Minimum pool liquidity is 0."
+			);
+			assert_ne!(
+				T::MinimumTradingLimit::get(),
+				Balance::zero(),
+				"Minimum trading limit is 0."
+			);
+			assert_ne!(
+				T::HdxAssetId::get(),
+				T::StableCoinAssetId::get(),
+				"Same Hdx asset id and stable asset id."
+			);
+			assert_ne!(T::MaxInRatio::get(), Balance::zero(), "MaxInRatio is 0.");
+			assert!(!T::FeeLedgerPeriod::get().is_zero(), "FeeLedgerPeriod is 0.");
+			assert!(!T::ImbalanceBurnRatio::get().is_zero(), "ImbalanceBurnRatio is 0.");
+			assert_ne!(T::MaxOutRatio::get(), Balance::zero(), "MaxOutRatio is 0.");
+		}
+	}
+}
 
 impl<T: Config> Pallet<T> {
 	/// Protocol account address
@@ -1531,6 +1682,15 @@ impl<T: Config> Pallet<T> {
 		Ok((state, reserve).into())
 	}
 
+	/// Convert price barrier failure into dispatch error.
+	/// Errors of the external price provider are passed through.
+	fn price_barrier_error(e: PriceBarrierError) -> DispatchError {
+		match e {
+			PriceBarrierError::PriceDifferenceTooHigh => Error::<T>::PriceDifferenceTooHigh.into(),
+			PriceBarrierError::ExternalPriceNotAvailable(err) => err,
+		}
+	}
+
 	/// Set new state of asset.
 	/// This converts the new state into correct state type ( by removing the reserve)
 	fn set_asset_state(asset_id: T::AssetId, new_state: AssetReserveState<Balance>) {
@@ -1608,66 +1768,158 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
-	/// Calculate new tvl balance and ensure that it is below TVL Cap.
-	fn ensure_tvl_cap() -> DispatchResult {
-		let current_hub_asset_liquidity = T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
-		let stable_asset = Self::stable_asset()?;
+	/// Burn hub asset amount left in pool account after trade and update imbalance.
+	///
+	/// If `ProtocolFeeCollector` is set, the amount is transferred to the collector instead.
+	/// Imbalance is not updated in such case as no hub asset is burned.
+	///
+	/// The amount is the part of protocol fee used to cover the imbalance, not the whole protocol fee of the trade.
+	fn burn_or_collect_hub_asset(amount: Balance, delta_imbalance: BalanceUpdate<Balance>) -> DispatchResult {
+		if let Some(collector) = T::ProtocolFeeCollector::get() {
+			if amount > Balance::zero() {
+				T::Currency::transfer(T::HubAssetId::get(), &Self::protocol_account(), &collector, amount)?;
+				<CollectedProtocolFees<T>>::try_mutate(|collected| -> DispatchResult {
+					*collected = collected.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+					Ok(())
+				})?;
+			}
+			return Ok(());
+		}
 
-		let updated_tvl = hydra_dx_math::omnipool::calculate_tvl(current_hub_asset_liquidity, stable_asset)
-			.ok_or(ArithmeticError::Overflow)?;
+		if amount > Balance::zero() {
+			T::Currency::withdraw(T::HubAssetId::get(), &Self::protocol_account(), amount)?;
+		}
 
-		ensure!(updated_tvl <= TvlCap::<T>::get(), Error::<T>::TVLCapExceeded);
-		Ok(())
+		Self::update_imbalance(delta_imbalance)
 	}
 
-	/// Check if assets can be traded - asset_in must be allowed to be sold and asset_out allowed to be bought.
-	fn allow_assets(asset_in: &AssetReserveState<Balance>, asset_out: &AssetReserveState<Balance>) -> bool {
-		asset_in.tradable.contains(Tradability::SELL) && asset_out.tradable.contains(Tradability::BUY)
-	}
+	/// Burn hub asset collected by `collector` to reduce negative hub asset imbalance.
+	///
+	/// At most `ImbalanceBurnRatio` of current imbalance ( rounded up ) is burned. Only protocol fees tracked
+	/// in `CollectedProtocolFees` are burned, other hub asset held by the collector is left untouched.
+	///
+	/// Returns burned amount.
+	#[transactional]
+	pub fn reduce_hub_asset_imbalance(collector: &T::AccountId) -> Result<Balance, DispatchError> {
+		let imbalance = <HubAssetImbalance<T>>::get();
+
+		if !imbalance.negative || imbalance.value.is_zero() {
+			return Ok(Balance::zero());
+		}
 
-	/// Swap hub asset for asset_out.
-	/// Special handling of sell trade where asset in is Hub Asset.
-	fn sell_hub_asset(
-		origin: T::RuntimeOrigin,
-		who: &T::AccountId,
-		asset_out: T::AssetId,
-		amount: Balance,
-		limit: Balance,
-	) -> DispatchResult {
-		ensure!(
-			HubAssetTradability::<T>::get().contains(Tradability::SELL),
-			Error::<T>::NotAllowed
-		);
+		let max_amount = T::ImbalanceBurnRatio::get().mul_ceil(imbalance.value);
+		let collected = <CollectedProtocolFees<T>>::get();
+		let amount = max_amount
+			.min(collected)
+			.min(T::Currency::free_balance(T::HubAssetId::get(), collector));
 
-		let asset_state = Self::load_asset_state(asset_out)?;
+		if amount.is_zero() {
+			return Ok(Balance::zero());
+		}
 
-		ensure!(asset_state.tradable.contains(Tradability::BUY), Error::<T>::NotAllowed);
-		ensure!(
-			amount
-				<= asset_state
-					.hub_reserve
-					.checked_div(T::MaxInRatio::get())
-					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
-			Error::<T>::MaxInRatioExceeded
-		);
+		T::Currency::withdraw(T::HubAssetId::get(), collector, amount)?;
+		<CollectedProtocolFees<T>>::put(collected.saturating_sub(amount));
 
-		let current_imbalance = <HubAssetImbalance<T>>::get();
+		Self::update_imbalance(BalanceUpdate::Increase(amount))?;
 
-		let current_hub_asset_liquidity = Self::get_hub_asset_balance_of_protocol_account();
+		Self::deposit_event(Event::HubAssetImbalanceReduced {
+			amount,
+			imbalance: <HubAssetImbalance<T>>::get().value,
+		});
 
-		let (asset_fee, _) = T::Fee::get(&asset_out);
+		Ok(amount)
+	}
 
-		let state_changes = hydra_dx_math::omnipool::calculate_sell_hub_state_changes(
-			&(&asset_state).into(),
-			amount,
-			asset_fee,
-			I129 {
-				value: current_imbalance.value,
-				negative: current_imbalance.negative,
-			},
-			current_hub_asset_liquidity,
-		)
-		.ok_or(ArithmeticError::Overflow)?;
+	/// Current fee ledger period index.
+	fn current_fee_ledger_period() -> T::BlockNumber {
+		frame_system::Pallet::<T>::block_number()
+			.checked_div(&T::FeeLedgerPeriod::get())
+			.unwrap_or_default()
+	}
+
+	/// Move fee ledger entry to given period.
+	fn roll_fee_ledger_entry(
+		entry: FeeLedgerEntry<Balance, T::BlockNumber>,
+		period: T::BlockNumber,
+	) -> FeeLedgerEntry<Balance, T::BlockNumber> {
+		if entry.period == period {
+			return entry;
+		}
+
+		let previous = if entry.period.saturating_add(One::one()) == period {
+			entry.current
+		} else {
+			FeeAccumulator::default()
+		};
+
+		FeeLedgerEntry {
+			period,
+			current: FeeAccumulator::default(),
+			previous,
+		}
+	}
+
+	/// Accumulate trade fees of an asset in fee ledger.
+	fn record_fees(asset_id: T::AssetId, asset_fee: Balance, protocol_fee: Balance) -> DispatchResult {
+		if asset_fee.is_zero() && protocol_fee.is_zero() {
+			return Ok(());
+		}
+
+		let period = Self::current_fee_ledger_period();
+
+		FeeLedger::<T>::try_mutate(asset_id, |maybe_entry| -> DispatchResult {
+			let mut entry = Self::roll_fee_ledger_entry(maybe_entry.take().unwrap_or_default(), period);
+
+			entry.current = entry
+				.current
+				.accumulate(asset_fee, protocol_fee)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			*maybe_entry = Some(entry);
+
+			Ok(())
+		})
+	}
+
+	/// Trade fees of an asset accumulated in current and previous fee ledger period.
+	pub fn fee_ledger(asset_id: T::AssetId) -> FeeLedgerEntry<Balance, T::BlockNumber> {
+		let period = Self::current_fee_ledger_period();
+
+		FeeLedger::<T>::get(asset_id)
+			.map(|entry| Self::roll_fee_ledger_entry(entry, period))
+			.unwrap_or(FeeLedgerEntry {
+				period,
+				..Default::default()
+			})
+	}
+
+	/// Calculate new tvl balance and ensure that it is below TVL Cap.
+	fn ensure_tvl_cap() -> DispatchResult {
+		let current_hub_asset_liquidity = T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
+		let stable_asset = Self::stable_asset()?;
+
+		let updated_tvl = hydra_dx_math::omnipool::calculate_tvl(current_hub_asset_liquidity, stable_asset)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(updated_tvl <= TvlCap::<T>::get(), Error::<T>::TVLCapExceeded);
+		Ok(())
+	}
+
+	/// Check if assets can be traded - asset_in must be allowed to be sold and asset_out allowed to be bought.
+	fn allow_assets(asset_in: &AssetReserveState<Balance>, asset_out: &AssetReserveState<Balance>) -> bool {
+		asset_in.tradable.contains(Tradability::SELL) && asset_out.tradable.contains(Tradability::BUY)
+	}
+
+	/// Swap hub asset for asset_out.
+	/// Special handling of sell trade where asset in is Hub Asset.
+	fn sell_hub_asset(
+		origin: T::RuntimeOrigin,
+		who: &T::AccountId,
+		asset_out: T::AssetId,
+		amount: Balance,
+		limit: Balance,
+	) -> DispatchResult {
+		let (asset_state, state_changes) = Self::calculate_sell_hub_trade(asset_out, amount)?;
 
 		ensure!(
 			*state_changes.asset.delta_reserve >= limit,
@@ -1709,6 +1961,8 @@ impl<T: Config> Pallet<T> {
 
 		Self::set_asset_state(asset_out, new_asset_out_state);
 
+		Self::record_fees(asset_out, state_changes.fee.asset_fee, state_changes.fee.protocol_fee)?;
+
 		Self::deposit_event(Event::SellExecuted {
 			who: who.clone(),
 			asset_in: T::HubAssetId::get(),
@@ -1733,41 +1987,7 @@ impl<T: Config> Pallet<T> {
 		amount: Balance,
 		limit: Balance,
 	) -> DispatchResult {
-		ensure!(
-			HubAssetTradability::<T>::get().contains(Tradability::SELL),
-			Error::<T>::NotAllowed
-		);
-
-		let asset_state = Self::load_asset_state(asset_out)?;
-
-		ensure!(asset_state.tradable.contains(Tradability::BUY), Error::<T>::NotAllowed);
-
-		ensure!(
-			amount
-				<= asset_state
-					.reserve
-					.checked_div(T::MaxOutRatio::get())
-					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
-			Error::<T>::MaxOutRatioExceeded
-		);
-
-		let current_imbalance = <HubAssetImbalance<T>>::get();
-
-		let current_hub_asset_liquidity = Self::get_hub_asset_balance_of_protocol_account();
-
-		let (asset_fee, _) = T::Fee::get(&asset_out);
-
-		let state_changes = hydra_dx_math::omnipool::calculate_buy_for_hub_asset_state_changes(
-			&(&asset_state).into(),
-			amount,
-			asset_fee,
-			I129 {
-				value: current_imbalance.value,
-				negative: current_imbalance.negative,
-			},
-			current_hub_asset_liquidity,
-		)
-		.ok_or(ArithmeticError::Overflow)?;
+		let (asset_state, state_changes) = Self::calculate_buy_for_hub_asset_trade(asset_out, amount)?;
 
 		ensure!(
 			*state_changes.asset.delta_hub_reserve <= limit,
@@ -1808,6 +2028,8 @@ impl<T: Config> Pallet<T> {
 
 		Self::set_asset_state(asset_out, new_asset_out_state);
 
+		Self::record_fees(asset_out, state_changes.fee.asset_fee, state_changes.fee.protocol_fee)?;
+
 		Self::deposit_event(Event::BuyExecuted {
 			who: who.clone(),
 			asset_in: T::HubAssetId::get(),
@@ -1861,6 +2083,330 @@ impl<T: Config> Pallet<T> {
 		T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account())
 	}
 
+	/// Add liquidity of asset `asset` in quantity `amount` to Omnipool.
+	///
+	/// Performs all checks and state updates of `add_liquidity` and returns amount of shares of the newly created position.
+	///
+	/// Used by `add_liquidity` and `add_liquidity_with_limit` extrinsics. Can be used by other pallets, e.g. router.
+	#[require_transactional]
+	pub fn do_add_liquidity(
+		origin: OriginFor<T>,
+		asset: T::AssetId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		let who = ensure_signed(origin.clone())?;
+
+		let (position_id, shares, info) = Self::add_liquidity_position(&who, asset, amount)?;
+
+		Self::ensure_asset_weight_cap(asset)?;
+
+		Self::ensure_tvl_cap()?;
+
+		Self::deposit_event(Event::LiquidityAdded {
+			who,
+			asset_id: asset,
+			amount,
+			position_id,
+		});
+
+		T::OmnipoolHooks::on_liquidity_changed(origin, info)?;
+
+		Ok(shares)
+	}
+
+	/// Add `amount` of `asset` to the pool and create new LP position owned by `who`.
+	///
+	/// Returns id of created position, amount of minted shares and asset info for hooks.
+	///
+	/// Asset weight cap and TVL cap are not checked and hooks are not called - it is up to the caller.
+	fn add_liquidity_position(
+		who: &T::AccountId,
+		asset: T::AssetId,
+		amount: Balance,
+	) -> Result<(T::PositionItemId, Balance, AssetInfo<T::AssetId, Balance>), DispatchError> {
+		//
+		// Preconditions
+		//
+		ensure!(
+			amount >= T::MinimumPoolLiquidity::get(),
+			Error::<T>::InsufficientLiquidity
+		);
+
+		ensure!(
+			T::Currency::ensure_can_withdraw(asset, who, amount).is_ok(),
+			Error::<T>::InsufficientBalance
+		);
+
+		let asset_state = Self::load_asset_state(asset)?;
+
+		ensure!(
+			asset_state.tradable.contains(Tradability::ADD_LIQUIDITY),
+			Error::<T>::NotAllowed
+		);
+
+		T::PriceBarrier::ensure_price(
+			who,
+			T::HubAssetId::get(),
+			asset,
+			EmaPrice::new(asset_state.hub_reserve, asset_state.reserve),
+		)
+		.map_err(Self::price_barrier_error)?;
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+		let current_hub_asset_liquidity = T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
+
+		//
+		// Calculate add liquidity state changes
+		//
+		let state_changes = hydra_dx_math::omnipool::calculate_add_liquidity_state_changes(
+			&(&asset_state).into(),
+			amount,
+			I129 {
+				value: current_imbalance.value,
+				negative: current_imbalance.negative,
+			},
+			current_hub_asset_liquidity,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		let new_asset_state = asset_state
+			.clone()
+			.delta_update(&state_changes.asset)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		//
+		// Post - update states
+		//
+
+		// Create LP position with given shares
+		let lp_position = Position::<Balance, T::AssetId> {
+			asset_id: asset,
+			amount,
+			shares: *state_changes.asset.delta_shares,
+			// Note: position needs price after asset state is updated.
+			price: (new_asset_state.hub_reserve, new_asset_state.reserve),
+		};
+
+		let instance_id = Self::create_and_mint_position_instance(who)?;
+
+		<Positions<T>>::insert(instance_id, lp_position);
+
+		Self::deposit_event(Event::PositionCreated {
+			position_id: instance_id,
+			owner: who.clone(),
+			asset,
+			amount,
+			shares: *state_changes.asset.delta_shares,
+			price: new_asset_state.price().ok_or(ArithmeticError::DivisionByZero)?,
+		});
+
+		T::Currency::transfer(
+			asset,
+			who,
+			&Self::protocol_account(),
+			*state_changes.asset.delta_reserve,
+		)?;
+
+		debug_assert_eq!(*state_changes.asset.delta_reserve, amount);
+
+		// Callback hook info
+		let info: AssetInfo<T::AssetId, Balance> =
+			AssetInfo::new(asset, &asset_state, &new_asset_state, &state_changes.asset);
+
+		Self::update_imbalance(state_changes.delta_imbalance)?;
+
+		Self::update_hub_asset_liquidity(&state_changes.asset.delta_hub_reserve)?;
+
+		Self::set_asset_state(asset, new_asset_state);
+
+		Ok((instance_id, *state_changes.asset.delta_shares, info))
+	}
+
+	/// Ensure that ratio between asset's hub reserve and total hub asset liquidity in Omnipool
+	/// does not exceed asset weight cap.
+	fn ensure_asset_weight_cap(asset: T::AssetId) -> DispatchResult {
+		let asset_state = Self::load_asset_state(asset)?;
+
+		let hub_reserve_ratio = FixedU128::checked_from_rational(
+			asset_state.hub_reserve,
+			Self::get_hub_asset_balance_of_protocol_account(),
+		)
+		.ok_or(ArithmeticError::DivisionByZero)?;
+
+		ensure!(
+			hub_reserve_ratio <= asset_state.weight_cap(),
+			Error::<T>::AssetWeightCapExceeded
+		);
+
+		Ok(())
+	}
+
+	/// Remove `amount` of shares from position `position_id`.
+	///
+	/// Performs all checks and state updates of `remove_liquidity` and returns amount of asset transferred to the position owner.
+	/// Hub asset possibly received by the owner is not included.
+	///
+	/// Used by `remove_liquidity` and `remove_liquidity_with_limit` extrinsics. Can be used by other pallets, e.g. router.
+	#[require_transactional]
+	pub fn do_remove_liquidity(
+		origin: OriginFor<T>,
+		position_id: T::PositionItemId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		//
+		// Preconditions
+		//
+		let who = ensure_signed(origin.clone())?;
+
+		ensure!(amount > Balance::zero(), Error::<T>::InvalidSharesAmount);
+
+		ensure!(
+			T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id) == Some(who.clone()),
+			Error::<T>::Forbidden
+		);
+
+		let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+
+		ensure!(position.shares >= amount, Error::<T>::InsufficientShares);
+
+		let asset_id = position.asset_id;
+
+		let asset_state = Self::load_asset_state(asset_id)?;
+
+		ensure!(
+			asset_state.tradable.contains(Tradability::REMOVE_LIQUIDITY),
+			Error::<T>::NotAllowed
+		);
+
+		T::PriceBarrier::ensure_price(
+			&who,
+			T::HubAssetId::get(),
+			asset_id,
+			EmaPrice::new(asset_state.hub_reserve, asset_state.reserve),
+		)
+		.map_err(Self::price_barrier_error)?;
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+		let current_hub_asset_liquidity = T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
+
+		let ext_asset_price = T::ExternalPriceOracle::get_price(T::HubAssetId::get(), asset_id)?;
+
+		if ext_asset_price.is_zero() {
+			return Err(Error::<T>::InvalidOraclePrice.into());
+		}
+
+		let withdrawal_fee = hydra_dx_math::omnipool::calculate_withdrawal_fee(
+			asset_state.price().ok_or(ArithmeticError::DivisionByZero)?,
+			FixedU128::checked_from_rational(ext_asset_price.n, ext_asset_price.d)
+				.defensive_ok_or(Error::<T>::InvalidOraclePrice)?,
+			T::MinWithdrawalFee::get(),
+		);
+
+		//
+		// calculate state changes of remove liquidity
+		//
+		let state_changes = hydra_dx_math::omnipool::calculate_remove_liquidity_state_changes(
+			&(&asset_state).into(),
+			amount,
+			&(&position).into(),
+			I129 {
+				value: current_imbalance.value,
+				negative: current_imbalance.negative,
+			},
+			current_hub_asset_liquidity,
+			withdrawal_fee,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		let new_asset_state = asset_state
+			.clone()
+			.delta_update(&state_changes.asset)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		// Update position state
+		let updated_position = position
+			.delta_update(
+				&state_changes.delta_position_reserve,
+				&state_changes.delta_position_shares,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		//
+		// Post - update states
+		//
+
+		T::Currency::transfer(
+			asset_id,
+			&Self::protocol_account(),
+			&who,
+			*state_changes.asset.delta_reserve,
+		)?;
+
+		Self::update_imbalance(state_changes.delta_imbalance)?;
+
+		// burn only difference between delta hub and lp hub amount.
+		Self::update_hub_asset_liquidity(
+			&state_changes
+				.asset
+				.delta_hub_reserve
+				.merge(BalanceUpdate::Increase(state_changes.lp_hub_amount))
+				.ok_or(ArithmeticError::Overflow)?,
+		)?;
+
+		// LP receives some hub asset
+		if state_changes.lp_hub_amount > Balance::zero() {
+			T::Currency::transfer(
+				T::HubAssetId::get(),
+				&Self::protocol_account(),
+				&who,
+				state_changes.lp_hub_amount,
+			)?;
+		}
+
+		if updated_position.shares == Balance::zero() {
+			// All liquidity removed, remove position and burn NFT instance
+
+			<Positions<T>>::remove(position_id);
+			T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
+
+			Self::deposit_event(Event::PositionDestroyed {
+				position_id,
+				owner: who.clone(),
+			});
+		} else {
+			Self::deposit_event(Event::PositionUpdated {
+				position_id,
+				owner: who.clone(),
+				asset: asset_id,
+				amount: updated_position.amount,
+				shares: updated_position.shares,
+				price: updated_position
+					.price_from_rational()
+					.ok_or(ArithmeticError::DivisionByZero)?,
+			});
+
+			<Positions<T>>::insert(position_id, updated_position);
+		}
+
+		// Callback hook info
+		let info: AssetInfo<T::AssetId, Balance> =
+			AssetInfo::new(asset_id, &asset_state, &new_asset_state, &state_changes.asset);
+
+		Self::set_asset_state(asset_id, new_asset_state);
+
+		Self::deposit_event(Event::LiquidityRemoved {
+			who,
+			position_id,
+			asset_id,
+			shares_removed: amount,
+			fee: withdrawal_fee,
+		});
+
+		T::OmnipoolHooks::on_liquidity_changed(origin, info)?;
+
+		Ok(*state_changes.asset.delta_reserve)
+	}
+
 	/// Remove asset from list of Omnipool assets.
 	/// No events emitted.
 	pub fn remove_asset(asset_id: T::AssetId) -> DispatchResult {
@@ -1916,4 +2462,335 @@ impl<T: Config> Pallet<T> {
 	pub fn exists(asset: T::AssetId) -> bool {
 		Assets::<T>::contains_key(asset)
 	}
+
+	/// Calculate result of selling `amount` of `asset_in` for `asset_out` without executing the trade.
+	///
+	/// Current asset and protocol fees are used. Same restrictions as for `sell` apply except the balance checks.
+	pub fn calculate_sell_quote(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount: Balance,
+	) -> Result<TradeQuote<Balance>, DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::SameAssetTradeNotAllowed);
+		ensure!(
+			amount >= T::MinimumTradingLimit::get(),
+			Error::<T>::InsufficientTradingAmount
+		);
+		ensure!(asset_out != T::HubAssetId::get(), Error::<T>::NotAllowed);
+
+		if asset_in == T::HubAssetId::get() {
+			let (asset_out_state, state_changes) = Self::calculate_sell_hub_trade(asset_out, amount)?;
+
+			ensure!(
+				*state_changes.asset.delta_reserve
+					<= asset_out_state
+						.reserve
+						.checked_div(T::MaxOutRatio::get())
+						.ok_or(ArithmeticError::DivisionByZero)?,
+				Error::<T>::MaxOutRatioExceeded
+			);
+
+			return Self::hub_trade_quote(asset_out_state, &state_changes);
+		}
+
+		let (asset_in_state, asset_out_state, state_changes) = Self::calculate_sell_trade(asset_in, asset_out, amount)?;
+
+		ensure!(
+			*state_changes.asset_out.delta_reserve
+				<= asset_out_state
+					.reserve
+					.checked_div(T::MaxOutRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?,
+			Error::<T>::MaxOutRatioExceeded
+		);
+
+		Self::trade_quote(asset_in_state, asset_out_state, &state_changes)
+	}
+
+	/// Calculate result of buying `amount` of `asset_out` for `asset_in` without executing the trade.
+	///
+	/// Current asset and protocol fees are used. Same restrictions as for `buy` apply except the balance checks.
+	pub fn calculate_buy_quote(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount: Balance,
+	) -> Result<TradeQuote<Balance>, DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::SameAssetTradeNotAllowed);
+		ensure!(
+			amount >= T::MinimumTradingLimit::get(),
+			Error::<T>::InsufficientTradingAmount
+		);
+		ensure!(asset_out != T::HubAssetId::get(), Error::<T>::NotAllowed);
+
+		if asset_in == T::HubAssetId::get() {
+			let (asset_out_state, state_changes) = Self::calculate_buy_for_hub_asset_trade(asset_out, amount)?;
+
+			ensure!(
+				*state_changes.asset.delta_hub_reserve
+					<= asset_out_state
+						.hub_reserve
+						.checked_div(T::MaxInRatio::get())
+						.ok_or(ArithmeticError::DivisionByZero)?,
+				Error::<T>::MaxInRatioExceeded
+			);
+
+			return Self::hub_trade_quote(asset_out_state, &state_changes);
+		}
+
+		let (asset_in_state, asset_out_state, state_changes) = Self::calculate_buy_trade(asset_in, asset_out, amount)?;
+
+		ensure!(
+			*state_changes.asset_in.delta_reserve
+				<= asset_in_state
+					.reserve
+					.checked_div(T::MaxInRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?,
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		Self::trade_quote(asset_in_state, asset_out_state, &state_changes)
+	}
+
+	/// Load states of `asset_in` and `asset_out` and calculate state changes of selling `amount` of `asset_in`.
+	///
+	/// Checks tradability of both assets and max in ratio of `amount`. Limit and max out ratio checks are left to the caller.
+	/// Shared by `sell` and `calculate_sell_quote`.
+	fn calculate_sell_trade(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount: Balance,
+	) -> Result<
+		(
+			AssetReserveState<Balance>,
+			AssetReserveState<Balance>,
+			TradeStateChange<Balance>,
+		),
+		DispatchError,
+	> {
+		let asset_in_state = Self::load_asset_state(asset_in)?;
+		let asset_out_state = Self::load_asset_state(asset_out)?;
+
+		ensure!(
+			Self::allow_assets(&asset_in_state, &asset_out_state),
+			Error::<T>::NotAllowed
+		);
+
+		ensure!(
+			amount
+				<= asset_in_state
+					.reserve
+					.checked_div(T::MaxInRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+
+		let (asset_fee, protocol_fee) = T::Fee::get(&asset_out);
+
+		let state_changes = hydra_dx_math::omnipool::calculate_sell_state_changes(
+			&(&asset_in_state).into(),
+			&(&asset_out_state).into(),
+			amount,
+			asset_fee,
+			protocol_fee,
+			current_imbalance.value,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		Ok((asset_in_state, asset_out_state, state_changes))
+	}
+
+	/// Load states of `asset_in` and `asset_out` and calculate state changes of buying `amount` of `asset_out`.
+	///
+	/// Checks tradability of both assets, available liquidity and max out ratio of `amount`. Balance, limit and max in ratio
+	/// checks are left to the caller. Shared by `buy` and `calculate_buy_quote`.
+	fn calculate_buy_trade(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount: Balance,
+	) -> Result<
+		(
+			AssetReserveState<Balance>,
+			AssetReserveState<Balance>,
+			TradeStateChange<Balance>,
+		),
+		DispatchError,
+	> {
+		let asset_in_state = Self::load_asset_state(asset_in)?;
+		let asset_out_state = Self::load_asset_state(asset_out)?;
+
+		ensure!(
+			Self::allow_assets(&asset_in_state, &asset_out_state),
+			Error::<T>::NotAllowed
+		);
+
+		ensure!(asset_out_state.reserve >= amount, Error::<T>::InsufficientLiquidity);
+
+		ensure!(
+			amount
+				<= asset_out_state
+					.reserve
+					.checked_div(T::MaxOutRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxOutRatio is zero.
+			Error::<T>::MaxOutRatioExceeded
+		);
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+
+		let (asset_fee, protocol_fee) = T::Fee::get(&asset_in);
+
+		let state_changes = hydra_dx_math::omnipool::calculate_buy_state_changes(
+			&(&asset_in_state).into(),
+			&(&asset_out_state).into(),
+			amount,
+			asset_fee,
+			protocol_fee,
+			current_imbalance.value,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		Ok((asset_in_state, asset_out_state, state_changes))
+	}
+
+	/// Load state of `asset_out` and calculate state changes of selling `amount` of Hub Asset for it.
+	///
+	/// Checks tradability of Hub Asset and `asset_out` and max in ratio of `amount`. Limit and max out ratio checks are left
+	/// to the caller. Shared by `sell` and `calculate_sell_quote`.
+	fn calculate_sell_hub_trade(
+		asset_out: T::AssetId,
+		amount: Balance,
+	) -> Result<(AssetReserveState<Balance>, HubTradeStateChange<Balance>), DispatchError> {
+		ensure!(Self::is_hub_asset_allowed(Tradability::SELL), Error::<T>::NotAllowed);
+
+		let asset_state = Self::load_asset_state(asset_out)?;
+
+		ensure!(asset_state.tradable.contains(Tradability::BUY), Error::<T>::NotAllowed);
+
+		ensure!(
+			amount
+				<= asset_state
+					.hub_reserve
+					.checked_div(T::MaxInRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+
+		let current_hub_asset_liquidity = Self::get_hub_asset_balance_of_protocol_account();
+
+		let (asset_fee, _) = T::Fee::get(&asset_out);
+
+		let state_changes = hydra_dx_math::omnipool::calculate_sell_hub_state_changes(
+			&(&asset_state).into(),
+			amount,
+			asset_fee,
+			I129 {
+				value: current_imbalance.value,
+				negative: current_imbalance.negative,
+			},
+			current_hub_asset_liquidity,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		Ok((asset_state, state_changes))
+	}
+
+	/// Load state of `asset_out` and calculate state changes of buying `amount` of it for Hub Asset.
+	///
+	/// Checks tradability of Hub Asset and `asset_out` and max out ratio of `amount`. Limit and max in ratio checks are left
+	/// to the caller. Shared by `buy` and `calculate_buy_quote`.
+	fn calculate_buy_for_hub_asset_trade(
+		asset_out: T::AssetId,
+		amount: Balance,
+	) -> Result<(AssetReserveState<Balance>, HubTradeStateChange<Balance>), DispatchError> {
+		ensure!(Self::is_hub_asset_allowed(Tradability::SELL), Error::<T>::NotAllowed);
+
+		let asset_state = Self::load_asset_state(asset_out)?;
+
+		ensure!(asset_state.tradable.contains(Tradability::BUY), Error::<T>::NotAllowed);
+
+		ensure!(
+			amount
+				<= asset_state
+					.reserve
+					.checked_div(T::MaxOutRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxOutRatio is zero.
+			Error::<T>::MaxOutRatioExceeded
+		);
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+
+		let current_hub_asset_liquidity = Self::get_hub_asset_balance_of_protocol_account();
+
+		let (asset_fee, _) = T::Fee::get(&asset_out);
+
+		let state_changes = hydra_dx_math::omnipool::calculate_buy_for_hub_asset_state_changes(
+			&(&asset_state).into(),
+			amount,
+			asset_fee,
+			I129 {
+				value: current_imbalance.value,
+				negative: current_imbalance.negative,
+			},
+			current_hub_asset_liquidity,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		Ok((asset_state, state_changes))
+	}
+
+	/// Build trade quote from calculated state changes of a trade between two omnipool assets.
+	fn trade_quote(
+		asset_in_state: AssetReserveState<Balance>,
+		asset_out_state: AssetReserveState<Balance>,
+		state_changes: &TradeStateChange<Balance>,
+	) -> Result<TradeQuote<Balance>, DispatchError> {
+		let new_asset_in_state = asset_in_state
+			.delta_update(&state_changes.asset_in)
+			.ok_or(ArithmeticError::Overflow)?;
+		let new_asset_out_state = asset_out_state
+			.delta_update(&state_changes.asset_out)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		Ok(TradeQuote {
+			amount_in: *state_changes.asset_in.delta_reserve,
+			amount_out: *state_changes.asset_out.delta_reserve,
+			asset_fee: state_changes.fee.asset_fee,
+			protocol_fee: state_changes.fee.protocol_fee,
+			spot_price: Self::spot_price_after_trade(&new_asset_in_state, &new_asset_out_state)?,
+		})
+	}
+
+	/// Build trade quote from calculated state changes of a trade where Hub Asset is sold.
+	fn hub_trade_quote(
+		asset_out_state: AssetReserveState<Balance>,
+		state_changes: &HubTradeStateChange<Balance>,
+	) -> Result<TradeQuote<Balance>, DispatchError> {
+		let new_asset_out_state = asset_out_state
+			.delta_update(&state_changes.asset)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		Ok(TradeQuote {
+			amount_in: *state_changes.asset.delta_hub_reserve,
+			amount_out: *state_changes.asset.delta_reserve,
+			asset_fee: state_changes.fee.asset_fee,
+			protocol_fee: state_changes.fee.protocol_fee,
+			spot_price: new_asset_out_state.price().ok_or(ArithmeticError::DivisionByZero)?,
+		})
+	}
+
+	/// Price of asset out denominated in asset in - (asset in / LRNA) * (LRNA / asset out).
+	fn spot_price_after_trade(
+		asset_in: &AssetReserveState<Balance>,
+		asset_out: &AssetReserveState<Balance>,
+	) -> Result<FixedU128, DispatchError> {
+		let price_in = FixedU128::checked_from_rational(asset_in.reserve, asset_in.hub_reserve)
+			.ok_or(ArithmeticError::DivisionByZero)?;
+		let price_out = asset_out.price().ok_or(ArithmeticError::DivisionByZero)?;
+		price_in
+			.checked_mul(&price_out)
+			.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
 }
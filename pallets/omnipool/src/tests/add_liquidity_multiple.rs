@@ -0,0 +1,209 @@
+use super::*;
+use frame_support::assert_noop;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn add_liquidity_multiple_should_create_position_for_each_asset() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 100, 5000 * ONE))
+		.add_endowed_accounts((LP1, 200, 5000 * ONE))
+		.add_endowed_accounts((LP2, 100, 2000 * ONE))
+		.add_endowed_accounts((LP2, 200, 2000 * ONE))
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let first_position_id = <NextPositionId<Test>>::get();
+			let second_position_id = first_position_id + 1;
+
+			// Act
+			assert_ok!(Omnipool::add_liquidity_multiple(
+				RuntimeOrigin::signed(LP1),
+				vec![(100, 400 * ONE), (200, 600 * ONE)]
+			));
+
+			// Assert
+			assert_eq!(
+				Positions::<Test>::get(first_position_id).unwrap(),
+				Position {
+					asset_id: 100,
+					amount: 400 * ONE,
+					shares: 400 * ONE,
+					price: (1560 * ONE, 2400 * ONE),
+				}
+			);
+			assert_eq!(
+				Positions::<Test>::get(second_position_id).unwrap(),
+				Position {
+					asset_id: 200,
+					amount: 600 * ONE,
+					shares: 600 * ONE,
+					price: (1690 * ONE, 2600 * ONE),
+				}
+			);
+			assert_eq!(
+				POSITIONS.with(|v| v.borrow().get(&first_position_id).copied()),
+				Some(LP1)
+			);
+			assert_eq!(
+				POSITIONS.with(|v| v.borrow().get(&second_position_id).copied()),
+				Some(LP1)
+			);
+
+			assert_balance!(LP1, 100, 4600 * ONE);
+			assert_balance!(LP1, 200, 4400 * ONE);
+			assert_balance!(Omnipool::protocol_account(), 100, 2400 * ONE);
+			assert_balance!(Omnipool::protocol_account(), 200, 2600 * ONE);
+
+			frame_system::Pallet::<Test>::assert_last_event(
+				crate::Event::LiquidityAddedMultiple {
+					who: LP1,
+					positions: vec![
+						(100, 400 * ONE, first_position_id),
+						(200, 600 * ONE, second_position_id),
+					],
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_multiple_should_check_weight_cap_after_all_assets_are_added() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 100, 5000 * ONE))
+		.add_endowed_accounts((LP1, 200, 5000 * ONE))
+		.add_endowed_accounts((LP2, 100, 100 * ONE))
+		.add_endowed_accounts((LP2, 200, 100 * ONE))
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_asset_weight_cap(Permill::from_percent(11))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 100 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP2, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			// Asset weight cap is exceeded when asset is added alone
+			assert_noop!(
+				Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 100, 2000 * ONE),
+				Error::<Test>::AssetWeightCapExceeded
+			);
+
+			assert_ok!(Omnipool::add_liquidity_multiple(
+				RuntimeOrigin::signed(LP1),
+				vec![(100, 2000 * ONE), (200, 2000 * ONE)]
+			));
+
+			assert_balance!(Omnipool::protocol_account(), 100, 2100 * ONE);
+			assert_balance!(Omnipool::protocol_account(), 200, 2100 * ONE);
+		});
+}
+
+#[test]
+fn add_liquidity_multiple_should_fail_when_weight_cap_is_exceeded_for_any_asset() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 100, 5000 * ONE))
+		.add_endowed_accounts((LP1, 200, 5000 * ONE))
+		.add_endowed_accounts((LP2, 100, 100 * ONE))
+		.add_endowed_accounts((LP2, 200, 100 * ONE))
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_asset_weight_cap(Permill::from_percent(11))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 100 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP2, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::add_liquidity_multiple(RuntimeOrigin::signed(LP1), vec![(100, 100 * ONE), (200, 2000 * ONE)]),
+				Error::<Test>::AssetWeightCapExceeded
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_multiple_should_fail_when_tvl_cap_is_exceeded() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 100, 5000 * ONE))
+		.add_endowed_accounts((LP1, 200, 5000 * ONE))
+		.add_endowed_accounts((LP2, 100, 2000 * ONE))
+		.add_endowed_accounts((LP2, 200, 2000 * ONE))
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_tvl_cap(26_500 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::add_liquidity_multiple(RuntimeOrigin::signed(LP1), vec![(100, 400 * ONE), (200, 400 * ONE)]),
+				Error::<Test>::TVLCapExceeded
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_multiple_should_fail_and_revert_all_when_any_addition_fails() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 100, 5000 * ONE))
+		.add_endowed_accounts((LP2, 100, 2000 * ONE))
+		.add_endowed_accounts((LP2, 200, 2000 * ONE))
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::add_liquidity_multiple(RuntimeOrigin::signed(LP1), vec![(100, 400 * ONE), (200, 400 * ONE)]),
+				Error::<Test>::InsufficientBalance
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_multiple_should_fail_when_asset_list_is_invalid() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 100, 5000 * ONE))
+		.add_endowed_accounts((LP2, 100, 2000 * ONE))
+		.with_registered_asset(100)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::add_liquidity_multiple(RuntimeOrigin::signed(LP1), vec![]),
+				Error::<Test>::InvalidAssetList
+			);
+			assert_noop!(
+				Omnipool::add_liquidity_multiple(RuntimeOrigin::signed(LP1), vec![(100, 400 * ONE), (100, 400 * ONE)]),
+				Error::<Test>::InvalidAssetList
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_multiple_should_fail_when_asset_list_is_too_long() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 100, 5000 * ONE))
+		.add_endowed_accounts((LP2, 100, 2000 * ONE))
+		.with_registered_asset(100)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// MaxAddLiquidityAssets is 5
+			let assets = (100..106).map(|asset_id| (asset_id, ONE)).collect();
+
+			assert_noop!(
+				Omnipool::add_liquidity_multiple(RuntimeOrigin::signed(LP1), assets),
+				Error::<Test>::TooManyAssets
+			);
+		});
+}
@@ -1,21 +1,23 @@
-use crate::traits::{EnsurePriceWithin, ExternalPriceProvider, ShouldAllow};
+use crate::traits::{EnsurePriceWithin, ExternalPriceProvider, PriceBarrierError, ShouldAllow};
 use frame_support::dispatch::Weight;
 use frame_support::{assert_err, assert_ok, parameter_types};
 use hydra_dx_math::ema::EmaPrice;
-use sp_runtime::Permill;
+use sp_runtime::{DispatchError, Permill};
 use std::cell::RefCell;
 
 thread_local! {
-	pub static EXTERNAL_PRICE: RefCell<EmaPrice> = RefCell::new(EmaPrice::default());
+	pub static EXTERNAL_PRICE: RefCell<Option<EmaPrice>> = RefCell::new(None);
 }
 
 struct SinglePriceProvider;
 
 impl ExternalPriceProvider<u32, EmaPrice> for SinglePriceProvider {
-	type Error = ();
+	type Error = DispatchError;
 
 	fn get_price(_asset_a: u32, _asset_b: u32) -> Result<EmaPrice, Self::Error> {
-		Ok(EXTERNAL_PRICE.with(|v| *v.borrow()))
+		EXTERNAL_PRICE
+			.with(|v| *v.borrow())
+			.ok_or(DispatchError::Other("price not available"))
 	}
 
 	fn get_price_weight() -> Weight {
@@ -29,7 +31,7 @@ parameter_types! {
 
 #[test]
 fn ensure_price_should_be_ok_when_price_is_within_allowed_difference() {
-	EXTERNAL_PRICE.with(|v| *v.borrow_mut() = EmaPrice::new(1, 10));
+	EXTERNAL_PRICE.with(|v| *v.borrow_mut() = Some(EmaPrice::new(1, 10)));
 	let spot_price = EmaPrice::new(999, 10000);
 	assert_ok!(EnsurePriceWithin::<u64, u32, SinglePriceProvider, MaxAllowed, ()>::ensure_price(&0, 1, 2, spot_price));
 
@@ -39,16 +41,26 @@ fn ensure_price_should_be_ok_when_price_is_within_allowed_difference() {
 
 #[test]
 fn ensure_price_should_fail_when_price_is_not_within_allowed_difference() {
-	EXTERNAL_PRICE.with(|v| *v.borrow_mut() = EmaPrice::new(1, 10));
+	EXTERNAL_PRICE.with(|v| *v.borrow_mut() = Some(EmaPrice::new(1, 10)));
 	let spot_price = EmaPrice::new(8, 1000);
 	assert_err!(
 		EnsurePriceWithin::<u64, u32, SinglePriceProvider, MaxAllowed, ()>::ensure_price(&0, 1, 2, spot_price),
-		()
+		PriceBarrierError::PriceDifferenceTooHigh
 	);
 
 	let spot_price = EmaPrice::new(2, 10);
 	assert_err!(
 		EnsurePriceWithin::<u64, u32, SinglePriceProvider, MaxAllowed, ()>::ensure_price(&0, 1, 2, spot_price),
-		()
+		PriceBarrierError::PriceDifferenceTooHigh
+	);
+}
+
+#[test]
+fn ensure_price_should_fail_with_external_error_when_external_price_is_not_available() {
+	EXTERNAL_PRICE.with(|v| *v.borrow_mut() = None);
+	let spot_price = EmaPrice::new(1, 10);
+	assert_err!(
+		EnsurePriceWithin::<u64, u32, SinglePriceProvider, MaxAllowed, ()>::ensure_price(&0, 1, 2, spot_price),
+		PriceBarrierError::ExternalPriceNotAvailable(DispatchError::Other("price not available"))
 	);
 }
@@ -0,0 +1,191 @@
+use super::*;
+use crate::types::{FeeAccumulator, FeeLedgerEntry};
+use pretty_assertions::assert_eq;
+
+const COLLECTOR: AccountId = 1_000;
+
+fn trading_pool() -> ExtBuilder {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 100, 5000 * ONE))
+		.add_endowed_accounts((LP1, 200, 5000 * ONE))
+		.add_endowed_accounts((LP2, 100, 2000 * ONE))
+		.add_endowed_accounts((LP3, 200, 2000 * ONE))
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_asset_fee(Permill::from_percent(10))
+		.with_protocol_fee(Permill::from_percent(20))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP3, 2000 * ONE)
+}
+
+#[test]
+fn sell_should_record_fees_of_asset_out_in_fee_ledger() {
+	trading_pool().build().execute_with(|| {
+		let quote = Omnipool::calculate_sell_quote(100, 200, 50 * ONE).unwrap();
+
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 100, 200, 50 * ONE, 0));
+
+		assert_eq!(
+			Omnipool::fee_ledger(200),
+			FeeLedgerEntry {
+				period: 0,
+				current: FeeAccumulator {
+					asset_fee: quote.asset_fee,
+					protocol_fee: quote.protocol_fee,
+				},
+				previous: FeeAccumulator::default(),
+			}
+		);
+		assert_eq!(FeeLedger::<Test>::get(100), None);
+	});
+}
+
+#[test]
+fn buy_should_record_fees_of_asset_in_in_fee_ledger() {
+	trading_pool().build().execute_with(|| {
+		let quote = Omnipool::calculate_buy_quote(100, 200, 50 * ONE).unwrap();
+
+		assert_ok!(Omnipool::buy(
+			RuntimeOrigin::signed(LP1),
+			200,
+			100,
+			50 * ONE,
+			Balance::MAX
+		));
+
+		assert_eq!(
+			Omnipool::fee_ledger(100),
+			FeeLedgerEntry {
+				period: 0,
+				current: FeeAccumulator {
+					asset_fee: quote.asset_fee,
+					protocol_fee: quote.protocol_fee,
+				},
+				previous: FeeAccumulator::default(),
+			}
+		);
+	});
+}
+
+#[test]
+fn fee_ledger_should_accumulate_fees_of_same_period() {
+	trading_pool().build().execute_with(|| {
+		let first = Omnipool::calculate_sell_quote(100, 200, 50 * ONE).unwrap();
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 100, 200, 50 * ONE, 0));
+
+		System::set_block_number(9);
+
+		let second = Omnipool::calculate_sell_quote(100, 200, 50 * ONE).unwrap();
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 100, 200, 50 * ONE, 0));
+
+		assert_eq!(
+			Omnipool::fee_ledger(200).current,
+			FeeAccumulator {
+				asset_fee: first.asset_fee + second.asset_fee,
+				protocol_fee: first.protocol_fee + second.protocol_fee,
+			}
+		);
+	});
+}
+
+#[test]
+fn fee_ledger_should_move_fees_to_previous_when_period_changes() {
+	trading_pool().build().execute_with(|| {
+		let first = Omnipool::calculate_sell_quote(100, 200, 50 * ONE).unwrap();
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 100, 200, 50 * ONE, 0));
+
+		System::set_block_number(10);
+
+		let second = Omnipool::calculate_sell_quote(100, 200, 50 * ONE).unwrap();
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 100, 200, 50 * ONE, 0));
+
+		assert_eq!(
+			Omnipool::fee_ledger(200),
+			FeeLedgerEntry {
+				period: 1,
+				current: FeeAccumulator {
+					asset_fee: second.asset_fee,
+					protocol_fee: second.protocol_fee,
+				},
+				previous: FeeAccumulator {
+					asset_fee: first.asset_fee,
+					protocol_fee: first.protocol_fee,
+				},
+			}
+		);
+
+		// no trades in next period
+		System::set_block_number(20);
+		assert_eq!(
+			Omnipool::fee_ledger(200),
+			FeeLedgerEntry {
+				period: 2,
+				current: FeeAccumulator::default(),
+				previous: FeeAccumulator {
+					asset_fee: second.asset_fee,
+					protocol_fee: second.protocol_fee,
+				},
+			}
+		);
+
+		// fees older than previous period are not reported
+		System::set_block_number(30);
+		assert_eq!(
+			Omnipool::fee_ledger(200),
+			FeeLedgerEntry {
+				period: 3,
+				..Default::default()
+			}
+		);
+	});
+}
+
+#[test]
+fn sell_should_burn_hub_asset_and_update_imbalance_when_collector_is_not_set() {
+	trading_pool()
+		.add_endowed_accounts((LP3, LRNA, 100 * ONE))
+		.build()
+		.execute_with(|| {
+			// Arrange - create negative imbalance
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), LRNA, 200, 50 * ONE, 0));
+
+			let imbalance = HubAssetImbalance::<Test>::get();
+			let lrna_issuance = Tokens::total_issuance(LRNA);
+
+			// Act
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 200, 100, 10 * ONE, 0));
+
+			// Assert
+			assert!(HubAssetImbalance::<Test>::get().value < imbalance.value);
+			assert!(Tokens::total_issuance(LRNA) < lrna_issuance);
+			assert_balance!(COLLECTOR, LRNA, 0);
+		});
+}
+
+#[test]
+fn sell_should_transfer_hub_asset_to_collector_when_collector_is_set() {
+	trading_pool()
+		.add_endowed_accounts((LP3, LRNA, 100 * ONE))
+		.with_protocol_fee_collector(COLLECTOR)
+		.build()
+		.execute_with(|| {
+			// Arrange - create negative imbalance
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), LRNA, 200, 50 * ONE, 0));
+
+			let imbalance = HubAssetImbalance::<Test>::get();
+			let lrna_issuance = Tokens::total_issuance(LRNA);
+			let pool_lrna = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+
+			// Act
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 200, 100, 10 * ONE, 0));
+
+			// Assert
+			let collected = Tokens::free_balance(LRNA, &COLLECTOR);
+			assert!(collected > 0);
+			assert_eq!(CollectedProtocolFees::<Test>::get(), collected);
+			assert_balance!(Omnipool::protocol_account(), LRNA, pool_lrna - collected);
+			assert_eq!(HubAssetImbalance::<Test>::get(), imbalance);
+			assert_eq!(Tokens::total_issuance(LRNA), lrna_issuance);
+		});
+}
@@ -0,0 +1,208 @@
+use super::*;
+use frame_support::traits::Hooks;
+use frame_support::weights::Weight;
+use pretty_assertions::assert_eq;
+
+const COLLECTOR: AccountId = 1_000;
+
+fn negative_imbalance_pool() -> ExtBuilder {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 200, 5000 * ONE))
+		.add_endowed_accounts((LP3, 200, 2000 * ONE))
+		.add_endowed_accounts((LP3, LRNA, 100 * ONE))
+		.with_registered_asset(200)
+		.with_protocol_fee(Permill::from_percent(20))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(200, FixedU128::from_float(0.65), LP3, 2000 * ONE)
+}
+
+fn create_negative_imbalance() -> SimpleImbalance<Balance> {
+	assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), LRNA, 200, 50 * ONE, 0));
+
+	let imbalance = HubAssetImbalance::<Test>::get();
+	assert!(imbalance.negative);
+	assert!(imbalance.value > 0);
+	imbalance
+}
+
+#[test]
+fn on_idle_should_burn_fraction_of_imbalance_when_collector_has_enough_hub_asset() {
+	negative_imbalance_pool()
+		.add_endowed_accounts((COLLECTOR, LRNA, 100 * ONE))
+		.with_protocol_fee_collector(COLLECTOR)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let imbalance = create_negative_imbalance();
+			CollectedProtocolFees::<Test>::put(100 * ONE);
+			let lrna_issuance = Tokens::total_issuance(LRNA);
+			let expected_amount = Permill::from_percent(10).mul_ceil(imbalance.value);
+
+			// Act
+			let weight = Omnipool::on_idle(1, Weight::MAX);
+
+			// Assert
+			assert_eq!(weight, <Test as Config>::WeightInfo::reduce_hub_asset_imbalance());
+			assert_eq!(
+				HubAssetImbalance::<Test>::get(),
+				SimpleImbalance {
+					value: imbalance.value - expected_amount,
+					negative: true,
+				}
+			);
+			assert_balance!(COLLECTOR, LRNA, 100 * ONE - expected_amount);
+			assert_eq!(CollectedProtocolFees::<Test>::get(), 100 * ONE - expected_amount);
+			assert_eq!(Tokens::total_issuance(LRNA), lrna_issuance - expected_amount);
+
+			frame_system::Pallet::<Test>::assert_last_event(
+				crate::Event::HubAssetImbalanceReduced {
+					amount: expected_amount,
+					imbalance: imbalance.value - expected_amount,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn on_idle_should_burn_only_collected_amount_when_collector_has_less_than_fraction_of_imbalance() {
+	negative_imbalance_pool()
+		.add_endowed_accounts((COLLECTOR, LRNA, 1_000))
+		.with_protocol_fee_collector(COLLECTOR)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let imbalance = create_negative_imbalance();
+			CollectedProtocolFees::<Test>::put(100 * ONE);
+
+			// Act
+			Omnipool::on_idle(1, Weight::MAX);
+
+			// Assert
+			assert_eq!(
+				HubAssetImbalance::<Test>::get(),
+				SimpleImbalance {
+					value: imbalance.value - 1_000,
+					negative: true,
+				}
+			);
+			assert_balance!(COLLECTOR, LRNA, 0);
+			assert_eq!(CollectedProtocolFees::<Test>::get(), 100 * ONE - 1_000);
+		});
+}
+
+#[test]
+fn on_idle_should_burn_only_collected_protocol_fees_when_collector_holds_more_hub_asset() {
+	negative_imbalance_pool()
+		.add_endowed_accounts((COLLECTOR, LRNA, 100 * ONE))
+		.with_protocol_fee_collector(COLLECTOR)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let imbalance = create_negative_imbalance();
+			CollectedProtocolFees::<Test>::put(1_000);
+
+			// Act
+			Omnipool::on_idle(1, Weight::MAX);
+
+			// Assert
+			assert_eq!(
+				HubAssetImbalance::<Test>::get(),
+				SimpleImbalance {
+					value: imbalance.value - 1_000,
+					negative: true,
+				}
+			);
+			assert_balance!(COLLECTOR, LRNA, 100 * ONE - 1_000);
+			assert_eq!(CollectedProtocolFees::<Test>::get(), 0);
+		});
+}
+
+#[test]
+fn on_idle_should_not_burn_collector_balance_when_no_protocol_fees_were_collected() {
+	negative_imbalance_pool()
+		.add_endowed_accounts((COLLECTOR, LRNA, 100 * ONE))
+		.with_protocol_fee_collector(COLLECTOR)
+		.build()
+		.execute_with(|| {
+			let imbalance = create_negative_imbalance();
+
+			Omnipool::on_idle(1, Weight::MAX);
+
+			assert_eq!(HubAssetImbalance::<Test>::get(), imbalance);
+			assert_balance!(COLLECTOR, LRNA, 100 * ONE);
+		});
+}
+
+#[test]
+fn on_idle_should_burn_protocol_fees_collected_from_trades() {
+	negative_imbalance_pool()
+		.with_protocol_fee_collector(COLLECTOR)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let imbalance = create_negative_imbalance();
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 200, DAI, 10 * ONE, 0));
+			let collected = CollectedProtocolFees::<Test>::get();
+			assert!(collected > 0);
+			assert_balance!(COLLECTOR, LRNA, collected);
+			let expected_amount = Permill::from_percent(10).mul_ceil(imbalance.value).min(collected);
+
+			// Act
+			Omnipool::on_idle(1, Weight::MAX);
+
+			// Assert
+			assert_eq!(
+				HubAssetImbalance::<Test>::get().value,
+				imbalance.value - expected_amount
+			);
+			assert_eq!(CollectedProtocolFees::<Test>::get(), collected - expected_amount);
+			assert_balance!(COLLECTOR, LRNA, collected - expected_amount);
+		});
+}
+
+#[test]
+fn on_idle_should_not_burn_when_collector_is_not_set() {
+	negative_imbalance_pool()
+		.add_endowed_accounts((COLLECTOR, LRNA, 100 * ONE))
+		.build()
+		.execute_with(|| {
+			let imbalance = create_negative_imbalance();
+
+			assert_eq!(Omnipool::on_idle(1, Weight::MAX), Weight::zero());
+
+			assert_eq!(HubAssetImbalance::<Test>::get(), imbalance);
+			assert_balance!(COLLECTOR, LRNA, 100 * ONE);
+		});
+}
+
+#[test]
+fn on_idle_should_not_burn_when_remaining_weight_is_not_sufficient() {
+	negative_imbalance_pool()
+		.add_endowed_accounts((COLLECTOR, LRNA, 100 * ONE))
+		.with_protocol_fee_collector(COLLECTOR)
+		.build()
+		.execute_with(|| {
+			let imbalance = create_negative_imbalance();
+
+			assert_eq!(Omnipool::on_idle(1, Weight::zero()), Weight::zero());
+
+			assert_eq!(HubAssetImbalance::<Test>::get(), imbalance);
+			assert_balance!(COLLECTOR, LRNA, 100 * ONE);
+		});
+}
+
+#[test]
+fn reduce_hub_asset_imbalance_should_not_burn_when_there_is_no_imbalance() {
+	negative_imbalance_pool()
+		.add_endowed_accounts((COLLECTOR, LRNA, 100 * ONE))
+		.with_protocol_fee_collector(COLLECTOR)
+		.build()
+		.execute_with(|| {
+			assert_eq!(HubAssetImbalance::<Test>::get().value, 0);
+
+			assert_eq!(Omnipool::reduce_hub_asset_imbalance(&COLLECTOR), Ok(0));
+
+			assert_balance!(COLLECTOR, LRNA, 100 * ONE);
+		});
+}
@@ -0,0 +1,119 @@
+use super::*;
+use frame_support::assert_noop;
+
+#[test]
+fn add_liquidity_with_limit_should_work_when_shares_are_above_limit() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 1_000, 5000 * ONE))
+		.add_endowed_accounts((LP2, 1_000, 2000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+
+			assert_ok!(Omnipool::add_liquidity_with_limit(
+				RuntimeOrigin::signed(LP1),
+				1_000,
+				400 * ONE,
+				400 * ONE
+			));
+
+			assert_balance!(LP1, 1_000, 4600 * ONE);
+
+			let position = Positions::<Test>::get(position_id).unwrap();
+			assert_eq!(position.shares, 400 * ONE);
+		});
+}
+
+#[test]
+fn add_liquidity_with_limit_should_fail_when_shares_are_below_limit() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 1_000, 5000 * ONE))
+		.add_endowed_accounts((LP2, 1_000, 2000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::add_liquidity_with_limit(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE, 400 * ONE + 1),
+				Error::<Test>::SlippageLimit
+			);
+		});
+}
+
+#[test]
+fn remove_liquidity_with_limit_should_work_when_received_amount_is_above_limit() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 1_000, 5000 * ONE))
+		.add_endowed_accounts((LP2, 1_000, 2000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE));
+
+			assert_ok!(Omnipool::remove_liquidity_with_limit(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				200 * ONE,
+				200 * ONE
+			));
+
+			assert_balance!(LP1, 1_000, 4800 * ONE);
+		});
+}
+
+#[test]
+fn remove_liquidity_with_limit_should_fail_when_received_amount_is_below_limit() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 1_000, 5000 * ONE))
+		.add_endowed_accounts((LP2, 1_000, 2000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE));
+
+			assert_noop!(
+				Omnipool::remove_liquidity_with_limit(
+					RuntimeOrigin::signed(LP1),
+					position_id,
+					200 * ONE,
+					200 * ONE + 1
+				),
+				Error::<Test>::SlippageLimit
+			);
+		});
+}
+
+#[test]
+fn remove_liquidity_with_limit_should_fail_when_withdrawal_fee_reduces_amount_below_limit() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 1_000, 5000 * ONE))
+		.add_endowed_accounts((LP2, 1_000, 2000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_min_withdrawal_fee(Permill::from_percent(1))
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE));
+
+			assert_noop!(
+				Omnipool::remove_liquidity_with_limit(RuntimeOrigin::signed(LP1), position_id, 200 * ONE, 200 * ONE),
+				Error::<Test>::SlippageLimit
+			);
+
+			assert_ok!(Omnipool::remove_liquidity_with_limit(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				200 * ONE,
+				198 * ONE
+			));
+
+			assert_balance!(LP1, 1_000, 4600 * ONE + 198 * ONE);
+		});
+}
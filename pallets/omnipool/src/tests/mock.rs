@@ -77,6 +77,7 @@ thread_local! {
 	pub static EXT_PRICE_ADJUSTMENT: RefCell<(u32,u32, bool)> = RefCell::new((0u32,0u32, false));
 	pub static WITHDRAWAL_FEE: RefCell<Permill> = RefCell::new(Permill::from_percent(0));
 	pub static WITHDRAWAL_ADJUSTMENT: RefCell<(u32,u32, bool)> = RefCell::new((0u32,0u32, false));
+	pub static PROTOCOL_FEE_COLLECTOR: RefCell<Option<AccountId>> = RefCell::new(None);
 }
 
 construct_runtime!(
@@ -168,6 +169,9 @@ parameter_types! {
 	pub MaxPriceDiff: Permill = MAX_PRICE_DIFF.with(|v| *v.borrow());
 	pub FourPercentDiff: Permill = Permill::from_percent(4);
 	pub MinWithdrawFee: Permill = WITHDRAWAL_FEE.with(|v| *v.borrow());
+	pub ProtocolFeeCollector: Option<AccountId> = PROTOCOL_FEE_COLLECTOR.with(|v| *v.borrow());
+	pub const ImbalanceBurnRatio: Permill = Permill::from_percent(10);
+	pub const MaxAddLiquidityAssets: u32 = 5;
 }
 
 impl Config for Test {
@@ -197,6 +201,10 @@ impl Config for Test {
 	type MinWithdrawalFee = MinWithdrawFee;
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
+	type ProtocolFeeCollector = ProtocolFeeCollector;
+	type FeeLedgerPeriod = ConstU64<10>;
+	type ImbalanceBurnRatio = ImbalanceBurnRatio;
+	type MaxAddLiquidityAssets = MaxAddLiquidityAssets;
 }
 
 pub struct ExtBuilder {
@@ -259,6 +267,9 @@ impl Default for ExtBuilder {
 		WITHDRAWAL_ADJUSTMENT.with(|v| {
 			*v.borrow_mut() = (0, 0, false);
 		});
+		PROTOCOL_FEE_COLLECTOR.with(|v| {
+			*v.borrow_mut() = None;
+		});
 
 		Self {
 			endowed_accounts: vec![
@@ -362,6 +373,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_protocol_fee_collector(self, collector: AccountId) -> Self {
+		PROTOCOL_FEE_COLLECTOR.with(|v| *v.borrow_mut() = Some(collector));
+		self
+	}
+
 	pub fn with_token(
 		mut self,
 		asset_id: AssetId,
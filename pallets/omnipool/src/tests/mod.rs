@@ -4,18 +4,25 @@ use frame_support::assert_ok;
 use sp_runtime::{FixedPointNumber, FixedU128};
 
 mod add_liquidity;
+mod add_liquidity_multiple;
 mod add_token;
 mod buy;
+mod fees;
 mod invariants;
+mod liquidity_with_limit;
 mod remove_liquidity;
 mod sell;
 
 mod barrier;
 mod imbalance;
+mod imbalance_burn;
 mod init_pool;
 pub(crate) mod mock;
 mod positions;
+mod quote;
 mod refund;
+mod remove_token;
+mod split_merge;
 mod tradability;
 mod tvl;
 mod types;
@@ -0,0 +1,211 @@
+use super::*;
+use frame_support::assert_noop;
+use hydradx_traits::pools::SpotPriceProvider;
+use pretty_assertions::assert_eq;
+use sp_runtime::Permill;
+
+#[test]
+fn sell_quote_should_match_executed_sell() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, 200, 2000 * ONE),
+			(LP1, 100, 1000 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_asset_fee(Permill::from_percent(10))
+		.with_protocol_fee(Permill::from_percent(20))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP3, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let sell_amount = 50 * ONE;
+
+			let quote = Omnipool::calculate_sell_quote(100, 200, sell_amount).unwrap();
+
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 100, 200, sell_amount, 0));
+
+			frame_system::Pallet::<Test>::assert_last_event(
+				crate::Event::SellExecuted {
+					who: LP1,
+					asset_in: 100,
+					asset_out: 200,
+					amount_in: quote.amount_in,
+					amount_out: quote.amount_out,
+					asset_fee_amount: quote.asset_fee,
+					protocol_fee_amount: quote.protocol_fee,
+				}
+				.into(),
+			);
+
+			assert_eq!(quote.amount_in, sell_amount);
+			assert_eq!(Tokens::free_balance(200, &LP1), quote.amount_out);
+			assert_eq!(
+				quote.spot_price,
+				<Omnipool as SpotPriceProvider<AssetId>>::spot_price(100, 200).unwrap()
+			);
+		});
+}
+
+#[test]
+fn buy_quote_should_match_executed_buy() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, 200, 2000 * ONE),
+			(LP1, 100, 1000 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_asset_fee(Permill::from_percent(10))
+		.with_protocol_fee(Permill::from_percent(20))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP3, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let buy_amount = 50 * ONE;
+
+			let quote = Omnipool::calculate_buy_quote(100, 200, buy_amount).unwrap();
+
+			assert_ok!(Omnipool::buy(
+				RuntimeOrigin::signed(LP1),
+				200,
+				100,
+				buy_amount,
+				Balance::MAX
+			));
+
+			frame_system::Pallet::<Test>::assert_last_event(
+				crate::Event::BuyExecuted {
+					who: LP1,
+					asset_in: 100,
+					asset_out: 200,
+					amount_in: quote.amount_in,
+					amount_out: quote.amount_out,
+					asset_fee_amount: quote.asset_fee,
+					protocol_fee_amount: quote.protocol_fee,
+				}
+				.into(),
+			);
+
+			assert_eq!(quote.amount_out, buy_amount);
+			assert_eq!(Tokens::free_balance(100, &LP1), 1000 * ONE - quote.amount_in);
+			assert_eq!(
+				quote.spot_price,
+				<Omnipool as SpotPriceProvider<AssetId>>::spot_price(100, 200).unwrap()
+			);
+		});
+}
+
+#[test]
+fn sell_quote_for_hub_asset_should_match_executed_sell() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP1, LRNA, 1000 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_asset_fee(Permill::from_percent(10))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			let sell_amount = 50 * ONE;
+
+			let quote = Omnipool::calculate_sell_quote(LRNA, 100, sell_amount).unwrap();
+
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), LRNA, 100, sell_amount, 0));
+
+			assert_eq!(quote.amount_in, sell_amount);
+			assert_eq!(Tokens::free_balance(100, &LP1), quote.amount_out);
+			assert_eq!(
+				quote.spot_price,
+				<Omnipool as SpotPriceProvider<AssetId>>::spot_price(LRNA, 100).unwrap()
+			);
+		});
+}
+
+#[test]
+fn quote_should_not_change_pool_state() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, 200, 2000 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP3, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let asset_in_state = Omnipool::load_asset_state(100).unwrap();
+			let asset_out_state = Omnipool::load_asset_state(200).unwrap();
+
+			assert!(Omnipool::calculate_sell_quote(100, 200, 50 * ONE).is_ok());
+			assert!(Omnipool::calculate_buy_quote(100, 200, 50 * ONE).is_ok());
+
+			assert_eq!(Omnipool::load_asset_state(100).unwrap(), asset_in_state);
+			assert_eq!(Omnipool::load_asset_state(200).unwrap(), asset_out_state);
+		});
+}
+
+#[test]
+fn quote_should_fail_when_trade_is_not_allowed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, 200, 2000 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP3, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::calculate_sell_quote(100, 100, 50 * ONE),
+				Error::<Test>::SameAssetTradeNotAllowed
+			);
+			assert_noop!(
+				Omnipool::calculate_sell_quote(100, LRNA, 50 * ONE),
+				Error::<Test>::NotAllowed
+			);
+			assert_noop!(
+				Omnipool::calculate_buy_quote(100, 300, 50 * ONE),
+				Error::<Test>::AssetNotFound
+			);
+
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				200,
+				Tradability::SELL
+			));
+
+			assert_noop!(
+				Omnipool::calculate_sell_quote(100, 200, 50 * ONE),
+				Error::<Test>::NotAllowed
+			);
+			assert_noop!(
+				Omnipool::calculate_buy_quote(100, 200, 50 * ONE),
+				Error::<Test>::NotAllowed
+			);
+		});
+}
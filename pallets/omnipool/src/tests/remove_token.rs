@@ -0,0 +1,162 @@
+use super::*;
+use frame_support::assert_noop;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn sacrifice_all_positions_of(asset_id: AssetId) {
+	let positions: Vec<(u32, u64)> = Positions::<Test>::iter()
+		.filter(|(_, position)| position.asset_id == asset_id)
+		.map(|(position_id, _)| {
+			let owner = POSITIONS.with(|v| v.borrow().get(&position_id).copied()).unwrap();
+			(position_id, owner)
+		})
+		.collect();
+
+	for (position_id, owner) in positions {
+		assert_ok!(Omnipool::sacrifice_position(RuntimeOrigin::signed(owner), position_id));
+	}
+}
+
+#[test]
+fn remove_token_should_work_when_asset_is_frozen_and_all_shares_are_protocol_owned() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			sacrifice_all_positions_of(asset_id);
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				asset_id,
+				Tradability::FROZEN
+			));
+
+			let asset_state = Omnipool::load_asset_state(asset_id).unwrap();
+			let hub_liquidity = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+			let imbalance = HubAssetImbalance::<Test>::get();
+			let expected_delta_imbalance = hydra_dx_math::omnipool::calculate_delta_imbalance(
+				asset_state.hub_reserve,
+				hydra_dx_math::omnipool::types::I129 {
+					value: imbalance.value,
+					negative: imbalance.negative,
+				},
+				hub_liquidity,
+			)
+			.unwrap();
+
+			let lp3_asset_balance = Tokens::free_balance(asset_id, &LP3);
+
+			// Act
+			assert_ok!(Omnipool::remove_token(RuntimeOrigin::root(), asset_id, LP3));
+
+			// Assert
+			assert_eq!(Assets::<Test>::get(asset_id), None);
+			assert_balance!(LP3, asset_id, lp3_asset_balance + 2400 * ONE);
+			assert_balance!(Omnipool::protocol_account(), asset_id, 0);
+			assert_balance!(
+				Omnipool::protocol_account(),
+				LRNA,
+				hub_liquidity - asset_state.hub_reserve
+			);
+			assert_eq!(
+				HubAssetImbalance::<Test>::get(),
+				SimpleImbalance {
+					value: imbalance.value - expected_delta_imbalance,
+					negative: true,
+				}
+			);
+			frame_system::Pallet::<Test>::assert_last_event(
+				crate::Event::TokenRemoved {
+					asset_id,
+					amount: 2400 * ONE,
+					hub_withdrawn: asset_state.hub_reserve,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn remove_token_should_fail_when_asset_is_not_frozen() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			sacrifice_all_positions_of(asset_id);
+
+			assert_noop!(
+				Omnipool::remove_token(RuntimeOrigin::root(), asset_id, LP3),
+				Error::<Test>::AssetNotFrozen
+			);
+		});
+}
+
+#[test]
+fn remove_token_should_fail_when_lp_shares_remain() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				asset_id,
+				Tradability::FROZEN
+			));
+
+			assert_noop!(
+				Omnipool::remove_token(RuntimeOrigin::root(), asset_id, LP3),
+				Error::<Test>::SharesRemaining
+			);
+		});
+}
+
+#[test]
+fn remove_token_should_fail_when_asset_is_not_in_pool() {
+	ExtBuilder::default()
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::remove_token(RuntimeOrigin::root(), 1_000, LP3),
+				Error::<Test>::AssetNotFound
+			);
+		});
+}
+
+#[test]
+fn remove_token_should_fail_when_origin_is_not_authority() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			sacrifice_all_positions_of(asset_id);
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				asset_id,
+				Tradability::FROZEN
+			));
+
+			assert_noop!(
+				Omnipool::remove_token(RuntimeOrigin::signed(LP1), asset_id, LP3),
+				BadOrigin
+			);
+		});
+}
@@ -0,0 +1,290 @@
+use super::*;
+use frame_support::assert_noop;
+use pretty_assertions::assert_eq;
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
+use sp_runtime::Rounding;
+
+const ASSET: AssetId = 1_000;
+
+fn hub_value(position: &Position<Balance, AssetId>) -> Balance {
+	multiply_by_rational_with_rounding(position.amount, position.price.0, position.price.1, Rounding::Down).unwrap()
+}
+
+#[test]
+fn split_position_should_work_when_shares_are_less_than_position_shares() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET, 400 * ONE));
+			let new_position_id = <NextPositionId<Test>>::get();
+
+			// Act
+			assert_ok!(Omnipool::split_position(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				100 * ONE
+			));
+
+			// Assert
+			assert_eq!(
+				Positions::<Test>::get(position_id).unwrap(),
+				Position {
+					asset_id: ASSET,
+					amount: 300 * ONE,
+					shares: 300 * ONE,
+					price: (1560 * ONE, 2400 * ONE),
+				}
+			);
+			assert_eq!(
+				Positions::<Test>::get(new_position_id).unwrap(),
+				Position {
+					asset_id: ASSET,
+					amount: 100 * ONE,
+					shares: 100 * ONE,
+					price: (1560 * ONE, 2400 * ONE),
+				}
+			);
+			assert_eq!(POSITIONS.with(|v| v.borrow().get(&new_position_id).copied()), Some(LP1));
+
+			frame_system::Pallet::<Test>::assert_last_event(
+				crate::Event::PositionCreated {
+					position_id: new_position_id,
+					owner: LP1,
+					asset: ASSET,
+					amount: 100 * ONE,
+					shares: 100 * ONE,
+					price: FixedU128::from_float(0.65),
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn split_position_should_fail_when_shares_amount_is_invalid() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET, 400 * ONE));
+
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 0),
+				Error::<Test>::InvalidSharesAmount
+			);
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 400 * ONE),
+				Error::<Test>::InvalidSharesAmount
+			);
+		});
+}
+
+#[test]
+fn split_position_should_fail_when_caller_is_not_position_owner() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET, 400 * ONE));
+
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP2), position_id, 100 * ONE),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_work_when_positions_have_same_price() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET, 400 * ONE));
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET, 400 * ONE));
+
+			// Act
+			assert_ok!(Omnipool::merge_positions(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				other_position_id
+			));
+
+			// Assert
+			assert_eq!(
+				Positions::<Test>::get(position_id).unwrap(),
+				Position {
+					asset_id: ASSET,
+					amount: 800 * ONE,
+					shares: 800 * ONE,
+					price: (520 * ONE, 800 * ONE),
+				}
+			);
+			assert_eq!(Positions::<Test>::get(other_position_id), None);
+			assert_eq!(POSITIONS.with(|v| v.borrow().get(&other_position_id).copied()), None);
+
+			frame_system::Pallet::<Test>::assert_last_event(
+				crate::Event::PositionUpdated {
+					position_id,
+					owner: LP1,
+					asset: ASSET,
+					amount: 800 * ONE,
+					shares: 800 * ONE,
+					price: FixedU128::from_float(0.65),
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_use_weighted_price_when_positions_have_different_prices() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET, 5000 * ONE))
+		.add_endowed_accounts((LP3, ASSET, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET, 400 * ONE));
+
+			// price of asset goes down
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), ASSET, DAI, 100 * ONE, 0));
+
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET, 400 * ONE));
+
+			let position = Positions::<Test>::get(position_id).unwrap();
+			let other_position = Positions::<Test>::get(other_position_id).unwrap();
+
+			// Act
+			assert_ok!(Omnipool::merge_positions(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				other_position_id
+			));
+
+			// Assert
+			assert_eq!(
+				Positions::<Test>::get(position_id).unwrap(),
+				Position {
+					asset_id: ASSET,
+					amount: 800 * ONE,
+					shares: position.shares + other_position.shares,
+					price: (hub_value(&position) + hub_value(&other_position), 800 * ONE),
+				}
+			);
+			assert_eq!(Positions::<Test>::get(other_position_id), None);
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_prices_are_on_different_sides_of_spot_price() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET, 5000 * ONE))
+		.add_endowed_accounts((LP3, ASSET, 5000 * ONE))
+		.add_endowed_accounts((LP3, DAI, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET, 400 * ONE));
+
+			// price of asset goes down
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), ASSET, DAI, 100 * ONE, 0));
+
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET, 400 * ONE));
+
+			// price of asset goes partially back up
+			assert_ok!(Omnipool::buy(
+				RuntimeOrigin::signed(LP3),
+				ASSET,
+				DAI,
+				50 * ONE,
+				5000 * ONE
+			));
+
+			// Act & Assert
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, other_position_id),
+				Error::<Test>::UnfairPositionMerge
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_positions_are_of_different_assets() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET, 5000 * ONE))
+		.add_endowed_accounts((LP1, 2_000, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET, 5000 * ONE))
+		.add_endowed_accounts((LP2, 2_000, 5000 * ONE))
+		.with_registered_asset(2_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(2_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET, 400 * ONE));
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 2_000, 400 * ONE));
+
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, other_position_id),
+				Error::<Test>::InvalidPositionMerge
+			);
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, position_id),
+				Error::<Test>::InvalidPositionMerge
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_caller_does_not_own_both_positions() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET, 400 * ONE));
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP2), ASSET, 400 * ONE));
+
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, other_position_id),
+				Error::<Test>::Forbidden
+			);
+		});
+}
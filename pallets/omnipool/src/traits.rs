@@ -51,8 +51,11 @@ where
 
 	fn on_hub_asset_trade(origin: Origin, asset: AssetInfo<AssetId, Balance>) -> Result<Weight, Self::Error>;
 
+	fn on_asset_removed(asset_id: AssetId) -> Result<Weight, Self::Error>;
+
 	fn on_liquidity_changed_weight() -> Weight;
 	fn on_trade_weight() -> Weight;
+	fn on_asset_removed_weight() -> Weight;
 }
 
 impl<Origin, AssetId, Balance> OmnipoolHooks<Origin, AssetId, Balance> for ()
@@ -77,6 +80,10 @@ where
 		Ok(Weight::zero())
 	}
 
+	fn on_asset_removed(_: AssetId) -> Result<Weight, Self::Error> {
+		Ok(Weight::zero())
+	}
+
 	fn on_liquidity_changed_weight() -> Weight {
 		Weight::zero()
 	}
@@ -84,6 +91,10 @@ where
 	fn on_trade_weight() -> Weight {
 		Weight::zero()
 	}
+
+	fn on_asset_removed_weight() -> Weight {
+		Weight::zero()
+	}
 }
 
 pub trait ExternalPriceProvider<AssetId, Price> {
@@ -93,9 +104,22 @@ pub trait ExternalPriceProvider<AssetId, Price> {
 	fn get_price_weight() -> Weight;
 }
 
-#[allow(clippy::result_unit_err)]
+/// Reason of a price check failure.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PriceBarrierError {
+	/// Current price differs too much from the external price.
+	PriceDifferenceTooHigh,
+	/// External price could not be retrieved.
+	ExternalPriceNotAvailable(DispatchError),
+}
+
 pub trait ShouldAllow<AccountId, AssetId, Price> {
-	fn ensure_price(who: &AccountId, asset_a: AssetId, asset_b: AssetId, current_price: Price) -> Result<(), ()>;
+	fn ensure_price(
+		who: &AccountId,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		current_price: Price,
+	) -> Result<(), PriceBarrierError>;
 }
 
 #[impl_trait_for_tuples::impl_for_tuples(5)]
@@ -105,20 +129,26 @@ where
 	AssetId: Debug + Copy,
 	Price: Debug + Copy,
 {
-	fn ensure_price(who: &AccountId, asset_a: AssetId, asset_b: AssetId, current_price: Price) -> Result<(), ()> {
+	fn ensure_price(
+		who: &AccountId,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		current_price: Price,
+	) -> Result<(), PriceBarrierError> {
 		for_tuples!( #(
 			match Tuple::ensure_price(who, asset_a, asset_b, current_price) {
 				Ok(()) => (),
-				Err(_) => {
+				Err(e) => {
 					log::trace!(
 						target: "omnipool::should_allow_price_change",
-						"did not pass the price check: who: {:?}, asset_a: {:?}, asset_b: {:?}, current_prie: {:?}",
+						"did not pass the price check: who: {:?}, asset_a: {:?}, asset_b: {:?}, current_prie: {:?}, error: {:?}",
 						who,
 						asset_a,
 						asset_b,
 						current_price,
+						e,
 					);
-					return Err(());
+					return Err(e);
 				},
 			}
 		)* );
@@ -135,23 +165,36 @@ impl<AccountId, AssetId, ExternalOracle, MaxAllowed, WhitelistedAccounts> Should
 	for EnsurePriceWithin<AccountId, AssetId, ExternalOracle, MaxAllowed, WhitelistedAccounts>
 where
 	ExternalOracle: ExternalPriceProvider<AssetId, EmaPrice>,
+	ExternalOracle::Error: Into<DispatchError>,
 	MaxAllowed: Get<Permill>,
 	WhitelistedAccounts: Contains<AccountId>,
 {
-	fn ensure_price(who: &AccountId, asset_a: AssetId, asset_b: AssetId, current_price: EmaPrice) -> Result<(), ()> {
+	fn ensure_price(
+		who: &AccountId,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		current_price: EmaPrice,
+	) -> Result<(), PriceBarrierError> {
 		if WhitelistedAccounts::contains(who) {
 			return Ok(());
 		}
 
 		let max_allowed = FixedU128::from(MaxAllowed::get());
 
-		let oracle_price = ExternalOracle::get_price(asset_a, asset_b).map_err(|_| ())?;
-		let external_price = FixedU128::checked_from_rational(oracle_price.n, oracle_price.d).ok_or(())?;
-		let current_spot_price = FixedU128::checked_from_rational(current_price.n, current_price.d).ok_or(())?;
+		let oracle_price = ExternalOracle::get_price(asset_a, asset_b)
+			.map_err(|e| PriceBarrierError::ExternalPriceNotAvailable(e.into()))?;
+		let external_price = FixedU128::checked_from_rational(oracle_price.n, oracle_price.d)
+			.ok_or(PriceBarrierError::PriceDifferenceTooHigh)?;
+		let current_spot_price = FixedU128::checked_from_rational(current_price.n, current_price.d)
+			.ok_or(PriceBarrierError::PriceDifferenceTooHigh)?;
 
 		let max_allowed_difference = max_allowed
-			.checked_mul(&current_spot_price.checked_add(&external_price).ok_or(())?)
-			.ok_or(())?;
+			.checked_mul(
+				&current_spot_price
+					.checked_add(&external_price)
+					.ok_or(PriceBarrierError::PriceDifferenceTooHigh)?,
+			)
+			.ok_or(PriceBarrierError::PriceDifferenceTooHigh)?;
 
 		let diff = if current_spot_price >= external_price {
 			current_spot_price.saturating_sub(external_price)
@@ -160,8 +203,10 @@ where
 		};
 
 		ensure!(
-			diff.checked_mul(&FixedU128::from(2)).ok_or(())? <= max_allowed_difference,
-			()
+			diff.checked_mul(&FixedU128::from(2))
+				.ok_or(PriceBarrierError::PriceDifferenceTooHigh)?
+				<= max_allowed_difference,
+			PriceBarrierError::PriceDifferenceTooHigh
 		);
 		Ok(())
 	}
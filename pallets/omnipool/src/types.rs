@@ -5,6 +5,9 @@ use hydra_dx_math::omnipool::types::{AssetReserveState as MathReserveState, Asse
 use sp_runtime::{FixedPointNumber, FixedU128};
 use sp_std::ops::{Add, Sub};
 
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
 /// Balance type used in Omnipool
 pub type Balance = u128;
 
@@ -311,3 +314,56 @@ where
 		})
 	}
 }
+
+/// Result of a hypothetical trade calculated against the current Omnipool state.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct TradeQuote<Balance> {
+	/// Amount of asset in which would be sold to the pool
+	pub amount_in: Balance,
+	/// Amount of asset out which would be received from the pool
+	pub amount_out: Balance,
+	/// Asset fee amount charged in asset out
+	pub asset_fee: Balance,
+	/// Protocol fee amount charged in hub asset
+	pub protocol_fee: Balance,
+	/// Spot price of asset out denominated in asset in after the trade
+	pub spot_price: Price,
+}
+
+/// Trade fees accumulated for an asset.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct FeeAccumulator<Balance> {
+	/// Accumulated asset fee amount
+	pub asset_fee: Balance,
+	/// Accumulated protocol fee amount in hub asset.
+	///
+	/// This is not the amount burned or received by `ProtocolFeeCollector`. Only the part of the protocol fee
+	/// up to current hub asset imbalance is burned ( or collected ), the rest is transferred to HDX subpool.
+	pub protocol_fee: Balance,
+}
+
+impl<Balance: CheckedAdd + Copy> FeeAccumulator<Balance> {
+	/// Add given fee amounts to the accumulated amounts.
+	pub(crate) fn accumulate(&self, asset_fee: Balance, protocol_fee: Balance) -> Option<Self> {
+		Some(Self {
+			asset_fee: self.asset_fee.checked_add(&asset_fee)?,
+			protocol_fee: self.protocol_fee.checked_add(&protocol_fee)?,
+		})
+	}
+}
+
+/// Fee ledger entry of an asset - fees accumulated in current and previous period.
+///
+/// Fees are recorded for the asset whose fee configuration was applied in the trade, in same amounts as reported in `SellExecuted` and `BuyExecuted` events.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct FeeLedgerEntry<Balance, BlockNumber> {
+	/// Index of the period of `current` fees
+	pub period: BlockNumber,
+	/// Fees accumulated in the period
+	pub current: FeeAccumulator<Balance>,
+	/// Fees accumulated in the period preceding `period`
+	pub previous: FeeAccumulator<Balance>,
+}
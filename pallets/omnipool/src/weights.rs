@@ -59,6 +59,11 @@ pub trait WeightInfo {
 	fn refund_refused_asset() -> Weight;
 	fn sacrifice_position() -> Weight;
 	fn set_asset_weight_cap() -> Weight;
+	fn remove_token() -> Weight;
+	fn split_position() -> Weight;
+	fn merge_positions() -> Weight;
+	fn reduce_hub_asset_imbalance() -> Weight;
+	fn add_liquidity_multiple(n: u32) -> Weight;
 }
 
 /// Weights for pallet_omnipool using the hydraDX node and recommended hardware.
@@ -87,13 +92,13 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	}
 	fn sell() -> Weight {
 		Weight::from_ref_time(121_641_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(14 as u64))
-			.saturating_add(T::DbWeight::get().writes(10 as u64))
+			.saturating_add(T::DbWeight::get().reads(16 as u64))
+			.saturating_add(T::DbWeight::get().writes(12 as u64))
 	}
 	fn buy() -> Weight {
 		Weight::from_ref_time(121_521_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(14 as u64))
-			.saturating_add(T::DbWeight::get().writes(10 as u64))
+			.saturating_add(T::DbWeight::get().reads(16 as u64))
+			.saturating_add(T::DbWeight::get().writes(12 as u64))
 	}
 	fn set_asset_tradable_state() -> Weight {
 		Weight::from_ref_time(21_030_000 as u64)
@@ -115,6 +120,27 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	fn remove_token() -> Weight {
+		Self::remove_liquidity()
+	}
+	// Not benchmarked yet - bounded by `add_liquidity`, which also reads and updates the position storage and
+	// mints a new position NFT.
+	fn split_position() -> Weight {
+		Self::add_liquidity()
+	}
+	// Not benchmarked yet - bounded by `remove_liquidity`, which also loads the asset state and burns a position
+	// NFT. One more read accounts for the second position.
+	fn merge_positions() -> Weight {
+		Self::remove_liquidity().saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
+	// Not benchmarked yet - bounded by `sell`, which also updates the hub asset imbalance and token balances.
+	fn reduce_hub_asset_imbalance() -> Weight {
+		Self::sell()
+	}
+	// Not benchmarked yet - bounded by `add_liquidity` for each asset.
+	fn add_liquidity_multiple(n: u32) -> Weight {
+		Self::add_liquidity().saturating_mul(n as u64)
+	}
 }
 
 // For backwards compatibility and tests
@@ -141,13 +167,13 @@ impl WeightInfo for () {
 	}
 	fn sell() -> Weight {
 		Weight::from_ref_time(121_641_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(14 as u64))
-			.saturating_add(RocksDbWeight::get().writes(10 as u64))
+			.saturating_add(RocksDbWeight::get().reads(16 as u64))
+			.saturating_add(RocksDbWeight::get().writes(12 as u64))
 	}
 	fn buy() -> Weight {
 		Weight::from_ref_time(121_521_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(14 as u64))
-			.saturating_add(RocksDbWeight::get().writes(10 as u64))
+			.saturating_add(RocksDbWeight::get().reads(16 as u64))
+			.saturating_add(RocksDbWeight::get().writes(12 as u64))
 	}
 	fn set_asset_tradable_state() -> Weight {
 		Weight::from_ref_time(21_030_000 as u64)
@@ -169,4 +195,25 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	fn remove_token() -> Weight {
+		Self::remove_liquidity()
+	}
+	// Not benchmarked yet - bounded by `add_liquidity`, which also reads and updates the position storage and
+	// mints a new position NFT.
+	fn split_position() -> Weight {
+		Self::add_liquidity()
+	}
+	// Not benchmarked yet - bounded by `remove_liquidity`, which also loads the asset state and burns a position
+	// NFT. One more read accounts for the second position.
+	fn merge_positions() -> Weight {
+		Self::remove_liquidity().saturating_add(RocksDbWeight::get().reads(1 as u64))
+	}
+	// Not benchmarked yet - bounded by `sell`, which also updates the hub asset imbalance and token balances.
+	fn reduce_hub_asset_imbalance() -> Weight {
+		Self::sell()
+	}
+	// Not benchmarked yet - bounded by `add_liquidity` for each asset.
+	fn add_liquidity_multiple(n: u32) -> Weight {
+		Self::add_liquidity().saturating_mul(n as u64)
+	}
 }
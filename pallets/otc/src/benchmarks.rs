@@ -33,7 +33,9 @@ benchmarks! {
 		let (hdx, dai) = seed_registry::<T>()?;
 
 		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(hdx, dai))?;
-  }:  _(RawOrigin::Signed(owner.clone()), dai.into(), hdx.into(), 20 * ONE, 100 * ONE, true)
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		let takers = allowed_takers::<T>(account("taker", 2, 2));
+  }:  _(RawOrigin::Signed(owner.clone()), dai.into(), hdx.into(), 20 * ONE, 100 * ONE, true, Some(expires_at), Some(ONE), Some(takers))
 	verify {
 		assert_eq!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, hdx.into(), &owner), 100 * ONE);
 	}
@@ -45,7 +47,7 @@ benchmarks! {
 		let filler: T::AccountId = create_account_with_balances::<T>("filler", 2, vec!(hdx, dai))?;
 
 		assert_ok!(
-			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), hdx.into(), 20 * ONE, 100 * ONE, true)
+			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), hdx.into(), 20 * ONE, 100 * ONE, true, None, None, Some(allowed_takers::<T>(filler.clone())))
 		);
   }:  _(RawOrigin::Signed(filler.clone()), 0u32, 10 * ONE)
 	verify {
@@ -58,8 +60,9 @@ benchmarks! {
 		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(hdx, dai))?;
 		let filler: T::AccountId = create_account_with_balances::<T>("filler", 2, vec!(hdx, dai))?;
 
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
 		assert_ok!(
-			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), hdx.into(), 20 * ONE, 100 * ONE, true)
+			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), hdx.into(), 20 * ONE, 100 * ONE, true, Some(expires_at), None, Some(allowed_takers::<T>(filler.clone())))
 		);
   }:  _(RawOrigin::Signed(filler.clone()), 0u32)
 	verify {
@@ -70,13 +73,53 @@ benchmarks! {
 		let (hdx, dai) = seed_registry::<T>()?;
 
 		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(hdx, dai))?;
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
 		assert_ok!(
-			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), hdx.into(), 20 * ONE, 100 * ONE, true)
+			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), hdx.into(), 20 * ONE, 100 * ONE, true, Some(expires_at), None, Some(allowed_takers::<T>(owner.clone())))
 		);
   }:  _(RawOrigin::Signed(owner.clone()), 0u32)
 	verify {
 		assert_eq!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, hdx.into(), &owner), 0);
 	}
+
+	expire_order {
+		let (hdx, dai) = seed_registry::<T>()?;
+
+		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(hdx, dai))?;
+		let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		assert_ok!(
+			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), hdx.into(), 20 * ONE, 100 * ONE, true, Some(expires_at), None, Some(allowed_takers::<T>(owner.clone())))
+		);
+
+		frame_system::Pallet::<T>::set_block_number(expires_at);
+  }: {
+		crate::Pallet::<T>::expire_order(expires_at, 0u32);
+  }
+	verify {
+		assert!(crate::Pallet::<T>::orders(0u32).is_none());
+		assert_eq!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, hdx.into(), &owner), 0);
+	}
+
+	update_order {
+		let (hdx, dai) = seed_registry::<T>()?;
+
+		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(hdx, dai))?;
+		assert_ok!(
+			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), hdx.into(), 20 * ONE, 100 * ONE, true, None, None, None)
+		);
+  }:  _(RawOrigin::Signed(owner.clone()), 0u32, 30 * ONE, 150 * ONE)
+	verify {
+		assert_eq!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, hdx.into(), &owner), 150 * ONE);
+	}
+}
+
+/// Worst case list of allowed takers - the taker is the last one of `MaxAllowedTakers` accounts.
+fn allowed_takers<T: Config>(taker: T::AccountId) -> BoundedVec<T::AccountId, T::MaxAllowedTakers> {
+	let mut takers: Vec<T::AccountId> = (1..T::MaxAllowedTakers::get())
+		.map(|i| account("allowed_taker", i, i))
+		.collect();
+	takers.push(taker);
+	takers.try_into().expect("number of takers is MaxAllowedTakers")
 }
 
 fn seed_registry<T: Config>() -> Result<(u32, u32), DispatchError>
@@ -25,28 +25,45 @@
 // This is validated at `place_order` but also at `partial_fill_order` - meaning that a user cannot leave dust amounts
 // below the defined threshold after filling an order (instead they should fill the order completely).
 //
+// An order can optionally expire at given block (`expires_at`). Expired order cannot be filled anymore. Expired orders
+// are removed in `on_idle` and reserved amount is returned to the owner.
+//
+// Partially fillable order can optionally specify `min_fill_amount` - the minimum amount of asset_in which has to be
+// provided by a partial fill.
+//
+// An order can optionally be restricted to a whitelist of distinct takers (`allowed_takers`), bounded by
+// `MaxAllowedTakers`. Such order can be filled only by accounts from the whitelist.
+//
+// An order can be filled with an asset other than `asset_in` by `fill_order_via_route`, which buys `asset_in`
+// through the `Router` first and fills the order in the same transaction.
+//
 // ## Dispatachable functions
 // * `place_order` -  create a new OTC order.
 // * `partial_fill_order` - fill an OTC order (partially).
 // * `fill_order` - fill an OTC order (completely).
 // * `cancel_order` - cancel an open OTC order.
+// * `fill_order_via_route` - fill an OTC order (completely) by swapping another asset through the router.
+// * `update_order` - update amounts of an open OTC order.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::MaxEncodedLen;
-use frame_support::{pallet_prelude::*, require_transactional};
+use frame_support::{pallet_prelude::*, require_transactional, transactional};
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+use hydradx_traits::router::{RouterT, Trade};
 use hydradx_traits::Registry;
 use orml_traits::{GetByKey, MultiCurrency, NamedMultiReservableCurrency};
 use sp_core::U256;
 use sp_runtime::{
-	traits::{One, Zero},
+	traits::{One, Saturating, Zero},
 	DispatchError,
 };
-use sp_std::vec::Vec;
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
 #[cfg(test)]
 mod tests;
 
+pub mod migration;
+
 #[cfg(any(feature = "runtime-benchmarks", test))]
 mod benchmarks;
 
@@ -64,13 +81,17 @@ pub type NamedReserveIdentifier = [u8; 8];
 pub const NAMED_RESERVE_ID: NamedReserveIdentifier = *b"otcorder";
 
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
-pub struct Order<AccountId, AssetId> {
+pub struct Order<AccountId, AssetId, BlockNumber> {
 	pub owner: AccountId,
 	pub asset_in: AssetId,
 	pub asset_out: AssetId,
 	pub amount_in: Balance,
 	pub amount_out: Balance,
 	pub partially_fillable: bool,
+	/// Block at which the order expires. Expired order cannot be filled.
+	pub expires_at: Option<BlockNumber>,
+	/// Minimum amount of asset_in which has to be provided by a partial fill.
+	pub min_fill_amount: Option<Balance>,
 }
 
 #[frame_support::pallet]
@@ -78,8 +99,11 @@ pub mod pallet {
 	use super::*;
 	use codec::HasCompact;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(crate) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -105,6 +129,17 @@ pub mod pallet {
 		#[pallet::constant]
 		type ExistentialDepositMultiplier: Get<u8>;
 
+		/// Maximum number of expired orders removed in `on_idle` in a single block.
+		#[pallet::constant]
+		type MaxExpiredOrdersPerBlock: Get<u32>;
+
+		/// Maximum number of accounts which can be allowed to fill a private order.
+		#[pallet::constant]
+		type MaxAllowedTakers: Get<u32>;
+
+		/// Router used to buy asset_in of an order filled by `fill_order_via_route`.
+		type Router: RouterT<Self::RuntimeOrigin, Self::AssetId, Balance>;
+
 		/// Weight information for the extrinsics.
 		type WeightInfo: WeightInfo;
 	}
@@ -114,6 +149,8 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// An Order has been cancelled
 		Cancelled { order_id: OrderId },
+		/// An Order has expired and has been removed
+		Expired { order_id: OrderId },
 		/// An Order has been completely filled
 		Filled {
 			order_id: OrderId,
@@ -136,6 +173,15 @@ pub mod pallet {
 			amount_in: Balance,
 			amount_out: Balance,
 			partially_fillable: bool,
+			expires_at: Option<T::BlockNumber>,
+			min_fill_amount: Option<Balance>,
+			allowed_takers: Option<Vec<T::AccountId>>,
+		},
+		/// An Order has been updated
+		Updated {
+			order_id: OrderId,
+			amount_in: Balance,
+			amount_out: Balance,
 		},
 	}
 
@@ -159,6 +205,20 @@ pub mod pallet {
 		Forbidden,
 		/// Reserved amount not sufficient.
 		InsufficientReservedAmount,
+		/// Order has expired
+		OrderExpired,
+		/// Expiry block must be in the future
+		InvalidExpiry,
+		/// Min fill amount can be set only for partially fillable order and must not exceed amount_in
+		InvalidMinFillAmount,
+		/// Fill amount is lower than min fill amount of the order
+		FillAmountTooSmall,
+		/// List of allowed takers is empty
+		InvalidAllowedTakers,
+		/// List of allowed takers contains the same account more than once
+		DuplicateAllowedTakers,
+		/// Asset sold through the route must be different from asset_in of the order
+		InvalidRouteAsset,
 	}
 
 	/// ID sequencer for Orders
@@ -168,7 +228,24 @@ pub mod pallet {
 
 	#[pallet::storage]
 	#[pallet::getter(fn orders)]
-	pub type Orders<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, Order<T::AccountId, T::AssetId>, OptionQuery>;
+	pub type Orders<T: Config> =
+		StorageMap<_, Blake2_128Concat, OrderId, Order<T::AccountId, T::AssetId, T::BlockNumber>, OptionQuery>;
+
+	/// Orders with expiry, keyed by the block at which they expire
+	#[pallet::storage]
+	pub type OrderExpiries<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, OrderId, (), OptionQuery>;
+
+	/// Next block to be checked for expired orders in `on_idle`.
+	/// Not set until first order with expiry is placed, cleared when no order with expiry is left.
+	#[pallet::storage]
+	pub type ExpiryCursor<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	/// Accounts allowed to fill a private order
+	#[pallet::storage]
+	#[pallet::getter(fn allowed_takers)]
+	pub type AllowedTakers<T: Config> =
+		StorageMap<_, Blake2_128Concat, OrderId, BoundedVec<T::AccountId, T::MaxAllowedTakers>, OptionQuery>;
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -180,6 +257,9 @@ pub mod pallet {
 		/// - `amount_in`: Amount that the order is seeking to buy
 		/// - `amount_out`: Amount that the order is selling
 		/// - `partially_fillable`: Flag indicating whether users can fill the order partially
+		/// - `expires_at`: Optional block at which the order expires
+		/// - `min_fill_amount`: Optional minimum amount of asset_in which has to be provided by a partial fill
+		/// - `allowed_takers`: Optional list of accounts which are allowed to fill the order
 		///
 		/// Validations:
 		/// - asset_in must be registered
@@ -187,6 +267,9 @@ pub mod pallet {
 		///   ExistentialDepositMultiplier
 		/// - amount_out must be higher than the existential deposit of asset_out multiplied by
 		///   ExistentialDepositMultiplier
+		/// - expires_at must be greater than current block number
+		/// - min_fill_amount can be set only if order is partially fillable and must not be greater than amount_in
+		/// - allowed_takers must not be empty and must not contain duplicate accounts
 		///
 		/// Events:
 		/// - `Placed` event when successful.
@@ -199,6 +282,9 @@ pub mod pallet {
 			amount_in: Balance,
 			amount_out: Balance,
 			partially_fillable: bool,
+			expires_at: Option<T::BlockNumber>,
+			min_fill_amount: Option<Balance>,
+			allowed_takers: Option<BoundedVec<T::AccountId, T::MaxAllowedTakers>>,
 		) -> DispatchResult {
 			let owner = ensure_signed(origin)?;
 			let order = Order {
@@ -208,18 +294,55 @@ pub mod pallet {
 				amount_in,
 				amount_out,
 				partially_fillable,
+				expires_at,
+				min_fill_amount,
 			};
 
 			ensure!(T::AssetRegistry::exists(order.asset_in), Error::<T>::AssetNotRegistered);
 			Self::ensure_min_order_amount(order.asset_in, order.amount_in)?;
 			Self::ensure_min_order_amount(order.asset_out, amount_out)?;
 
+			if let Some(expires_at) = expires_at {
+				ensure!(
+					expires_at > frame_system::Pallet::<T>::block_number(),
+					Error::<T>::InvalidExpiry
+				);
+			}
+
+			if let Some(min_fill_amount) = min_fill_amount {
+				ensure!(
+					partially_fillable && !min_fill_amount.is_zero() && min_fill_amount <= amount_in,
+					Error::<T>::InvalidMinFillAmount
+				);
+			}
+
+			if let Some(takers) = allowed_takers.as_ref() {
+				ensure!(!takers.is_empty(), Error::<T>::InvalidAllowedTakers);
+				let mut unique = BTreeSet::new();
+				ensure!(
+					takers.iter().all(|taker| unique.insert(taker)),
+					Error::<T>::DuplicateAllowedTakers
+				);
+			}
+
 			<NextOrderId<T>>::try_mutate(|next_id| -> DispatchResult {
 				let order_id = *next_id;
 
 				T::Currency::reserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out)?;
 				<Orders<T>>::insert(order_id, &order);
 
+				if let Some(expires_at) = expires_at {
+					<OrderExpiries<T>>::insert(expires_at, order_id, ());
+					<ExpiryCursor<T>>::mutate(|cursor| match cursor {
+						Some(cursor) if *cursor <= expires_at => {}
+						_ => *cursor = Some(expires_at),
+					});
+				}
+
+				if let Some(takers) = allowed_takers.as_ref() {
+					<AllowedTakers<T>>::insert(order_id, takers);
+				}
+
 				Self::deposit_event(Event::Placed {
 					order_id,
 					asset_in: order.asset_in,
@@ -227,6 +350,9 @@ pub mod pallet {
 					amount_in: order.amount_in,
 					amount_out,
 					partially_fillable: order.partially_fillable,
+					expires_at,
+					min_fill_amount,
+					allowed_takers: allowed_takers.map(|takers| takers.into_inner()),
 				});
 
 				*next_id = next_id.checked_add(One::one()).ok_or(Error::<T>::OrderIdOutOfBound)?;
@@ -242,6 +368,9 @@ pub mod pallet {
 		///
 		/// Validations:
 		/// - order must be partially_fillable
+		/// - order must not be expired
+		/// - caller must be allowed taker if the order is private
+		/// - amount_in must not be lower than min_fill_amount of the order
 		/// - after the partial_fill, the remaining order.amount_in must be higher than the existential deposit
 		///   of asset_in multiplied by ExistentialDepositMultiplier
 		/// - after the partial_fill, the remaining order.amount_out must be higher than the existential deposit
@@ -257,6 +386,12 @@ pub mod pallet {
 				let order = maybe_order.as_mut().ok_or(Error::<T>::OrderNotFound)?;
 
 				ensure!(order.partially_fillable, Error::<T>::OrderNotPartiallyFillable);
+				Self::ensure_not_expired(order)?;
+				Self::ensure_allowed_taker(order_id, &who)?;
+
+				if let Some(min_fill_amount) = order.min_fill_amount {
+					ensure!(amount_in >= min_fill_amount, Error::<T>::FillAmountTooSmall);
+				}
 
 				let amount_out_calculation = U256::from(order.amount_out)
 					.checked_mul(U256::from(amount_in))
@@ -287,6 +422,10 @@ pub mod pallet {
 		/// Parameters:
 		/// - `order_id`: ID of the order
 		///
+		/// Validations:
+		/// - order must not be expired
+		/// - caller must be allowed taker if the order is private
+		///
 		/// Events:
 		/// `Filled` event when successful.
 		#[pallet::call_index(2)]
@@ -295,16 +434,10 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			let order = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
 
-			Self::execute_order(&order, &who, order.amount_in, order.amount_out)?;
-			<Orders<T>>::remove(order_id);
+			Self::ensure_not_expired(&order)?;
+			Self::ensure_allowed_taker(order_id, &who)?;
 
-			Self::deposit_event(Event::Filled {
-				order_id,
-				who,
-				amount_in: order.amount_in,
-				amount_out: order.amount_out,
-			});
-			Ok(())
+			Self::do_fill_order(order_id, &order, who)
 		}
 
 		/// Cancel an open OTC order
@@ -317,6 +450,8 @@ pub mod pallet {
 		/// Validations:
 		/// - caller is order owner
 		///
+		/// Expired order which has not been removed yet can be cancelled as well.
+		///
 		/// Emits `Cancelled` event when successful.
 		#[pallet::call_index(3)]
 		#[pallet::weight(<T as Config>::WeightInfo::cancel_order())]
@@ -330,12 +465,197 @@ pub mod pallet {
 				let remaining_to_unreserve =
 					T::Currency::unreserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out);
 				ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
+				Self::remove_order_expiry(order_id, order);
+				<AllowedTakers<T>>::remove(order_id);
 				*maybe_order = None;
 
 				Self::deposit_event(Event::Cancelled { order_id });
 				Ok(())
 			})
 		}
+
+		/// Fill an OTC order (completely) with an asset other than asset_in of the order.
+		/// The asset_in of the order is bought through the `Router` first.
+		///
+		/// Parameters:
+		/// - `order_id`: ID of the order
+		/// - `asset_in`: Asset which is sold to buy asset_in of the order
+		/// - `max_amount_in`: Max amount of `asset_in` to spend on the buy
+		/// - `route`: Series of trades from `asset_in` to asset_in of the order. If empty, the stored route is used.
+		///
+		/// Validations:
+		/// - order must not be expired
+		/// - caller must be allowed taker if the order is private
+		/// - `asset_in` must be different from asset_in of the order
+		/// - amount of `asset_in` spent on the buy must not exceed `max_amount_in`
+		///
+		/// Events:
+		/// `Filled` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(Pallet::<T>::fill_order_via_route_weight(route))]
+		#[transactional]
+		pub fn fill_order_via_route(
+			origin: OriginFor<T>,
+			order_id: OrderId,
+			asset_in: T::AssetId,
+			max_amount_in: Balance,
+			route: Vec<Trade<T::AssetId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+			let order = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+
+			Self::ensure_not_expired(&order)?;
+			Self::ensure_allowed_taker(order_id, &who)?;
+			ensure!(asset_in != order.asset_in, Error::<T>::InvalidRouteAsset);
+
+			T::Router::buy(origin, asset_in, order.asset_in, order.amount_in, max_amount_in, route)?;
+
+			Self::do_fill_order(order_id, &order, who)
+		}
+
+		/// Update amounts of an open OTC order
+		///
+		/// Reserved amount of asset_out is increased or decreased to match the new amount_out.
+		/// Order keeps its ID and all other parameters.
+		///
+		/// Parameters:
+		/// - `order_id`: ID of the order
+		/// - `amount_in`: New amount that the order is seeking to buy
+		/// - `amount_out`: New amount that the order is selling
+		///
+		/// Validations:
+		/// - caller is order owner
+		/// - order must not be expired
+		/// - amount_in must be higher than the existential deposit of asset_in multiplied by
+		///   ExistentialDepositMultiplier
+		/// - amount_out must be higher than the existential deposit of asset_out multiplied by
+		///   ExistentialDepositMultiplier
+		/// - min_fill_amount of the order must not be greater than amount_in
+		///
+		/// Events:
+		/// - `Updated` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_order())]
+		pub fn update_order(
+			origin: OriginFor<T>,
+			order_id: OrderId,
+			amount_in: Balance,
+			amount_out: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			<Orders<T>>::try_mutate(order_id, |maybe_order| -> DispatchResult {
+				let order = maybe_order.as_mut().ok_or(Error::<T>::OrderNotFound)?;
+
+				ensure!(order.owner == who, Error::<T>::Forbidden);
+				Self::ensure_not_expired(order)?;
+
+				Self::ensure_min_order_amount(order.asset_in, amount_in)?;
+				Self::ensure_min_order_amount(order.asset_out, amount_out)?;
+
+				if let Some(min_fill_amount) = order.min_fill_amount {
+					ensure!(min_fill_amount <= amount_in, Error::<T>::InvalidMinFillAmount);
+				}
+
+				if amount_out > order.amount_out {
+					T::Currency::reserve_named(
+						&NAMED_RESERVE_ID,
+						order.asset_out,
+						&order.owner,
+						amount_out - order.amount_out,
+					)?;
+				} else {
+					let remaining_to_unreserve = T::Currency::unreserve_named(
+						&NAMED_RESERVE_ID,
+						order.asset_out,
+						&order.owner,
+						order.amount_out - amount_out,
+					);
+					ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
+				}
+
+				order.amount_in = amount_in;
+				order.amount_out = amount_out;
+
+				Self::deposit_event(Event::Updated {
+					order_id,
+					amount_in,
+					amount_out,
+				});
+				Ok(())
+			})
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_idle(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let initial_cursor = match <ExpiryCursor<T>>::get() {
+				Some(cursor) => cursor,
+				None => return Weight::zero(),
+			};
+
+			// cursor read
+			let mut weight = T::DbWeight::get().reads(1);
+			// check of remaining expiries and cursor write, only when cursor moves
+			let update_weight = T::DbWeight::get().reads_writes(1, 1);
+			if remaining_weight.any_lt(weight.saturating_add(update_weight)) {
+				return Weight::zero();
+			}
+
+			let mut cursor = initial_cursor;
+			let mut expired_orders: u32 = 0;
+
+			'blocks: while cursor <= now {
+				let remaining_orders = T::MaxExpiredOrdersPerBlock::get().saturating_sub(expired_orders);
+				if remaining_orders.is_zero() {
+					break;
+				}
+
+				// one more key is taken to find out whether the block has more expired orders than the limit
+				let keys_to_take = remaining_orders.saturating_add(1);
+
+				// prefix scan and one read per key
+				let scan_weight = T::DbWeight::get().reads(keys_to_take.saturating_add(1) as u64);
+				if remaining_weight.any_lt(weight.saturating_add(update_weight).saturating_add(scan_weight)) {
+					break;
+				}
+
+				let order_ids: Vec<OrderId> = <OrderExpiries<T>>::iter_key_prefix(cursor)
+					.take(keys_to_take as usize)
+					.collect();
+				weight.saturating_accrue(T::DbWeight::get().reads(order_ids.len().saturating_add(1) as u64));
+
+				for order_id in order_ids.iter().take(remaining_orders as usize) {
+					let expire_weight = T::WeightInfo::expire_order();
+					if remaining_weight.any_lt(weight.saturating_add(update_weight).saturating_add(expire_weight)) {
+						break 'blocks;
+					}
+
+					Self::expire_order(cursor, *order_id);
+
+					weight.saturating_accrue(expire_weight);
+					expired_orders.saturating_inc();
+				}
+
+				if order_ids.len() > remaining_orders as usize {
+					break;
+				}
+
+				cursor.saturating_inc();
+			}
+
+			if cursor != initial_cursor {
+				weight.saturating_accrue(update_weight);
+
+				if <OrderExpiries<T>>::iter_keys().next().is_none() {
+					<ExpiryCursor<T>>::kill();
+				} else {
+					<ExpiryCursor<T>>::put(cursor);
+				}
+			}
+
+			weight
+		}
 	}
 }
 
@@ -350,9 +670,76 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn ensure_not_expired(order: &Order<T::AccountId, T::AssetId, T::BlockNumber>) -> DispatchResult {
+		if let Some(expires_at) = order.expires_at {
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < expires_at,
+				Error::<T>::OrderExpired
+			);
+		}
+
+		Ok(())
+	}
+
+	fn do_fill_order(
+		order_id: OrderId,
+		order: &Order<T::AccountId, T::AssetId, T::BlockNumber>,
+		who: T::AccountId,
+	) -> DispatchResult {
+		Self::execute_order(order, &who, order.amount_in, order.amount_out)?;
+		<Orders<T>>::remove(order_id);
+		<AllowedTakers<T>>::remove(order_id);
+		Self::remove_order_expiry(order_id, order);
+
+		Self::deposit_event(Event::Filled {
+			order_id,
+			who,
+			amount_in: order.amount_in,
+			amount_out: order.amount_out,
+		});
+		Ok(())
+	}
+
+	/// Weight of filling the order increased by the weight of the buy through the route.
+	/// Empty route is weighted as the longest possible route because the stored route is used.
+	fn fill_order_via_route_weight(route: &[Trade<T::AssetId>]) -> Weight {
+		T::WeightInfo::fill_order().saturating_add(T::Router::buy_weight(route))
+	}
+
+	fn ensure_allowed_taker(order_id: OrderId, who: &T::AccountId) -> DispatchResult {
+		if let Some(takers) = <AllowedTakers<T>>::get(order_id) {
+			ensure!(takers.contains(who), Error::<T>::Forbidden);
+		}
+
+		Ok(())
+	}
+
+	fn remove_order_expiry(order_id: OrderId, order: &Order<T::AccountId, T::AssetId, T::BlockNumber>) {
+		if let Some(expires_at) = order.expires_at {
+			<OrderExpiries<T>>::remove(expires_at, order_id);
+		}
+	}
+
+	/// Remove expired order and return reserved amount to the owner.
+	pub(crate) fn expire_order(expires_at: T::BlockNumber, order_id: OrderId) {
+		<OrderExpiries<T>>::remove(expires_at, order_id);
+
+		if let Some(order) = <Orders<T>>::take(order_id) {
+			<AllowedTakers<T>>::remove(order_id);
+
+			let remaining_to_unreserve =
+				T::Currency::unreserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out);
+			if !remaining_to_unreserve.is_zero() {
+				frame_support::defensive!("Reserved amount of expired order is not sufficient");
+			}
+
+			Self::deposit_event(Event::Expired { order_id });
+		}
+	}
+
 	#[require_transactional]
 	fn execute_order(
-		order: &Order<T::AccountId, T::AssetId>,
+		order: &Order<T::AccountId, T::AssetId, T::BlockNumber>,
 		who: &T::AccountId,
 		amount_in: Balance,
 		amount_out: Balance,
@@ -0,0 +1,104 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Balance, Config, Order, Orders, Pallet};
+use frame_support::{
+	log,
+	traits::{Get, StorageVersion},
+	weights::Weight,
+};
+
+/// Migrate orders to v1 - orders without expiry and min fill amount.
+pub mod v1 {
+	use super::*;
+	use codec::{Decode, Encode};
+	use scale_info::TypeInfo;
+	use sp_core::RuntimeDebug;
+
+	#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+	pub struct OldOrder<AccountId, AssetId> {
+		pub owner: AccountId,
+		pub asset_in: AssetId,
+		pub asset_out: AssetId,
+		pub amount_in: Balance,
+		pub amount_out: Balance,
+		pub partially_fillable: bool,
+	}
+
+	pub fn pre_migrate<T: Config>() {
+		assert_eq!(StorageVersion::get::<Pallet<T>>(), 0, "Storage version too high.");
+
+		log::info!(
+			target: "runtime::otc",
+			"OTC migration: PRE checks successful!"
+		);
+	}
+
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain_storage_version = StorageVersion::get::<Pallet<T>>();
+
+		if on_chain_storage_version >= 1 {
+			log::warn!(
+				target: "runtime::otc",
+				"Attempted to apply migration to v1 but failed because storage version is {:?}",
+				on_chain_storage_version,
+			);
+			return T::DbWeight::get().reads(1);
+		}
+
+		log::info!(
+			target: "runtime::otc",
+			"Running migration to v1 for OTC"
+		);
+
+		let mut i = 0;
+		Orders::<T>::translate(
+			|_key,
+			 OldOrder {
+			     owner,
+			     asset_in,
+			     asset_out,
+			     amount_in,
+			     amount_out,
+			     partially_fillable,
+			 }: OldOrder<T::AccountId, T::AssetId>| {
+				i += 1;
+				Some(Order {
+					owner,
+					asset_in,
+					asset_out,
+					amount_in,
+					amount_out,
+					partially_fillable,
+					expires_at: None,
+					min_fill_amount: None,
+				})
+			},
+		);
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(i + 1, i + 1)
+	}
+
+	pub fn post_migrate<T: Config>() {
+		assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "Unexpected storage version.");
+
+		log::info!(
+			target: "runtime::otc",
+			"OTC migration: POST checks successful!"
+		);
+	}
+}
@@ -0,0 +1,340 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as otc;
+use crate::tests::mock::*;
+use crate::weights::WeightInfo;
+use crate::{Error, Event, ExpiryCursor, OrderExpiries};
+use frame_support::traits::Hooks;
+use frame_support::weights::Weight;
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pretty_assertions::assert_eq;
+
+fn place_order_with_expiry(expires_at: u64) {
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		DAI,
+		HDX,
+		20 * ONE,
+		100 * ONE,
+		true,
+		Some(expires_at),
+		None,
+		None
+	));
+}
+
+#[test]
+fn place_order_should_index_order_expiry() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		place_order_with_expiry(10);
+		place_order_with_expiry(5);
+
+		// Assert
+		let order = OTC::orders(0).unwrap();
+		assert_eq!(order.expires_at, Some(10));
+
+		assert_eq!(OrderExpiries::<Test>::get(10, 0), Some(()));
+		assert_eq!(OrderExpiries::<Test>::get(5, 1), Some(()));
+		assert_eq!(ExpiryCursor::<Test>::get(), Some(5));
+	});
+}
+
+#[test]
+fn place_order_should_throw_error_when_expiry_is_not_in_future() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(10);
+
+		assert_noop!(
+			OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				100 * ONE,
+				true,
+				Some(10),
+				None,
+				None
+			),
+			Error::<Test>::InvalidExpiry
+		);
+	});
+}
+
+#[test]
+fn place_order_should_throw_error_when_min_fill_amount_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		// not partially fillable
+		assert_noop!(
+			OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				100 * ONE,
+				false,
+				None,
+				Some(5 * ONE),
+				None
+			),
+			Error::<Test>::InvalidMinFillAmount
+		);
+
+		// zero
+		assert_noop!(
+			OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				100 * ONE,
+				true,
+				None,
+				Some(0),
+				None
+			),
+			Error::<Test>::InvalidMinFillAmount
+		);
+
+		// greater than amount_in
+		assert_noop!(
+			OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				100 * ONE,
+				true,
+				None,
+				Some(20 * ONE + 1),
+				None
+			),
+			Error::<Test>::InvalidMinFillAmount
+		);
+	});
+}
+
+#[test]
+fn partial_fill_order_should_throw_error_when_amount_is_below_min_fill_amount() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			None,
+			Some(10 * ONE),
+			None
+		));
+
+		// Act & Assert
+		assert_noop!(
+			OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 10 * ONE - 1),
+			Error::<Test>::FillAmountTooSmall
+		);
+
+		assert_ok!(OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 10 * ONE));
+	});
+}
+
+#[test]
+fn fill_order_should_throw_error_when_order_is_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order_with_expiry(10);
+		System::set_block_number(10);
+
+		// Act & Assert
+		assert_noop!(
+			OTC::fill_order(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::OrderExpired
+		);
+		assert_noop!(
+			OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 10 * ONE),
+			Error::<Test>::OrderExpired
+		);
+	});
+}
+
+#[test]
+fn fill_order_should_work_when_order_is_not_expired_yet() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order_with_expiry(10);
+		System::set_block_number(9);
+
+		// Act
+		assert_ok!(OTC::fill_order(RuntimeOrigin::signed(BOB), 0));
+
+		// Assert
+		assert_eq!(OrderExpiries::<Test>::get(10, 0), None);
+	});
+}
+
+#[test]
+fn cancel_order_should_remove_order_expiry() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order_with_expiry(10);
+
+		// Act
+		assert_ok!(OTC::cancel_order(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		assert_eq!(OrderExpiries::<Test>::get(10, 0), None);
+	});
+}
+
+#[test]
+fn on_idle_should_remove_expired_order_and_unreserve_amount() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order_with_expiry(10);
+		let alice_free_hdx = Tokens::free_balance(HDX, &ALICE);
+		System::set_block_number(10);
+
+		// Act
+		let weight = OTC::on_idle(10, Weight::MAX);
+
+		// Assert
+		assert!(weight.all_gte(<Test as otc::Config>::WeightInfo::expire_order()));
+		assert!(OTC::orders(0).is_none());
+		assert_eq!(OrderExpiries::<Test>::get(10, 0), None);
+		assert_eq!(ExpiryCursor::<Test>::get(), None);
+		assert_eq!(Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), alice_free_hdx + 100 * ONE);
+
+		expect_events(vec![Event::Expired { order_id: 0 }.into()]);
+	});
+}
+
+#[test]
+fn on_idle_should_not_remove_order_before_expiry() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order_with_expiry(10);
+		System::set_block_number(9);
+
+		// Act
+		OTC::on_idle(9, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_some());
+		assert_eq!(ExpiryCursor::<Test>::get(), Some(10));
+	});
+}
+
+#[test]
+fn on_idle_should_remove_at_most_max_expired_orders_per_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange - MaxExpiredOrdersPerBlock is 2
+		place_order_with_expiry(10);
+		place_order_with_expiry(10);
+		place_order_with_expiry(11);
+		System::set_block_number(12);
+
+		// Act
+		OTC::on_idle(12, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert!(OTC::orders(1).is_none());
+		assert!(OTC::orders(2).is_some());
+		assert_eq!(ExpiryCursor::<Test>::get(), Some(11));
+
+		// Act - remaining order is removed in next block
+		System::set_block_number(13);
+		OTC::on_idle(13, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(2).is_none());
+		assert_eq!(ExpiryCursor::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn on_idle_should_not_move_cursor_when_block_has_more_expired_orders_than_max_expired_orders_per_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange - MaxExpiredOrdersPerBlock is 2
+		place_order_with_expiry(10);
+		place_order_with_expiry(10);
+		place_order_with_expiry(10);
+		System::set_block_number(10);
+
+		// Act
+		OTC::on_idle(10, Weight::MAX);
+
+		// Assert
+		assert_eq!(OrderExpiries::<Test>::iter_prefix(10).count(), 1);
+		assert_eq!(ExpiryCursor::<Test>::get(), Some(10));
+
+		// Act - remaining order is removed in next block
+		System::set_block_number(11);
+		OTC::on_idle(11, Weight::MAX);
+
+		// Assert
+		assert_eq!(OrderExpiries::<Test>::iter_prefix(10).count(), 0);
+		assert_eq!(ExpiryCursor::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn on_idle_should_keep_cursor_when_orders_with_later_expiry_remain() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order_with_expiry(10);
+		place_order_with_expiry(20);
+		System::set_block_number(12);
+
+		// Act
+		OTC::on_idle(12, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert!(OTC::orders(1).is_some());
+		assert_eq!(ExpiryCursor::<Test>::get(), Some(13));
+	});
+}
+
+#[test]
+fn on_idle_should_not_remove_expired_order_when_remaining_weight_is_not_sufficient() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order_with_expiry(10);
+		System::set_block_number(10);
+
+		// Act
+		let weight = OTC::on_idle(10, Weight::zero());
+
+		// Assert
+		assert_eq!(weight, Weight::zero());
+		assert!(OTC::orders(0).is_some());
+		assert_eq!(ExpiryCursor::<Test>::get(), Some(10));
+	});
+}
+
+#[test]
+fn on_idle_should_do_nothing_when_there_are_no_orders_with_expiry() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(OTC::on_idle(10, Weight::MAX), Weight::zero());
+		assert_eq!(ExpiryCursor::<Test>::get(), None);
+	});
+}
@@ -0,0 +1,190 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as otc;
+use crate::tests::mock::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::PoolType;
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pallet_route_executor::Trade;
+use pretty_assertions::assert_eq;
+
+fn usdt_to_dai_route() -> Vec<Trade<AssetId>> {
+	vec![Trade {
+		pool: PoolType::XYK,
+		asset_in: USDT,
+		asset_out: DAI,
+	}]
+}
+
+fn with_pool() -> ExtBuilder {
+	ExtBuilder::default().with_endowed_accounts(vec![(ASSET_PAIR_ACCOUNT, DAI, 1_000), (BOB, USDT, 1_000)])
+}
+
+fn place_order(allowed_takers: Option<Vec<AccountId>>, expires_at: Option<u64>) {
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		DAI,
+		HDX,
+		20 * ONE,
+		100 * ONE,
+		true,
+		expires_at,
+		None,
+		allowed_takers.map(|takers| takers.try_into().unwrap())
+	));
+}
+
+#[test]
+fn fill_order_via_route_should_work() {
+	with_pool().build().execute_with(|| {
+		// Arrange
+		place_order(None, None);
+
+		let alice_dai_balance_before = Tokens::free_balance(DAI, &ALICE);
+		let bob_dai_balance_before = Tokens::free_balance(DAI, &BOB);
+		let bob_hdx_balance_before = Tokens::free_balance(HDX, &BOB);
+		let bob_usdt_balance_before = Tokens::free_balance(USDT, &BOB);
+
+		// Act
+		assert_ok!(OTC::fill_order_via_route(
+			RuntimeOrigin::signed(BOB),
+			0,
+			USDT,
+			20 * ONE * POOL_PRICE,
+			usdt_to_dai_route()
+		));
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+
+		assert_eq!(Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), alice_dai_balance_before + 20 * ONE);
+
+		assert_eq!(Tokens::free_balance(DAI, &BOB), bob_dai_balance_before);
+		assert_eq!(Tokens::free_balance(HDX, &BOB), bob_hdx_balance_before + 100 * ONE);
+		assert_eq!(
+			Tokens::free_balance(USDT, &BOB),
+			bob_usdt_balance_before - 20 * ONE * POOL_PRICE
+		);
+
+		expect_events(vec![
+			pallet_route_executor::Event::RouteExecuted {
+				asset_in: USDT,
+				asset_out: DAI,
+				amount_in: 20 * ONE * POOL_PRICE,
+				amount_out: 20 * ONE,
+			}
+			.into(),
+			Event::Filled {
+				order_id: 0,
+				who: BOB,
+				amount_in: 20 * ONE,
+				amount_out: 100 * ONE,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn fill_order_via_route_should_throw_error_when_max_amount_in_is_exceeded() {
+	with_pool().build().execute_with(|| {
+		// Arrange
+		place_order(None, None);
+
+		// Act & Assert
+		assert_noop!(
+			OTC::fill_order_via_route(
+				RuntimeOrigin::signed(BOB),
+				0,
+				USDT,
+				20 * ONE * POOL_PRICE - 1,
+				usdt_to_dai_route()
+			),
+			pallet_route_executor::Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn fill_order_via_route_should_throw_error_when_asset_in_is_asset_in_of_order() {
+	with_pool().build().execute_with(|| {
+		// Arrange
+		place_order(None, None);
+
+		// Act & Assert
+		assert_noop!(
+			OTC::fill_order_via_route(RuntimeOrigin::signed(BOB), 0, DAI, 20 * ONE, vec![]),
+			Error::<Test>::InvalidRouteAsset
+		);
+	});
+}
+
+#[test]
+fn fill_order_via_route_should_throw_error_when_there_is_no_route() {
+	with_pool().build().execute_with(|| {
+		// Arrange
+		place_order(None, None);
+
+		// Act & Assert
+		assert_noop!(
+			OTC::fill_order_via_route(RuntimeOrigin::signed(BOB), 0, USDT, 20 * ONE * POOL_PRICE, vec![]),
+			pallet_route_executor::Error::<Test>::RouteHasNoTrades
+		);
+	});
+}
+
+#[test]
+fn fill_order_via_route_should_throw_error_when_caller_is_not_allowed_taker() {
+	with_pool().build().execute_with(|| {
+		// Arrange
+		place_order(Some(vec![ALICE]), None);
+
+		// Act & Assert
+		assert_noop!(
+			OTC::fill_order_via_route(
+				RuntimeOrigin::signed(BOB),
+				0,
+				USDT,
+				20 * ONE * POOL_PRICE,
+				usdt_to_dai_route()
+			),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn fill_order_via_route_should_throw_error_when_order_is_expired() {
+	with_pool().build().execute_with(|| {
+		// Arrange
+		place_order(None, Some(10));
+		System::set_block_number(10);
+
+		// Act & Assert
+		assert_noop!(
+			OTC::fill_order_via_route(
+				RuntimeOrigin::signed(BOB),
+				0,
+				USDT,
+				20 * ONE * POOL_PRICE,
+				usdt_to_dai_route()
+			),
+			Error::<Test>::OrderExpired
+		);
+	});
+}
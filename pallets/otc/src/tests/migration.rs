@@ -0,0 +1,59 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::migration::v1::{self, OldOrder};
+use crate::tests::mock::*;
+use crate::{Order, Orders};
+use frame_support::storage::unhashed;
+use frame_support::traits::{GetStorageVersion, StorageVersion};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn migration_to_v1_should_add_expiry_and_min_fill_amount_to_orders() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		StorageVersion::new(0).put::<OTC>();
+		let old_order = OldOrder {
+			owner: ALICE,
+			asset_in: DAI,
+			asset_out: HDX,
+			amount_in: 20 * ONE,
+			amount_out: 100 * ONE,
+			partially_fillable: true,
+		};
+		unhashed::put(&Orders::<Test>::hashed_key_for(0), &old_order);
+
+		// Act
+		v1::pre_migrate::<Test>();
+		v1::migrate::<Test>();
+		v1::post_migrate::<Test>();
+
+		// Assert
+		assert_eq!(OTC::on_chain_storage_version(), 1);
+		assert_eq!(
+			OTC::orders(0).unwrap(),
+			Order {
+				owner: ALICE,
+				asset_in: DAI,
+				asset_out: HDX,
+				amount_in: 20 * ONE,
+				amount_out: 100 * ONE,
+				partially_fillable: true,
+				expires_at: None,
+				min_fill_amount: None,
+			}
+		);
+	});
+}
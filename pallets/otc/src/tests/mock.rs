@@ -20,9 +20,11 @@ use frame_support::{
 	traits::{Everything, GenesisBuild, Nothing},
 };
 use frame_system as system;
+use frame_system::{ensure_signed, EnsureRoot};
+use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
 use hydradx_traits::Registry;
 use orml_tokens::AccountData;
-use orml_traits::parameter_type_with_key;
+use orml_traits::{parameter_type_with_key, MultiCurrency};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
@@ -43,12 +45,17 @@ pub type NamedReserveIdentifier = [u8; 8];
 pub const HDX: AssetId = 0;
 pub const DAI: AssetId = 2;
 pub const DOGE: AssetId = 333;
+pub const USDT: AssetId = 4;
 pub const REGISTERED_ASSET: AssetId = 1000;
 
 pub const ONE: Balance = 1_000_000_000_000;
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const ASSET_PAIR_ACCOUNT: AccountId = 100;
+
+/// Amount of asset_in required by the mocked pool to buy one unit of asset_out
+pub const POOL_PRICE: Balance = 2;
 
 frame_support::construct_runtime!(
 	pub enum Test where
@@ -59,6 +66,7 @@ frame_support::construct_runtime!(
 		 System: frame_system,
 		 OTC: otc,
 		 Tokens: orml_tokens,
+		 RouteExecutor: pallet_route_executor,
 	 }
 );
 
@@ -71,6 +79,8 @@ thread_local! {
 parameter_types! {
 	pub NativeCurrencyId: AssetId = HDX;
 	pub ExistentialDepositMultiplier: u8 = 5;
+	pub MaxExpiredOrdersPerBlock: u32 = 2;
+	pub MaxAllowedTakers: u32 = 3;
 }
 
 parameter_type_with_key! {
@@ -86,6 +96,9 @@ impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ExistentialDeposits = ExistentialDeposits;
 	type ExistentialDepositMultiplier = ExistentialDepositMultiplier;
+	type MaxExpiredOrdersPerBlock = MaxExpiredOrdersPerBlock;
+	type MaxAllowedTakers = MaxAllowedTakers;
+	type Router = RouteExecutor;
 	type WeightInfo = ();
 }
 
@@ -136,6 +149,100 @@ impl orml_tokens::Config for Test {
 	type CurrencyHooks = ();
 }
 
+parameter_types! {
+	pub const MaxNumberOfTrades: u8 = 3;
+	pub const MinimumTradingLimit: Balance = 1_000;
+}
+
+impl pallet_route_executor::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type MaxNumberOfTrades = MaxNumberOfTrades;
+	type ExistentialDepositMultiplier = ExistentialDepositMultiplier;
+	type MinimumTradingLimit = MinimumTradingLimit;
+	type Currency = Tokens;
+	type AMM = Xyk;
+	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
+	type WeightInfo = ();
+}
+
+/// Pool trading any asset pair at the fixed price of `POOL_PRICE`.
+pub struct Xyk;
+
+impl TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance> for Xyk {
+	type Error = DispatchError;
+
+	fn calculate_sell(
+		pool_type: PoolType<AssetId>,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		amount_in: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		if !matches!(pool_type, PoolType::XYK) {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		Ok(amount_in / POOL_PRICE)
+	}
+
+	fn calculate_buy(
+		pool_type: PoolType<AssetId>,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		amount_out: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		if !matches!(pool_type, PoolType::XYK) {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		Ok(amount_out * POOL_PRICE)
+	}
+
+	fn execute_sell(
+		origin: RuntimeOrigin,
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		_min_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		let amount_out = Self::calculate_sell(pool_type, asset_in, asset_out, amount_in)?;
+		Self::swap(origin, asset_in, asset_out, amount_in, amount_out)
+	}
+
+	fn execute_buy(
+		origin: RuntimeOrigin,
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		_max_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		let amount_in = Self::calculate_buy(pool_type, asset_in, asset_out, amount_out)?;
+		Self::swap(origin, asset_in, asset_out, amount_in, amount_out)
+	}
+}
+
+impl Xyk {
+	fn swap(
+		origin: RuntimeOrigin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		amount_out: Balance,
+	) -> Result<(), ExecutorError<DispatchError>> {
+		let who = ensure_signed(origin).map_err(|e| ExecutorError::Error(e.into()))?;
+
+		<Tokens as MultiCurrency<AccountId>>::transfer(asset_in, &who, &ASSET_PAIR_ACCOUNT, amount_in)
+			.map_err(ExecutorError::Error)?;
+		<Tokens as MultiCurrency<AccountId>>::transfer(asset_out, &ASSET_PAIR_ACCOUNT, &who, amount_out)
+			.map_err(ExecutorError::Error)?;
+
+		Ok(())
+	}
+}
+
 pub struct DummyRegistry<T>(sp_std::marker::PhantomData<T>);
 
 impl<T: Config> Registry<AssetId, Vec<u8>, Balance, DispatchError> for DummyRegistry<T> {
@@ -188,6 +295,11 @@ impl Default for ExtBuilder {
 }
 
 impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(u64, AssetId, Balance)>) -> Self {
+		self.endowed_accounts.extend(accounts);
+		self
+	}
+
 	pub fn with_existential_deposit(self, asset_id: AssetId, precision: u32) -> Self {
 		EXISTENTIAL_DEPOSIT.with(|v| {
 			v.borrow_mut().insert(asset_id, 10u128.pow(precision));
@@ -16,7 +16,12 @@
 pub mod mock;
 
 pub mod cancel_order;
+pub mod expiry;
 pub mod fill_order;
+pub mod fill_order_via_route;
 pub mod invariants;
+pub mod migration;
 pub mod partial_fill_order;
 pub mod place_order;
+pub mod private_order;
+pub mod update_order;
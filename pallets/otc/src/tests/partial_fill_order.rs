@@ -30,7 +30,10 @@ fn partial_fill_order_should_work_when_order_is_partially_fillable() {
 			HDX,
 			20 * ONE,
 			100 * ONE,
-			true
+			true,
+			None,
+			None,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -92,7 +95,10 @@ fn partial_fill_order_should_throw_error_when_order_is_not_partially_fillable()
 			HDX,
 			20 * ONE,
 			100 * ONE,
-			false
+			false,
+			None,
+			None,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -138,7 +144,10 @@ fn partial_fill_order_should_throw_error_when_fill_is_complete() {
 			HDX,
 			20 * ONE,
 			100 * ONE,
-			true
+			true,
+			None,
+			None,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -184,7 +193,10 @@ fn partial_fill_order_should_throw_error_when_remaining_amounts_are_too_low() {
 			HDX,
 			20 * ONE,
 			100 * ONE,
-			true
+			true,
+			None,
+			None,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -230,7 +242,10 @@ fn fill_order_should_throw_error_when_insufficient_balance() {
 			HDX,
 			200 * ONE,
 			100 * ONE,
-			true
+			true,
+			None,
+			None,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -276,7 +291,10 @@ fn partial_fill_order_should_throw_error_when_amount_is_larger_than_order() {
 			HDX,
 			20 * ONE,
 			100 * ONE,
-			true
+			true,
+			None,
+			None,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
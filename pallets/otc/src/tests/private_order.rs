@@ -0,0 +1,191 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as otc;
+use crate::tests::mock::*;
+use crate::{AllowedTakers, Error, Event};
+use frame_support::traits::Hooks;
+use frame_support::weights::Weight;
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use orml_traits::NamedMultiReservableCurrency;
+use pretty_assertions::assert_eq;
+
+const CHARLIE: AccountId = 3;
+
+fn place_private_order(allowed_takers: Vec<AccountId>) {
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		DAI,
+		HDX,
+		20 * ONE,
+		100 * ONE,
+		true,
+		Some(10),
+		None,
+		Some(allowed_takers.try_into().unwrap())
+	));
+}
+
+#[test]
+fn place_order_should_store_allowed_takers() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		place_private_order(vec![BOB]);
+
+		// Assert
+		assert_eq!(OTC::allowed_takers(0).unwrap().into_inner(), vec![BOB]);
+
+		expect_events(vec![Event::Placed {
+			order_id: 0,
+			asset_in: DAI,
+			asset_out: HDX,
+			amount_in: 20 * ONE,
+			amount_out: 100 * ONE,
+			partially_fillable: true,
+			expires_at: Some(10),
+			min_fill_amount: None,
+			allowed_takers: Some(vec![BOB]),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn place_order_should_throw_error_when_allowed_takers_are_empty() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				100 * ONE,
+				true,
+				None,
+				None,
+				Some(BoundedVec::default())
+			),
+			Error::<Test>::InvalidAllowedTakers
+		);
+	});
+}
+
+#[test]
+fn place_order_should_throw_error_when_allowed_takers_contain_duplicates() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				100 * ONE,
+				true,
+				None,
+				None,
+				Some(vec![BOB, CHARLIE, BOB].try_into().unwrap())
+			),
+			Error::<Test>::DuplicateAllowedTakers
+		);
+	});
+}
+
+#[test]
+fn fill_order_should_work_when_caller_is_allowed_taker() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_private_order(vec![CHARLIE, BOB]);
+
+		// Act
+		assert_ok!(OTC::fill_order(RuntimeOrigin::signed(BOB), 0));
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert_eq!(AllowedTakers::<Test>::get(0), None);
+	});
+}
+
+#[test]
+fn fill_order_should_throw_error_when_caller_is_not_allowed_taker() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_private_order(vec![CHARLIE]);
+
+		// Act & Assert
+		assert_noop!(OTC::fill_order(RuntimeOrigin::signed(BOB), 0), Error::<Test>::Forbidden);
+	});
+}
+
+#[test]
+fn partial_fill_order_should_throw_error_when_caller_is_not_allowed_taker() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_private_order(vec![CHARLIE]);
+
+		// Act & Assert
+		assert_noop!(
+			OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 10 * ONE),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn partial_fill_order_should_keep_allowed_takers_when_order_is_not_filled_completely() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_private_order(vec![BOB]);
+
+		// Act
+		assert_ok!(OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 10 * ONE));
+
+		// Assert
+		assert_eq!(OTC::allowed_takers(0).unwrap().into_inner(), vec![BOB]);
+		assert_eq!(
+			Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE),
+			50 * ONE
+		);
+	});
+}
+
+#[test]
+fn cancel_order_should_remove_allowed_takers() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_private_order(vec![BOB]);
+
+		// Act
+		assert_ok!(OTC::cancel_order(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		assert_eq!(AllowedTakers::<Test>::get(0), None);
+	});
+}
+
+#[test]
+fn expired_order_should_remove_allowed_takers() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_private_order(vec![BOB]);
+		System::set_block_number(10);
+
+		// Act
+		OTC::on_idle(10, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert_eq!(AllowedTakers::<Test>::get(0), None);
+	});
+}
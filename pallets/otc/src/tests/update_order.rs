@@ -0,0 +1,211 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as otc;
+use crate::tests::mock::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pretty_assertions::assert_eq;
+
+fn place_order() {
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		DAI,
+		HDX,
+		20 * ONE,
+		100 * ONE,
+		true,
+		None,
+		None,
+		None
+	));
+}
+
+#[test]
+fn update_order_should_increase_reserved_amount_when_amount_out_increases() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order();
+		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
+
+		// Act
+		assert_ok!(OTC::update_order(RuntimeOrigin::signed(ALICE), 0, 30 * ONE, 150 * ONE));
+
+		// Assert
+		let order = OTC::orders(0).unwrap();
+		assert_eq!(order.amount_in, 30 * ONE);
+		assert_eq!(order.amount_out, 150 * ONE);
+
+		assert_eq!(
+			Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE),
+			150 * ONE
+		);
+		assert_eq!(
+			Tokens::free_balance(HDX, &ALICE),
+			alice_free_hdx_balance_before - 50 * ONE
+		);
+
+		expect_events(vec![Event::Updated {
+			order_id: 0,
+			amount_in: 30 * ONE,
+			amount_out: 150 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn update_order_should_decrease_reserved_amount_when_amount_out_decreases() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order();
+		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
+
+		// Act
+		assert_ok!(OTC::update_order(RuntimeOrigin::signed(ALICE), 0, 20 * ONE, 60 * ONE));
+
+		// Assert
+		assert_eq!(OTC::orders(0).unwrap().amount_out, 60 * ONE);
+		assert_eq!(
+			Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE),
+			60 * ONE
+		);
+		assert_eq!(
+			Tokens::free_balance(HDX, &ALICE),
+			alice_free_hdx_balance_before + 40 * ONE
+		);
+	});
+}
+
+#[test]
+fn update_order_should_keep_order_id() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order();
+
+		// Act
+		assert_ok!(OTC::update_order(RuntimeOrigin::signed(ALICE), 0, 30 * ONE, 100 * ONE));
+
+		// Assert
+		assert_eq!(OTC::next_order_id(), 1);
+		assert_eq!(OTC::orders(0).unwrap().amount_in, 30 * ONE);
+	});
+}
+
+#[test]
+fn update_order_should_throw_error_when_called_by_non_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order();
+
+		// Act & Assert
+		assert_noop!(
+			OTC::update_order(RuntimeOrigin::signed(BOB), 0, 30 * ONE, 150 * ONE),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn update_order_should_throw_error_when_order_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			OTC::update_order(RuntimeOrigin::signed(ALICE), 0, 30 * ONE, 150 * ONE),
+			Error::<Test>::OrderNotFound
+		);
+	});
+}
+
+#[test]
+fn update_order_should_throw_error_when_amounts_are_too_low() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order();
+
+		// Act & Assert
+		assert_noop!(
+			OTC::update_order(RuntimeOrigin::signed(ALICE), 0, 4 * ONE, 100 * ONE),
+			Error::<Test>::OrderAmountTooSmall
+		);
+		assert_noop!(
+			OTC::update_order(RuntimeOrigin::signed(ALICE), 0, 20 * ONE, 4 * ONE),
+			Error::<Test>::OrderAmountTooSmall
+		);
+	});
+}
+
+#[test]
+fn update_order_should_throw_error_when_owner_has_insufficient_balance() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order();
+
+		// Act & Assert
+		assert_noop!(
+			OTC::update_order(RuntimeOrigin::signed(ALICE), 0, 20 * ONE, 100_000 * ONE),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn update_order_should_throw_error_when_amount_in_is_below_min_fill_amount() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			None,
+			Some(15 * ONE),
+			None
+		));
+
+		// Act & Assert
+		assert_noop!(
+			OTC::update_order(RuntimeOrigin::signed(ALICE), 0, 10 * ONE, 100 * ONE),
+			Error::<Test>::InvalidMinFillAmount
+		);
+	});
+}
+
+#[test]
+fn update_order_should_throw_error_when_order_is_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10),
+			None,
+			None
+		));
+		System::set_block_number(10);
+
+		// Act & Assert
+		assert_noop!(
+			OTC::update_order(RuntimeOrigin::signed(ALICE), 0, 30 * ONE, 150 * ONE),
+			Error::<Test>::OrderExpired
+		);
+	});
+}
@@ -51,6 +51,8 @@ pub trait WeightInfo {
 	fn partial_fill_order() -> Weight;
 	fn fill_order() -> Weight;
 	fn cancel_order() -> Weight;
+	fn expire_order() -> Weight;
+	fn update_order() -> Weight;
 }
 
 /// Weights for pallet_otc using the hydraDX node and recommended hardware.
@@ -58,23 +60,33 @@ pub struct HydraWeight<T>(PhantomData<T>);
 
 impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	fn place_order() -> Weight {
-		Weight::from_ref_time(69_013_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(5 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
+		Weight::from_ref_time(79_436_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	fn partial_fill_order() -> Weight {
-		Weight::from_ref_time(125_008_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(8 as u64))
+		Weight::from_ref_time(128_417_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
 			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
 	fn fill_order() -> Weight {
-		Weight::from_ref_time(120_345_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(7 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+		Weight::from_ref_time(127_953_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	fn cancel_order() -> Weight {
-		Weight::from_ref_time(55_731_000 as u64)
+		Weight::from_ref_time(60_128_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	fn expire_order() -> Weight {
+		Weight::from_ref_time(59_274_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	fn update_order() -> Weight {
+		Weight::from_ref_time(63_725_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 }
@@ -82,23 +94,33 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	fn place_order() -> Weight {
-		Weight::from_ref_time(69_013_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(5 as u64))
-			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+		Weight::from_ref_time(79_436_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
 	}
 	fn partial_fill_order() -> Weight {
-		Weight::from_ref_time(125_008_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+		Weight::from_ref_time(128_417_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
 			.saturating_add(RocksDbWeight::get().writes(6 as u64))
 	}
 	fn fill_order() -> Weight {
-		Weight::from_ref_time(120_345_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(7 as u64))
-			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+		Weight::from_ref_time(127_953_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
 	}
 	fn cancel_order() -> Weight {
-		Weight::from_ref_time(55_731_000 as u64)
+		Weight::from_ref_time(60_128_000 as u64)
 			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	fn expire_order() -> Weight {
+		Weight::from_ref_time(59_274_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	fn update_order() -> Weight {
+		Weight::from_ref_time(63_725_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
 			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
 }
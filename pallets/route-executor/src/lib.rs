@@ -18,16 +18,22 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::dispatch::DispatchResult;
 use frame_support::ensure;
 use frame_support::traits::fungibles::Inspect;
 use frame_support::traits::Get;
 use frame_support::transactional;
+use frame_support::weights::Weight;
+use frame_support::BoundedVec;
 use frame_system::ensure_signed;
+use frame_system::pallet_prelude::OriginFor;
 use hydradx_traits::router::TradeExecution;
-use hydradx_traits::router::{ExecutorError, PoolType};
+use hydradx_traits::router::{ExecutorError, RouterT};
 use orml_traits::arithmetic::{CheckedAdd, CheckedSub};
 use scale_info::TypeInfo;
+use sp_runtime::traits::Saturating;
 use sp_runtime::{ArithmeticError, DispatchError};
+use sp_std::marker::PhantomData;
 use sp_std::vec::Vec;
 
 #[cfg(test)]
@@ -40,6 +46,8 @@ use weights::WeightInfo;
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
+pub use hydradx_traits::router::Trade;
+
 pub trait TradeAmountsCalculator<AssetId, Balance> {
 	fn calculate_buy_trade_amounts(
 		route: &[Trade<AssetId>],
@@ -52,19 +60,35 @@ pub trait TradeAmountsCalculator<AssetId, Balance> {
 	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError>;
 }
 
-///A single trade for buy/sell, describing the asset pair and the pool type in which the trade is executed
+pub struct AmountInAndOut<Balance> {
+	pub amount_in: Balance,
+	pub amount_out: Balance,
+}
+
+///The directed asset pair a route is stored for
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Copy, Clone, TypeInfo, MaxEncodedLen)]
-pub struct Trade<AssetId> {
-	pub pool: PoolType<AssetId>,
+pub struct AssetPair<AssetId> {
 	pub asset_in: AssetId,
 	pub asset_out: AssetId,
 }
 
-pub struct AmountInAndOut<Balance> {
-	pub amount_in: Balance,
-	pub amount_out: Balance,
+impl<AssetId> AssetPair<AssetId> {
+	pub fn new(asset_in: AssetId, asset_out: AssetId) -> Self {
+		Self { asset_in, asset_out }
+	}
 }
 
+///Bound of a stored route, derived from the `MaxNumberOfTrades` config parameter
+pub struct MaxNumberOfTradesOf<T>(PhantomData<T>);
+
+impl<T: Config> Get<u32> for MaxNumberOfTradesOf<T> {
+	fn get() -> u32 {
+		T::MaxNumberOfTrades::get() as u32
+	}
+}
+
+pub type StoredRoute<T> = BoundedVec<Trade<<T as Config>::AssetId>, MaxNumberOfTradesOf<T>>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -90,12 +114,24 @@ pub mod pallet {
 			+ MaybeSerializeDeserialize
 			+ Default
 			+ CheckedSub
-			+ CheckedAdd;
+			+ CheckedAdd
+			+ Ord
+			+ Saturating
+			+ From<u8>;
 
 		/// Max limit for the number of trades within a route
 		#[pallet::constant]
 		type MaxNumberOfTrades: Get<u8>;
 
+		/// Multiplier of the existential deposit of `asset_in`, giving the amount at which routes are compared in `set_route`
+		#[pallet::constant]
+		type ExistentialDepositMultiplier: Get<u8>;
+
+		/// Lower bound of the amount at which routes are compared in `set_route`.
+		/// Should not be lower than the minimum trading limits of the AMMs.
+		#[pallet::constant]
+		type MinimumTradingLimit: Get<Self::Balance>;
+
 		/// Currency for checking balances
 		type Currency: Inspect<Self::AccountId, AssetId = Self::AssetId, Balance = Self::Balance>;
 
@@ -108,10 +144,18 @@ pub mod pallet {
 			Error = DispatchError,
 		>;
 
+		/// Origin able to insert a route without comparing it to the currently stored one
+		type TechnicalOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Weight information for the extrinsics.
 		type WeightInfo: WeightInfo;
 	}
 
+	/// Best known routes per asset pair, used when `sell` or `buy` is executed with an empty route
+	#[pallet::storage]
+	#[pallet::getter(fn route)]
+	pub type Routes<T: Config> = StorageMap<_, Blake2_128Concat, AssetPair<T::AssetId>, StoredRoute<T>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -122,6 +166,12 @@ pub mod pallet {
 			amount_in: T::Balance,
 			amount_out: T::Balance,
 		},
+		///The stored route of an asset pair has been updated
+		RouteUpdated {
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			route: Vec<Trade<T::AssetId>>,
+		},
 	}
 
 	#[pallet::error]
@@ -140,6 +190,10 @@ pub mod pallet {
 		InvalidRouteExecution,
 		///The calculation of route trade amounts failed in the underlying AMM
 		RouteCalculationFailed,
+		///The trades of the route do not connect `asset_in` with `asset_out`, or `asset_in` is the same as `asset_out`
+		InvalidRoute,
+		///The route does not provide a better price than the stored one
+		RouteNotImproved,
 	}
 
 	#[pallet::call]
@@ -153,10 +207,11 @@ pub mod pallet {
 		/// - `amount_in`: The amount of `asset_in` to sell
 		/// - `min_amount_out`: The minimum amount of `asset_out` to receive.
 		/// - `route`: Series of [`Trade<AssetId>`] to be executed. A [`Trade<AssetId>`] specifies the asset pair (`asset_in`, `asset_out`) and the AMM (`pool`) in which the trade is executed.
+		/// If empty, the route stored for the asset pair is used.
 		///
 		/// Emits `RouteExecuted` when successful.
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::sell(route.len() as u32))]
+		#[pallet::weight(<T as Config>::WeightInfo::sell(Pallet::<T>::trade_count_for_weight(route)))]
 		#[transactional]
 		pub fn sell(
 			origin: OriginFor<T>,
@@ -167,6 +222,7 @@ pub mod pallet {
 			route: Vec<Trade<T::AssetId>>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin.clone())?;
+			let route = Self::resolve_route(asset_in, asset_out, route);
 			Self::ensure_route_size(route.len())?;
 
 			let user_balance_of_asset_in_before_trade = T::Currency::reducible_balance(asset_in, &who, false);
@@ -232,10 +288,11 @@ pub mod pallet {
 		/// - `amount_out`: The amount of `asset_out` to buy
 		/// - `max_amount_in`: The max amount of `asset_in` to spend on the buy.
 		/// - `route`: Series of [`Trade<AssetId>`] to be executed. A [`Trade<AssetId>`] specifies the asset pair (`asset_in`, `asset_out`) and the AMM (`pool`) in which the trade is executed.
+		/// If empty, the route stored for the asset pair is used.
 		///
 		/// Emits `RouteExecuted` when successful.
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::buy(route.len() as u32))]
+		#[pallet::weight(<T as Config>::WeightInfo::buy(Pallet::<T>::trade_count_for_weight(route)))]
 		#[transactional]
 		pub fn buy(
 			origin: OriginFor<T>,
@@ -246,6 +303,7 @@ pub mod pallet {
 			route: Vec<Trade<T::AssetId>>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin.clone())?;
+			let route = Self::resolve_route(asset_in, asset_out, route);
 			Self::ensure_route_size(route.len())?;
 
 			let user_balance_of_asset_in_before_trade = T::Currency::reducible_balance(asset_in, &who, true);
@@ -297,10 +355,150 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Stores a route for the asset pair (`asset_in`, `asset_out`).
+		///
+		/// The route is accepted only if there is no stored route for the asset pair yet,
+		/// the stored route can no longer be calculated,
+		/// or the new route gives more `asset_out` than the stored one when selling the reference amount of `asset_in`.
+		/// The reference amount is the existential deposit of `asset_in` multiplied by `ExistentialDepositMultiplier`,
+		/// but at least `MinimumTradingLimit`.
+		///
+		/// - `origin`: Any signed account
+		/// - `asset_in`: The identifier of the asset to sell
+		/// - `asset_out`: The identifier of the asset to receive
+		/// - `route`: Series of [`Trade<AssetId>`] connecting `asset_in` with `asset_out`
+		///
+		/// The stored route is calculated as well, so the call is weighed for the longest possible route.
+		///
+		/// Emits `RouteUpdated` when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_route(T::MaxNumberOfTrades::get() as u32))]
+		pub fn set_route(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			route: Vec<Trade<T::AssetId>>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let asset_pair = AssetPair::new(asset_in, asset_out);
+			let new_route = Self::ensure_route_is_valid(asset_pair, route)?;
+
+			let reference_amount_in = Self::reference_amount_in(asset_in);
+			let new_amount_out = Self::calculate_sell_amount_out(&new_route, reference_amount_in)?;
+
+			if let Some(existing_route) = Routes::<T>::get(asset_pair) {
+				if let Ok(existing_amount_out) = Self::calculate_sell_amount_out(&existing_route, reference_amount_in) {
+					ensure!(new_amount_out > existing_amount_out, Error::<T>::RouteNotImproved);
+				}
+			}
+
+			Self::insert_route(asset_pair, new_route);
+
+			Ok(())
+		}
+
+		/// Stores a route for the asset pair (`asset_in`, `asset_out`) without comparing it to the stored one.
+		///
+		/// Can only be called by `TechnicalOrigin`.
+		///
+		/// - `origin`: `TechnicalOrigin`
+		/// - `asset_in`: The identifier of the asset to sell
+		/// - `asset_out`: The identifier of the asset to receive
+		/// - `route`: Series of [`Trade<AssetId>`] connecting `asset_in` with `asset_out`
+		///
+		/// Emits `RouteUpdated` when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_insert_route())]
+		pub fn force_insert_route(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			route: Vec<Trade<T::AssetId>>,
+		) -> DispatchResult {
+			T::TechnicalOrigin::ensure_origin(origin)?;
+
+			let asset_pair = AssetPair::new(asset_in, asset_out);
+			let route = Self::ensure_route_is_valid(asset_pair, route)?;
+
+			Self::insert_route(asset_pair, route);
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	fn resolve_route(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		route: Vec<Trade<T::AssetId>>,
+	) -> Vec<Trade<T::AssetId>> {
+		if !route.is_empty() {
+			return route;
+		}
+
+		Routes::<T>::get(AssetPair::new(asset_in, asset_out))
+			.map(|stored_route| stored_route.into_inner())
+			.unwrap_or_default()
+	}
+
+	fn trade_count_for_weight(route: &[Trade<T::AssetId>]) -> u32 {
+		if route.is_empty() {
+			T::MaxNumberOfTrades::get() as u32
+		} else {
+			route.len() as u32
+		}
+	}
+
+	fn ensure_route_is_valid(
+		asset_pair: AssetPair<T::AssetId>,
+		route: Vec<Trade<T::AssetId>>,
+	) -> Result<StoredRoute<T>, DispatchError> {
+		Self::ensure_route_size(route.len())?;
+		ensure!(asset_pair.asset_in != asset_pair.asset_out, Error::<T>::InvalidRoute);
+
+		let first_trade = route.first().ok_or(Error::<T>::RouteHasNoTrades)?;
+		let last_trade = route.last().ok_or(Error::<T>::RouteHasNoTrades)?;
+		ensure!(
+			first_trade.asset_in == asset_pair.asset_in && last_trade.asset_out == asset_pair.asset_out,
+			Error::<T>::InvalidRoute
+		);
+		ensure!(
+			route.windows(2).all(|trades| trades[0].asset_out == trades[1].asset_in),
+			Error::<T>::InvalidRoute
+		);
+
+		StoredRoute::<T>::try_from(route).map_err(|_| Error::<T>::MaxTradesExceeded.into())
+	}
+
+	fn reference_amount_in(asset_in: T::AssetId) -> T::Balance {
+		T::Currency::minimum_balance(asset_in)
+			.saturating_mul(T::ExistentialDepositMultiplier::get().into())
+			.max(T::MinimumTradingLimit::get())
+	}
+
+	fn calculate_sell_amount_out(
+		route: &[Trade<T::AssetId>],
+		amount_in: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		let trade_amounts = Self::calculate_sell_trade_amounts(route, amount_in)?;
+		let last_trade_amount = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
+
+		Ok(last_trade_amount.amount_out)
+	}
+
+	fn insert_route(asset_pair: AssetPair<T::AssetId>, route: StoredRoute<T>) {
+		Routes::<T>::insert(asset_pair, route.clone());
+
+		Self::deposit_event(Event::RouteUpdated {
+			asset_in: asset_pair.asset_in,
+			asset_out: asset_pair.asset_out,
+			route: route.into_inner(),
+		});
+	}
+
 	fn ensure_route_size(route_length: usize) -> Result<(), DispatchError> {
 		ensure!(route_length > 0, Error::<T>::RouteHasNoTrades);
 		ensure!(
@@ -394,6 +592,23 @@ impl<T: Config> TradeAmountsCalculator<T::AssetId, T::Balance> for Pallet<T> {
 	}
 }
 
+impl<T: Config> RouterT<OriginFor<T>, T::AssetId, T::Balance> for Pallet<T> {
+	fn buy(
+		origin: OriginFor<T>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: T::Balance,
+		max_amount_in: T::Balance,
+		route: Vec<Trade<T::AssetId>>,
+	) -> DispatchResult {
+		Pallet::<T>::buy(origin, asset_in, asset_out, amount_out, max_amount_in, route)
+	}
+
+	fn buy_weight(route: &[Trade<T::AssetId>]) -> Weight {
+		T::WeightInfo::buy(Self::trade_count_for_weight(route))
+	}
+}
+
 #[macro_export]
 macro_rules! handle_execution_error {
 	($execution_result:expr) => {{
@@ -313,6 +313,42 @@ fn buy_should_fail_when_called_with_non_signed_origin() {
 		});
 }
 
+#[test]
+fn buy_should_use_stored_route_when_route_has_no_trades() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_buy = 10;
+		let limit = 5;
+
+		assert_ok!(Router::set_route(
+			RuntimeOrigin::signed(ALICE),
+			BSX,
+			AUSD,
+			vec![BSX_AUSD_TRADE_IN_XYK]
+		));
+
+		//Act
+		assert_ok!(Router::buy(
+			RuntimeOrigin::signed(ALICE),
+			BSX,
+			AUSD,
+			amount_to_buy,
+			limit,
+			vec![]
+		));
+
+		//Assert
+		assert_executed_buy_trades(vec![(PoolType::XYK, amount_to_buy, BSX, AUSD)]);
+		expect_events(vec![Event::RouteExecuted {
+			asset_in: BSX,
+			asset_out: AUSD,
+			amount_in: XYK_BUY_CALCULATION_RESULT,
+			amount_out: amount_to_buy,
+		}
+		.into()]);
+	});
+}
+
 #[test]
 fn buy_should_fail_when_route_has_no_trades() {
 	ExtBuilder::default()
@@ -21,6 +21,7 @@ use frame_support::parameter_types;
 use frame_support::traits::{Everything, GenesisBuild, Nothing};
 use frame_system as system;
 use frame_system::pallet_prelude::OriginFor;
+use frame_system::EnsureRoot;
 use hydradx_adapters::inspect::MultiInspectAdapter;
 use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
 use orml_traits::parameter_type_with_key;
@@ -140,6 +141,8 @@ type Pools = (XYK, StableSwap, OmniPool);
 parameter_types! {
 	pub NativeCurrencyId: AssetId = 1000;
 	pub MaxNumberOfTrades: u8 = MAX_LIMIT_FOR_TRADES;
+	pub const ExistentialDepositMultiplier: u8 = 10;
+	pub const MinimumTradingLimit: Balance = 1_000;
 }
 
 impl Config for Test {
@@ -147,8 +150,11 @@ impl Config for Test {
 	type AssetId = AssetId;
 	type Balance = Balance;
 	type MaxNumberOfTrades = MaxNumberOfTrades;
+	type ExistentialDepositMultiplier = ExistentialDepositMultiplier;
+	type MinimumTradingLimit = MinimumTradingLimit;
 	type Currency = MultiInspectAdapter<AccountId, AssetId, Balance, Balances, Tokens, NativeCurrencyId>;
 	type AMM = Pools;
+	type TechnicalOrigin = EnsureRoot<AccountId>;
 	type WeightInfo = ();
 }
 
@@ -240,6 +246,7 @@ type ExecutedTradeInfo = (PoolType<AssetId>, Balance, AssetId, AssetId);
 thread_local! {
 	pub static EXECUTED_SELLS: RefCell<Vec<ExecutedTradeInfo>> = RefCell::new(Vec::default());
 	pub static EXECUTED_BUYS: RefCell<Vec<ExecutedTradeInfo>> = RefCell::new(Vec::default());
+	pub static CALCULATED_SELL_AMOUNTS: RefCell<Vec<Balance>> = RefCell::new(Vec::default());
 }
 
 type OriginForRuntime = OriginFor<Test>;
@@ -263,6 +270,8 @@ macro_rules! impl_fake_executor {
 					return Err(ExecutorError::Error(DispatchError::Other("Some error happened")));
 				}
 
+				CALCULATED_SELL_AMOUNTS.with(|v| v.borrow_mut().push(amount_in));
+
 				Ok($sell_calculation_result)
 			}
 
@@ -400,3 +409,10 @@ pub fn assert_executed_buy_trades(expected_trades: Vec<(PoolType<AssetId>, Balan
 pub fn expect_events(e: Vec<RuntimeEvent>) {
 	test_utils::expect_events::<RuntimeEvent, Test>(e);
 }
+
+pub fn assert_calculated_sell_amounts(expected_amounts: Vec<Balance>) {
+	CALCULATED_SELL_AMOUNTS.borrow().with(|v| {
+		let amounts = v.borrow().deref().clone();
+		assert_eq!(amounts, expected_amounts);
+	});
+}
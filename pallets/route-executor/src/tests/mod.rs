@@ -1,3 +1,4 @@
 mod buy;
 pub mod mock;
 pub mod sell;
+mod set_route;
@@ -298,6 +298,42 @@ fn sell_should_fail_when_called_with_non_signed_origin() {
 	});
 }
 
+#[test]
+fn sell_should_use_stored_route_when_route_has_no_trades() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 10;
+		let limit = 5;
+
+		assert_ok!(Router::set_route(
+			RuntimeOrigin::signed(ALICE),
+			BSX,
+			AUSD,
+			vec![BSX_AUSD_TRADE_IN_XYK]
+		));
+
+		//Act
+		assert_ok!(Router::sell(
+			RuntimeOrigin::signed(ALICE),
+			BSX,
+			AUSD,
+			amount_to_sell,
+			limit,
+			vec![]
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![(PoolType::XYK, amount_to_sell, BSX, AUSD)]);
+		expect_events(vec![Event::RouteExecuted {
+			asset_in: BSX,
+			asset_out: AUSD,
+			amount_in: amount_to_sell,
+			amount_out: XYK_SELL_CALCULATION_RESULT,
+		}
+		.into()]);
+	});
+}
+
 #[test]
 fn sell_should_fail_when_route_has_no_trades() {
 	ExtBuilder::default().build().execute_with(|| {
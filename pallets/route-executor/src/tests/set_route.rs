@@ -0,0 +1,383 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{AssetPair, Error, Event, Trade};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::PoolType;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+const BSX_AUSD_TRADE_IN_OMNIPOOL: Trade<AssetId> = Trade {
+	pool: PoolType::Omnipool,
+	asset_in: BSX,
+	asset_out: AUSD,
+};
+
+fn stored_route(asset_in: AssetId, asset_out: AssetId) -> Option<Vec<Trade<AssetId>>> {
+	Router::route(AssetPair::new(asset_in, asset_out)).map(|route| route.into_inner())
+}
+
+#[test]
+fn set_route_should_work_when_no_route_is_stored() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let route = vec![BSX_AUSD_TRADE_IN_OMNIPOOL];
+
+		//Act
+		assert_ok!(Router::set_route(
+			RuntimeOrigin::signed(ALICE),
+			BSX,
+			AUSD,
+			route.clone()
+		));
+
+		//Assert
+		assert_eq!(stored_route(BSX, AUSD), Some(route.clone()));
+		assert_eq!(stored_route(AUSD, BSX), None);
+		expect_events(vec![Event::RouteUpdated {
+			asset_in: BSX,
+			asset_out: AUSD,
+			route,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_route_should_work_when_new_route_gives_better_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Router::set_route(
+			RuntimeOrigin::signed(ALICE),
+			BSX,
+			AUSD,
+			vec![BSX_AUSD_TRADE_IN_OMNIPOOL]
+		));
+
+		let route = vec![BSX_AUSD_TRADE_IN_XYK];
+
+		//Act
+		assert_ok!(Router::set_route(
+			RuntimeOrigin::signed(ALICE),
+			BSX,
+			AUSD,
+			route.clone()
+		));
+
+		//Assert
+		assert_eq!(stored_route(BSX, AUSD), Some(route));
+	});
+}
+
+#[test]
+fn set_route_should_work_when_stored_route_cannot_be_calculated() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Router::force_insert_route(
+			RuntimeOrigin::root(),
+			BSX,
+			AUSD,
+			vec![Trade {
+				pool: PoolType::LBP,
+				asset_in: BSX,
+				asset_out: AUSD,
+			}]
+		));
+
+		let route = vec![BSX_AUSD_TRADE_IN_OMNIPOOL];
+
+		//Act
+		assert_ok!(Router::set_route(
+			RuntimeOrigin::signed(ALICE),
+			BSX,
+			AUSD,
+			route.clone()
+		));
+
+		//Assert
+		assert_eq!(stored_route(BSX, AUSD), Some(route));
+	});
+}
+
+#[test]
+fn set_route_should_fail_when_new_route_does_not_give_better_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Router::set_route(
+			RuntimeOrigin::signed(ALICE),
+			BSX,
+			AUSD,
+			vec![BSX_AUSD_TRADE_IN_XYK]
+		));
+
+		//Act and Assert
+		assert_noop!(
+			Router::set_route(
+				RuntimeOrigin::signed(ALICE),
+				BSX,
+				AUSD,
+				vec![BSX_AUSD_TRADE_IN_OMNIPOOL]
+			),
+			Error::<Test>::RouteNotImproved
+		);
+		assert_noop!(
+			Router::set_route(RuntimeOrigin::signed(ALICE), BSX, AUSD, vec![BSX_AUSD_TRADE_IN_XYK]),
+			Error::<Test>::RouteNotImproved
+		);
+	});
+}
+
+#[test]
+fn set_route_should_fail_when_new_route_cannot_be_calculated() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and Assert
+		assert_noop!(
+			Router::set_route(
+				RuntimeOrigin::signed(ALICE),
+				BSX,
+				AUSD,
+				vec![Trade {
+					pool: PoolType::LBP,
+					asset_in: BSX,
+					asset_out: AUSD,
+				}]
+			),
+			Error::<Test>::PoolNotSupported
+		);
+	});
+}
+
+#[test]
+fn set_route_should_compare_routes_at_existential_deposit_multiplied_by_multiplier() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Router::set_route(
+			RuntimeOrigin::signed(ALICE),
+			BSX,
+			AUSD,
+			vec![BSX_AUSD_TRADE_IN_OMNIPOOL]
+		));
+
+		//Act
+		assert_ok!(Router::set_route(
+			RuntimeOrigin::signed(ALICE),
+			BSX,
+			AUSD,
+			vec![BSX_AUSD_TRADE_IN_XYK]
+		));
+
+		//Assert
+		let reference_amount = ExistentialDeposit::get() * ExistentialDepositMultiplier::get() as u128;
+		assert_calculated_sell_amounts(vec![reference_amount; 3]);
+	});
+}
+
+#[test]
+fn set_route_should_compare_routes_at_minimum_trading_limit_when_existential_deposit_is_low() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let route = vec![Trade {
+			pool: PoolType::XYK,
+			asset_in: AUSD,
+			asset_out: KSM,
+		}];
+
+		//Act
+		assert_ok!(Router::set_route(
+			RuntimeOrigin::signed(ALICE),
+			AUSD,
+			KSM,
+			route.clone()
+		));
+
+		//Assert
+		assert_eq!(stored_route(AUSD, KSM), Some(route));
+		assert_calculated_sell_amounts(vec![MinimumTradingLimit::get()]);
+	});
+}
+
+#[test]
+fn set_route_should_fail_when_asset_in_is_same_as_asset_out() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let route = vec![
+			BSX_AUSD_TRADE_IN_XYK,
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: AUSD,
+				asset_out: BSX,
+			},
+		];
+
+		//Act and Assert
+		assert_noop!(
+			Router::set_route(RuntimeOrigin::signed(ALICE), BSX, BSX, route),
+			Error::<Test>::InvalidRoute
+		);
+	});
+}
+
+#[test]
+fn set_route_should_fail_when_route_does_not_start_with_asset_in() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and Assert
+		assert_noop!(
+			Router::set_route(RuntimeOrigin::signed(ALICE), KSM, AUSD, vec![BSX_AUSD_TRADE_IN_XYK]),
+			Error::<Test>::InvalidRoute
+		);
+	});
+}
+
+#[test]
+fn set_route_should_fail_when_route_does_not_end_with_asset_out() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and Assert
+		assert_noop!(
+			Router::set_route(RuntimeOrigin::signed(ALICE), BSX, KSM, vec![BSX_AUSD_TRADE_IN_XYK]),
+			Error::<Test>::InvalidRoute
+		);
+	});
+}
+
+#[test]
+fn set_route_should_fail_when_trades_are_not_connected() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let route = vec![
+			BSX_AUSD_TRADE_IN_XYK,
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: MOVR,
+				asset_out: KSM,
+			},
+		];
+
+		//Act and Assert
+		assert_noop!(
+			Router::set_route(RuntimeOrigin::signed(ALICE), BSX, KSM, route),
+			Error::<Test>::InvalidRoute
+		);
+	});
+}
+
+#[test]
+fn set_route_should_fail_when_route_has_no_trades() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and Assert
+		assert_noop!(
+			Router::set_route(RuntimeOrigin::signed(ALICE), BSX, AUSD, vec![]),
+			Error::<Test>::RouteHasNoTrades
+		);
+	});
+}
+
+#[test]
+fn set_route_should_fail_when_max_number_of_trades_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let route = vec![
+			BSX_AUSD_TRADE_IN_XYK,
+			Trade {
+				pool: PoolType::XYK,
+				asset_in: AUSD,
+				asset_out: MOVR,
+			},
+			Trade {
+				pool: PoolType::XYK,
+				asset_in: MOVR,
+				asset_out: KSM,
+			},
+			Trade {
+				pool: PoolType::XYK,
+				asset_in: KSM,
+				asset_out: RMRK,
+			},
+		];
+
+		//Act and Assert
+		assert_noop!(
+			Router::set_route(RuntimeOrigin::signed(ALICE), BSX, RMRK, route),
+			Error::<Test>::MaxTradesExceeded
+		);
+	});
+}
+
+#[test]
+fn set_route_should_fail_when_called_by_unsigned_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and Assert
+		assert_noop!(
+			Router::set_route(RuntimeOrigin::none(), BSX, AUSD, vec![BSX_AUSD_TRADE_IN_XYK]),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn force_insert_route_should_override_better_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		assert_ok!(Router::set_route(
+			RuntimeOrigin::signed(ALICE),
+			BSX,
+			AUSD,
+			vec![BSX_AUSD_TRADE_IN_XYK]
+		));
+
+		let route = vec![BSX_AUSD_TRADE_IN_OMNIPOOL];
+
+		//Act
+		assert_ok!(Router::force_insert_route(
+			RuntimeOrigin::root(),
+			BSX,
+			AUSD,
+			route.clone()
+		));
+
+		//Assert
+		assert_eq!(stored_route(BSX, AUSD), Some(route.clone()));
+		expect_events(vec![Event::RouteUpdated {
+			asset_in: BSX,
+			asset_out: AUSD,
+			route,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn force_insert_route_should_fail_when_called_by_non_technical_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and Assert
+		assert_noop!(
+			Router::force_insert_route(RuntimeOrigin::signed(ALICE), BSX, AUSD, vec![BSX_AUSD_TRADE_IN_XYK]),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn force_insert_route_should_fail_when_route_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and Assert
+		assert_noop!(
+			Router::force_insert_route(RuntimeOrigin::root(), BSX, KSM, vec![BSX_AUSD_TRADE_IN_XYK]),
+			Error::<Test>::InvalidRoute
+		);
+	});
+}
@@ -48,6 +48,8 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn sell(n: u32) -> Weight;
 	fn buy(n: u32) -> Weight;
+	fn set_route(n: u32) -> Weight;
+	fn force_insert_route() -> Weight;
 }
 
 pub struct BasiliskWeight<T>(PhantomData<T>);
@@ -69,6 +71,16 @@ impl<T: frame_system::Config> WeightInfo for BasiliskWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(n as u64)))
 	}
+	fn set_route(n: u32) -> Weight {
+		Weight::from_ref_time(293_317_000 as u64) // Standard Error: 96_000
+			.saturating_add(Weight::from_ref_time(52_684_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(24 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	fn force_insert_route() -> Weight {
+		Weight::from_ref_time(22_310_000 as u64).saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -89,4 +101,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(n as u64)))
 	}
+	fn set_route(n: u32) -> Weight {
+		Weight::from_ref_time(293_317_000 as u64) // Standard Error: 96_000
+			.saturating_add(Weight::from_ref_time(52_684_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(24 as u64))
+			.saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	fn force_insert_route() -> Weight {
+		Weight::from_ref_time(22_310_000 as u64).saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
 }
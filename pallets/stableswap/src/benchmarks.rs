@@ -22,7 +22,8 @@ use super::*;
 use frame_benchmarking::account;
 use frame_benchmarking::benchmarks;
 use frame_support::pallet_prelude::DispatchError;
-use frame_support::traits::EnsureOrigin;
+use frame_support::traits::{ConstU32, EnsureOrigin};
+use frame_support::BoundedVec;
 use frame_system::{Pallet as System, RawOrigin};
 use orml_traits::MultiCurrency;
 use orml_traits::MultiCurrencyExtended;
@@ -109,12 +110,63 @@ benchmarks! {
 		crate::Pallet::<T>::add_liquidity(RawOrigin::Signed(caller).into(),
 			pool_id,
 			initial,
+			0u128,
 		)?;
-	}: _(RawOrigin::Signed(lp_provider.clone()), pool_id, added_liquidity)
+	}: _(RawOrigin::Signed(lp_provider.clone()), pool_id, added_liquidity, 0u128)
 	verify {
 		assert!(T::Currency::free_balance(pool_id, &lp_provider) > 0u128);
 	}
 
+	add_liquidity_shares{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		let initial_liquidity = 1_000_000_000_000_000u128;
+		let liquidity_added = 300_000_000_000_000u128;
+
+		let mut initial: Vec<AssetLiquidity<T::AssetId>> = vec![];
+
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let name: Vec<u8> = idx.to_ne_bytes().to_vec();
+			let asset_id = T::AssetRegistry::create_asset(&name, 1u128)?;
+			asset_ids.push(asset_id);
+			T::Currency::update_balance(asset_id, &caller, 1_000_000_000_000_000i128)?;
+			T::Currency::update_balance(asset_id, &lp_provider, liquidity_added as i128)?;
+			initial.push(AssetLiquidity{
+				asset_id,
+				amount: initial_liquidity
+			});
+		}
+		let pool_id = T::AssetRegistry::create_asset(&b"pool".to_vec(), 1u128)?;
+
+		let asset_id_to_provide: T::AssetId = *asset_ids.last().unwrap();
+
+		let amplification = 100u16;
+		let trade_fee = Permill::from_percent(1);
+		let withdraw_fee = Permill::from_percent(1);
+
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin,
+			pool_id,
+			asset_ids,
+			amplification,
+			trade_fee,
+			withdraw_fee,
+		)?;
+
+		crate::Pallet::<T>::add_liquidity(RawOrigin::Signed(caller).into(),
+			pool_id,
+			initial,
+			0u128,
+		)?;
+
+		let desired_shares = 100_000_000_000_000u128;
+	}: _(RawOrigin::Signed(lp_provider.clone()), pool_id, desired_shares, asset_id_to_provide, liquidity_added)
+	verify {
+		assert_eq!(T::Currency::free_balance(pool_id, &lp_provider), desired_shares);
+		assert!(T::Currency::free_balance(asset_id_to_provide, &lp_provider) < liquidity_added);
+	}
+
 	remove_liquidity_one_asset{
 		let caller: T::AccountId = account("caller", 0, 1);
 		let lp_provider: T::AccountId = account("provider", 0, 1);
@@ -161,11 +213,13 @@ benchmarks! {
 		crate::Pallet::<T>::add_liquidity(RawOrigin::Signed(caller).into(),
 			pool_id,
 			initial,
+			0u128,
 		)?;
 
 		crate::Pallet::<T>::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(),
 			pool_id,
-			added_liquidity
+			added_liquidity,
+			0u128
 		)?;
 
 		// just make sure that LP provided all his liquidity of this asset
@@ -179,6 +233,76 @@ benchmarks! {
 		assert_eq!(T::Currency::free_balance(asset_id_to_withdraw, &lp_provider), 1296846466078107);
 	}
 
+	remove_liquidity{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		let initial_liquidity = 1_000_000_000_000_000u128;
+		let liquidity_added = 300_000_000_000_000u128;
+
+		let mut initial: Vec<AssetLiquidity<T::AssetId>> = vec![];
+		let mut added_liquidity: Vec<AssetLiquidity<T::AssetId>> = vec![];
+		let mut min_amounts_out: Vec<AssetLiquidity<T::AssetId>> = vec![];
+
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let name: Vec<u8> = idx.to_ne_bytes().to_vec();
+			let asset_id = T::AssetRegistry::create_asset(&name, 1u128)?;
+			asset_ids.push(asset_id);
+			T::Currency::update_balance(asset_id, &caller, 1_000_000_000_000_000i128)?;
+			T::Currency::update_balance(asset_id, &lp_provider, liquidity_added as i128)?;
+			initial.push(AssetLiquidity{
+				asset_id,
+				amount: initial_liquidity
+			});
+			added_liquidity.push(AssetLiquidity{
+				asset_id,
+				amount: liquidity_added
+			});
+			min_amounts_out.push(AssetLiquidity{
+				asset_id,
+				amount: 1u128
+			});
+		}
+		let pool_id = T::AssetRegistry::create_asset(&b"pool".to_vec(), 1u128)?;
+
+		let amplification = 100u16;
+		let trade_fee = Permill::from_percent(1);
+		let withdraw_fee = Permill::from_percent(1);
+
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin,
+			pool_id,
+			asset_ids.clone(),
+			amplification,
+			trade_fee,
+			withdraw_fee,
+		)?;
+
+		crate::Pallet::<T>::add_liquidity(RawOrigin::Signed(caller).into(),
+			pool_id,
+			initial,
+			0u128,
+		)?;
+
+		crate::Pallet::<T>::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(),
+			pool_id,
+			added_liquidity,
+			0u128
+		)?;
+
+		let shares = T::Currency::free_balance(pool_id, &lp_provider);
+
+		let min_amounts_out: BoundedVec<AssetLiquidity<T::AssetId>, ConstU32<MAX_ASSETS_IN_POOL>> =
+			min_amounts_out.try_into().unwrap();
+
+	}: _(RawOrigin::Signed(lp_provider.clone()), pool_id, shares, min_amounts_out)
+	verify {
+		assert_eq!(T::Currency::free_balance(pool_id, &lp_provider), 0u128);
+		for asset_id in asset_ids {
+			assert!(T::Currency::free_balance(asset_id, &lp_provider) > 0u128);
+		}
+	}
+
 	sell{
 		let caller: T::AccountId = account("caller", 0, 1);
 		let lp_provider: T::AccountId = account("provider", 0, 1);
@@ -225,6 +349,7 @@ benchmarks! {
 		crate::Pallet::<T>::add_liquidity(RawOrigin::Signed(caller).into(),
 			pool_id,
 			initial,
+			0u128,
 		)?;
 
 		let seller : T::AccountId = account("seller", 0, 1);
@@ -296,6 +421,7 @@ benchmarks! {
 		crate::Pallet::<T>::add_liquidity(RawOrigin::Signed(caller).into(),
 			pool_id,
 			initial,
+			0u128,
 		)?;
 
 		let buyer: T::AccountId = account("buyer", 0, 1);
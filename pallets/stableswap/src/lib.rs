@@ -31,11 +31,15 @@
 //!
 //! First LP to provided liquidity must add initial liquidity of all pool assets. Subsequent calls to add_liquidity, LP can provide only 1 asset.
 //!
+//! Alternatively, LP can request exact amount of shares and provide required amount of single asset (`add_liquidity_shares`).
+//!
 //! Initial liquidity is first liquidity added to the pool (that is first call of `add_liquidity`).
 //!
-//! LP is given certain amount of shares by minting a pool's share token.
+//! LP is given certain amount of shares by minting a pool's share token. Unless the liquidity is added proportionally
+//! to the pool reserves, trade fee is applied on the imbalance.
 //!
-//! When LP decides to withdraw liquidity, it receives selected asset.
+//! When LP decides to withdraw liquidity, it receives selected asset (`remove_liquidity_one_asset`, withdraw fee is applied)
+//! or a proportional amount of all pool assets (`remove_liquidity`, no fee is applied).
 //!
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -53,11 +57,13 @@ use sp_std::prelude::*;
 pub use pallet::*;
 
 mod trade_execution;
+pub mod traits;
 pub mod types;
 pub mod weights;
 
 pub use trade_execution::*;
 
+use crate::traits::{PoolState, StableswapHooks};
 use crate::types::{AssetLiquidity, Balance, PoolInfo, Tradability};
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
 use orml_traits::MultiCurrency;
@@ -142,6 +148,9 @@ pub mod pallet {
 		#[pallet::constant]
 		type AmplificationRange: Get<RangeInclusive<NonZeroU16>>;
 
+		/// Hooks are actions executed on trade and liquidity changes.
+		type Hooks: StableswapHooks<Self::AssetId>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -190,6 +199,13 @@ pub mod pallet {
 			amount: Balance,
 			fee: Balance,
 		},
+		/// Liquidity removed proportionally from all pool assets.
+		LiquidityRemovedProportionally {
+			pool_id: T::AssetId,
+			who: T::AccountId,
+			shares: Balance,
+			amounts: Vec<AssetLiquidity<T::AssetId>>,
+		},
 		/// Sell trade executed. Trade fee paid in asset leaving the pool (already subtracted from amount_out).
 		SellExecuted {
 			who: T::AccountId,
@@ -305,6 +321,12 @@ pub mod pallet {
 
 		/// New amplification is equal to the previous value.
 		SameAmplification,
+
+		/// Amount of an asset received is less than the requested minimum.
+		SlippageLimit,
+
+		/// Minimum amount of an asset is provided more than once.
+		DuplicateAssetLimit,
 	}
 
 	#[pallet::call]
@@ -469,26 +491,32 @@ pub mod pallet {
 		///
 		/// LP must have sufficient amount of each assets.
 		///
-		/// Origin is given corresponding amount of shares.
+		/// Origin is given corresponding amount of shares. Trade fee is applied on the part of the liquidity
+		/// which is not added proportionally to the pool reserves.
 		///
 		/// Parameters:
 		/// - `origin`: liquidity provider
 		/// - `pool_id`: Pool Id
 		/// - `assets`: asset id and liquidity amount provided
+		/// - `min_shares`: minimum amount of shares to receive
 		///
 		/// Emits `LiquidityAdded` event when successful.
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity())]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
+			.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
 		#[transactional]
 		pub fn add_liquidity(
 			origin: OriginFor<T>,
 			pool_id: T::AssetId,
 			assets: Vec<AssetLiquidity<T::AssetId>>,
+			min_shares: Balance,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
 			let shares = Self::do_add_liquidity(&who, pool_id, &assets)?;
 
+			ensure!(shares >= min_shares, Error::<T>::SlippageLimit);
+
 			Self::deposit_event(Event::LiquidityAdded {
 				pool_id,
 				who,
@@ -515,7 +543,8 @@ pub mod pallet {
 		///
 		/// Emits `LiquidityRemoved` event when successful.
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity_one_asset())]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity_one_asset()
+			.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
 		#[transactional]
 		pub fn remove_liquidity_one_asset(
 			origin: OriginFor<T>,
@@ -568,6 +597,9 @@ pub mod pallet {
 			T::Currency::withdraw(pool_id, &who, share_amount)?;
 			T::Currency::transfer(asset_id, &pool_account, &who, amount)?;
 
+			let state = Self::collect_pool_state(pool_id, &pool, balances, share_issuance);
+			T::Hooks::on_liquidity_changed(pool_id, state)?;
+
 			Self::deposit_event(Event::LiquidityRemoved {
 				pool_id,
 				who,
@@ -593,7 +625,8 @@ pub mod pallet {
 		/// Emits `SellExecuted` event when successful.
 		///
 		#[pallet::call_index(5)]
-		#[pallet::weight(<T as Config>::WeightInfo::sell())]
+		#[pallet::weight(<T as Config>::WeightInfo::sell()
+			.saturating_add(T::Hooks::on_trade_weight()))]
 		#[transactional]
 		pub fn sell(
 			origin: OriginFor<T>,
@@ -625,11 +658,17 @@ pub mod pallet {
 
 			ensure!(amount_out >= min_buy_amount, Error::<T>::BuyLimitNotReached);
 
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 			let pool_account = Self::pool_account(pool_id);
+			let initial_balances = pool.balances::<T>(&pool_account);
+			let share_issuance = T::Currency::total_issuance(pool_id);
 
 			T::Currency::transfer(asset_in, &who, &pool_account, amount_in)?;
 			T::Currency::transfer(asset_out, &pool_account, &who, amount_out)?;
 
+			let state = Self::collect_pool_state(pool_id, &pool, initial_balances, share_issuance);
+			T::Hooks::on_trade(pool_id, asset_in, asset_out, state)?;
+
 			Self::deposit_event(Event::SellExecuted {
 				who,
 				pool_id,
@@ -656,7 +695,8 @@ pub mod pallet {
 		/// Emits `BuyExecuted` event when successful.
 		///
 		#[pallet::call_index(6)]
-		#[pallet::weight(<T as Config>::WeightInfo::buy())]
+		#[pallet::weight(<T as Config>::WeightInfo::buy()
+			.saturating_add(T::Hooks::on_trade_weight()))]
 		#[transactional]
 		pub fn buy(
 			origin: OriginFor<T>,
@@ -681,8 +721,6 @@ pub mod pallet {
 
 			let (amount_in, fee_amount) = Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out)?;
 
-			let pool_account = Self::pool_account(pool_id);
-
 			ensure!(amount_in <= max_sell_amount, Error::<T>::SellLimitExceeded);
 
 			ensure!(
@@ -690,9 +728,17 @@ pub mod pallet {
 				Error::<T>::InsufficientBalance
 			);
 
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let pool_account = Self::pool_account(pool_id);
+			let initial_balances = pool.balances::<T>(&pool_account);
+			let share_issuance = T::Currency::total_issuance(pool_id);
+
 			T::Currency::transfer(asset_in, &who, &pool_account, amount_in)?;
 			T::Currency::transfer(asset_out, &pool_account, &who, amount_out)?;
 
+			let state = Self::collect_pool_state(pool_id, &pool, initial_balances, share_issuance);
+			T::Hooks::on_trade(pool_id, asset_in, asset_out, state)?;
+
 			Self::deposit_event(Event::BuyExecuted {
 				who,
 				pool_id,
@@ -729,6 +775,145 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Remove liquidity proportionally from selected pool.
+		///
+		/// Share amount is burnt and LP receives corresponding amount of each pool asset,
+		/// proportional to the pool reserves.
+		///
+		/// No withdraw fee is applied.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider
+		/// - `pool_id`: Pool Id
+		/// - 'share_amount': amount of shares to withdraw
+		/// - `min_amounts_out`: minimum amount of an asset to receive. Assets not present in the list have no limit.
+		///   Each asset must be in the pool and can be present only once.
+		///
+		/// Emits `LiquidityRemovedProportionally` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity()
+			.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+		#[transactional]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			share_amount: Balance,
+			min_amounts_out: BoundedVec<AssetLiquidity<T::AssetId>, ConstU32<MAX_ASSETS_IN_POOL>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(share_amount > Balance::zero(), Error::<T>::InvalidAssetAmount);
+
+			let current_share_balance = T::Currency::free_balance(pool_id, &who);
+
+			ensure!(current_share_balance >= share_amount, Error::<T>::InsufficientShares);
+
+			ensure!(
+				current_share_balance == share_amount
+					|| current_share_balance.saturating_sub(share_amount) >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientShareBalance
+			);
+
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			for asset_id in pool.assets.iter() {
+				ensure!(
+					Self::is_asset_allowed(pool_id, *asset_id, Tradability::REMOVE_LIQUIDITY),
+					Error::<T>::NotAllowed
+				);
+			}
+
+			let mut min_amounts = BTreeMap::<T::AssetId, Balance>::new();
+			for limit in min_amounts_out.iter() {
+				ensure!(pool.find_asset(limit.asset_id).is_some(), Error::<T>::AssetNotInPool);
+				ensure!(
+					min_amounts.insert(limit.asset_id, limit.amount).is_none(),
+					Error::<T>::DuplicateAssetLimit
+				);
+			}
+
+			let pool_account = Self::pool_account(pool_id);
+			let balances = pool.balances::<T>(&pool_account);
+			let share_issuance = T::Currency::total_issuance(pool_id);
+
+			ensure!(
+				share_issuance == share_amount
+					|| share_issuance.saturating_sub(share_amount) >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientLiquidityRemaining
+			);
+
+			let amounts = hydra_dx_math::stableswap::calculate_liquidity_out(&balances, share_amount, share_issuance)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			T::Currency::withdraw(pool_id, &who, share_amount)?;
+
+			let mut withdrawn = Vec::with_capacity(pool.assets.len());
+			for (asset_id, amount) in pool.assets.iter().zip(amounts.into_iter()) {
+				ensure!(
+					amount >= min_amounts.get(asset_id).copied().unwrap_or_default(),
+					Error::<T>::SlippageLimit
+				);
+				T::Currency::transfer(*asset_id, &pool_account, &who, amount)?;
+				withdrawn.push(AssetLiquidity {
+					asset_id: *asset_id,
+					amount,
+				});
+			}
+
+			let state = Self::collect_pool_state(pool_id, &pool, balances, share_issuance);
+			T::Hooks::on_liquidity_changed(pool_id, state)?;
+
+			Self::deposit_event(Event::LiquidityRemovedProportionally {
+				pool_id,
+				who,
+				shares: share_amount,
+				amounts: withdrawn,
+			});
+
+			Ok(())
+		}
+
+		/// Add liquidity to selected pool given exact amount of shares to receive.
+		///
+		/// Given amount of shares, LP provides required amount of selected asset. Amount is calculated
+		/// so that LP receives exactly `shares` of pool's share token. Trade fee is included in the amount
+		/// the same way as when the asset is provided via `add_liquidity`.
+		///
+		/// Pool must be already initialized with initial liquidity of all assets.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider
+		/// - `pool_id`: Pool Id
+		/// - `shares`: amount of shares to receive
+		/// - `asset_id`: id of asset to provide
+		/// - `max_asset_amount`: maximum amount of asset to provide
+		///
+		/// Emits `LiquidityAdded` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity_shares()
+			.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+		#[transactional]
+		pub fn add_liquidity_shares(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			shares: Balance,
+			asset_id: T::AssetId,
+			max_asset_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let amount = Self::do_add_liquidity_shares(&who, pool_id, shares, asset_id, max_asset_amount)?;
+
+			Self::deposit_event(Event::LiquidityAdded {
+				pool_id,
+				who,
+				shares,
+				assets: vec![AssetLiquidity { asset_id, amount }],
+			});
+
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -738,8 +923,8 @@ pub mod pallet {
 impl<T: Config> Pallet<T> {
 	fn calculate_out_amount(
 		pool_id: T::AssetId,
-		asset_out: T::AssetId,
 		asset_in: T::AssetId,
+		asset_out: T::AssetId,
 		amount_in: Balance,
 	) -> Result<(Balance, Balance), DispatchError> {
 		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
@@ -795,7 +980,7 @@ impl<T: Config> Pallet<T> {
 	}
 
 	#[require_transactional]
-	fn do_create_pool(
+	pub fn do_create_pool(
 		share_asset: T::AssetId,
 		assets: &[T::AssetId],
 		amplification: NonZeroU16,
@@ -888,6 +1073,7 @@ impl<T: Config> Pallet<T> {
 			&updated_reserves,
 			amplification,
 			share_issuance,
+			pool.trade_fee,
 		)
 		.ok_or(ArithmeticError::Overflow)?;
 
@@ -905,16 +1091,170 @@ impl<T: Config> Pallet<T> {
 			T::Currency::transfer(asset.asset_id, who, &pool_account, asset.amount)?;
 		}
 
+		let state = Self::collect_pool_state(pool_id, &pool, initial_reserves, share_issuance);
+		T::Hooks::on_liquidity_changed(pool_id, state)?;
+
 		Ok(share_amount)
 	}
 
+	#[require_transactional]
+	fn do_add_liquidity_shares(
+		who: &T::AccountId,
+		pool_id: T::AssetId,
+		shares: Balance,
+		asset_id: T::AssetId,
+		max_asset_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		ensure!(
+			Self::is_asset_allowed(pool_id, asset_id, Tradability::ADD_LIQUIDITY),
+			Error::<T>::NotAllowed
+		);
+		ensure!(shares > Balance::zero(), Error::<T>::InvalidAssetAmount);
+
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		let asset_idx = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
+		let pool_account = Self::pool_account(pool_id);
+		let initial_reserves = pool.balances::<T>(&pool_account);
+		let share_issuance = T::Currency::total_issuance(pool_id);
+
+		ensure!(
+			!share_issuance.is_zero() && initial_reserves.iter().all(|reserve| !reserve.is_zero()),
+			Error::<T>::InvalidInitialLiquidity
+		);
+
+		let current_share_balance = T::Currency::free_balance(pool_id, who);
+		ensure!(
+			current_share_balance.saturating_add(shares) >= T::MinPoolLiquidity::get(),
+			Error::<T>::InsufficientShareBalance
+		);
+
+		let amplification = Self::get_amplification(&pool);
+		let amount = hydra_dx_math::stableswap::calculate_add_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+			&initial_reserves,
+			shares,
+			asset_idx,
+			share_issuance,
+			amplification,
+			pool.trade_fee,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(amount <= max_asset_amount, Error::<T>::SlippageLimit);
+		ensure!(
+			amount >= T::MinTradingLimit::get(),
+			Error::<T>::InsufficientTradingAmount
+		);
+		ensure!(
+			T::Currency::free_balance(asset_id, who) >= amount,
+			Error::<T>::InsufficientBalance
+		);
+
+		T::Currency::deposit(pool_id, who, shares)?;
+		T::Currency::transfer(asset_id, who, &pool_account, amount)?;
+
+		let state = Self::collect_pool_state(pool_id, &pool, initial_reserves, share_issuance);
+		T::Hooks::on_liquidity_changed(pool_id, state)?;
+
+		Ok(amount)
+	}
+
+	/// Move liquidity of given assets from `from` account to the pool account without issuing any shares.
+	///
+	/// Used when the liquidity is already backed by shares issued elsewhere, eg. when assets are migrated
+	/// from another pool.
+	#[require_transactional]
+	pub fn move_liquidity_to_pool(
+		from: &T::AccountId,
+		pool_id: T::AssetId,
+		assets: &[AssetLiquidity<T::AssetId>],
+	) -> DispatchResult {
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		let pool_account = Self::pool_account(pool_id);
+
+		for asset in assets.iter() {
+			ensure!(pool.find_asset(asset.asset_id).is_some(), Error::<T>::AssetNotInPool);
+			T::Currency::transfer(asset.asset_id, from, &pool_account, asset.amount)?;
+		}
+
+		Ok(())
+	}
+
+	/// Add a registered asset to an existing pool.
+	///
+	/// Note that the pool invariant changes once the asset's liquidity is moved to the pool, so it is up to the caller
+	/// to issue corresponding amount of shares.
+	pub fn add_asset_to_pool(pool_id: T::AssetId, asset_id: T::AssetId) -> DispatchResult {
+		ensure!(T::AssetRegistry::exists(asset_id), Error::<T>::AssetNotRegistered);
+		ensure!(asset_id != pool_id, Error::<T>::ShareAssetInPoolAssets);
+
+		Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+			let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(pool.find_asset(asset_id).is_none(), Error::<T>::AssetInPool);
+
+			let mut assets = pool.assets.to_vec();
+			assets.push(asset_id);
+			assets.sort();
+
+			pool.assets = assets.try_into().map_err(|_| Error::<T>::MaxAssetsExceeded)?;
+			Ok(())
+		})
+	}
+
+	/// Calculate amount of shares which has to be burned to withdraw given amount of an asset from the pool,
+	/// withdraw fee included.
+	pub fn calculate_shares_for_amount(
+		pool_id: T::AssetId,
+		asset_id: T::AssetId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		let asset_idx = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
+		let balances = pool.balances::<T>(&Self::pool_account(pool_id));
+
+		ensure!(balances[asset_idx] > amount, Error::<T>::InsufficientLiquidity);
+
+		let amplification = Self::get_amplification(&pool);
+		hydra_dx_math::stableswap::calculate_shares_for_amount::<D_ITERATIONS, Y_ITERATIONS>(
+			&balances,
+			asset_idx,
+			amount,
+			amplification,
+			T::Currency::total_issuance(pool_id),
+			pool.withdraw_fee,
+		)
+		.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	fn collect_pool_state(
+		pool_id: T::AssetId,
+		pool: &PoolInfo<T::AssetId, T::BlockNumber>,
+		initial_balances: Vec<Balance>,
+		initial_issuance: Balance,
+	) -> PoolState<T::AssetId> {
+		let updated_balances = pool.balances::<T>(&Self::pool_account(pool_id));
+		let delta = initial_balances
+			.iter()
+			.zip(updated_balances.iter())
+			.map(|(initial, updated)| initial.abs_diff(*updated))
+			.collect();
+
+		PoolState {
+			assets: pool.assets.to_vec(),
+			before: initial_balances,
+			after: updated_balances,
+			delta,
+			issuance_before: initial_issuance,
+			issuance_after: T::Currency::total_issuance(pool_id),
+		}
+	}
+
 	#[inline]
 	fn is_asset_allowed(pool_id: T::AssetId, asset_id: T::AssetId, operation: Tradability) -> bool {
 		AssetTradability::<T>::get(pool_id, asset_id).contains(operation)
 	}
 
 	#[inline]
-	fn pool_account(pool_id: T::AssetId) -> T::AccountId {
+	pub fn pool_account(pool_id: T::AssetId) -> T::AccountId {
 		T::ShareAccountId::from_assets(&pool_id, Some(POOL_IDENTIFIER))
 	}
 
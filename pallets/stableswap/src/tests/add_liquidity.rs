@@ -49,7 +49,8 @@ fn add_initial_liquidity_should_work_when_called_first_time() {
 						asset_id: asset_b,
 						amount: initial_liquidity_amount,
 					}
-				]
+				],
+				0u128
 			));
 
 			assert_balance!(BOB, asset_a, 100 * ONE);
@@ -105,7 +106,8 @@ fn add_initial_liquidity_should_fail_when_lp_has_insufficient_balance() {
 							asset_id: asset_b,
 							amount: initial_liquidity_amount
 						}
-					]
+					],
+					0u128
 				),
 				Error::<Test>::InsufficientBalance
 			);
@@ -176,7 +178,8 @@ fn add_liquidity_should_work_when_initial_liquidity_has_been_provided() {
 						asset_id: asset_b,
 						amount: amount_added
 					}
-				]
+				],
+				0u128
 			));
 
 			assert_balance!(BOB, asset_a, 100 * ONE);
@@ -246,7 +249,8 @@ fn add_liquidity_should_work_when_order_is_not_sorted() {
 						asset_id: asset_a,
 						amount: amount_added
 					}
-				]
+				],
+				0u128
 			));
 
 			assert_balance!(BOB, asset_a, 100 * ONE);
@@ -314,7 +318,8 @@ fn add_liquidity_should_fail_when_providing_insufficient_liquidity() {
 							asset_id: asset_b,
 							amount: amount_added
 						}
-					]
+					],
+					0u128
 				),
 				Error::<Test>::InsufficientTradingAmount
 			);
@@ -384,7 +389,8 @@ fn add_liquidity_should_work_when_providing_one_asset_only() {
 				vec![AssetLiquidity {
 					asset_id: asset_a,
 					amount: amount_added
-				},]
+				},],
+				0u128
 			));
 		});
 }
@@ -462,9 +468,275 @@ fn add_liquidity_should_fail_when_providing_one_asset_not_in_pool() {
 							asset_id: asset_e,
 							amount: amount_added
 						},
-					]
+					],
+					0u128
 				),
 				Error::<Test>::AssetNotInPool
 			);
 		});
 }
+
+#[test]
+fn add_liquidity_should_fail_when_min_shares_is_not_reached() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, asset_a, 200 * ONE),
+			(BOB, asset_b, 200 * ONE),
+			(ALICE, asset_a, 200 * ONE),
+			(ALICE, asset_b, 200 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![asset_a, asset_b].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				trade_fee: Permill::from_percent(0),
+				withdraw_fee: Permill::from_percent(0),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetLiquidity {
+						asset_id: asset_a,
+						amount: 100 * ONE,
+					},
+					AssetLiquidity {
+						asset_id: asset_b,
+						amount: 100 * ONE,
+					},
+				],
+			},
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			assert_noop!(
+				Stableswap::add_liquidity(
+					RuntimeOrigin::signed(BOB),
+					pool_id,
+					vec![
+						AssetLiquidity {
+							asset_id: asset_a,
+							amount: 100 * ONE
+						},
+						AssetLiquidity {
+							asset_id: asset_b,
+							amount: 100 * ONE
+						}
+					],
+					200 * ONE,
+				),
+				Error::<Test>::SlippageLimit
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_shares_should_work_when_initial_liquidity_has_been_provided() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, asset_a, 200 * ONE),
+			(ALICE, asset_a, 200 * ONE),
+			(ALICE, asset_b, 200 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![asset_a, asset_b].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				trade_fee: Permill::from_percent(0),
+				withdraw_fee: Permill::from_percent(0),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetLiquidity {
+						asset_id: asset_a,
+						amount: 100 * ONE,
+					},
+					AssetLiquidity {
+						asset_id: asset_b,
+						amount: 100 * ONE,
+					},
+				],
+			},
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+			let pool_account = pool_account(pool_id);
+
+			let desired_shares = 10 * ONE;
+
+			assert_ok!(Stableswap::add_liquidity_shares(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				desired_shares,
+				asset_a,
+				20 * ONE,
+			));
+
+			let amount_provided = 200 * ONE - Tokens::free_balance(asset_a, &BOB);
+
+			assert_balance!(BOB, pool_id, desired_shares);
+			assert!(amount_provided > 10 * ONE);
+			assert!(amount_provided < 11 * ONE);
+			assert_balance!(pool_account, asset_a, 100 * ONE + amount_provided);
+			assert_balance!(pool_account, asset_b, 100 * ONE);
+
+			// Removing the liquidity must not give back more than provided
+			assert_ok!(Stableswap::remove_liquidity_one_asset(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				asset_a,
+				desired_shares,
+			));
+			assert!(Tokens::free_balance(asset_a, &BOB) <= 200 * ONE);
+		});
+}
+
+#[test]
+fn add_liquidity_shares_should_fail_when_max_asset_amount_is_exceeded() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, asset_a, 200 * ONE),
+			(ALICE, asset_a, 200 * ONE),
+			(ALICE, asset_b, 200 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![asset_a, asset_b].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				trade_fee: Permill::from_percent(0),
+				withdraw_fee: Permill::from_percent(0),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetLiquidity {
+						asset_id: asset_a,
+						amount: 100 * ONE,
+					},
+					AssetLiquidity {
+						asset_id: asset_b,
+						amount: 100 * ONE,
+					},
+				],
+			},
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			assert_noop!(
+				Stableswap::add_liquidity_shares(RuntimeOrigin::signed(BOB), pool_id, 10 * ONE, asset_a, 10 * ONE,),
+				Error::<Test>::SlippageLimit
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_shares_should_fail_when_asset_is_not_in_pool() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let asset_c: AssetId = 3;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, asset_c, 200 * ONE),
+			(ALICE, asset_a, 200 * ONE),
+			(ALICE, asset_b, 200 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b)
+		.with_registered_asset("three".as_bytes().to_vec(), asset_c)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![asset_a, asset_b].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				trade_fee: Permill::from_percent(0),
+				withdraw_fee: Permill::from_percent(0),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetLiquidity {
+						asset_id: asset_a,
+						amount: 100 * ONE,
+					},
+					AssetLiquidity {
+						asset_id: asset_b,
+						amount: 100 * ONE,
+					},
+				],
+			},
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			assert_noop!(
+				Stableswap::add_liquidity_shares(RuntimeOrigin::signed(BOB), pool_id, 10 * ONE, asset_c, 20 * ONE,),
+				Error::<Test>::AssetNotInPool
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_shares_should_fail_when_pool_has_no_liquidity() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, asset_a, 200 * ONE)])
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100u16,
+				Permill::from_percent(0),
+				Permill::from_percent(0),
+			));
+
+			assert_noop!(
+				Stableswap::add_liquidity_shares(RuntimeOrigin::signed(BOB), pool_id, 10 * ONE, asset_a, 20 * ONE,),
+				Error::<Test>::InvalidInitialLiquidity
+			);
+		});
+}
@@ -1,6 +1,6 @@
 use crate::tests::mock::*;
 use crate::types::{AssetLiquidity, PoolInfo};
-use frame_support::assert_ok;
+use frame_support::{assert_ok, BoundedVec};
 use sp_runtime::{FixedU128, Permill};
 use std::num::NonZeroU16;
 
@@ -111,7 +111,8 @@ proptest! {
 						asset_id: asset_b,
 						amount: added_liquidity
 					}
-				]
+				],
+				0u128
 				));
 
 				let new_asset_a_reserve = Tokens::free_balance(asset_a, &pool_account);
@@ -456,3 +457,101 @@ proptest! {
 			});
 	}
 }
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn remove_liquidity_proportionally_invariants(
+		initial_liquidity in asset_reserve(),
+		added_liquidity in asset_reserve(),
+		amplification in some_amplification(),
+	) {
+		let asset_a: AssetId = 1000;
+		let asset_b: AssetId = 2000;
+
+		ExtBuilder::default()
+			.with_endowed_accounts(vec![
+				(BOB, asset_a, added_liquidity),
+				(BOB, asset_b, added_liquidity),
+				(ALICE, asset_a, initial_liquidity),
+				(ALICE, asset_b, initial_liquidity),
+			])
+			.with_registered_asset("one".as_bytes().to_vec(), asset_a)
+			.with_registered_asset("two".as_bytes().to_vec(), asset_b)
+			.with_pool(
+				ALICE,
+				PoolInfo::<AssetId, u64> {
+					assets: vec![asset_a,asset_b].try_into().unwrap(),
+					initial_amplification: amplification,
+					final_amplification: amplification,
+					initial_block: 0,
+					final_block: 0,
+					trade_fee: Permill::from_percent(0),
+					withdraw_fee: Permill::from_percent(0),
+				},
+				InitialLiquidity{ account: ALICE, assets:
+				vec![
+					AssetLiquidity{
+						asset_id: asset_a,
+						amount: initial_liquidity
+					},
+					AssetLiquidity{
+						asset_id: asset_b,
+						amount: initial_liquidity
+					}
+				]},
+			)
+			.build()
+			.execute_with(|| {
+				let pool_id = get_pool_id_at(0);
+
+				let pool_account = pool_account(pool_id);
+
+				assert_ok!(Stableswap::add_liquidity(
+					RuntimeOrigin::signed(BOB),
+					pool_id,
+					vec![AssetLiquidity{
+						asset_id: asset_a,
+						amount: added_liquidity
+					},
+					AssetLiquidity{
+						asset_id: asset_b,
+						amount: added_liquidity
+					}
+				],
+				0u128
+				));
+
+				let asset_a_reserve = Tokens::free_balance(asset_a, &pool_account);
+				let asset_b_reserve = Tokens::free_balance(asset_b, &pool_account);
+				let issuance_prev = Tokens::total_issuance(pool_id);
+				let d_prev = calculate_d::<128u8>(&[asset_a_reserve,asset_b_reserve], amplification.get().into()).unwrap();
+
+				let shares = Tokens::free_balance(pool_id, &BOB);
+
+				assert_ok!(Stableswap::remove_liquidity(
+					RuntimeOrigin::signed(BOB),
+					pool_id,
+					shares,
+					BoundedVec::default(),
+				));
+
+				let new_asset_a_reserve = Tokens::free_balance(asset_a, &pool_account);
+				let new_asset_b_reserve = Tokens::free_balance(asset_b, &pool_account);
+				let issuance = Tokens::total_issuance(pool_id);
+				let d = calculate_d::<128u8>(&[new_asset_a_reserve,new_asset_b_reserve], amplification.get().into()).unwrap();
+
+				assert_eq_approx!(
+					FixedU128::from((asset_a_reserve, asset_b_reserve)),
+					FixedU128::from((new_asset_a_reserve, new_asset_b_reserve)),
+					FixedU128::from_float(0.0000000001),
+					"Price has changed after remove liquidity"
+				);
+
+				// D per share must not decrease
+				let d_per_share_prev = FixedU128::from((d_prev, issuance_prev));
+				let d_per_share = FixedU128::from((d, issuance));
+				assert!(d_per_share >= d_per_share_prev - FixedU128::from_float(0.0000000001));
+			});
+	}
+}
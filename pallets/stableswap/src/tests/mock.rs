@@ -180,6 +180,7 @@ impl Config for Test {
 	type MinTradingLimit = MinimumTradingLimit;
 	type WeightInfo = ();
 	type BlockNumberProvider = System;
+	type Hooks = ();
 	type DustAccountHandler = Whitelist;
 }
 
@@ -290,7 +291,8 @@ impl ExtBuilder {
 					assert_ok!(Stableswap::add_liquidity(
 						RuntimeOrigin::signed(initial_liquid.account),
 						pool_id,
-						initial_liquid.assets
+						initial_liquid.assets,
+						0u128
 					));
 				}
 			}
@@ -1,7 +1,7 @@
 use crate::tests::mock::*;
-use crate::types::{AssetLiquidity, PoolInfo};
+use crate::types::{AssetLiquidity, PoolInfo, Tradability};
 use crate::{assert_balance, Error};
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
 use sp_runtime::Permill;
 use std::num::NonZeroU16;
 
@@ -64,7 +64,8 @@ fn remove_liquidity_should_work_when_withdrawing_all_shares() {
 				vec![AssetLiquidity {
 					asset_id: asset_a,
 					amount: amount_added
-				},]
+				},],
+				0u128
 			));
 
 			let shares = Tokens::free_balance(pool_id, &BOB);
@@ -144,7 +145,8 @@ fn remove_liquidity_should_apply_fee_when_withdrawing_all_shares() {
 				vec![AssetLiquidity {
 					asset_id: asset_a,
 					amount: amount_added
-				},]
+				},],
+				0u128
 			));
 
 			let shares = Tokens::free_balance(pool_id, &BOB);
@@ -280,7 +282,8 @@ fn remove_liquidity_should_fail_when_requested_asset_not_in_pool() {
 				vec![AssetLiquidity {
 					asset_id: asset_a,
 					amount: amount_added
-				},]
+				},],
+				0u128
 			));
 
 			let shares = Tokens::free_balance(pool_id, &BOB);
@@ -349,7 +352,8 @@ fn remove_liquidity_should_fail_when_remaining_shares_below_min_liquidity() {
 				vec![AssetLiquidity {
 					asset_id: asset_a,
 					amount: amount_added
-				},]
+				},],
+				0u128
 			));
 
 			let shares = Tokens::free_balance(pool_id, &BOB);
@@ -393,7 +397,7 @@ fn verify_remove_liquidity_against_research_impl() {
 				final_amplification: NonZeroU16::new(100).unwrap(),
 				initial_block: 0,
 				final_block: 0,
-				trade_fee: Permill::from_float(0.003),
+				trade_fee: Permill::from_percent(0),
 				withdraw_fee: Permill::from_float(0.003),
 			},
 			InitialLiquidity {
@@ -432,7 +436,8 @@ fn verify_remove_liquidity_against_research_impl() {
 				vec![AssetLiquidity {
 					asset_id: asset_a,
 					amount: amount_added
-				},]
+				},],
+				0u128
 			));
 
 			let shares = Tokens::free_balance(pool_id, &BOB);
@@ -453,3 +458,409 @@ fn verify_remove_liquidity_against_research_impl() {
 			assert_balance!(pool_account, asset_b, 900152793953094461);
 		});
 }
+
+#[test]
+fn remove_liquidity_proportionally_should_work_when_withdrawing_all_shares() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let asset_c: AssetId = 3;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, asset_a, 100 * ONE),
+			(ALICE, asset_b, 200 * ONE),
+			(ALICE, asset_c, 300 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b)
+		.with_registered_asset("three".as_bytes().to_vec(), asset_c)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![asset_a, asset_b, asset_c].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				trade_fee: Permill::from_percent(0),
+				withdraw_fee: Permill::from_percent(10),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetLiquidity {
+						asset_id: asset_a,
+						amount: 100 * ONE,
+					},
+					AssetLiquidity {
+						asset_id: asset_b,
+						amount: 200 * ONE,
+					},
+					AssetLiquidity {
+						asset_id: asset_c,
+						amount: 300 * ONE,
+					},
+				],
+			},
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+			let pool_account = pool_account(pool_id);
+
+			let shares = Tokens::free_balance(pool_id, &ALICE);
+
+			assert_ok!(Stableswap::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				shares,
+				vec![
+					AssetLiquidity {
+						asset_id: asset_a,
+						amount: 100 * ONE,
+					},
+					AssetLiquidity {
+						asset_id: asset_c,
+						amount: 300 * ONE,
+					},
+				]
+				.try_into()
+				.unwrap(),
+			));
+
+			assert_balance!(ALICE, pool_id, 0u128);
+			assert_balance!(ALICE, asset_a, 100 * ONE);
+			assert_balance!(ALICE, asset_b, 200 * ONE);
+			assert_balance!(ALICE, asset_c, 300 * ONE);
+			assert_balance!(pool_account, asset_a, 0u128);
+			assert_balance!(pool_account, asset_b, 0u128);
+			assert_balance!(pool_account, asset_c, 0u128);
+		});
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_withdraw_proportional_amounts_without_fee() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let asset_c: AssetId = 3;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, asset_a, 200 * ONE),
+			(ALICE, asset_a, 100 * ONE),
+			(ALICE, asset_b, 200 * ONE),
+			(ALICE, asset_c, 300 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b)
+		.with_registered_asset("three".as_bytes().to_vec(), asset_c)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![asset_a, asset_b, asset_c].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				trade_fee: Permill::from_percent(0),
+				withdraw_fee: Permill::from_percent(10),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetLiquidity {
+						asset_id: asset_a,
+						amount: 100 * ONE,
+					},
+					AssetLiquidity {
+						asset_id: asset_b,
+						amount: 200 * ONE,
+					},
+					AssetLiquidity {
+						asset_id: asset_c,
+						amount: 300 * ONE,
+					},
+				],
+			},
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+			let pool_account = pool_account(pool_id);
+
+			assert_ok!(Stableswap::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				vec![AssetLiquidity {
+					asset_id: asset_a,
+					amount: 200 * ONE,
+				}],
+				0u128
+			));
+
+			let shares = Tokens::free_balance(pool_id, &BOB);
+			let issuance = Tokens::total_issuance(pool_id);
+
+			assert_ok!(Stableswap::remove_liquidity(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				shares,
+				BoundedVec::default(),
+			));
+
+			let expected_a = 300 * ONE * shares / issuance;
+			let expected_b = 200 * ONE * shares / issuance;
+			let expected_c = 300 * ONE * shares / issuance;
+
+			assert_balance!(BOB, pool_id, 0u128);
+			assert_balance!(BOB, asset_a, expected_a);
+			assert_balance!(BOB, asset_b, expected_b);
+			assert_balance!(BOB, asset_c, expected_c);
+			assert_balance!(pool_account, asset_a, 300 * ONE - expected_a);
+			assert_balance!(pool_account, asset_b, 200 * ONE - expected_b);
+			assert_balance!(pool_account, asset_c, 300 * ONE - expected_c);
+		});
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_fail_when_min_amount_out_is_not_reached() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 100 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![asset_a, asset_b].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				trade_fee: Permill::from_percent(0),
+				withdraw_fee: Permill::from_percent(0),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetLiquidity {
+						asset_id: asset_a,
+						amount: 100 * ONE,
+					},
+					AssetLiquidity {
+						asset_id: asset_b,
+						amount: 200 * ONE,
+					},
+				],
+			},
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			let shares = Tokens::free_balance(pool_id, &ALICE);
+
+			assert_noop!(
+				Stableswap::remove_liquidity(
+					RuntimeOrigin::signed(ALICE),
+					pool_id,
+					shares / 2,
+					vec![AssetLiquidity {
+						asset_id: asset_b,
+						amount: 100 * ONE + 1,
+					}]
+					.try_into()
+					.unwrap(),
+				),
+				Error::<Test>::SlippageLimit
+			);
+		});
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_fail_when_min_amount_asset_is_not_in_pool() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let asset_c: AssetId = 3;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 100 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b)
+		.with_registered_asset("three".as_bytes().to_vec(), asset_c)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![asset_a, asset_b].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				trade_fee: Permill::from_percent(0),
+				withdraw_fee: Permill::from_percent(0),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetLiquidity {
+						asset_id: asset_a,
+						amount: 100 * ONE,
+					},
+					AssetLiquidity {
+						asset_id: asset_b,
+						amount: 200 * ONE,
+					},
+				],
+			},
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			let shares = Tokens::free_balance(pool_id, &ALICE);
+
+			assert_noop!(
+				Stableswap::remove_liquidity(
+					RuntimeOrigin::signed(ALICE),
+					pool_id,
+					shares / 2,
+					vec![AssetLiquidity {
+						asset_id: asset_c,
+						amount: ONE,
+					}]
+					.try_into()
+					.unwrap(),
+				),
+				Error::<Test>::AssetNotInPool
+			);
+		});
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_fail_when_min_amount_asset_is_duplicated() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 100 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![asset_a, asset_b].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				trade_fee: Permill::from_percent(0),
+				withdraw_fee: Permill::from_percent(0),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetLiquidity {
+						asset_id: asset_a,
+						amount: 100 * ONE,
+					},
+					AssetLiquidity {
+						asset_id: asset_b,
+						amount: 200 * ONE,
+					},
+				],
+			},
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			let shares = Tokens::free_balance(pool_id, &ALICE);
+
+			assert_noop!(
+				Stableswap::remove_liquidity(
+					RuntimeOrigin::signed(ALICE),
+					pool_id,
+					shares / 2,
+					vec![
+						AssetLiquidity {
+							asset_id: asset_a,
+							amount: ONE,
+						},
+						AssetLiquidity {
+							asset_id: asset_a,
+							amount: 0,
+						},
+					]
+					.try_into()
+					.unwrap(),
+				),
+				Error::<Test>::DuplicateAssetLimit
+			);
+		});
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_fail_when_removing_liquidity_of_an_asset_is_not_allowed() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 100 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![asset_a, asset_b].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				trade_fee: Permill::from_percent(0),
+				withdraw_fee: Permill::from_percent(0),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetLiquidity {
+						asset_id: asset_a,
+						amount: 100 * ONE,
+					},
+					AssetLiquidity {
+						asset_id: asset_b,
+						amount: 200 * ONE,
+					},
+				],
+			},
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			assert_ok!(Stableswap::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				pool_id,
+				asset_b,
+				Tradability::SELL | Tradability::BUY | Tradability::ADD_LIQUIDITY,
+			));
+
+			let shares = Tokens::free_balance(pool_id, &ALICE);
+
+			assert_noop!(
+				Stableswap::remove_liquidity(RuntimeOrigin::signed(ALICE), pool_id, shares / 2, BoundedVec::default()),
+				Error::<Test>::NotAllowed
+			);
+		});
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_fail_when_shares_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Stableswap::remove_liquidity(RuntimeOrigin::signed(ALICE), 0u32, 0u128, BoundedVec::default()),
+			Error::<Test>::InvalidAssetAmount
+		);
+	});
+}
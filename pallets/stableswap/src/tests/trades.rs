@@ -1,9 +1,10 @@
 use crate::tests::mock::*;
 use crate::types::{AssetLiquidity, PoolInfo};
-use crate::{assert_balance, Error};
+use crate::{assert_balance, Error, D_ITERATIONS, Y_ITERATIONS};
 use std::num::NonZeroU16;
 
 use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::{PoolType, TradeExecution};
 use sp_runtime::Permill;
 
 #[test]
@@ -502,3 +503,137 @@ fn buy_should_fail_when_insufficient_amount_is_provided() {
 			);
 		});
 }
+
+#[test]
+fn sell_should_use_correct_reserves_when_pool_is_unbalanced() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, 1, 200 * ONE), (ALICE, 1, 200 * ONE), (ALICE, 2, 200 * ONE)])
+		.with_registered_asset("one".as_bytes().to_vec(), 1)
+		.with_registered_asset("two".as_bytes().to_vec(), 2)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![asset_a, asset_b].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				trade_fee: Permill::from_percent(0),
+				withdraw_fee: Permill::from_percent(0),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetLiquidity {
+						asset_id: asset_a,
+						amount: 100 * ONE,
+					},
+					AssetLiquidity {
+						asset_id: asset_b,
+						amount: 50 * ONE,
+					},
+				],
+			},
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			let expected = hydra_dx_math::stableswap::calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(
+				&[100 * ONE, 50 * ONE],
+				0,
+				1,
+				30 * ONE,
+				100,
+			)
+			.unwrap();
+
+			assert_ok!(Stableswap::sell(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				asset_a,
+				asset_b,
+				30 * ONE,
+				0,
+			));
+
+			let pool_account = pool_account(pool_id);
+
+			assert_balance!(BOB, asset_a, 170 * ONE);
+			assert_balance!(BOB, asset_b, expected);
+			assert_balance!(pool_account, asset_a, 130 * ONE);
+			assert_balance!(pool_account, asset_b, 50 * ONE - expected);
+		});
+}
+
+#[test]
+fn calculate_sell_should_use_correct_reserves_when_pool_is_unbalanced() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, 2, 200 * ONE), (ALICE, 1, 200 * ONE), (ALICE, 2, 200 * ONE)])
+		.with_registered_asset("one".as_bytes().to_vec(), 1)
+		.with_registered_asset("two".as_bytes().to_vec(), 2)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![asset_a, asset_b].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				trade_fee: Permill::from_percent(0),
+				withdraw_fee: Permill::from_percent(0),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetLiquidity {
+						asset_id: asset_a,
+						amount: 100 * ONE,
+					},
+					AssetLiquidity {
+						asset_id: asset_b,
+						amount: 50 * ONE,
+					},
+				],
+			},
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			let expected = hydra_dx_math::stableswap::calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(
+				&[100 * ONE, 50 * ONE],
+				1,
+				0,
+				30 * ONE,
+				100,
+			)
+			.unwrap();
+
+			let calculated = <Stableswap as TradeExecution<_, _, _, _>>::calculate_sell(
+				PoolType::Stableswap(pool_id),
+				asset_b,
+				asset_a,
+				30 * ONE,
+			)
+			.unwrap();
+
+			assert_eq!(calculated, expected);
+
+			assert_ok!(Stableswap::sell(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				asset_b,
+				asset_a,
+				30 * ONE,
+				calculated,
+			));
+
+			assert_balance!(BOB, asset_a, calculated);
+			assert_balance!(BOB, asset_b, 170 * ONE);
+		});
+}
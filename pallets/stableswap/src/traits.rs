@@ -0,0 +1,50 @@
+use crate::types::Balance;
+use frame_support::weights::Weight;
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+/// State of a pool before and after a trade or a liquidity change.
+pub struct PoolState<AssetId> {
+	pub assets: Vec<AssetId>,
+	pub before: Vec<Balance>,
+	pub after: Vec<Balance>,
+	pub delta: Vec<Balance>,
+	pub issuance_before: Balance,
+	pub issuance_after: Balance,
+}
+
+pub trait StableswapHooks<AssetId> {
+	fn on_liquidity_changed(pool_id: AssetId, state: PoolState<AssetId>) -> Result<(), DispatchError>;
+	fn on_trade(
+		pool_id: AssetId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		state: PoolState<AssetId>,
+	) -> Result<(), DispatchError>;
+
+	fn on_liquidity_changed_weight(n: usize) -> Weight;
+	fn on_trade_weight() -> Weight;
+}
+
+impl<AssetId> StableswapHooks<AssetId> for () {
+	fn on_liquidity_changed(_pool_id: AssetId, _state: PoolState<AssetId>) -> Result<(), DispatchError> {
+		Ok(())
+	}
+
+	fn on_trade(
+		_pool_id: AssetId,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_state: PoolState<AssetId>,
+	) -> Result<(), DispatchError> {
+		Ok(())
+	}
+
+	fn on_liquidity_changed_weight(_n: usize) -> Weight {
+		Weight::zero()
+	}
+
+	fn on_trade_weight() -> Weight {
+		Weight::zero()
+	}
+}
@@ -14,7 +14,7 @@ use orml_traits::MultiCurrency;
 use scale_info::TypeInfo;
 use sp_core::RuntimeDebug;
 
-pub(crate) type Balance = u128;
+pub type Balance = u128;
 
 /// Pool properties for 2-asset pool (v1)
 /// `assets`: pool assets
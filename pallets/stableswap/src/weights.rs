@@ -47,7 +47,9 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn create_pool() -> Weight;
 	fn add_liquidity() -> Weight;
+	fn add_liquidity_shares() -> Weight;
 	fn remove_liquidity_one_asset() -> Weight;
+	fn remove_liquidity() -> Weight;
 	fn sell() -> Weight;
 	fn buy() -> Weight;
 	fn set_asset_tradable_state() -> Weight;
@@ -74,6 +76,16 @@ impl<T: frame_system::Config> WeightInfo for BasiliskWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(9 as u64))
 			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
+	fn add_liquidity_shares() -> Weight {
+		Weight::from_ref_time(61_322_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	fn remove_liquidity() -> Weight {
+		Weight::from_ref_time(56_207_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
 	fn sell() -> Weight {
 		Weight::from_ref_time(47_851_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(9 as u64))
@@ -112,6 +124,16 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(9 as u64))
 			.saturating_add(RocksDbWeight::get().writes(4 as u64))
 	}
+	fn add_liquidity_shares() -> Weight {
+		Weight::from_ref_time(61_322_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	fn remove_liquidity() -> Weight {
+		Weight::from_ref_time(56_207_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(13 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
 	fn sell() -> Weight {
 		Weight::from_ref_time(47_851_000 as u64)
 			.saturating_add(RocksDbWeight::get().reads(9 as u64))
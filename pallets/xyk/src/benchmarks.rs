@@ -0,0 +1,125 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+use hydradx_traits::Registry;
+use orml_traits::MultiCurrencyExtended;
+use sp_std::vec;
+
+const SEED: u32 = 1;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+fn funded_account<T: Config>(name: &'static str, index: u32, assets: &[AssetId]) -> T::AccountId {
+	let caller: T::AccountId = account(name, index, SEED);
+
+	for asset in assets.iter() {
+		T::Currency::update_balance(*asset, &caller, 1_000_000_000_000_000i128).unwrap();
+	}
+
+	caller
+}
+
+fn register_assets<T: Config>() -> Result<(AssetId, AssetId), DispatchError> {
+	let asset_a = T::AssetRegistry::create_asset(&b"TKNA".to_vec(), 1u128)?;
+	let asset_b = T::AssetRegistry::create_asset(&b"TKNB".to_vec(), 1u128)?;
+
+	Ok((asset_a, asset_b))
+}
+
+benchmarks! {
+	create_pool {
+		let (asset_a, asset_b) = register_assets::<T>()?;
+
+		let caller = funded_account::<T>("caller", 0, &[asset_a, asset_b]);
+
+		let amount_a: Balance = 10 * ONE;
+		let amount_b: Balance = 20 * ONE;
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, amount_a, asset_b, amount_b)
+	verify {
+		assert_eq!(T::Currency::free_balance(asset_a, &caller), 990 * ONE);
+		assert_eq!(T::Currency::free_balance(asset_b, &caller), 980 * ONE);
+	}
+
+	add_liquidity {
+		let (asset_a, asset_b) = register_assets::<T>()?;
+
+		let caller = funded_account::<T>("caller", 0, &[asset_a, asset_b]);
+		let maker = funded_account::<T>("maker", 1, &[asset_a, asset_b]);
+
+		let amount_a: Balance = 10 * ONE;
+		let amount_b_max_limit: Balance = 20 * ONE;
+
+		crate::Pallet::<T>::create_pool(RawOrigin::Signed(maker).into(), asset_a, 100 * ONE, asset_b, 200 * ONE)?;
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, amount_a, amount_b_max_limit)
+	verify {
+		assert_eq!(T::Currency::free_balance(asset_a, &caller), 990 * ONE);
+		assert_eq!(T::Currency::free_balance(asset_b, &caller), 980 * ONE - 1);
+	}
+
+	remove_liquidity {
+		let (asset_a, asset_b) = register_assets::<T>()?;
+
+		let caller = funded_account::<T>("caller", 0, &[asset_a, asset_b]);
+		let maker = funded_account::<T>("maker", 1, &[asset_a, asset_b]);
+
+		crate::Pallet::<T>::create_pool(RawOrigin::Signed(maker).into(), asset_a, 100 * ONE, asset_b, 200 * ONE)?;
+		crate::Pallet::<T>::add_liquidity(RawOrigin::Signed(caller.clone()).into(), asset_a, asset_b, 10 * ONE, 30 * ONE)?;
+
+		let share_token = <Pallet<T> as AMM<_, _, _, _>>::get_share_token(AssetPair::new(asset_a, asset_b));
+		let shares = T::Currency::free_balance(share_token, &caller);
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, shares)
+	verify {
+		assert_eq!(T::Currency::free_balance(share_token, &caller), 0u128);
+	}
+
+	sell {
+		let (asset_a, asset_b) = register_assets::<T>()?;
+
+		let caller = funded_account::<T>("caller", 0, &[asset_a, asset_b]);
+		let maker = funded_account::<T>("maker", 1, &[asset_a, asset_b]);
+
+		crate::Pallet::<T>::create_pool(RawOrigin::Signed(maker).into(), asset_a, 100 * ONE, asset_b, 200 * ONE)?;
+
+		let amount: Balance = ONE;
+		let min_bought: Balance = 1_000;
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, amount, min_bought)
+	verify {
+		assert_eq!(T::Currency::free_balance(asset_a, &caller), 999 * ONE);
+		assert!(T::Currency::free_balance(asset_b, &caller) > 1_000 * ONE);
+	}
+
+	buy {
+		let (asset_a, asset_b) = register_assets::<T>()?;
+
+		let caller = funded_account::<T>("caller", 0, &[asset_a, asset_b]);
+		let maker = funded_account::<T>("maker", 1, &[asset_a, asset_b]);
+
+		crate::Pallet::<T>::create_pool(RawOrigin::Signed(maker).into(), asset_a, 100 * ONE, asset_b, 200 * ONE)?;
+
+		let amount: Balance = ONE;
+		let max_sold: Balance = 10 * ONE;
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, amount, max_sold)
+	verify {
+		assert_eq!(T::Currency::free_balance(asset_a, &caller), 1_001 * ONE);
+		assert!(T::Currency::free_balance(asset_b, &caller) < 1_000 * ONE);
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
+}
@@ -0,0 +1,1048 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # XYK pallet
+//!
+//! Constant product AMM implementation.
+//!
+//! ## Overview
+//!
+//! Pool of two assets is created by `create_pool` with initial liquidity of both assets. The initial liquidity
+//! determines the initial price of the pool.
+//!
+//! LP receives shares of the pool's share token. Share token is registered in the asset registry as
+//! `AssetType::PoolShare` of the pool assets.
+//!
+//! Trades are executed according to the `x * y = k` invariant. Trade fee is paid in the asset leaving the pool
+//! for sells and in the asset entering the pool for buys.
+//!
+//! Pool is destroyed when all liquidity is removed.
+//!
+//! Trades and liquidity changes are reported to `AMMHandler` (e.g. the EMA oracle).
+//!
+//! ## Interface
+//!
+//! ### Dispatchable functions
+//!
+//! * `create_pool` - create new pool with initial liquidity.
+//! * `add_liquidity` - add liquidity of both assets to a pool.
+//! * `remove_liquidity` - remove liquidity from a pool.
+//! * `sell` - sell an asset to a pool.
+//! * `buy` - buy an asset from a pool.
+//!
+//! ### Traits
+//!
+//! Pallet implements `AMM`, `AMMPosition`, `TradeExecution` and `SpotPriceProvider`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::sp_runtime::{
+	traits::{Hash, Zero},
+	DispatchError,
+};
+use frame_support::{dispatch::DispatchResult, ensure, traits::Get, transactional};
+use frame_system::ensure_signed;
+use hydra_dx_math::xyk;
+use hydradx_traits::{
+	pools::DustRemovalAccountWhitelist, AMMPosition, AMMTransfer, AssetPairAccountIdFor, CanCreatePool,
+	OnCreatePoolHandler, OnLiquidityChangedHandler, OnTradeHandler, ShareTokenRegistry, Source, AMM,
+};
+use orml_traits::{MultiCurrency, MultiCurrencyExtended};
+use sp_core::crypto::UncheckedFrom;
+use sp_std::{marker::PhantomData, vec, vec::Vec};
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarks;
+
+mod provider;
+mod trade_execution;
+pub mod types;
+pub mod weights;
+
+pub use provider::*;
+pub use trade_execution::*;
+pub use types::*;
+use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+/// XYK pool account id identifier.
+pub const POOL_IDENTIFIER: &str = "xyk";
+
+type AMMTransferOf<T> = AMMTransfer<<T as frame_system::Config>::AccountId, AssetId, AssetPair, Balance>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::OriginFor;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(crate) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Registry support - used to register share tokens.
+		type AssetRegistry: ShareTokenRegistry<AssetId, Vec<u8>, Balance, DispatchError>;
+
+		/// Account id constructor - pool accounts are derived from pool assets.
+		type AssetPairAccountId: AssetPairAccountIdFor<AssetId, Self::AccountId>;
+
+		/// Multi currency for transfers.
+		type Currency: MultiCurrencyExtended<Self::AccountId, CurrencyId = AssetId, Balance = Balance, Amount = Amount>;
+
+		/// Native asset id.
+		#[pallet::constant]
+		type NativeAssetId: Get<AssetId>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+
+		/// Trading fee rate.
+		#[pallet::constant]
+		type GetExchangeFee: Get<(u32, u32)>;
+
+		/// Minimum trading limit.
+		#[pallet::constant]
+		type MinTradingLimit: Get<Balance>;
+
+		/// Minimum pool liquidity.
+		#[pallet::constant]
+		type MinPoolLiquidity: Get<Balance>;
+
+		/// Max fraction of pool to sell in single transaction.
+		#[pallet::constant]
+		type MaxInRatio: Get<u128>;
+
+		/// Max fraction of pool to buy in single transaction.
+		#[pallet::constant]
+		type MaxOutRatio: Get<u128>;
+
+		/// Called to ensure that pool can be created.
+		type CanCreatePool: CanCreatePool<AssetId>;
+
+		/// AMM handlers - notified about pool creation, trades and liquidity changes.
+		type AMMHandler: OnCreatePoolHandler<AssetId>
+			+ OnTradeHandler<AssetId, Balance>
+			+ OnLiquidityChangedHandler<AssetId, Balance>;
+
+		/// Account whitelist manager to exclude pool accounts from dusting mechanism.
+		type NonDustableWhitelistHandler: DustRemovalAccountWhitelist<Self::AccountId, Error = DispatchError>;
+
+		/// Source identifier of the pallet used when reporting to `AMMHandler`.
+		#[pallet::constant]
+		type OracleSource: Get<Source>;
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// It is not allowed to create a pool between same assets.
+		CannotCreatePoolWithSameAssets,
+
+		/// Liquidity has not reached the required minimum.
+		InsufficientLiquidity,
+
+		/// Amount is less than min trading limit.
+		InsufficientTradingAmount,
+
+		/// Liquidity is zero.
+		ZeroLiquidity,
+
+		/// It is not allowed to create a pool.
+		CannotCreatePool,
+
+		/// Asset balance is not sufficient.
+		InsufficientAssetBalance,
+
+		/// Not enough asset liquidity in the pool.
+		InsufficientPoolAssetBalance,
+
+		/// Liquidity pool for given assets does not exist.
+		TokenPoolNotFound,
+
+		/// Liquidity pool for given assets already exists.
+		TokenPoolAlreadyExists,
+
+		/// Overflow
+		AddAssetAmountInvalid,
+
+		/// Overflow
+		RemoveAssetAmountInvalid,
+
+		/// Overflow
+		SellAssetAmountInvalid,
+
+		/// Overflow
+		BuyAssetAmountInvalid,
+
+		/// Overflow
+		FeeAmountInvalid,
+
+		/// Discounted fee is not supported.
+		CannotApplyDiscount,
+
+		/// Max fraction of pool to buy in single transaction has been exceeded.
+		MaxOutRatioExceeded,
+
+		/// Max fraction of pool to sell in single transaction has been exceeded.
+		MaxInRatioExceeded,
+
+		/// Overflow
+		Overflow,
+
+		/// Invalid amount of minted shares.
+		InvalidMintedLiquidity,
+
+		/// Invalid amount of liquidity to remove.
+		InvalidLiquidityAmount,
+
+		/// Asset amount has exceeded given limit.
+		AssetAmountExceededLimit,
+
+		/// Asset amount has not reached given limit.
+		AssetAmountNotReachedLimit,
+
+		/// Share balance is not sufficient.
+		InsufficientShares,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// New liquidity was provided to the pool.
+		LiquidityAdded {
+			who: T::AccountId,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			amount_a: Balance,
+			amount_b: Balance,
+		},
+
+		/// Liquidity was removed from the pool.
+		LiquidityRemoved {
+			who: T::AccountId,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			shares: Balance,
+		},
+
+		/// Pool was created.
+		PoolCreated {
+			who: T::AccountId,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			initial_shares_amount: Balance,
+			share_token: AssetId,
+			pool: T::AccountId,
+		},
+
+		/// Pool was destroyed.
+		PoolDestroyed {
+			who: T::AccountId,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			share_token: AssetId,
+			pool: T::AccountId,
+		},
+
+		/// Asset sale executed. Trade fee paid in asset leaving the pool (already subtracted from sale_price).
+		SellExecuted {
+			who: T::AccountId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+			sale_price: Balance,
+			fee_asset: AssetId,
+			fee_amount: Balance,
+			pool: T::AccountId,
+		},
+
+		/// Asset purchase executed. Trade fee paid in asset entering the pool (already included in buy_price).
+		BuyExecuted {
+			who: T::AccountId,
+			asset_out: AssetId,
+			asset_in: AssetId,
+			amount: Balance,
+			buy_price: Balance,
+			fee_asset: AssetId,
+			fee_amount: Balance,
+			pool: T::AccountId,
+		},
+	}
+
+	/// Asset id of the share token of a pool.
+	#[pallet::storage]
+	#[pallet::getter(fn share_token)]
+	pub type ShareToken<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, AssetId, ValueQuery>;
+
+	/// Total liquidity (share token issuance) of a pool.
+	#[pallet::storage]
+	#[pallet::getter(fn total_liquidity)]
+	pub type TotalLiquidity<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
+	/// Asset pair of a pool.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_assets)]
+	pub type PoolAssets<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (AssetId, AssetId), OptionQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create new pool for given asset pair.
+		///
+		/// Registers new pool for given asset pair (`asset_a` and `asset_b`) in asset registry.
+		/// Asset registry creates new id or returns previously created one if such pool existed before.
+		///
+		/// Pool is created with initial liquidity provided by `origin`.
+		/// Shares are issued with specified initial price and total share issuance is equal to the amount
+		/// of the asset with the lower asset id.
+		///
+		/// Emits `PoolCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool()
+			.saturating_add(T::AMMHandler::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			amount_a: Balance,
+			asset_b: AssetId,
+			amount_b: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::CanCreatePool::can_create(asset_a, asset_b),
+				Error::<T>::CannotCreatePool
+			);
+
+			ensure!(
+				amount_a >= T::MinPoolLiquidity::get() && amount_b >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientLiquidity
+			);
+
+			ensure!(asset_a != asset_b, Error::<T>::CannotCreatePoolWithSameAssets);
+
+			let asset_pair = AssetPair::new(asset_a, asset_b);
+
+			ensure!(!Self::exists(asset_pair), Error::<T>::TokenPoolAlreadyExists);
+
+			ensure!(
+				T::Currency::free_balance(asset_a, &who) >= amount_a,
+				Error::<T>::InsufficientAssetBalance
+			);
+
+			ensure!(
+				T::Currency::free_balance(asset_b, &who) >= amount_b,
+				Error::<T>::InsufficientAssetBalance
+			);
+
+			let shares_added = if asset_a < asset_b { amount_a } else { amount_b };
+
+			let pair_account = Self::get_pair_id(asset_pair);
+
+			let token_name = asset_pair.name();
+
+			let share_token = T::AssetRegistry::get_or_create_shared_asset(
+				token_name,
+				vec![asset_a, asset_b],
+				T::MinPoolLiquidity::get(),
+			)?;
+
+			T::AMMHandler::on_create_pool(asset_pair.asset_in, asset_pair.asset_out)?;
+
+			T::NonDustableWhitelistHandler::add_account(&pair_account)?;
+
+			<ShareToken<T>>::insert(&pair_account, &share_token);
+			<PoolAssets<T>>::insert(&pair_account, (asset_a, asset_b));
+
+			T::Currency::transfer(asset_a, &who, &pair_account, amount_a)?;
+			T::Currency::transfer(asset_b, &who, &pair_account, amount_b)?;
+
+			T::Currency::deposit(share_token, &who, shares_added)?;
+
+			<TotalLiquidity<T>>::insert(&pair_account, shares_added);
+
+			T::AMMHandler::on_liquidity_changed(
+				T::OracleSource::get(),
+				asset_a,
+				asset_b,
+				amount_a,
+				amount_b,
+				amount_a,
+				amount_b,
+			)
+			.map_err(|(_w, e)| e)?;
+
+			Self::deposit_event(Event::PoolCreated {
+				who,
+				asset_a,
+				asset_b,
+				initial_shares_amount: shares_added,
+				share_token,
+				pool: pair_account,
+			});
+
+			Ok(())
+		}
+
+		/// Add liquidity to previously created asset pair pool.
+		///
+		/// Shares are issued with current price.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider
+		/// - `asset_a`: first asset of the pool
+		/// - `asset_b`: second asset of the pool
+		/// - `amount_a`: amount of `asset_a` to provide
+		/// - `amount_b_max_limit`: maximum amount of `asset_b` to provide
+		///
+		/// Emits `LiquidityAdded` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
+			.saturating_add(T::AMMHandler::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn add_liquidity(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			amount_a: Balance,
+			amount_b_max_limit: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let asset_pair = AssetPair::new(asset_a, asset_b);
+
+			ensure!(Self::exists(asset_pair), Error::<T>::TokenPoolNotFound);
+
+			ensure!(
+				amount_a >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+
+			ensure!(!amount_b_max_limit.is_zero(), Error::<T>::ZeroLiquidity);
+
+			ensure!(
+				T::Currency::free_balance(asset_a, &who) >= amount_a,
+				Error::<T>::InsufficientAssetBalance
+			);
+
+			ensure!(
+				T::Currency::free_balance(asset_b, &who) >= amount_b_max_limit,
+				Error::<T>::InsufficientAssetBalance
+			);
+
+			let pair_account = Self::get_pair_id(asset_pair);
+
+			let share_token = Self::share_token(&pair_account);
+
+			let asset_a_reserve = T::Currency::free_balance(asset_a, &pair_account);
+			let asset_b_reserve = T::Currency::free_balance(asset_b, &pair_account);
+			let total_liquidity = Self::total_liquidity(&pair_account);
+
+			let amount_b = xyk::calculate_liquidity_in(asset_a_reserve, asset_b_reserve, amount_a)
+				.map_err(|_| Error::<T>::AddAssetAmountInvalid)?;
+
+			ensure!(!amount_b.is_zero(), Error::<T>::InvalidLiquidityAmount);
+
+			ensure!(amount_b <= amount_b_max_limit, Error::<T>::AssetAmountExceededLimit);
+
+			let shares_added =
+				xyk::calculate_shares(asset_a_reserve, amount_a, total_liquidity).ok_or(Error::<T>::Overflow)?;
+
+			ensure!(!shares_added.is_zero(), Error::<T>::InvalidMintedLiquidity);
+
+			// Make sure that the resulting share balance is above the minimum pool liquidity.
+			ensure!(
+				T::Currency::free_balance(share_token, &who).saturating_add(shares_added) >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientLiquidity
+			);
+
+			let liquidity_amount = total_liquidity
+				.checked_add(shares_added)
+				.ok_or(Error::<T>::InvalidLiquidityAmount)?;
+
+			T::Currency::transfer(asset_a, &who, &pair_account, amount_a)?;
+			T::Currency::transfer(asset_b, &who, &pair_account, amount_b)?;
+
+			T::Currency::deposit(share_token, &who, shares_added)?;
+
+			<TotalLiquidity<T>>::insert(&pair_account, liquidity_amount);
+
+			let liquidity_a = T::Currency::total_balance(asset_a, &pair_account);
+			let liquidity_b = T::Currency::total_balance(asset_b, &pair_account);
+			T::AMMHandler::on_liquidity_changed(
+				T::OracleSource::get(),
+				asset_a,
+				asset_b,
+				amount_a,
+				amount_b,
+				liquidity_a,
+				liquidity_b,
+			)
+			.map_err(|(_w, e)| e)?;
+
+			Self::deposit_event(Event::LiquidityAdded {
+				who,
+				asset_a,
+				asset_b,
+				amount_a,
+				amount_b,
+			});
+
+			Ok(())
+		}
+
+		/// Remove liquidity from specific liquidity pool in the form of burning shares.
+		///
+		/// If liquidity in the pool reaches 0, it is destroyed.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider
+		/// - `asset_a`: first asset of the pool
+		/// - `asset_b`: second asset of the pool
+		/// - `liquidity_amount`: amount of shares to burn
+		///
+		/// Emits 'LiquidityRemoved' when successful.
+		/// Emits 'PoolDestroyed' when pool is destroyed.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity()
+			.saturating_add(T::AMMHandler::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			liquidity_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let asset_pair = AssetPair::new(asset_a, asset_b);
+
+			ensure!(!liquidity_amount.is_zero(), Error::<T>::ZeroLiquidity);
+
+			ensure!(Self::exists(asset_pair), Error::<T>::TokenPoolNotFound);
+
+			let pair_account = Self::get_pair_id(asset_pair);
+
+			let share_token = Self::share_token(&pair_account);
+
+			let total_shares = Self::total_liquidity(&pair_account);
+
+			ensure!(total_shares >= liquidity_amount, Error::<T>::InsufficientLiquidity);
+
+			ensure!(
+				T::Currency::free_balance(share_token, &who) >= liquidity_amount,
+				Error::<T>::InsufficientShares
+			);
+
+			let remaining_shares = T::Currency::free_balance(share_token, &who).saturating_sub(liquidity_amount);
+			ensure!(
+				remaining_shares.is_zero() || remaining_shares >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientLiquidity
+			);
+
+			let asset_a_reserve = T::Currency::free_balance(asset_a, &pair_account);
+			let asset_b_reserve = T::Currency::free_balance(asset_b, &pair_account);
+
+			let (remove_amount_a, remove_amount_b) =
+				xyk::calculate_liquidity_out(asset_a_reserve, asset_b_reserve, liquidity_amount, total_shares)
+					.map_err(|_| Error::<T>::RemoveAssetAmountInvalid)?;
+
+			let liquidity_left = total_shares
+				.checked_sub(liquidity_amount)
+				.ok_or(Error::<T>::InvalidLiquidityAmount)?;
+
+			T::Currency::transfer(asset_a, &pair_account, &who, remove_amount_a)?;
+			T::Currency::transfer(asset_b, &pair_account, &who, remove_amount_b)?;
+
+			T::Currency::withdraw(share_token, &who, liquidity_amount)?;
+
+			<TotalLiquidity<T>>::insert(&pair_account, liquidity_left);
+
+			let liquidity_a = T::Currency::total_balance(asset_a, &pair_account);
+			let liquidity_b = T::Currency::total_balance(asset_b, &pair_account);
+			T::AMMHandler::on_liquidity_changed(
+				T::OracleSource::get(),
+				asset_a,
+				asset_b,
+				remove_amount_a,
+				remove_amount_b,
+				liquidity_a,
+				liquidity_b,
+			)
+			.map_err(|(_w, e)| e)?;
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				who: who.clone(),
+				asset_a,
+				asset_b,
+				shares: liquidity_amount,
+			});
+
+			if liquidity_left.is_zero() {
+				<ShareToken<T>>::remove(&pair_account);
+				<PoolAssets<T>>::remove(&pair_account);
+				<TotalLiquidity<T>>::remove(&pair_account);
+
+				// Ignore the failure, this cant stop liquidity removal
+				let r = T::NonDustableWhitelistHandler::remove_account(&pair_account);
+
+				if r.is_err() {
+					log::trace!(target: "xyk::remove_liquidity", "Failed to remove account {:?} from dust-removal whitelist. Reason: {:?}", pair_account, r);
+				}
+
+				Self::deposit_event(Event::PoolDestroyed {
+					who,
+					asset_a,
+					asset_b,
+					share_token,
+					pool: pair_account,
+				});
+			}
+
+			Ok(())
+		}
+
+		/// Trade asset in for asset out.
+		///
+		/// Executes a swap of `asset_in` for `asset_out`. Price is determined by the liquidity pool.
+		///
+		/// `max_limit` - minimum amount of `asset_out` / amount of asset_out to be obtained from the pool in exchange for `asset_in`.
+		///
+		/// Emits `SellExecuted` when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::sell()
+			.saturating_add(T::AMMHandler::on_trade_weight()))]
+		#[transactional]
+		pub fn sell(
+			origin: OriginFor<T>,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+			max_limit: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as AMM<_, _, _, _>>::sell(&who, AssetPair { asset_in, asset_out }, amount, max_limit, false)?;
+
+			Ok(())
+		}
+
+		/// Trade asset in for asset out.
+		///
+		/// Executes a swap of `asset_in` for `asset_out`. Price is determined by the liquidity pool.
+		///
+		/// `max_limit` - maximum amount of `asset_in` to be sold in exchange for `asset_out`.
+		///
+		/// Emits `BuyExecuted` when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy()
+			.saturating_add(T::AMMHandler::on_trade_weight()))]
+		#[transactional]
+		pub fn buy(
+			origin: OriginFor<T>,
+			asset_out: AssetId,
+			asset_in: AssetId,
+			amount: Balance,
+			max_limit: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<Self as AMM<_, _, _, _>>::buy(&who, AssetPair { asset_in, asset_out }, amount, max_limit, false)?;
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Return balance of each asset in selected liquidity pool.
+	pub fn get_pool_balances(pool_address: T::AccountId) -> Option<Vec<(AssetId, Balance)>> {
+		let mut balances = Vec::new();
+
+		if let Some(assets) = Self::get_pool_assets(&pool_address) {
+			for item in &assets {
+				let reserve = T::Currency::free_balance(*item, &pool_address);
+				balances.push((*item, reserve));
+			}
+		}
+		Some(balances)
+	}
+
+	/// Calculate discounted trade fee
+	fn calculate_fee(amount: Balance) -> Result<Balance, DispatchError> {
+		hydra_dx_math::fee::calculate_pool_trade_fee(amount, T::GetExchangeFee::get())
+			.ok_or_else(|| Error::<T>::FeeAmountInvalid.into())
+	}
+
+	/// Calculate amount received for given amount of `asset_in` and the fee paid in `asset_out`.
+	pub(crate) fn calculate_sell_amounts(
+		assets: AssetPair,
+		amount: Balance,
+	) -> Result<(Balance, Balance), DispatchError> {
+		ensure!(Self::exists(assets), Error::<T>::TokenPoolNotFound);
+
+		let pair_account = Self::get_pair_id(assets);
+
+		let asset_in_reserve = T::Currency::free_balance(assets.asset_in, &pair_account);
+		let asset_out_reserve = T::Currency::free_balance(assets.asset_out, &pair_account);
+
+		ensure!(
+			amount <= asset_in_reserve / T::MaxInRatio::get(),
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		let amount_out = xyk::calculate_out_given_in(asset_in_reserve, asset_out_reserve, amount)
+			.map_err(|_| Error::<T>::SellAssetAmountInvalid)?;
+
+		let transfer_fee = Self::calculate_fee(amount_out)?;
+
+		let amount_out_without_fee = amount_out
+			.checked_sub(transfer_fee)
+			.ok_or(Error::<T>::SellAssetAmountInvalid)?;
+
+		ensure!(asset_out_reserve > amount_out, Error::<T>::InsufficientPoolAssetBalance);
+
+		ensure!(
+			amount_out <= asset_out_reserve / T::MaxOutRatio::get(),
+			Error::<T>::MaxOutRatioExceeded
+		);
+
+		Ok((amount_out_without_fee, transfer_fee))
+	}
+
+	/// Calculate amount of `asset_in` required to receive given amount of `asset_out` and the fee paid in `asset_in`.
+	pub(crate) fn calculate_buy_amounts(
+		assets: AssetPair,
+		amount: Balance,
+	) -> Result<(Balance, Balance), DispatchError> {
+		ensure!(Self::exists(assets), Error::<T>::TokenPoolNotFound);
+
+		let pair_account = Self::get_pair_id(assets);
+
+		let asset_out_reserve = T::Currency::free_balance(assets.asset_out, &pair_account);
+		let asset_in_reserve = T::Currency::free_balance(assets.asset_in, &pair_account);
+
+		ensure!(asset_out_reserve > amount, Error::<T>::InsufficientPoolAssetBalance);
+
+		ensure!(
+			amount <= asset_out_reserve / T::MaxOutRatio::get(),
+			Error::<T>::MaxOutRatioExceeded
+		);
+
+		let buy_price = xyk::calculate_in_given_out(asset_out_reserve, asset_in_reserve, amount)
+			.map_err(|_| Error::<T>::BuyAssetAmountInvalid)?;
+
+		let transfer_fee = Self::calculate_fee(buy_price)?;
+
+		ensure!(
+			buy_price <= asset_in_reserve / T::MaxInRatio::get(),
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		let amount_in = buy_price
+			.checked_add(transfer_fee)
+			.ok_or(Error::<T>::BuyAssetAmountInvalid)?;
+
+		Ok((amount_in, transfer_fee))
+	}
+}
+
+// Implementation of AMM API which makes possible to plug the AMM pool into the exchange pallet.
+impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
+	fn exists(assets: AssetPair) -> bool {
+		let pair_account = Self::get_pair_id(assets);
+		<ShareToken<T>>::contains_key(&pair_account)
+	}
+
+	fn get_pair_id(assets: AssetPair) -> T::AccountId {
+		T::AssetPairAccountId::from_assets(assets.asset_in, assets.asset_out, POOL_IDENTIFIER)
+	}
+
+	fn get_share_token(assets: AssetPair) -> AssetId {
+		let pair_account = Self::get_pair_id(assets);
+		Self::share_token(&pair_account)
+	}
+
+	fn get_pool_assets(pool_account_id: &T::AccountId) -> Option<Vec<AssetId>> {
+		Self::pool_assets(pool_account_id).map(|pool_assets| vec![pool_assets.0, pool_assets.1])
+	}
+
+	fn get_spot_price_unchecked(asset_a: AssetId, asset_b: AssetId, amount: Balance) -> Balance {
+		let pair_account = Self::get_pair_id(AssetPair {
+			asset_out: asset_a,
+			asset_in: asset_b,
+		});
+
+		let asset_a_reserve = T::Currency::free_balance(asset_a, &pair_account);
+		let asset_b_reserve = T::Currency::free_balance(asset_b, &pair_account);
+
+		xyk::calculate_spot_price(asset_a_reserve, asset_b_reserve, amount).unwrap_or_else(|_| Balance::zero())
+	}
+
+	/// Validate a sell. Perform all necessary checks and calculations.
+	/// No storage changes are performed yet.
+	///
+	/// Result is transfer object which is used to execute the sell.
+	fn validate_sell(
+		who: &T::AccountId,
+		assets: AssetPair,
+		amount: Balance,
+		min_bought: Balance,
+		discount: bool,
+	) -> Result<AMMTransferOf<T>, DispatchError> {
+		ensure!(!discount, Error::<T>::CannotApplyDiscount);
+
+		ensure!(
+			amount >= T::MinTradingLimit::get(),
+			Error::<T>::InsufficientTradingAmount
+		);
+
+		ensure!(Self::exists(assets), Error::<T>::TokenPoolNotFound);
+
+		ensure!(
+			T::Currency::free_balance(assets.asset_in, who) >= amount,
+			Error::<T>::InsufficientAssetBalance
+		);
+
+		let (amount_out, transfer_fee) = Self::calculate_sell_amounts(assets, amount)?;
+
+		ensure!(min_bought <= amount_out, Error::<T>::AssetAmountNotReachedLimit);
+
+		Ok(AMMTransfer {
+			origin: who.clone(),
+			assets,
+			amount,
+			amount_b: amount_out,
+			discount,
+			discount_amount: Balance::zero(),
+			fee: (assets.asset_out, transfer_fee),
+		})
+	}
+
+	/// Execute sell. validate_sell must be called first.
+	/// Perform necessary storage/state changes.
+	/// Note : the execution should not return error as everything was previously verified and validated.
+	#[transactional]
+	fn execute_sell(transfer: &AMMTransferOf<T>) -> DispatchResult {
+		let pair_account = Self::get_pair_id(transfer.assets);
+
+		T::Currency::transfer(
+			transfer.assets.asset_in,
+			&transfer.origin,
+			&pair_account,
+			transfer.amount,
+		)?;
+		T::Currency::transfer(
+			transfer.assets.asset_out,
+			&pair_account,
+			&transfer.origin,
+			transfer.amount_b,
+		)?;
+
+		let liquidity_in = T::Currency::total_balance(transfer.assets.asset_in, &pair_account);
+		let liquidity_out = T::Currency::total_balance(transfer.assets.asset_out, &pair_account);
+		T::AMMHandler::on_trade(
+			T::OracleSource::get(),
+			transfer.assets.asset_in,
+			transfer.assets.asset_out,
+			transfer.amount,
+			transfer.amount_b,
+			liquidity_in,
+			liquidity_out,
+		)
+		.map_err(|(_w, e)| e)?;
+
+		Self::deposit_event(Event::<T>::SellExecuted {
+			who: transfer.origin.clone(),
+			asset_in: transfer.assets.asset_in,
+			asset_out: transfer.assets.asset_out,
+			amount: transfer.amount,
+			sale_price: transfer.amount_b,
+			fee_asset: transfer.fee.0,
+			fee_amount: transfer.fee.1,
+			pool: pair_account,
+		});
+
+		Ok(())
+	}
+
+	/// Validate a buy. Perform all necessary checks and calculations.
+	/// No storage changes are performed yet.
+	///
+	/// Result is transfer object which is used to execute the buy.
+	fn validate_buy(
+		who: &T::AccountId,
+		assets: AssetPair,
+		amount: Balance,
+		max_limit: Balance,
+		discount: bool,
+	) -> Result<AMMTransferOf<T>, DispatchError> {
+		ensure!(!discount, Error::<T>::CannotApplyDiscount);
+
+		ensure!(
+			amount >= T::MinTradingLimit::get(),
+			Error::<T>::InsufficientTradingAmount
+		);
+
+		ensure!(Self::exists(assets), Error::<T>::TokenPoolNotFound);
+
+		let (amount_in, transfer_fee) = Self::calculate_buy_amounts(assets, amount)?;
+
+		ensure!(
+			T::Currency::free_balance(assets.asset_in, who) >= amount_in,
+			Error::<T>::InsufficientAssetBalance
+		);
+
+		ensure!(max_limit >= amount_in, Error::<T>::AssetAmountExceededLimit);
+
+		Ok(AMMTransfer {
+			origin: who.clone(),
+			assets,
+			amount,
+			amount_b: amount_in,
+			discount,
+			discount_amount: Balance::zero(),
+			fee: (assets.asset_in, transfer_fee),
+		})
+	}
+
+	/// Execute buy. validate_buy must be called first.
+	/// Perform necessary storage/state changes.
+	/// Note : the execution should not return error as everything was previously verified and validated.
+	#[transactional]
+	fn execute_buy(transfer: &AMMTransferOf<T>) -> DispatchResult {
+		let pair_account = Self::get_pair_id(transfer.assets);
+
+		T::Currency::transfer(
+			transfer.assets.asset_out,
+			&pair_account,
+			&transfer.origin,
+			transfer.amount,
+		)?;
+		T::Currency::transfer(
+			transfer.assets.asset_in,
+			&transfer.origin,
+			&pair_account,
+			transfer.amount_b,
+		)?;
+
+		let liquidity_in = T::Currency::total_balance(transfer.assets.asset_in, &pair_account);
+		let liquidity_out = T::Currency::total_balance(transfer.assets.asset_out, &pair_account);
+		T::AMMHandler::on_trade(
+			T::OracleSource::get(),
+			transfer.assets.asset_in,
+			transfer.assets.asset_out,
+			transfer.amount_b,
+			transfer.amount,
+			liquidity_in,
+			liquidity_out,
+		)
+		.map_err(|(_w, e)| e)?;
+
+		Self::deposit_event(Event::<T>::BuyExecuted {
+			who: transfer.origin.clone(),
+			asset_out: transfer.assets.asset_out,
+			asset_in: transfer.assets.asset_in,
+			amount: transfer.amount,
+			buy_price: transfer.amount_b,
+			fee_asset: transfer.fee.0,
+			fee_amount: transfer.fee.1,
+			pool: pair_account,
+		});
+
+		Ok(())
+	}
+
+	fn get_min_trading_limit() -> Balance {
+		T::MinTradingLimit::get()
+	}
+
+	fn get_min_pool_liquidity() -> Balance {
+		T::MinPoolLiquidity::get()
+	}
+
+	fn get_max_in_ratio() -> u128 {
+		T::MaxInRatio::get()
+	}
+
+	fn get_max_out_ratio() -> u128 {
+		T::MaxOutRatio::get()
+	}
+
+	fn get_fee(_pool_account_id: &T::AccountId) -> (u32, u32) {
+		T::GetExchangeFee::get()
+	}
+}
+
+impl<T: Config> AMMPosition<AssetId, Balance> for Pallet<T> {
+	type Error = DispatchError;
+
+	fn get_liquidity_behind_shares(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		shares_amount: Balance,
+	) -> Result<(Balance, Balance), Self::Error> {
+		let asset_pair = AssetPair::new(asset_a, asset_b);
+		let pair_account = Self::get_pair_id(asset_pair);
+
+		let total_shares = Self::total_liquidity(&pair_account);
+
+		let asset_a_reserve = T::Currency::free_balance(asset_a, &pair_account);
+		let asset_b_reserve = T::Currency::free_balance(asset_b, &pair_account);
+
+		xyk::calculate_liquidity_out(asset_a_reserve, asset_b_reserve, shares_amount, total_shares)
+			.map_err(|_| Error::<T>::RemoveAssetAmountInvalid.into())
+	}
+}
+
+/// Account id constructor for XYK pools.
+///
+/// Account id is derived from the hash of the identifier and ordered pool assets.
+pub struct AssetPairAccountId<T: Config>(PhantomData<T>);
+
+impl<T: Config> AssetPairAccountIdFor<AssetId, T::AccountId> for AssetPairAccountId<T>
+where
+	T::AccountId: UncheckedFrom<T::Hash> + AsRef<[u8]>,
+{
+	fn from_assets(asset_a: AssetId, asset_b: AssetId, identifier: &str) -> T::AccountId {
+		let (asset_a, asset_b) = AssetPair::new(asset_a, asset_b).ordered_pair();
+
+		let mut buf: Vec<u8> = identifier.as_bytes().to_vec();
+		buf.extend_from_slice(&asset_a.to_le_bytes());
+		buf.extend_from_slice(&asset_b.to_le_bytes());
+
+		T::AccountId::unchecked_from(<T::Hashing as Hash>::hash(&buf[..]))
+	}
+}
+
+/// Allows creation of any pool.
+pub struct AllowAllPools;
+
+impl CanCreatePool<AssetId> for AllowAllPools {
+	fn can_create(_asset_a: AssetId, _asset_b: AssetId) -> bool {
+		true
+	}
+}
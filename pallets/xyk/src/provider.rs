@@ -0,0 +1,40 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{AssetId, AssetPair, Config, Pallet};
+use hydradx_traits::pools::SpotPriceProvider;
+use hydradx_traits::AMM;
+use orml_traits::MultiCurrency;
+use sp_runtime::{FixedPointNumber, FixedU128};
+
+impl<T: Config> SpotPriceProvider<AssetId> for Pallet<T> {
+	type Price = FixedU128;
+
+	fn pair_exists(asset_a: AssetId, asset_b: AssetId) -> bool {
+		<Self as AMM<_, _, _, _>>::exists(AssetPair::new(asset_a, asset_b))
+	}
+
+	fn spot_price(asset_a: AssetId, asset_b: AssetId) -> Option<Self::Price> {
+		if !Self::pair_exists(asset_a, asset_b) {
+			return None;
+		}
+
+		let pair_account = <Self as AMM<_, _, _, _>>::get_pair_id(AssetPair::new(asset_a, asset_b));
+		let asset_a_reserve = T::Currency::free_balance(asset_a, &pair_account);
+		let asset_b_reserve = T::Currency::free_balance(asset_b, &pair_account);
+
+		FixedU128::checked_from_rational(asset_a_reserve, asset_b_reserve)
+	}
+}
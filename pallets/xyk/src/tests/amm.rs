@@ -0,0 +1,78 @@
+use super::mock::*;
+use crate::types::AssetPair;
+use frame_support::assert_ok;
+use hydradx_traits::{pools::SpotPriceProvider, AMMPosition, AMM};
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+const POOL: AccountId = 2_003_000;
+
+fn with_pool() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, ACA, 1_000 * ONE), (ALICE, DOT, 1_000 * ONE)])
+		.build();
+	ext.execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			ACA,
+			100 * ONE,
+			DOT,
+			200 * ONE
+		));
+	});
+	ext
+}
+
+#[test]
+fn exists_should_not_depend_on_asset_order() {
+	with_pool().execute_with(|| {
+		assert!(XYK::exists(AssetPair::new(ACA, DOT)));
+		assert!(XYK::exists(AssetPair::new(DOT, ACA)));
+		assert!(!XYK::exists(AssetPair::new(ACA, DAI)));
+	});
+}
+
+#[test]
+fn get_pool_assets_should_return_pool_assets() {
+	with_pool().execute_with(|| {
+		assert_eq!(XYK::get_pool_assets(&POOL), Some(vec![ACA, DOT]));
+		assert_eq!(XYK::get_pool_assets(&ALICE), None);
+	});
+}
+
+#[test]
+fn get_pool_balances_should_return_reserves() {
+	with_pool().execute_with(|| {
+		assert_eq!(
+			XYK::get_pool_balances(POOL),
+			Some(vec![(ACA, 100 * ONE), (DOT, 200 * ONE)])
+		);
+	});
+}
+
+#[test]
+fn spot_price_should_return_price_of_asset_b_denominated_in_asset_a() {
+	with_pool().execute_with(|| {
+		assert_eq!(XYK::spot_price(ACA, DOT), Some(FixedU128::from_rational(1, 2)));
+		assert_eq!(XYK::spot_price(DOT, ACA), Some(FixedU128::from(2)));
+		assert_eq!(XYK::spot_price(ACA, DAI), None);
+	});
+}
+
+#[test]
+fn get_spot_price_unchecked_should_work() {
+	with_pool().execute_with(|| {
+		assert_eq!(XYK::get_spot_price_unchecked(ACA, DOT, ONE), 2 * ONE);
+		assert_eq!(XYK::get_spot_price_unchecked(ACA, DAI, ONE), 0);
+	});
+}
+
+#[test]
+fn get_liquidity_behind_shares_should_return_proportional_amounts() {
+	with_pool().execute_with(|| {
+		assert_eq!(
+			XYK::get_liquidity_behind_shares(ACA, DOT, 10 * ONE),
+			Ok((10 * ONE, 20 * ONE))
+		);
+	});
+}
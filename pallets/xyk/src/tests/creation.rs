@@ -0,0 +1,197 @@
+use super::mock::*;
+use crate::types::AssetPair;
+use crate::{Error, Event, PoolAssets, ShareToken, TotalLiquidity};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::{ShareTokenRegistry, AMM};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+const POOL: AccountId = 2_003_000;
+const SHARE_TOKEN: AssetId = 1_000_000;
+
+#[test]
+fn create_pool_should_work() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, ACA, 1_000 * ONE), (ALICE, DOT, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(XYK::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				ACA,
+				100 * ONE,
+				DOT,
+				200 * ONE
+			));
+
+			assert_eq!(XYK::get_pair_id(AssetPair::new(ACA, DOT)), POOL);
+			assert_eq!(ShareToken::<Test>::get(POOL), SHARE_TOKEN);
+			assert_eq!(TotalLiquidity::<Test>::get(POOL), 100 * ONE);
+			assert_eq!(PoolAssets::<Test>::get(POOL), Some((ACA, DOT)));
+
+			assert_eq!(Tokens::free_balance(ACA, &POOL), 100 * ONE);
+			assert_eq!(Tokens::free_balance(DOT, &POOL), 200 * ONE);
+			assert_eq!(Tokens::free_balance(ACA, &ALICE), 900 * ONE);
+			assert_eq!(Tokens::free_balance(DOT, &ALICE), 800 * ONE);
+			assert_eq!(Tokens::free_balance(SHARE_TOKEN, &ALICE), 100 * ONE);
+
+			assert!(is_whitelisted(POOL));
+			assert_eq!(created_pools(), vec![(ACA, DOT)]);
+			assert_eq!(
+				liquidity_changes(),
+				vec![Activity {
+					source: XYK_SOURCE,
+					asset_a: ACA,
+					asset_b: DOT,
+					amount_a: 100 * ONE,
+					amount_b: 200 * ONE,
+					liquidity_a: 100 * ONE,
+					liquidity_b: 200 * ONE,
+				}]
+			);
+
+			expect_events(vec![Event::PoolCreated {
+				who: ALICE,
+				asset_a: ACA,
+				asset_b: DOT,
+				initial_shares_amount: 100 * ONE,
+				share_token: SHARE_TOKEN,
+				pool: POOL,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn create_pool_should_register_share_token_in_registry() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, ACA, 1_000 * ONE), (ALICE, DOT, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(XYK::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				ACA,
+				100 * ONE,
+				DOT,
+				200 * ONE
+			));
+
+			assert_eq!(
+				AssetRegistry::retrieve_shared_asset(&AssetPair::new(DOT, ACA).name(), &[ACA, DOT]),
+				Ok(SHARE_TOKEN)
+			);
+		});
+}
+
+#[test]
+fn create_pool_should_issue_shares_in_amount_of_lower_asset_id_when_assets_are_reversed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, ACA, 1_000 * ONE), (ALICE, DOT, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(XYK::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				DOT,
+				200 * ONE,
+				ACA,
+				100 * ONE
+			));
+
+			assert_eq!(XYK::get_pair_id(AssetPair::new(DOT, ACA)), POOL);
+			assert_eq!(TotalLiquidity::<Test>::get(POOL), 100 * ONE);
+			assert_eq!(PoolAssets::<Test>::get(POOL), Some((DOT, ACA)));
+			assert_eq!(Tokens::free_balance(SHARE_TOKEN, &ALICE), 100 * ONE);
+		});
+}
+
+#[test]
+fn create_pool_should_fail_when_assets_are_same() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, ACA, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				XYK::create_pool(RuntimeOrigin::signed(ALICE), ACA, 100 * ONE, ACA, 100 * ONE),
+				Error::<Test>::CannotCreatePoolWithSameAssets
+			);
+		});
+}
+
+#[test]
+fn create_pool_should_fail_when_initial_liquidity_is_below_min_pool_liquidity() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, ACA, 1_000 * ONE), (ALICE, DOT, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				XYK::create_pool(RuntimeOrigin::signed(ALICE), ACA, 999, DOT, 100 * ONE),
+				Error::<Test>::InsufficientLiquidity
+			);
+			assert_noop!(
+				XYK::create_pool(RuntimeOrigin::signed(ALICE), ACA, 100 * ONE, DOT, 999),
+				Error::<Test>::InsufficientLiquidity
+			);
+		});
+}
+
+#[test]
+fn create_pool_should_fail_when_pool_already_exists() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, ACA, 1_000 * ONE), (ALICE, DOT, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(XYK::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				ACA,
+				100 * ONE,
+				DOT,
+				200 * ONE
+			));
+
+			assert_noop!(
+				XYK::create_pool(RuntimeOrigin::signed(ALICE), DOT, 100 * ONE, ACA, 100 * ONE),
+				Error::<Test>::TokenPoolAlreadyExists
+			);
+		});
+}
+
+#[test]
+fn create_pool_should_fail_when_balance_is_not_sufficient() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, ACA, 1_000 * ONE), (ALICE, DOT, 100 * ONE)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				XYK::create_pool(RuntimeOrigin::signed(ALICE), ACA, 100 * ONE, DOT, 200 * ONE),
+				Error::<Test>::InsufficientAssetBalance
+			);
+		});
+}
+
+#[test]
+fn create_pool_should_reuse_share_token_when_pool_was_destroyed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, ACA, 1_000 * ONE), (ALICE, DOT, 1_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(XYK::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				ACA,
+				100 * ONE,
+				DOT,
+				200 * ONE
+			));
+			assert_ok!(XYK::remove_liquidity(RuntimeOrigin::signed(ALICE), ACA, DOT, 100 * ONE));
+			assert!(!XYK::exists(AssetPair::new(ACA, DOT)));
+
+			assert_ok!(XYK::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				ACA,
+				50 * ONE,
+				DOT,
+				50 * ONE
+			));
+
+			assert_eq!(ShareToken::<Test>::get(POOL), SHARE_TOKEN);
+			assert_eq!(Tokens::free_balance(SHARE_TOKEN, &ALICE), 50 * ONE);
+		});
+}
@@ -0,0 +1,109 @@
+use super::mock::*;
+use frame_support::assert_ok;
+use orml_traits::MultiCurrency;
+use sp_core::U256;
+
+use proptest::prelude::*;
+use proptest::proptest;
+
+const POOL: AccountId = 2_003_000;
+const SHARE_TOKEN: AssetId = 1_000_000;
+
+const RESERVE_RANGE: (Balance, Balance) = (10_000 * ONE, 10_000_000 * ONE);
+
+fn asset_reserve() -> impl Strategy<Value = Balance> {
+	RESERVE_RANGE.0..RESERVE_RANGE.1
+}
+
+fn trade_amount() -> impl Strategy<Value = Balance> {
+	1000..1_000 * ONE
+}
+
+fn pool_invariant() -> U256 {
+	U256::from(Tokens::free_balance(ACA, &POOL)) * U256::from(Tokens::free_balance(DOT, &POOL))
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn sell_should_not_decrease_pool_invariant(
+		reserve_a in asset_reserve(),
+		reserve_b in asset_reserve(),
+		amount in trade_amount(),
+	) {
+		ExtBuilder::default()
+			.with_endowed_accounts(vec![
+				(ALICE, ACA, reserve_a),
+				(ALICE, DOT, reserve_b),
+				(BOB, ACA, amount),
+			])
+			.build()
+			.execute_with(|| {
+				assert_ok!(XYK::create_pool(RuntimeOrigin::signed(ALICE), ACA, reserve_a, DOT, reserve_b));
+
+				let invariant_before = pool_invariant();
+
+				assert_ok!(XYK::sell(RuntimeOrigin::signed(BOB), ACA, DOT, amount, 0));
+
+				assert!(pool_invariant() >= invariant_before);
+			});
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn buy_should_not_decrease_pool_invariant(
+		reserve_a in asset_reserve(),
+		reserve_b in asset_reserve(),
+		amount in trade_amount(),
+	) {
+		ExtBuilder::default()
+			.with_endowed_accounts(vec![
+				(ALICE, ACA, reserve_a),
+				(ALICE, DOT, reserve_b),
+				(BOB, ACA, RESERVE_RANGE.1),
+			])
+			.build()
+			.execute_with(|| {
+				assert_ok!(XYK::create_pool(RuntimeOrigin::signed(ALICE), ACA, reserve_a, DOT, reserve_b));
+
+				let invariant_before = pool_invariant();
+
+				assert_ok!(XYK::buy(RuntimeOrigin::signed(BOB), DOT, ACA, amount, Balance::MAX));
+
+				assert!(pool_invariant() >= invariant_before);
+			});
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn add_and_remove_liquidity_should_not_return_more_than_provided(
+		reserve_a in asset_reserve(),
+		reserve_b in asset_reserve(),
+		amount in trade_amount(),
+	) {
+		ExtBuilder::default()
+			.with_endowed_accounts(vec![
+				(ALICE, ACA, reserve_a),
+				(ALICE, DOT, reserve_b),
+				(BOB, ACA, amount),
+				(BOB, DOT, RESERVE_RANGE.1),
+			])
+			.build()
+			.execute_with(|| {
+				assert_ok!(XYK::create_pool(RuntimeOrigin::signed(ALICE), ACA, reserve_a, DOT, reserve_b));
+
+				let shares_before = Tokens::free_balance(SHARE_TOKEN, &BOB);
+				assert_ok!(XYK::add_liquidity(RuntimeOrigin::signed(BOB), ACA, DOT, amount, Balance::MAX));
+				let shares = Tokens::free_balance(SHARE_TOKEN, &BOB) - shares_before;
+
+				assert_ok!(XYK::remove_liquidity(RuntimeOrigin::signed(BOB), ACA, DOT, shares));
+
+				assert!(Tokens::free_balance(ACA, &BOB) <= amount);
+				assert!(Tokens::free_balance(DOT, &BOB) <= RESERVE_RANGE.1);
+			});
+	}
+}
@@ -0,0 +1,270 @@
+use super::mock::*;
+use crate::types::AssetPair;
+use crate::{Error, Event, PoolAssets, ShareToken, TotalLiquidity};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::AMM;
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+const POOL: AccountId = 2_003_000;
+const SHARE_TOKEN: AssetId = 1_000_000;
+
+fn with_pool() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, ACA, 1_000 * ONE),
+			(ALICE, DOT, 1_000 * ONE),
+			(BOB, ACA, 1_000 * ONE),
+			(BOB, DOT, 1_000 * ONE),
+		])
+		.build();
+	ext.execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			ACA,
+			100 * ONE,
+			DOT,
+			200 * ONE
+		));
+	});
+	ext
+}
+
+#[test]
+fn add_liquidity_should_work() {
+	with_pool().execute_with(|| {
+		assert_ok!(XYK::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			ACA,
+			DOT,
+			10 * ONE,
+			21 * ONE
+		));
+
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 990 * ONE);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 980 * ONE - 1);
+		assert_eq!(Tokens::free_balance(SHARE_TOKEN, &BOB), 10 * ONE);
+		assert_eq!(Tokens::free_balance(ACA, &POOL), 110 * ONE);
+		assert_eq!(Tokens::free_balance(DOT, &POOL), 220 * ONE + 1);
+		assert_eq!(TotalLiquidity::<Test>::get(POOL), 110 * ONE);
+
+		assert_eq!(
+			liquidity_changes().last(),
+			Some(&Activity {
+				source: XYK_SOURCE,
+				asset_a: ACA,
+				asset_b: DOT,
+				amount_a: 10 * ONE,
+				amount_b: 20 * ONE + 1,
+				liquidity_a: 110 * ONE,
+				liquidity_b: 220 * ONE + 1,
+			})
+		);
+
+		expect_events(vec![Event::LiquidityAdded {
+			who: BOB,
+			asset_a: ACA,
+			asset_b: DOT,
+			amount_a: 10 * ONE,
+			amount_b: 20 * ONE + 1,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn add_liquidity_should_work_when_assets_are_reversed() {
+	with_pool().execute_with(|| {
+		assert_ok!(XYK::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			DOT,
+			ACA,
+			20 * ONE,
+			11 * ONE
+		));
+
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 980 * ONE);
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 990 * ONE - 1);
+		assert_eq!(Tokens::free_balance(SHARE_TOKEN, &BOB), 10 * ONE);
+		assert_eq!(TotalLiquidity::<Test>::get(POOL), 110 * ONE);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_limit_is_exceeded() {
+	with_pool().execute_with(|| {
+		assert_noop!(
+			XYK::add_liquidity(RuntimeOrigin::signed(BOB), ACA, DOT, 10 * ONE, 20 * ONE),
+			Error::<Test>::AssetAmountExceededLimit
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_pool_does_not_exist() {
+	with_pool().execute_with(|| {
+		assert_noop!(
+			XYK::add_liquidity(RuntimeOrigin::signed(BOB), ACA, DAI, 10 * ONE, 20 * ONE),
+			Error::<Test>::TokenPoolNotFound
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_amount_is_below_min_trading_limit() {
+	with_pool().execute_with(|| {
+		assert_noop!(
+			XYK::add_liquidity(RuntimeOrigin::signed(BOB), ACA, DOT, 999, 20 * ONE),
+			Error::<Test>::InsufficientTradingAmount
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_max_limit_is_zero() {
+	with_pool().execute_with(|| {
+		assert_noop!(
+			XYK::add_liquidity(RuntimeOrigin::signed(BOB), ACA, DOT, 10 * ONE, 0),
+			Error::<Test>::ZeroLiquidity
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_balance_is_not_sufficient() {
+	with_pool().execute_with(|| {
+		assert_noop!(
+			XYK::add_liquidity(RuntimeOrigin::signed(CHARLIE), ACA, DOT, 10 * ONE, 21 * ONE),
+			Error::<Test>::InsufficientAssetBalance
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_work() {
+	with_pool().execute_with(|| {
+		assert_ok!(XYK::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			ACA,
+			DOT,
+			10 * ONE,
+			21 * ONE
+		));
+
+		assert_ok!(XYK::remove_liquidity(RuntimeOrigin::signed(BOB), ACA, DOT, 10 * ONE));
+
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 1_000 * ONE);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000 * ONE - 1);
+		assert_eq!(Tokens::free_balance(SHARE_TOKEN, &BOB), 0);
+		assert_eq!(Tokens::free_balance(ACA, &POOL), 100 * ONE);
+		assert_eq!(Tokens::free_balance(DOT, &POOL), 200 * ONE + 1);
+		assert_eq!(TotalLiquidity::<Test>::get(POOL), 100 * ONE);
+
+		assert_eq!(
+			liquidity_changes().last(),
+			Some(&Activity {
+				source: XYK_SOURCE,
+				asset_a: ACA,
+				asset_b: DOT,
+				amount_a: 10 * ONE,
+				amount_b: 20 * ONE,
+				liquidity_a: 100 * ONE,
+				liquidity_b: 200 * ONE + 1,
+			})
+		);
+
+		expect_events(vec![Event::LiquidityRemoved {
+			who: BOB,
+			asset_a: ACA,
+			asset_b: DOT,
+			shares: 10 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_destroy_pool_when_all_liquidity_is_removed() {
+	with_pool().execute_with(|| {
+		assert_ok!(XYK::remove_liquidity(RuntimeOrigin::signed(ALICE), ACA, DOT, 100 * ONE));
+
+		assert_eq!(Tokens::free_balance(ACA, &ALICE), 1_000 * ONE);
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 1_000 * ONE);
+		assert_eq!(Tokens::free_balance(ACA, &POOL), 0);
+		assert_eq!(Tokens::free_balance(DOT, &POOL), 0);
+
+		assert!(!XYK::exists(AssetPair::new(ACA, DOT)));
+		assert!(!ShareToken::<Test>::contains_key(POOL));
+		assert!(!TotalLiquidity::<Test>::contains_key(POOL));
+		assert_eq!(PoolAssets::<Test>::get(POOL), None);
+		assert!(!is_whitelisted(POOL));
+
+		expect_events(vec![
+			Event::LiquidityRemoved {
+				who: ALICE,
+				asset_a: ACA,
+				asset_b: DOT,
+				shares: 100 * ONE,
+			}
+			.into(),
+			Event::PoolDestroyed {
+				who: ALICE,
+				asset_a: ACA,
+				asset_b: DOT,
+				share_token: SHARE_TOKEN,
+				pool: POOL,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_amount_is_zero() {
+	with_pool().execute_with(|| {
+		assert_noop!(
+			XYK::remove_liquidity(RuntimeOrigin::signed(ALICE), ACA, DOT, 0),
+			Error::<Test>::ZeroLiquidity
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_pool_does_not_exist() {
+	with_pool().execute_with(|| {
+		assert_noop!(
+			XYK::remove_liquidity(RuntimeOrigin::signed(ALICE), ACA, DAI, ONE),
+			Error::<Test>::TokenPoolNotFound
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_shares_are_not_sufficient() {
+	with_pool().execute_with(|| {
+		assert_noop!(
+			XYK::remove_liquidity(RuntimeOrigin::signed(BOB), ACA, DOT, ONE),
+			Error::<Test>::InsufficientShares
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_amount_exceeds_total_liquidity() {
+	with_pool().execute_with(|| {
+		assert_noop!(
+			XYK::remove_liquidity(RuntimeOrigin::signed(ALICE), ACA, DOT, 100 * ONE + 1),
+			Error::<Test>::InsufficientLiquidity
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_remaining_shares_are_below_min_pool_liquidity() {
+	with_pool().execute_with(|| {
+		assert_noop!(
+			XYK::remove_liquidity(RuntimeOrigin::signed(ALICE), ACA, DOT, 100 * ONE - 999),
+			Error::<Test>::InsufficientLiquidity
+		);
+	});
+}
@@ -0,0 +1,361 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as xyk;
+use crate::{AllowAllPools, Config};
+use frame_support::{
+	parameter_types,
+	traits::{Everything, GenesisBuild, Nothing},
+	weights::Weight,
+};
+use frame_system as system;
+use hydradx_traits::{
+	pools::DustRemovalAccountWhitelist, AssetPairAccountIdFor, OnCreatePoolHandler, OnLiquidityChangedHandler,
+	OnTradeHandler, Source,
+};
+use orml_traits::parameter_type_with_key;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	DispatchError, DispatchResult,
+};
+use std::cell::RefCell;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+pub type Amount = i128;
+pub type AssetId = u32;
+pub type Balance = u128;
+
+pub const HDX: AssetId = 0;
+pub const ACA: AssetId = 2_000;
+pub const DOT: AssetId = 3_000;
+pub const DAI: AssetId = 4_000;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const XYK_SOURCE: Source = *b"hydraxyk";
+
+frame_support::construct_runtime!(
+	pub enum Test where
+	 Block = Block,
+	 NodeBlock = Block,
+	 UncheckedExtrinsic = UncheckedExtrinsic,
+	 {
+		 System: frame_system,
+		 XYK: xyk,
+		 Tokens: orml_tokens,
+		 AssetRegistry: pallet_asset_registry,
+	 }
+);
+
+/// Trade or liquidity change reported to the AMM handler.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Activity {
+	pub source: Source,
+	pub asset_a: AssetId,
+	pub asset_b: AssetId,
+	pub amount_a: Balance,
+	pub amount_b: Balance,
+	pub liquidity_a: Balance,
+	pub liquidity_b: Balance,
+}
+
+thread_local! {
+	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = RefCell::new(Vec::new());
+	pub static CREATED_POOLS: RefCell<Vec<(AssetId, AssetId)>> = RefCell::new(Vec::new());
+	pub static TRADES: RefCell<Vec<Activity>> = RefCell::new(Vec::new());
+	pub static LIQUIDITY_CHANGES: RefCell<Vec<Activity>> = RefCell::new(Vec::new());
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+	pub const NativeAssetId: AssetId = HDX;
+	pub const RegistryStringLimit: u32 = 32;
+	pub const SequentialIdStart: u32 = 1_000_000;
+	pub const MaxReserves: u32 = 50;
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Nothing;
+	type ReserveIdentifier = [u8; 8];
+	type MaxReserves = MaxReserves;
+	type CurrencyHooks = ();
+}
+
+impl pallet_asset_registry::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RegistryOrigin = frame_system::EnsureRoot<AccountId>;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type AssetNativeLocation = u8;
+	type StringLimit = RegistryStringLimit;
+	type SequentialIdStartAt = SequentialIdStart;
+	type NativeAssetId = NativeAssetId;
+	type WeightInfo = ();
+}
+
+pub struct AssetPairAccountIdTest;
+
+impl AssetPairAccountIdFor<AssetId, AccountId> for AssetPairAccountIdTest {
+	fn from_assets(asset_a: AssetId, asset_b: AssetId, _: &str) -> AccountId {
+		let mut a = asset_a as u128;
+		let mut b = asset_b as u128;
+		if a > b {
+			std::mem::swap(&mut a, &mut b)
+		}
+		(a * 1000 + b) as u64
+	}
+}
+
+pub struct Whitelist;
+
+impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
+	type Error = DispatchError;
+
+	fn add_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().push(*account));
+		Ok(())
+	}
+
+	fn remove_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| {
+			let mut v = v.borrow_mut();
+
+			let idx = v.iter().position(|x| *x == *account).unwrap();
+			v.remove(idx);
+
+			Ok(())
+		})
+	}
+}
+
+pub struct AMMHandlerMock;
+
+impl OnCreatePoolHandler<AssetId> for AMMHandlerMock {
+	fn on_create_pool(asset_a: AssetId, asset_b: AssetId) -> DispatchResult {
+		CREATED_POOLS.with(|v| v.borrow_mut().push((asset_a, asset_b)));
+		Ok(())
+	}
+}
+
+impl OnTradeHandler<AssetId, Balance> for AMMHandlerMock {
+	fn on_trade(
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		amount_b: Balance,
+		liquidity_a: Balance,
+		liquidity_b: Balance,
+	) -> Result<Weight, (Weight, DispatchError)> {
+		TRADES.with(|v| {
+			v.borrow_mut().push(Activity {
+				source,
+				asset_a,
+				asset_b,
+				amount_a,
+				amount_b,
+				liquidity_a,
+				liquidity_b,
+			})
+		});
+		Ok(Weight::zero())
+	}
+
+	fn on_trade_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+impl OnLiquidityChangedHandler<AssetId, Balance> for AMMHandlerMock {
+	fn on_liquidity_changed(
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		amount_b: Balance,
+		liquidity_a: Balance,
+		liquidity_b: Balance,
+	) -> Result<Weight, (Weight, DispatchError)> {
+		LIQUIDITY_CHANGES.with(|v| {
+			v.borrow_mut().push(Activity {
+				source,
+				asset_a,
+				asset_b,
+				amount_a,
+				amount_b,
+				liquidity_a,
+				liquidity_b,
+			})
+		});
+		Ok(Weight::zero())
+	}
+
+	fn on_liquidity_changed_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+parameter_types! {
+	pub ExchangeFeeRate: (u32, u32) = (2, 1_000);
+	pub const MinTradingLimit: Balance = 1_000;
+	pub const MinPoolLiquidity: Balance = 1_000;
+	pub const MaxInRatio: u128 = 3;
+	pub const MaxOutRatio: u128 = 3;
+	pub const OracleSource: Source = XYK_SOURCE;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetRegistry = AssetRegistry;
+	type AssetPairAccountId = AssetPairAccountIdTest;
+	type Currency = Tokens;
+	type NativeAssetId = NativeAssetId;
+	type WeightInfo = ();
+	type GetExchangeFee = ExchangeFeeRate;
+	type MinTradingLimit = MinTradingLimit;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type MaxInRatio = MaxInRatio;
+	type MaxOutRatio = MaxOutRatio;
+	type CanCreatePool = AllowAllPools;
+	type AMMHandler = AMMHandlerMock;
+	type NonDustableWhitelistHandler = Whitelist;
+	type OracleSource = OracleSource;
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	registered_assets: Vec<(Vec<u8>, AssetId)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each  test case
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().clear());
+		CREATED_POOLS.with(|v| v.borrow_mut().clear());
+		TRADES.with(|v| v.borrow_mut().clear());
+		LIQUIDITY_CHANGES.with(|v| v.borrow_mut().clear());
+
+		Self {
+			endowed_accounts: vec![],
+			registered_assets: vec![(b"ACA".to_vec(), ACA), (b"DOT".to_vec(), DOT), (b"DAI".to_vec(), DAI)],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		<pallet_asset_registry::GenesisConfig<Test> as GenesisBuild<Test>>::assimilate_storage(
+			&pallet_asset_registry::GenesisConfig::<Test> {
+				registered_assets: self
+					.registered_assets
+					.into_iter()
+					.map(|(name, asset_id)| (name, 1u128, Some(asset_id)))
+					.collect(),
+				native_asset_name: b"HDX".to_vec(),
+				native_existential_deposit: 1u128,
+			},
+			&mut t,
+		)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+		r.execute_with(|| System::set_block_number(1));
+		r
+	}
+}
+
+pub(crate) fn expect_events(e: Vec<RuntimeEvent>) {
+	test_utils::expect_events::<RuntimeEvent, Test>(e);
+}
+
+pub(crate) fn trades() -> Vec<Activity> {
+	TRADES.with(|v| v.borrow().clone())
+}
+
+pub(crate) fn liquidity_changes() -> Vec<Activity> {
+	LIQUIDITY_CHANGES.with(|v| v.borrow().clone())
+}
+
+pub(crate) fn created_pools() -> Vec<(AssetId, AssetId)> {
+	CREATED_POOLS.with(|v| v.borrow().clone())
+}
+
+pub(crate) fn is_whitelisted(account: AccountId) -> bool {
+	DUSTER_WHITELIST.with(|v| v.borrow().contains(&account))
+}
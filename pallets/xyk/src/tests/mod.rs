@@ -0,0 +1,7 @@
+mod amm;
+mod creation;
+mod invariants;
+mod liquidity;
+pub(crate) mod mock;
+mod trade_execution;
+mod trades;
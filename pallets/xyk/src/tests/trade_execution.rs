@@ -0,0 +1,78 @@
+use super::mock::*;
+use frame_support::assert_ok;
+use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+fn with_pool() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, ACA, 1_000 * ONE),
+			(ALICE, DOT, 1_000 * ONE),
+			(BOB, ACA, 1_000 * ONE),
+		])
+		.build();
+	ext.execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			ACA,
+			100 * ONE,
+			DOT,
+			200 * ONE
+		));
+	});
+	ext
+}
+
+#[test]
+fn calculate_sell_should_return_amount_received_by_execute_sell() {
+	with_pool().execute_with(|| {
+		let amount_out = XYK::calculate_sell(PoolType::XYK, ACA, DOT, 10 * ONE).unwrap();
+		assert_eq!(amount_out, 18_145_454_545_456);
+
+		assert_ok!(<XYK as TradeExecution<_, _, _, _>>::execute_sell(
+			RuntimeOrigin::signed(BOB),
+			PoolType::XYK,
+			ACA,
+			DOT,
+			10 * ONE,
+			amount_out
+		));
+
+		assert_eq!(Tokens::free_balance(DOT, &BOB), amount_out);
+	});
+}
+
+#[test]
+fn calculate_buy_should_return_amount_paid_by_execute_buy() {
+	with_pool().execute_with(|| {
+		let amount_in = XYK::calculate_buy(PoolType::XYK, ACA, DOT, 10 * ONE).unwrap();
+		assert_eq!(amount_in, 5_273_684_210_525);
+
+		assert_ok!(<XYK as TradeExecution<_, _, _, _>>::execute_buy(
+			RuntimeOrigin::signed(BOB),
+			PoolType::XYK,
+			ACA,
+			DOT,
+			10 * ONE,
+			amount_in
+		));
+
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 1_000 * ONE - amount_in);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 10 * ONE);
+	});
+}
+
+#[test]
+fn trade_execution_should_not_support_other_pool_types() {
+	with_pool().execute_with(|| {
+		assert_eq!(
+			XYK::calculate_sell(PoolType::Omnipool, ACA, DOT, 10 * ONE),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			XYK::calculate_buy(PoolType::Omnipool, ACA, DOT, 10 * ONE),
+			Err(ExecutorError::NotSupported)
+		);
+	});
+}
@@ -0,0 +1,202 @@
+use super::mock::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::AMM;
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+const POOL: AccountId = 2_003_000;
+
+fn with_pool() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, ACA, 1_000 * ONE),
+			(ALICE, DOT, 1_000 * ONE),
+			(BOB, ACA, 1_000 * ONE),
+		])
+		.build();
+	ext.execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			ACA,
+			100 * ONE,
+			DOT,
+			200 * ONE
+		));
+	});
+	ext
+}
+
+#[test]
+fn sell_should_work() {
+	with_pool().execute_with(|| {
+		let expected_out = 18_145_454_545_456;
+		let expected_fee = 36_363_636_362;
+
+		assert_ok!(XYK::sell(RuntimeOrigin::signed(BOB), ACA, DOT, 10 * ONE, expected_out));
+
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 990 * ONE);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), expected_out);
+		assert_eq!(Tokens::free_balance(ACA, &POOL), 110 * ONE);
+		assert_eq!(Tokens::free_balance(DOT, &POOL), 200 * ONE - expected_out);
+
+		assert_eq!(
+			trades(),
+			vec![Activity {
+				source: XYK_SOURCE,
+				asset_a: ACA,
+				asset_b: DOT,
+				amount_a: 10 * ONE,
+				amount_b: expected_out,
+				liquidity_a: 110 * ONE,
+				liquidity_b: 200 * ONE - expected_out,
+			}]
+		);
+
+		expect_events(vec![Event::SellExecuted {
+			who: BOB,
+			asset_in: ACA,
+			asset_out: DOT,
+			amount: 10 * ONE,
+			sale_price: expected_out,
+			fee_asset: DOT,
+			fee_amount: expected_fee,
+			pool: POOL,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_min_limit_is_not_reached() {
+	with_pool().execute_with(|| {
+		assert_noop!(
+			XYK::sell(RuntimeOrigin::signed(BOB), ACA, DOT, 10 * ONE, 18_145_454_545_457),
+			Error::<Test>::AssetAmountNotReachedLimit
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_amount_is_below_min_trading_limit() {
+	with_pool().execute_with(|| {
+		assert_noop!(
+			XYK::sell(RuntimeOrigin::signed(BOB), ACA, DOT, 999, 0),
+			Error::<Test>::InsufficientTradingAmount
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_pool_does_not_exist() {
+	with_pool().execute_with(|| {
+		assert_noop!(
+			XYK::sell(RuntimeOrigin::signed(BOB), ACA, DAI, 10 * ONE, 0),
+			Error::<Test>::TokenPoolNotFound
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_balance_is_not_sufficient() {
+	with_pool().execute_with(|| {
+		assert_noop!(
+			XYK::sell(RuntimeOrigin::signed(CHARLIE), ACA, DOT, 10 * ONE, 0),
+			Error::<Test>::InsufficientAssetBalance
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_max_in_ratio_is_exceeded() {
+	with_pool().execute_with(|| {
+		assert_noop!(
+			XYK::sell(RuntimeOrigin::signed(BOB), ACA, DOT, 100 * ONE / 3 + 1, 0),
+			Error::<Test>::MaxInRatioExceeded
+		);
+	});
+}
+
+#[test]
+fn buy_should_work() {
+	with_pool().execute_with(|| {
+		let expected_in = 5_273_684_210_525;
+		let expected_fee = 10_526_315_788;
+
+		assert_ok!(XYK::buy(RuntimeOrigin::signed(BOB), DOT, ACA, 10 * ONE, expected_in));
+
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 1_000 * ONE - expected_in);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 10 * ONE);
+		assert_eq!(Tokens::free_balance(ACA, &POOL), 100 * ONE + expected_in);
+		assert_eq!(Tokens::free_balance(DOT, &POOL), 190 * ONE);
+
+		assert_eq!(
+			trades(),
+			vec![Activity {
+				source: XYK_SOURCE,
+				asset_a: ACA,
+				asset_b: DOT,
+				amount_a: expected_in,
+				amount_b: 10 * ONE,
+				liquidity_a: 100 * ONE + expected_in,
+				liquidity_b: 190 * ONE,
+			}]
+		);
+
+		expect_events(vec![Event::BuyExecuted {
+			who: BOB,
+			asset_out: DOT,
+			asset_in: ACA,
+			amount: 10 * ONE,
+			buy_price: expected_in,
+			fee_asset: ACA,
+			fee_amount: expected_fee,
+			pool: POOL,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_max_limit_is_exceeded() {
+	with_pool().execute_with(|| {
+		assert_noop!(
+			XYK::buy(RuntimeOrigin::signed(BOB), DOT, ACA, 10 * ONE, 5_273_684_210_524),
+			Error::<Test>::AssetAmountExceededLimit
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_max_out_ratio_is_exceeded() {
+	with_pool().execute_with(|| {
+		assert_noop!(
+			XYK::buy(RuntimeOrigin::signed(BOB), DOT, ACA, 200 * ONE / 3 + 1, 1_000 * ONE),
+			Error::<Test>::MaxOutRatioExceeded
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_balance_is_not_sufficient() {
+	with_pool().execute_with(|| {
+		assert_noop!(
+			XYK::buy(RuntimeOrigin::signed(CHARLIE), DOT, ACA, 10 * ONE, 1_000 * ONE),
+			Error::<Test>::InsufficientAssetBalance
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_discount_is_requested() {
+	with_pool().execute_with(|| {
+		assert_noop!(
+			<XYK as AMM<_, _, _, _>>::sell(&BOB, crate::AssetPair::new(ACA, DOT), 10 * ONE, 0, true),
+			Error::<Test>::CannotApplyDiscount
+		);
+		assert_noop!(
+			<XYK as AMM<_, _, _, _>>::buy(&BOB, crate::AssetPair::new(ACA, DOT), 10 * ONE, 1_000 * ONE, true),
+			Error::<Test>::CannotApplyDiscount
+		);
+	});
+}
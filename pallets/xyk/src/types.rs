@@ -0,0 +1,66 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+pub use primitives::{Amount, AssetId, Balance};
+
+/// Pair of assets traded in a pool.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen)]
+pub struct AssetPair {
+	pub asset_in: AssetId,
+	pub asset_out: AssetId,
+}
+
+impl AssetPair {
+	pub fn new(asset_in: AssetId, asset_out: AssetId) -> Self {
+		Self { asset_in, asset_out }
+	}
+
+	/// Return pair with swapped assets.
+	pub fn swap(&self) -> Self {
+		Self {
+			asset_in: self.asset_out,
+			asset_out: self.asset_in,
+		}
+	}
+
+	/// Return assets ordered by asset id. Pool of a pair is the same regardless of the trade direction.
+	pub fn ordered_pair(&self) -> (AssetId, AssetId) {
+		if self.asset_in <= self.asset_out {
+			(self.asset_in, self.asset_out)
+		} else {
+			(self.asset_out, self.asset_in)
+		}
+	}
+
+	/// Name of the share token of the pair's pool.
+	pub fn name(&self) -> Vec<u8> {
+		let (asset_a, asset_b) = self.ordered_pair();
+
+		let mut buf: Vec<u8> = Vec::new();
+		buf.extend_from_slice(&asset_a.to_le_bytes());
+		buf.extend_from_slice(b"HDT");
+		buf.extend_from_slice(&asset_b.to_le_bytes());
+		buf
+	}
+}
@@ -84,6 +84,15 @@ pub mod chain {
 
 	/// The source of the data for the oracle.
 	pub const OMNIPOOL_SOURCE: [u8; 8] = *b"omnipool";
+
+	/// The source of the stableswap data for the oracle.
+	pub const STABLESWAP_SOURCE: [u8; 8] = *b"stablesw";
+
+	/// The source of the XYK data for the oracle.
+	pub const XYK_SOURCE: [u8; 8] = *b"hydraxyk";
+
+	/// The source of the LBP data for the oracle.
+	pub const LBP_SOURCE: [u8; 8] = *b"hydralbp";
 }
 
 #[cfg(test)]
@@ -32,18 +32,22 @@ use hydra_dx_math::{
 	support::rational::{round_to_rational, Rounding},
 };
 use hydradx_traits::{
-	liquidity_mining::PriceAdjustment, AggregatedOracle, AggregatedPriceOracle, NativePriceOracle,
-	OnLiquidityChangedHandler, OnTradeHandler, OraclePeriod, PriceOracle,
+	liquidity_mining::PriceAdjustment, pools::SpotPriceProvider, AggregatedOracle, AggregatedPriceOracle,
+	NativePriceOracle, OnLiquidityChangedHandler, OnTradeHandler, OraclePeriod, PriceOracle, Source,
 };
 use orml_xcm_support::{OnDepositFail, UnknownAsset as UnknownAssetT};
 use pallet_circuit_breaker::WeightInfo;
 use pallet_ema_oracle::{OnActivityHandler, OracleError, Price};
 use pallet_omnipool::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks};
+use pallet_stableswap::traits::{PoolState, StableswapHooks};
 use pallet_transaction_multi_payment::DepositFee;
 use polkadot_xcm::latest::prelude::*;
 use primitive_types::U128;
-use primitives::{constants::chain::OMNIPOOL_SOURCE, AssetId, Balance, BlockNumber};
-use sp_std::{collections::btree_map::BTreeMap, fmt::Debug, marker::PhantomData};
+use primitives::{
+	constants::chain::{OMNIPOOL_SOURCE, STABLESWAP_SOURCE},
+	AssetId, Balance, BlockNumber,
+};
+use sp_std::{collections::btree_map::BTreeMap, fmt::Debug, marker::PhantomData, vec, vec::Vec};
 use warehouse_liquidity_mining::GlobalFarmData;
 use xcm_builder::TakeRevenue;
 use xcm_executor::{
@@ -247,7 +251,11 @@ pub struct OmnipoolHookAdapter<Origin, Lrna, Runtime>(PhantomData<(Origin, Lrna,
 impl<Origin, Lrna, Runtime> OmnipoolHooks<Origin, AssetId, Balance> for OmnipoolHookAdapter<Origin, Lrna, Runtime>
 where
 	Lrna: Get<AssetId>,
-	Runtime: pallet_ema_oracle::Config + pallet_circuit_breaker::Config + frame_system::Config<RuntimeOrigin = Origin>,
+	Runtime: pallet_ema_oracle::Config
+		+ pallet_circuit_breaker::Config
+		+ pallet_dynamic_fees::Config
+		+ frame_system::Config<RuntimeOrigin = Origin>,
+	<Runtime as pallet_dynamic_fees::Config>::AssetId: From<AssetId>,
 {
 	type Error = DispatchError;
 
@@ -351,6 +359,13 @@ where
 		Ok(Self::on_trade_weight())
 	}
 
+	fn on_asset_removed(asset_id: AssetId) -> Result<Weight, Self::Error> {
+		pallet_circuit_breaker::Pallet::<Runtime>::remove_asset_limits(asset_id.into());
+		pallet_dynamic_fees::Pallet::<Runtime>::remove_asset_fee(asset_id.into());
+
+		Ok(Self::on_asset_removed_weight())
+	}
+
 	fn on_liquidity_changed_weight() -> Weight {
 		let w1 = OnActivityHandler::<Runtime>::on_liquidity_changed_weight();
 		let w2 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_add_liquidity_limit()
@@ -365,6 +380,73 @@ where
 		let w3 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::on_finalize_single_trade_limit_entry();
 		w1.saturating_add(w2).saturating_add(w3)
 	}
+
+	fn on_asset_removed_weight() -> Weight {
+		// 6 circuit breaker entries and 1 dynamic fee entry
+		<Runtime as frame_system::Config>::DbWeight::get().writes(7)
+	}
+}
+
+/// Passes on trade and liquidity data from the stableswap to the oracle.
+///
+/// Trades are recorded for the traded asset pair, liquidity changes for each pool asset against the pool share asset.
+pub struct StableswapHooksAdapter<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> StableswapHooks<AssetId> for StableswapHooksAdapter<Runtime>
+where
+	Runtime: pallet_ema_oracle::Config,
+{
+	fn on_liquidity_changed(pool_id: AssetId, state: PoolState<AssetId>) -> Result<(), DispatchError> {
+		let delta_issuance = state.issuance_before.abs_diff(state.issuance_after);
+
+		for (idx, asset_id) in state.assets.iter().enumerate() {
+			OnActivityHandler::<Runtime>::on_liquidity_changed(
+				STABLESWAP_SOURCE,
+				*asset_id,
+				pool_id,
+				state.delta[idx],
+				delta_issuance,
+				state.after[idx],
+				state.issuance_after,
+			)
+			.map_err(|(_, e)| e)?;
+		}
+
+		Ok(())
+	}
+
+	fn on_trade(
+		_pool_id: AssetId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		state: PoolState<AssetId>,
+	) -> Result<(), DispatchError> {
+		let idx_in = state.assets.iter().position(|asset| *asset == asset_in);
+		let idx_out = state.assets.iter().position(|asset| *asset == asset_out);
+
+		if let (Some(idx_in), Some(idx_out)) = (idx_in, idx_out) {
+			OnActivityHandler::<Runtime>::on_trade(
+				STABLESWAP_SOURCE,
+				asset_in,
+				asset_out,
+				state.delta[idx_in],
+				state.delta[idx_out],
+				state.after[idx_in],
+				state.after[idx_out],
+			)
+			.map_err(|(_, e)| e)?;
+		}
+
+		Ok(())
+	}
+
+	fn on_liquidity_changed_weight(n: usize) -> Weight {
+		OnActivityHandler::<Runtime>::on_liquidity_changed_weight().saturating_mul(n as u64)
+	}
+
+	fn on_trade_weight() -> Weight {
+		OnActivityHandler::<Runtime>::on_trade_weight()
+	}
 }
 
 /// Passes ema oracle price to the omnipool.
@@ -373,14 +455,14 @@ pub struct EmaOraclePriceAdapter<Period, Runtime>(PhantomData<(Period, Runtime)>
 impl<Period, Runtime> ExternalPriceProvider<AssetId, Price> for EmaOraclePriceAdapter<Period, Runtime>
 where
 	Period: Get<OraclePeriod>,
-	Runtime: pallet_ema_oracle::Config + pallet_omnipool::Config,
+	Runtime: pallet_ema_oracle::Config,
 {
 	type Error = DispatchError;
 
 	fn get_price(asset_a: AssetId, asset_b: AssetId) -> Result<Price, Self::Error> {
 		let (price, _) =
 			pallet_ema_oracle::Pallet::<Runtime>::get_price(asset_a, asset_b, Period::get(), OMNIPOOL_SOURCE)
-				.map_err(|_| pallet_omnipool::Error::<Runtime>::PriceDifferenceTooHigh)?;
+				.map_err(pallet_ema_oracle::Error::<Runtime>::from)?;
 		Ok(price)
 	}
 
@@ -389,66 +471,173 @@ where
 	}
 }
 
-pub struct OraclePriceProviderAdapterForOmnipool<AssetId, AggregatedPriceGetter, Lrna>(
-	PhantomData<(AssetId, AggregatedPriceGetter, Lrna)>,
+/// Passes the median of the ema oracle prices of `Periods` to the omnipool.
+///
+/// Fails if the oracle of any of the periods is younger than `MinOracleAge` blocks or its EMA
+/// liquidity of any of the assets is below `MinLiquidity`.
+pub struct EmaOracleMedianPriceAdapter<Periods, MinOracleAge, MinLiquidity, Runtime>(
+	PhantomData<(Periods, MinOracleAge, MinLiquidity, Runtime)>,
 );
 
-impl<AssetId, AggregatedPriceGetter, Lrna> PriceOracle<AssetId>
-	for OraclePriceProviderAdapterForOmnipool<AssetId, AggregatedPriceGetter, Lrna>
+impl<Periods, MinOracleAge, MinLiquidity, Runtime> ExternalPriceProvider<AssetId, Price>
+	for EmaOracleMedianPriceAdapter<Periods, MinOracleAge, MinLiquidity, Runtime>
 where
-	u32: From<AssetId>,
-	AggregatedPriceGetter: AggregatedPriceOracle<AssetId, BlockNumber, EmaPrice, Error = OracleError>,
+	Periods: Get<Vec<OraclePeriod>>,
+	MinOracleAge: Get<BlockNumber>,
+	MinLiquidity: Get<Balance>,
+	Runtime: pallet_ema_oracle::Config<BlockNumber = BlockNumber>,
+{
+	type Error = DispatchError;
+
+	fn get_price(asset_a: AssetId, asset_b: AssetId) -> Result<Price, Self::Error> {
+		let price = pallet_ema_oracle::Pallet::<Runtime>::get_median_price(
+			asset_a,
+			asset_b,
+			&Periods::get(),
+			OMNIPOOL_SOURCE,
+			MinOracleAge::get(),
+			MinLiquidity::get(),
+		)
+		.map_err(pallet_ema_oracle::Error::<Runtime>::from)?;
+		Ok(price)
+	}
+
+	fn get_price_weight() -> Weight {
+		pallet_ema_oracle::Pallet::<Runtime>::get_median_price_weight(Periods::get().len() as u32)
+	}
+}
+
+/// A hop of an oracle price path. Price of `asset_a/asset_b` is taken from the oracles of `source`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OraclePriceHop<AssetId> {
+	pub source: Source,
+	pub asset_a: AssetId,
+	pub asset_b: AssetId,
+}
+
+/// Provides the path of oracle hops the price of an asset pair is composed from.
+pub trait OraclePathProvider<AssetId> {
+	/// Return hops leading from `asset_a` to `asset_b` or `None` if there is no path for the pair.
+	fn get_path(asset_a: AssetId, asset_b: AssetId) -> Option<Vec<OraclePriceHop<AssetId>>>;
+}
+
+/// Path through the hub asset of the Omnipool: `asset_a/LRNA` and `LRNA/asset_b`.
+pub struct OmnipoolOraclePath<Lrna>(PhantomData<Lrna>);
+
+impl<AssetId, Lrna> OraclePathProvider<AssetId> for OmnipoolOraclePath<Lrna>
+where
+	AssetId: Copy,
 	Lrna: Get<AssetId>,
+{
+	fn get_path(asset_a: AssetId, asset_b: AssetId) -> Option<Vec<OraclePriceHop<AssetId>>> {
+		Some(vec![
+			OraclePriceHop {
+				source: OMNIPOOL_SOURCE,
+				asset_a,
+				asset_b: Lrna::get(),
+			},
+			OraclePriceHop {
+				source: OMNIPOOL_SOURCE,
+				asset_a: Lrna::get(),
+				asset_b,
+			},
+		])
+	}
+}
+
+/// Composes EMA oracle prices along the path of hops provided by `PathProvider`.
+///
+/// Price of `asset_a/asset_b` is the product of the prices of all hops, rounded to the nearest
+/// price representable by `EmaPrice` after each hop. Hops between the same asset are skipped.
+/// No price is returned if the oracle of any hop is younger than `MinOracleAge` blocks or the
+/// liquidity of any of its assets is below `MinLiquidity`.
+pub struct OraclePriceProvider<AssetId, AggregatedOracleGetter, PathProvider, MinOracleAge, MinLiquidity>(
+	PhantomData<(
+		AssetId,
+		AggregatedOracleGetter,
+		PathProvider,
+		MinOracleAge,
+		MinLiquidity,
+	)>,
+);
+
+impl<AssetId, AggregatedOracleGetter, PathProvider, MinOracleAge, MinLiquidity> PriceOracle<AssetId>
+	for OraclePriceProvider<AssetId, AggregatedOracleGetter, PathProvider, MinOracleAge, MinLiquidity>
+where
+	AssetId: PartialEq,
+	AggregatedOracleGetter: AggregatedOracle<AssetId, Balance, BlockNumber, EmaPrice, Error = OracleError>,
+	PathProvider: OraclePathProvider<AssetId>,
+	MinOracleAge: Get<BlockNumber>,
+	MinLiquidity: Get<Balance>,
 {
 	type Price = EmaPrice;
 
 	fn price(asset_a: AssetId, asset_b: AssetId, period: OraclePeriod) -> Option<EmaPrice> {
-		let price_asset_a_lrna = AggregatedPriceGetter::get_price(asset_a, Lrna::get(), period, OMNIPOOL_SOURCE);
+		PathProvider::get_path(asset_a, asset_b)?
+			.into_iter()
+			.filter(|hop| hop.asset_a != hop.asset_b)
+			.try_fold(EmaPrice::one(), |price, hop| {
+				let entry = AggregatedOracleGetter::get_entry(hop.asset_a, hop.asset_b, period, hop.source).ok()?;
+
+				if entry.oracle_age < MinOracleAge::get()
+					|| entry.liquidity.a < MinLiquidity::get()
+					|| entry.liquidity.b < MinLiquidity::get()
+				{
+					return None;
+				}
 
-		let price_asset_a_lrna = match price_asset_a_lrna {
-			Ok(price) => price.0,
-			Err(OracleError::SameAsset) => EmaPrice::from(1),
-			Err(_) => return None,
-		};
+				let nominator = U128::full_mul(price.n.into(), entry.price.n.into());
+				let denominator = U128::full_mul(price.d.into(), entry.price.d.into());
+				let (n, d) = round_to_rational((nominator, denominator), Rounding::Nearest);
 
-		let price_lrna_asset_b = AggregatedPriceGetter::get_price(Lrna::get(), asset_b, period, OMNIPOOL_SOURCE);
+				Some(EmaPrice::new(n, d))
+			})
+	}
+}
 
-		let price_lrna_asset_b = match price_lrna_asset_b {
-			Ok(price) => price.0,
-			Err(OracleError::SameAsset) => EmaPrice::from(1),
-			Err(_) => return None,
-		};
+/// Exposes oracle prices of `Period` provided by `PriceProvider` as spot prices, e.g. for the fee
+/// conversion in the multi payment pallet.
+pub struct OraclePriceAsSpotPrice<PriceProvider, Period>(PhantomData<(PriceProvider, Period)>);
 
-		let nominator = U128::full_mul(price_asset_a_lrna.n.into(), price_lrna_asset_b.n.into());
-		let denominator = U128::full_mul(price_asset_a_lrna.d.into(), price_lrna_asset_b.d.into());
+impl<AssetId, PriceProvider, Period> SpotPriceProvider<AssetId> for OraclePriceAsSpotPrice<PriceProvider, Period>
+where
+	PriceProvider: PriceOracle<AssetId, Price = EmaPrice>,
+	Period: Get<OraclePeriod>,
+{
+	type Price = FixedU128;
 
-		let rational_as_u128 = round_to_rational((nominator, denominator), Rounding::Nearest);
-		let price_in_ema_price = EmaPrice::new(rational_as_u128.0, rational_as_u128.1);
+	fn pair_exists(asset_a: AssetId, asset_b: AssetId) -> bool {
+		PriceProvider::price(asset_a, asset_b, Period::get()).is_some()
+	}
 
-		Some(price_in_ema_price)
+	fn spot_price(asset_a: AssetId, asset_b: AssetId) -> Option<Self::Price> {
+		let price = PriceProvider::price(asset_a, asset_b, Period::get())?;
+		FixedU128::checked_from_rational(price.n, price.d)
 	}
 }
 
-pub struct PriceAdjustmentAdapter<Runtime, LMInstance>(PhantomData<(Runtime, LMInstance)>);
+/// Provides the price adjustment of global farms from the price of the reward currency in the
+/// incentivized asset provided by `PriceProvider`.
+pub struct PriceAdjustmentAdapter<Runtime, LMInstance, PriceProvider>(
+	PhantomData<(Runtime, LMInstance, PriceProvider)>,
+);
 
-impl<Runtime, LMInstance> PriceAdjustment<GlobalFarmData<Runtime, LMInstance>>
-	for PriceAdjustmentAdapter<Runtime, LMInstance>
+impl<Runtime, LMInstance, PriceProvider> PriceAdjustment<GlobalFarmData<Runtime, LMInstance>>
+	for PriceAdjustmentAdapter<Runtime, LMInstance, PriceProvider>
 where
-	Runtime: warehouse_liquidity_mining::Config<LMInstance>
-		+ pallet_ema_oracle::Config
-		+ pallet_omnipool_liquidity_mining::Config,
+	Runtime: warehouse_liquidity_mining::Config<LMInstance> + pallet_omnipool_liquidity_mining::Config,
+	PriceProvider: PriceOracle<AssetId, Price = EmaPrice>,
 {
 	type Error = DispatchError;
 	type PriceAdjustment = FixedU128;
 
 	fn get(global_farm: &GlobalFarmData<Runtime, LMInstance>) -> Result<Self::PriceAdjustment, Self::Error> {
-		let (price, _) = pallet_ema_oracle::Pallet::<Runtime>::get_price(
+		let price = PriceProvider::price(
 			global_farm.reward_currency.into(),
 			global_farm.incentivized_asset.into(), //LRNA
 			OraclePeriod::TenMinutes,
-			OMNIPOOL_SOURCE,
 		)
-		.map_err(|_| pallet_omnipool_liquidity_mining::Error::<Runtime>::PriceAdjustmentNotAvailable)?;
+		.ok_or(pallet_omnipool_liquidity_mining::Error::<Runtime>::PriceAdjustmentNotAvailable)?;
 
 		FixedU128::checked_from_rational(price.n, price.d).ok_or_else(|| ArithmeticError::Overflow.into())
 	}
@@ -18,6 +18,8 @@
 use super::*;
 use codec::{Decode, Encode};
 use frame_support::{weights::IdentityFee, BoundedVec};
+use hydradx_traits::{AggregatedEntry, Liquidity, Volume};
+use primitives::constants::chain::XYK_SOURCE;
 use sp_runtime::{traits::One, DispatchResult, FixedU128};
 use sp_std::cell::RefCell;
 use sp_std::collections::btree_set::BTreeSet;
@@ -395,3 +397,209 @@ macro_rules! assert_that_fee_is_deposited {
 		});
 	};
 }
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+const ASSET_C: AssetId = 3;
+const ASSET_D: AssetId = 4;
+const HUB_ASSET: AssetId = 100;
+
+type OracleKey = (Source, AssetId, AssetId);
+
+thread_local! {
+	pub static ORACLE_ENTRIES: RefCell<BTreeMap<OracleKey, AggregatedEntry<Balance, BlockNumber, EmaPrice>>> = RefCell::new(BTreeMap::new());
+}
+
+/// Mock aggregated oracle returning the registered entries, inverted if requested in reverse order.
+struct MockAggregatedOracle;
+impl MockAggregatedOracle {
+	fn set_entry(
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		price: (Balance, Balance),
+		oracle_age: BlockNumber,
+	) {
+		Self::set_entry_with_liquidity(source, asset_a, asset_b, price, price, oracle_age);
+	}
+
+	fn set_entry_with_liquidity(
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		price: (Balance, Balance),
+		liquidity: (Balance, Balance),
+		oracle_age: BlockNumber,
+	) {
+		let entry = AggregatedEntry {
+			price: price.into(),
+			volume: Volume::default(),
+			liquidity: Liquidity::new(liquidity.0, liquidity.1),
+			oracle_age,
+		};
+		ORACLE_ENTRIES.with(|e| e.borrow_mut().insert((source, asset_a, asset_b), entry));
+	}
+}
+
+impl AggregatedOracle<AssetId, Balance, BlockNumber, EmaPrice> for MockAggregatedOracle {
+	type Error = OracleError;
+
+	fn get_entry(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		_period: OraclePeriod,
+		source: Source,
+	) -> Result<AggregatedEntry<Balance, BlockNumber, EmaPrice>, OracleError> {
+		if asset_a == asset_b {
+			return Err(OracleError::SameAsset);
+		}
+		ORACLE_ENTRIES.with(|e| {
+			let entries = e.borrow();
+			if let Some(entry) = entries.get(&(source, asset_a, asset_b)) {
+				return Ok(entry.clone());
+			}
+			entries
+				.get(&(source, asset_b, asset_a))
+				.map(|entry| AggregatedEntry {
+					price: EmaPrice::new(entry.price.d, entry.price.n),
+					volume: entry.volume.clone(),
+					liquidity: Liquidity::new(entry.liquidity.b, entry.liquidity.a),
+					oracle_age: entry.oracle_age,
+				})
+				.ok_or(OracleError::NotPresent)
+		})
+	}
+
+	fn get_entry_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Path `A -> B` in the Omnipool, `B -> C` in Stableswap and `C -> D` in XYK.
+struct MockPath;
+impl OraclePathProvider<AssetId> for MockPath {
+	fn get_path(asset_a: AssetId, asset_b: AssetId) -> Option<Vec<OraclePriceHop<AssetId>>> {
+		if (asset_a, asset_b) != (ASSET_A, ASSET_D) {
+			return None;
+		}
+		Some(vec![
+			OraclePriceHop {
+				source: OMNIPOOL_SOURCE,
+				asset_a: ASSET_A,
+				asset_b: ASSET_B,
+			},
+			OraclePriceHop {
+				source: STABLESWAP_SOURCE,
+				asset_a: ASSET_B,
+				asset_b: ASSET_C,
+			},
+			OraclePriceHop {
+				source: XYK_SOURCE,
+				asset_a: ASSET_C,
+				asset_b: ASSET_D,
+			},
+		])
+	}
+}
+
+frame_support::parameter_types! {
+	pub const HubAsset: AssetId = HUB_ASSET;
+	pub const MinOracleAge: BlockNumber = 10;
+	pub const MinLiquidity: Balance = 1_000;
+	pub const SpotPricePeriod: OraclePeriod = OraclePeriod::Short;
+}
+
+type MultiHopPrice = OraclePriceProvider<AssetId, MockAggregatedOracle, MockPath, MinOracleAge, MinLiquidity>;
+type SpotPrice = OraclePriceAsSpotPrice<MultiHopPrice, SpotPricePeriod>;
+type OmnipoolPrice =
+	OraclePriceProvider<AssetId, MockAggregatedOracle, OmnipoolOraclePath<HubAsset>, MinOracleAge, MinLiquidity>;
+
+fn set_multi_hop_entries() {
+	MockAggregatedOracle::set_entry(OMNIPOOL_SOURCE, ASSET_A, ASSET_B, (2_000, 1_000), 10);
+	MockAggregatedOracle::set_entry(STABLESWAP_SOURCE, ASSET_B, ASSET_C, (3_000, 1_000), 10);
+	MockAggregatedOracle::set_entry(XYK_SOURCE, ASSET_C, ASSET_D, (1_000, 4_000), 10);
+}
+
+#[test]
+fn oracle_price_provider_should_compose_prices_of_all_hops() {
+	set_multi_hop_entries();
+
+	assert_eq!(
+		MultiHopPrice::price(ASSET_A, ASSET_D, OraclePeriod::Short),
+		Some(EmaPrice::new(6_000_000_000, 4_000_000_000))
+	);
+}
+
+#[test]
+fn oracle_price_provider_should_return_none_when_there_is_no_path() {
+	set_multi_hop_entries();
+
+	assert_eq!(MultiHopPrice::price(ASSET_D, ASSET_A, OraclePeriod::Short), None);
+}
+
+#[test]
+fn oracle_price_provider_should_return_none_when_oracle_of_hop_is_missing() {
+	ORACLE_ENTRIES.with(|e| e.borrow_mut().clear());
+	MockAggregatedOracle::set_entry(OMNIPOOL_SOURCE, ASSET_A, ASSET_B, (2_000, 1_000), 10);
+	MockAggregatedOracle::set_entry(XYK_SOURCE, ASSET_C, ASSET_D, (1_000, 4_000), 10);
+
+	assert_eq!(MultiHopPrice::price(ASSET_A, ASSET_D, OraclePeriod::Short), None);
+}
+
+#[test]
+fn oracle_price_provider_should_return_none_when_oracle_of_hop_is_too_young() {
+	set_multi_hop_entries();
+	MockAggregatedOracle::set_entry(STABLESWAP_SOURCE, ASSET_B, ASSET_C, (3_000, 1_000), 9);
+
+	assert_eq!(MultiHopPrice::price(ASSET_A, ASSET_D, OraclePeriod::Short), None);
+}
+
+#[test]
+fn oracle_price_provider_should_return_none_when_liquidity_of_hop_is_too_low() {
+	set_multi_hop_entries();
+	MockAggregatedOracle::set_entry_with_liquidity(XYK_SOURCE, ASSET_C, ASSET_D, (1_000, 4_000), (999, 4_000), 10);
+	assert_eq!(MultiHopPrice::price(ASSET_A, ASSET_D, OraclePeriod::Short), None);
+
+	MockAggregatedOracle::set_entry_with_liquidity(XYK_SOURCE, ASSET_C, ASSET_D, (1_000, 4_000), (1_000, 999), 10);
+	assert_eq!(MultiHopPrice::price(ASSET_A, ASSET_D, OraclePeriod::Short), None);
+}
+
+#[test]
+fn omnipool_oracle_path_should_compose_prices_through_hub_asset() {
+	ORACLE_ENTRIES.with(|e| e.borrow_mut().clear());
+	MockAggregatedOracle::set_entry(OMNIPOOL_SOURCE, ASSET_A, HUB_ASSET, (2_000, 1_000), 10);
+	MockAggregatedOracle::set_entry(OMNIPOOL_SOURCE, ASSET_B, HUB_ASSET, (5_000, 1_000), 10);
+
+	assert_eq!(
+		OmnipoolPrice::price(ASSET_A, ASSET_B, OraclePeriod::Short),
+		Some(EmaPrice::new(2_000_000, 5_000_000))
+	);
+}
+
+#[test]
+fn omnipool_oracle_path_should_skip_hop_between_same_assets() {
+	ORACLE_ENTRIES.with(|e| e.borrow_mut().clear());
+	MockAggregatedOracle::set_entry(OMNIPOOL_SOURCE, ASSET_A, HUB_ASSET, (2_000, 1_000), 10);
+
+	assert_eq!(
+		OmnipoolPrice::price(HUB_ASSET, ASSET_A, OraclePeriod::Short),
+		Some(EmaPrice::new(1_000, 2_000))
+	);
+	assert_eq!(
+		OmnipoolPrice::price(ASSET_A, HUB_ASSET, OraclePeriod::Short),
+		Some(EmaPrice::new(2_000, 1_000))
+	);
+}
+
+#[test]
+fn oracle_price_as_spot_price_should_convert_oracle_price() {
+	set_multi_hop_entries();
+
+	assert!(SpotPrice::pair_exists(ASSET_A, ASSET_D));
+	assert_eq!(
+		SpotPrice::spot_price(ASSET_A, ASSET_D),
+		Some(FixedU128::from_float(1.5))
+	);
+	assert!(!SpotPrice::pair_exists(ASSET_D, ASSET_A));
+	assert_eq!(SpotPrice::spot_price(ASSET_D, ASSET_A), None);
+}
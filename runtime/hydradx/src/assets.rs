@@ -19,30 +19,33 @@ use super::*;
 use crate::system::NativeAssetId;
 
 use hydradx_adapters::{
-	inspect::MultiInspectAdapter, EmaOraclePriceAdapter, OmnipoolHookAdapter, OracleAssetVolumeProvider,
-	OraclePriceProviderAdapterForOmnipool, PriceAdjustmentAdapter,
+	inspect::MultiInspectAdapter, EmaOracleMedianPriceAdapter, EmaOraclePriceAdapter, OmnipoolHookAdapter,
+	OmnipoolOraclePath, OracleAssetVolumeProvider, OraclePriceProvider, PriceAdjustmentAdapter, StableswapHooksAdapter,
 };
-use hydradx_traits::{OraclePeriod, Source};
+use hydradx_traits::{AccountIdFor, OraclePeriod, Source};
 use pallet_currencies::BasicCurrencyAdapter;
 use pallet_dca::RelayChainBlockHashProvider;
 use pallet_omnipool::traits::EnsurePriceWithin;
 use pallet_otc::NamedReserveIdentifier;
 use pallet_transaction_multi_payment::{AddTxAssetOnAccount, RemoveTxAssetOnKilled};
 use primitives::constants::{
-	chain::OMNIPOOL_SOURCE,
+	chain::{LBP_SOURCE, OMNIPOOL_SOURCE, STABLESWAP_SOURCE, XYK_SOURCE},
 	currency::{NATIVE_EXISTENTIAL_DEPOSIT, UNITS},
+	time::DAYS,
 };
 
+use core::ops::RangeInclusive;
 use frame_support::{
 	parameter_types,
-	sp_runtime::traits::One,
+	sp_runtime::traits::{BlakeTwo256, Hash as HashT, One},
 	sp_runtime::{FixedU128, Permill},
-	traits::{AsEnsureOriginWithArg, ConstU32, Contains, EnsureOrigin, NeverEnsureOrigin},
+	traits::{AsEnsureOriginWithArg, ConstU32, Contains, EnsureOrigin, IsInVec, NeverEnsureOrigin},
 	BoundedVec, PalletId,
 };
 use frame_system::{EnsureRoot, RawOrigin};
 use orml_traits::currency::MutationHooks;
 use pallet_dynamic_fees::types::FeeParams;
+use sp_std::num::NonZeroU16;
 
 parameter_types! {
 	pub const NativeExistentialDeposit: u128 = NATIVE_EXISTENTIAL_DEPOSIT;
@@ -206,8 +209,18 @@ parameter_types! {
 	pub const OmnipoolCollectionId: CollectionId = 1337u128;
 	pub const EmaOracleSpotPriceLastBlock: OraclePeriod = OraclePeriod::LastBlock;
 	pub const EmaOracleSpotPriceShort: OraclePeriod = OraclePeriod::Short;
+	/// Spot price is also compared with the median of the oracle prices of these periods.
+	pub OmnipoolPriceBarrierPeriods: Vec<OraclePeriod> = vec![OraclePeriod::LastBlock, OraclePeriod::Short, OraclePeriod::TenMinutes];
+	/// Freshly initialized oracles are not trusted by the price barrier.
+	pub const OmnipoolPriceBarrierMinOracleAge: BlockNumber = 10;
+	pub OmnipoolPriceBarrierMinOracleLiquidity: Balance = MinPoolLiquidity::get();
 	pub const OmnipoolMaxAllowedPriceDifference: Permill = Permill::from_percent(1);
 	pub MinimumWithdrawalFee: Permill = Permill::from_rational(1u32,10000);
+	pub const OmnipoolFeeLedgerPeriod: BlockNumber = DAYS;
+	// Protocol fee is burned. Trade weights do not account for transferring it to a collector.
+	pub const OmnipoolProtocolFeeCollector: Option<AccountId> = None;
+	pub const OmnipoolImbalanceBurnRatio: Permill = Permill::from_percent(1);
+	pub const OmnipoolMaxAddLiquidityAssets: u32 = 10;
 }
 
 impl pallet_omnipool::Config for Runtime {
@@ -246,9 +259,25 @@ impl pallet_omnipool::Config for Runtime {
 			OmnipoolMaxAllowedPriceDifference,
 			CircuitBreakerWhitelist,
 		>,
+		EnsurePriceWithin<
+			AccountId,
+			AssetId,
+			EmaOracleMedianPriceAdapter<
+				OmnipoolPriceBarrierPeriods,
+				OmnipoolPriceBarrierMinOracleAge,
+				OmnipoolPriceBarrierMinOracleLiquidity,
+				Runtime,
+			>,
+			OmnipoolMaxAllowedPriceDifference,
+			CircuitBreakerWhitelist,
+		>,
 	);
 	type ExternalPriceOracle = EmaOraclePriceAdapter<EmaOracleSpotPriceShort, Runtime>;
 	type Fee = pallet_dynamic_fees::UpdateAndRetrieveFees<Runtime>;
+	type ProtocolFeeCollector = OmnipoolProtocolFeeCollector;
+	type FeeLedgerPeriod = OmnipoolFeeLedgerPeriod;
+	type ImbalanceBurnRatio = OmnipoolImbalanceBurnRatio;
+	type MaxAddLiquidityAssets = OmnipoolMaxAddLiquidityAssets;
 }
 
 pub struct CircuitBreakerWhitelist;
@@ -280,21 +309,38 @@ impl pallet_circuit_breaker::Config for Runtime {
 parameter_types! {
 	pub SupportedPeriods: BoundedVec<OraclePeriod, ConstU32<{ pallet_ema_oracle::MAX_PERIODS }>> = BoundedVec::truncate_from(vec![
 		OraclePeriod::LastBlock, OraclePeriod::Short, OraclePeriod::TenMinutes]);
+	pub LocalOracleSources: Vec<Source> = vec![OMNIPOOL_SOURCE, STABLESWAP_SOURCE, XYK_SOURCE, LBP_SOURCE];
 }
 
 impl pallet_ema_oracle::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::ema_oracle::HydraWeight<Runtime>;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
 	/// The definition of the oracle time periods currently assumes a 6 second block time.
 	/// We use the parachain blocks anyway, because we want certain guarantees over how many blocks correspond
 	/// to which smoothing factor.
 	type BlockNumberProvider = System;
 	type SupportedPeriods = SupportedPeriods;
-	/// With every asset trading against LRNA we will only have as many pairs as there will be assets, so
-	/// 20 seems a decent upper bound for the forseeable future.
-	type MaxUniqueEntries = ConstU32<20>;
+	/// With every asset trading against LRNA we will only have as many pairs as there will be assets.
+	/// Stableswap pools add an entry per traded pair and per pool asset against the pool's share asset,
+	/// so 40 seems a decent upper bound for the forseeable future.
+	type MaxUniqueEntries = ConstU32<40>;
+	/// External feeders update a few pairs arriving over XCM per block.
+	type MaxUniqueExternalEntries = ConstU32<10>;
+	type LocalSources = IsInVec<LocalOracleSources>;
 }
 
+parameter_types! {
+	/// Oracles initialized less than 5 blocks ago are not used for pricing.
+	pub const OmnipoolOracleMinAge: BlockNumber = 5;
+	/// Oracles of pools below the minimum Omnipool liquidity are not used for pricing.
+	pub OmnipoolOracleMinLiquidity: Balance = MinPoolLiquidity::get();
+}
+
+/// Composes Omnipool oracle prices of a pair through LRNA.
+pub type OmnipoolOraclePriceProvider =
+	OraclePriceProvider<AssetId, EmaOracle, OmnipoolOraclePath<LRNA>, OmnipoolOracleMinAge, OmnipoolOracleMinLiquidity>;
+
 pub struct DustRemovalWhitelist;
 
 impl Contains<AccountId> for DustRemovalWhitelist {
@@ -343,7 +389,8 @@ impl warehouse_liquidity_mining::Config<OmnipoolLiquidityMiningInstance> for Run
 	type MaxYieldFarmsPerGlobalFarm = MaxYieldFarmsPerGlobalFarm;
 	type AssetRegistry = AssetRegistry;
 	type NonDustableWhitelistHandler = Duster;
-	type PriceAdjustment = PriceAdjustmentAdapter<Runtime, OmnipoolLiquidityMiningInstance>;
+	type PriceAdjustment =
+		PriceAdjustmentAdapter<Runtime, OmnipoolLiquidityMiningInstance, OmnipoolOraclePriceProvider>;
 }
 
 parameter_types! {
@@ -402,6 +449,7 @@ parameter_types! {
 	pub MaxPriceDifference: Permill = Permill::from_rational(15u32, 1000u32);
 	pub NamedReserveId: NamedReserveIdentifier = *b"dcaorder";
 	pub MaxNumberOfRetriesOnError: u8 = 3;
+	pub MaxLimitOrderDuration: BlockNumber = 7 * DAYS;
 }
 
 impl pallet_dca::Config for Runtime {
@@ -410,7 +458,7 @@ impl pallet_dca::Config for Runtime {
 	type Currencies = Currencies;
 	type RelayChainBlockHashProvider = RelayChainBlockHashProviderAdapter<Runtime>;
 	type RandomnessProvider = DCA;
-	type OraclePriceProvider = OraclePriceProviderAdapterForOmnipool<AssetId, EmaOracle, LRNA>;
+	type OraclePriceProvider = OmnipoolOraclePriceProvider;
 	type SpotPriceProvider = Omnipool;
 	type MaxPriceDifferenceBetweenBlocks = MaxPriceDifference;
 	type MaxSchedulePerBlock = MaxSchedulesPerBlock;
@@ -418,12 +466,110 @@ impl pallet_dca::Config for Runtime {
 	type NativeAssetId = NativeAssetId;
 	type MinBudgetInNativeCurrency = MinBudgetInNativeCurrency;
 	type MinimumTradingLimit = MinTradingLimit;
+	type MaxLimitOrderDuration = MaxLimitOrderDuration;
 	type FeeReceiver = TreasuryAccount;
 	type NamedReserveId = NamedReserveId;
 	type WeightToFee = WeightToFee;
 	type WeightInfo = weights::dca::HydraWeight<Runtime>;
 }
 
+pub struct StableswapAccountIdConstructor;
+
+impl AccountIdFor<AssetId> for StableswapAccountIdConstructor {
+	type AccountId = AccountId;
+
+	fn from_assets(asset: &AssetId, identifier: Option<&[u8]>) -> Self::AccountId {
+		let name = Self::name(asset, identifier);
+		AccountId::from(<[u8; 32]>::from(BlakeTwo256::hash(&name[..])))
+	}
+
+	fn name(asset: &AssetId, identifier: Option<&[u8]>) -> Vec<u8> {
+		let mut buf: Vec<u8> = if let Some(ident) = identifier {
+			ident.to_vec()
+		} else {
+			vec![]
+		};
+		buf.extend_from_slice(&(asset).to_le_bytes());
+
+		buf
+	}
+}
+
+parameter_types! {
+	pub StableswapAmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
+}
+
+impl pallet_stableswap::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type BlockNumberProvider = System;
+	type AssetId = AssetId;
+	type Currency = Currencies;
+	type ShareAccountId = StableswapAccountIdConstructor;
+	type AssetRegistry = AssetRegistry;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type DustAccountHandler = Duster;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type MinTradingLimit = MinTradingLimit;
+	type AmplificationRange = StableswapAmplificationRange;
+	type Hooks = StableswapHooksAdapter<Runtime>;
+	type WeightInfo = weights::stableswap::HydraWeight<Runtime>;
+}
+
+impl pallet_omnipool_subpools::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = weights::omnipool_subpools::HydraWeight<Runtime>;
+}
+
+parameter_types! {
+	pub XYKExchangeFee: (u32, u32) = (3, 1_000);
+	pub const XYKMaxInRatio: u128 = 3;
+	pub const XYKMaxOutRatio: u128 = 3;
+	pub const XYKOracleSourceIdentifier: Source = XYK_SOURCE;
+}
+
+impl pallet_xyk::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetRegistry = AssetRegistry;
+	type AssetPairAccountId = pallet_xyk::AssetPairAccountId<Self>;
+	type Currency = Currencies;
+	type NativeAssetId = NativeAssetId;
+	type WeightInfo = weights::xyk::HydraWeight<Runtime>;
+	type GetExchangeFee = XYKExchangeFee;
+	type MinTradingLimit = MinTradingLimit;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type MaxInRatio = XYKMaxInRatio;
+	type MaxOutRatio = XYKMaxOutRatio;
+	type CanCreatePool = pallet_xyk::AllowAllPools;
+	type AMMHandler = pallet_ema_oracle::OnActivityHandler<Runtime>;
+	type NonDustableWhitelistHandler = Duster;
+	type OracleSource = XYKOracleSourceIdentifier;
+}
+
+parameter_types! {
+	pub LBPRepayFee: (u32, u32) = (2, 10);
+	pub const LBPMaxInRatio: u128 = 3;
+	pub const LBPMaxOutRatio: u128 = 3;
+	pub const LBPOracleSourceIdentifier: Source = LBP_SOURCE;
+}
+
+impl pallet_lbp::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type AssetRegistry = AssetRegistry;
+	type CreatePoolOrigin = SuperMajorityTechCommittee;
+	type AssetPairAccountId = pallet_xyk::AssetPairAccountId<Self>;
+	type BlockNumberProvider = System;
+	type WeightInfo = weights::lbp::HydraWeight<Runtime>;
+	type MinTradingLimit = MinTradingLimit;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type MaxInRatio = LBPMaxInRatio;
+	type MaxOutRatio = LBPMaxOutRatio;
+	type RepayFee = LBPRepayFee;
+	type AMMHandler = pallet_ema_oracle::OnActivityHandler<Runtime>;
+	type OracleSource = LBPOracleSourceIdentifier;
+}
+
 parameter_types! {
 	pub const MaxNumberOfTrades: u8 = 5;
 }
@@ -433,13 +579,18 @@ impl pallet_route_executor::Config for Runtime {
 	type AssetId = AssetId;
 	type Balance = Balance;
 	type MaxNumberOfTrades = MaxNumberOfTrades;
+	type ExistentialDepositMultiplier = ExistentialDepositMultiplier;
+	type MinimumTradingLimit = MinTradingLimit;
 	type Currency = MultiInspectAdapter<AccountId, AssetId, Balance, Balances, Tokens, NativeAssetId>;
-	type AMM = Omnipool;
+	type AMM = (Omnipool, Stableswap, XYK, LBP);
+	type TechnicalOrigin = SuperMajorityTechCommittee;
 	type WeightInfo = weights::route_executor::HydraWeight<Runtime>;
 }
 
 parameter_types! {
 	pub const ExistentialDepositMultiplier: u8 = 5;
+	pub const MaxExpiredOrdersPerBlock: u32 = 20;
+	pub const MaxAllowedTakers: u32 = 10;
 }
 
 impl pallet_otc::Config for Runtime {
@@ -449,6 +600,9 @@ impl pallet_otc::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type ExistentialDeposits = AssetRegistry;
 	type ExistentialDepositMultiplier = ExistentialDepositMultiplier;
+	type MaxExpiredOrdersPerBlock = MaxExpiredOrdersPerBlock;
+	type MaxAllowedTakers = MaxAllowedTakers;
+	type Router = Router;
 	type WeightInfo = weights::otc::HydraWeight<Runtime>;
 }
 
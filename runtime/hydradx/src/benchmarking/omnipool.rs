@@ -29,6 +29,7 @@ pub fn update_balance(currency_id: AssetId, who: &AccountId, balance: Balance) {
 
 const TVL_CAP: Balance = 222_222_000_000_000_000_000_000;
 
+// Price barrier does not accept oracles younger than `OmnipoolPriceBarrierMinOracleAge` blocks.
 fn run_to_block(to: u32) {
 	while System::block_number() < to {
 		let b = System::block_number();
@@ -144,12 +145,63 @@ runtime_benchmarks! {
 
 		let current_position_id = Omnipool::next_position_id();
 
-		run_to_block(10);
+		run_to_block(20);
 	}: { Omnipool::add_liquidity(RawOrigin::Signed(lp_provider).into(), token_id, liquidity_added)? }
 	verify {
 		assert!(Omnipool::positions(current_position_id).is_some());
 	}
 
+	add_liquidity_multiple {
+		let n in 1 .. <Runtime as pallet_omnipool::Config>::MaxAddLiquidityAssets::get();
+
+		// Initialize pool
+		let stable_amount: Balance = 1_000_000_000_000_000u128;
+		let native_amount: Balance = 1_000_000_000_000_000u128;
+		let stable_price: FixedU128= FixedU128::from((1,2));
+		let native_price: FixedU128= FixedU128::from(1);
+
+		let acc = Omnipool::protocol_account();
+		let native_id = <Runtime as pallet_omnipool::Config>::HdxAssetId::get();
+		let stable_id = <Runtime as pallet_omnipool::Config>::StableCoinAssetId::get();
+
+		Omnipool::set_tvl_cap(RawOrigin::Root.into(), TVL_CAP)?;
+
+		update_balance(stable_id, &acc, stable_amount);
+		update_balance(native_id, &acc, native_amount);
+
+		Omnipool::initialize_pool(RawOrigin::Root.into(), stable_price, native_price,Permill::from_percent(100), Permill::from_percent(100))?;
+
+		let owner: AccountId = account("owner", 0, 1);
+		let lp_provider: AccountId = account("provider", 1, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000u128;
+		let liquidity_added = 1_000_000_000_000_u128;
+
+		let mut assets = vec![];
+
+		for i in 0..n {
+			//Register new asset in asset registry
+			let token_id = AssetRegistry::create_asset(&[b"FCK".to_vec(), i.to_be_bytes().to_vec()].concat(), Balance::one())?;
+
+			update_balance(token_id, &acc, token_amount);
+
+			// Add the token to the pool
+			Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price, Permill::from_percent(100), owner.clone())?;
+
+			update_balance(token_id, &lp_provider, 500_000_000_000_000);
+
+			assets.push((token_id, liquidity_added));
+		}
+
+		let current_position_id = Omnipool::next_position_id();
+
+		run_to_block(20);
+	}: { Omnipool::add_liquidity_multiple(RawOrigin::Signed(lp_provider).into(), assets)? }
+	verify {
+		assert!(Omnipool::positions(current_position_id).is_some());
+	}
+
 	remove_liquidity {
 		// Initialize pool
 		let stable_amount: Balance = 1_000_000_000_000_000u128;
@@ -190,7 +242,7 @@ runtime_benchmarks! {
 
 		let current_position_id = Omnipool::next_position_id();
 
-		run_to_block(10);
+		run_to_block(20);
 		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
 
 		// to ensure worst case - Let's do a trade to make sure price changes, so LP provider receives some LRNA ( which does additional transfer)
@@ -248,7 +300,7 @@ runtime_benchmarks! {
 
 		let current_position_id = Omnipool::next_position_id();
 
-		run_to_block(10);
+		run_to_block(20);
 		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider).into(), token_id, liquidity_added)?;
 
 		let buyer: AccountId = account("buyer", 2, 1);
@@ -264,6 +316,8 @@ runtime_benchmarks! {
 	}: { Omnipool::sell(RawOrigin::Signed(seller.clone()).into(), token_id, stable_id, amount_sell, buy_min_amount)? }
 	verify {
 		assert!(<Runtime as pallet_omnipool::Config>::Currency::free_balance(stable_id, &seller) >= buy_min_amount);
+		// fees of the trade are recorded in fee ledger
+		assert!(Omnipool::fee_ledger(stable_id).current.asset_fee > Balance::zero());
 	}
 
 	buy {
@@ -306,7 +360,7 @@ runtime_benchmarks! {
 
 		let current_position_id = Omnipool::next_position_id();
 
-		run_to_block(10);
+		run_to_block(20);
 		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider).into(), token_id, liquidity_added)?;
 
 		let buyer: AccountId = account("buyer", 2, 1);
@@ -322,6 +376,8 @@ runtime_benchmarks! {
 	}: { Omnipool::buy(RawOrigin::Signed(seller.clone()).into(), stable_id, token_id, amount_buy, sell_max_limit)? }
 	verify {
 		assert!(<Runtime as pallet_omnipool::Config>::Currency::free_balance(stable_id, &seller) >= Balance::zero());
+		// fees of the trade are recorded in fee ledger
+		assert!(Omnipool::fee_ledger(token_id).current.asset_fee > Balance::zero());
 	}
 
 	set_asset_tradable_state {
@@ -401,7 +457,7 @@ runtime_benchmarks! {
 
 		let current_position_id = Omnipool::next_position_id();
 
-		run_to_block(10);
+		run_to_block(20);
 		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
 
 	}: {Omnipool::sacrifice_position(RawOrigin::Signed(lp_provider).into(), current_position_id)? }
@@ -433,6 +489,192 @@ runtime_benchmarks! {
 		assert!(asset_state.cap == 100_000_000_000_000_000u128);
 	}
 
+	split_position {
+		// Initialize pool
+		let stable_amount: Balance = 1_000_000_000_000_000u128;
+		let native_amount: Balance = 1_000_000_000_000_000u128;
+		let stable_price: FixedU128 = FixedU128::from((1,2));
+		let native_price: FixedU128 = FixedU128::from(1);
+
+		let acc = Omnipool::protocol_account();
+		let native_id = <Runtime as pallet_omnipool::Config>::HdxAssetId::get();
+		let stable_id = <Runtime as pallet_omnipool::Config>::StableCoinAssetId::get();
+
+		Omnipool::set_tvl_cap(RawOrigin::Root.into(), TVL_CAP)?;
+
+		update_balance(stable_id, &acc, stable_amount);
+		update_balance(native_id, &acc, native_amount);
+
+		Omnipool::initialize_pool(RawOrigin::Root.into(), stable_price, native_price, Permill::from_percent(100), Permill::from_percent(100))?;
+
+		// Register new asset in asset registry
+		let token_id = AssetRegistry::create_asset(&b"FCK".to_vec(), Balance::one())?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price,Permill::from_percent(100), owner)?;
+
+		// Create LP provider account with correct balance
+		let lp_provider: AccountId = account("provider", 1, 1);
+		update_balance(token_id, &lp_provider, 500_000_000_000_000_u128);
+
+		let liquidity_added = 1_000_000_000_000_u128;
+
+		let current_position_id = Omnipool::next_position_id();
+
+		run_to_block(20);
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+
+		let next_position_id = Omnipool::next_position_id();
+
+	}: { Omnipool::split_position(RawOrigin::Signed(lp_provider).into(), current_position_id, liquidity_added / 2)? }
+	verify {
+		assert!(Omnipool::positions(next_position_id).is_some());
+	}
+
+	merge_positions {
+		// Initialize pool
+		let stable_amount: Balance = 1_000_000_000_000_000u128;
+		let native_amount: Balance = 1_000_000_000_000_000u128;
+		let stable_price: FixedU128 = FixedU128::from((1,2));
+		let native_price: FixedU128 = FixedU128::from(1);
+
+		let acc = Omnipool::protocol_account();
+		let native_id = <Runtime as pallet_omnipool::Config>::HdxAssetId::get();
+		let stable_id = <Runtime as pallet_omnipool::Config>::StableCoinAssetId::get();
+
+		Omnipool::set_tvl_cap(RawOrigin::Root.into(), TVL_CAP)?;
+
+		update_balance(stable_id, &acc, stable_amount);
+		update_balance(native_id, &acc, native_amount);
+
+		Omnipool::initialize_pool(RawOrigin::Root.into(), stable_price, native_price, Permill::from_percent(100), Permill::from_percent(100))?;
+
+		// Register new asset in asset registry
+		let token_id = AssetRegistry::create_asset(&b"FCK".to_vec(), Balance::one())?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price,Permill::from_percent(100), owner)?;
+
+		// Create LP provider account with correct balance
+		let lp_provider: AccountId = account("provider", 1, 1);
+		update_balance(token_id, &lp_provider, 500_000_000_000_000_u128);
+
+		let liquidity_added = 1_000_000_000_000_u128;
+
+		let current_position_id = Omnipool::next_position_id();
+
+		run_to_block(20);
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+
+		// Move the price so the positions have different prices
+		Omnipool::sell(RawOrigin::Signed(lp_provider.clone()).into(), token_id, stable_id, 100_000_000_000_u128, 0u128)?;
+
+		let other_position_id = Omnipool::next_position_id();
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+
+	}: { Omnipool::merge_positions(RawOrigin::Signed(lp_provider).into(), current_position_id, other_position_id)? }
+	verify {
+		assert!(Omnipool::positions(other_position_id).is_none());
+	}
+
+	remove_token {
+		// Initialize pool
+		let stable_amount: Balance = 1_000_000_000_000_000u128;
+		let native_amount: Balance = 1_000_000_000_000_000u128;
+		let stable_price: FixedU128 = FixedU128::from((1,2));
+		let native_price: FixedU128 = FixedU128::from(1);
+
+		let acc = Omnipool::protocol_account();
+		let native_id = <Runtime as pallet_omnipool::Config>::HdxAssetId::get();
+		let stable_id = <Runtime as pallet_omnipool::Config>::StableCoinAssetId::get();
+
+		Omnipool::set_tvl_cap(RawOrigin::Root.into(), TVL_CAP)?;
+
+		update_balance(stable_id, &acc, stable_amount);
+		update_balance(native_id, &acc, native_amount);
+
+		Omnipool::initialize_pool(RawOrigin::Root.into(), stable_price, native_price, Permill::from_percent(100), Permill::from_percent(100))?;
+
+		// Register new asset in asset registry
+		let token_id = AssetRegistry::create_asset(&b"FCK".to_vec(), Balance::one())?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		let position_id = Omnipool::next_position_id();
+
+		// Add the token to the pool
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price,Permill::from_percent(100), owner.clone())?;
+
+		Omnipool::sacrifice_position(RawOrigin::Signed(owner).into(), position_id)?;
+		Omnipool::set_asset_tradable_state(RawOrigin::Root.into(), token_id, Tradability::FROZEN)?;
+
+		let beneficiary: AccountId = account("beneficiary", 2, 1);
+
+	}: { Omnipool::remove_token(RawOrigin::Root.into(), token_id, beneficiary.clone())? }
+	verify {
+		assert!(Omnipool::assets(token_id).is_none());
+		assert!(<Runtime as pallet_omnipool::Config>::Currency::free_balance(token_id, &beneficiary) == token_amount);
+	}
+
+	reduce_hub_asset_imbalance {
+		// Initialize pool
+		let stable_amount: Balance = 1_000_000_000_000_000u128;
+		let native_amount: Balance = 1_000_000_000_000_000u128;
+		let stable_price: FixedU128 = FixedU128::from((1,2));
+		let native_price: FixedU128 = FixedU128::from(1);
+
+		let acc = Omnipool::protocol_account();
+		let native_id = <Runtime as pallet_omnipool::Config>::HdxAssetId::get();
+		let stable_id = <Runtime as pallet_omnipool::Config>::StableCoinAssetId::get();
+		let hub_id = <Runtime as pallet_omnipool::Config>::HubAssetId::get();
+
+		Omnipool::set_tvl_cap(RawOrigin::Root.into(), TVL_CAP)?;
+
+		update_balance(stable_id, &acc, stable_amount);
+		update_balance(native_id, &acc, native_amount);
+
+		Omnipool::initialize_pool(RawOrigin::Root.into(), stable_price, native_price, Permill::from_percent(100), Permill::from_percent(100))?;
+
+		// Selling hub asset creates negative imbalance
+		let seller: AccountId = account("seller", 1, 1);
+		update_balance(hub_id, &seller, 100_000_000_000_000_u128);
+
+		run_to_block(20);
+		Omnipool::sell(RawOrigin::Signed(seller).into(), hub_id, stable_id, 50_000_000_000_000_u128, 0u128)?;
+
+		let collector: AccountId = account("collector", 2, 1);
+		update_balance(hub_id, &collector, 100_000_000_000_000_u128);
+		pallet_omnipool::CollectedProtocolFees::<Runtime>::put(100_000_000_000_000_u128);
+
+		let imbalance = Omnipool::current_imbalance();
+
+	}: { Omnipool::reduce_hub_asset_imbalance(&collector)? }
+	verify {
+		assert!(Omnipool::current_imbalance().value < imbalance.value);
+	}
+
 }
 
 #[cfg(test)]
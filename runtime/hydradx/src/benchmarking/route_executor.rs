@@ -16,7 +16,7 @@
 // limitations under the License.
 #![allow(clippy::result_large_err)]
 
-use crate::{AccountId, AssetId, Balance, Currencies, Omnipool, Runtime, Tokens};
+use crate::{AccountId, AssetId, Balance, Currencies, Omnipool, Runtime, Stableswap, Tokens};
 
 use super::*;
 
@@ -30,6 +30,9 @@ pub const TVL_CAP: Balance = 222_222_000_000_000_000_000_000;
 pub const HDX: AssetId = 0;
 pub const LRNA: AssetId = 1;
 pub const DAI: AssetId = 2;
+pub const USDC: AssetId = 3;
+pub const USDT: AssetId = 4;
+pub const STABLE_POOL: AssetId = 5;
 
 pub const ONE: Balance = 1_000_000_000_000;
 
@@ -84,6 +87,48 @@ fn initialize_omnipool() -> DispatchResult {
 	Ok(())
 }
 
+fn initialize_stableswap() -> DispatchResult {
+	let _ = regi_asset(b"USDC".to_vec(), UNITS, USDC);
+	let _ = regi_asset(b"USDT".to_vec(), UNITS, USDT);
+	let _ = regi_asset(b"STABLE".to_vec(), UNITS, STABLE_POOL);
+
+	Stableswap::create_pool(
+		RawOrigin::Root.into(),
+		STABLE_POOL,
+		vec![DAI, USDC, USDT],
+		100,
+		Permill::from_rational(1u32, 10_000u32),
+		Permill::zero(),
+	)?;
+
+	let provider: AccountId = account("provider", 0, SEED);
+	let liquidity: Balance = 1_000_000 * ONE;
+	let mut assets = vec![];
+	for asset_id in [DAI, USDC, USDT] {
+		assert_ok!(Tokens::set_balance(
+			RawOrigin::Root.into(),
+			provider.clone(),
+			asset_id,
+			liquidity,
+			0
+		));
+		assets.push(AssetLiquidity {
+			asset_id,
+			amount: liquidity,
+		});
+	}
+
+	Stableswap::add_liquidity(RawOrigin::Signed(provider).into(), STABLE_POOL, assets, 0)
+}
+
+fn trade(pool: PoolType<AssetId>, asset_in: AssetId, asset_out: AssetId) -> Trade<AssetId> {
+	Trade {
+		pool,
+		asset_in,
+		asset_out,
+	}
+}
+
 pub fn regi_asset(name: Vec<u8>, deposit: Balance, asset_id: AssetId) -> Result<AssetId, DispatchError> {
 	let name = AssetRegistry::to_bounded_name(name)?;
 	AssetRegistry::register_asset(
@@ -132,7 +177,8 @@ fn fund<T: pallet_omnipool::Config>(
 use frame_support::assert_ok;
 use frame_support::traits::Hooks;
 use hydradx_traits::router::PoolType;
-use pallet_route_executor::Trade;
+use pallet_route_executor::{AssetPair, Trade};
+use pallet_stableswap::types::AssetLiquidity;
 use sp_runtime::{DispatchError, DispatchResult, FixedU128, Permill};
 use sp_std::vec;
 
@@ -227,8 +273,60 @@ runtime_benchmarks! {
 		assert!(<Currencies as MultiCurrency<_>>::total_balance(asset_out, &caller) > 0);
 	}
 
+	set_route {
+		// Worst case is the longest stored route, which has to be calculated as well
+		let n in 1..5;
+
+		initialize_omnipool()?;
+		initialize_stableswap()?;
+
+		// New route: HDX -> DAI in Omnipool followed by n - 1 trades in Stableswap
+		let stable_assets = [DAI, USDC, USDT];
+		let mut trades = vec![trade(PoolType::Omnipool, HDX, DAI)];
+		for i in 1..n as usize {
+			trades.push(trade(PoolType::Stableswap(STABLE_POOL), stable_assets[(i - 1) % 3], stable_assets[i % 3]));
+		}
+
+		let asset_in = HDX;
+		let asset_out = stable_assets[(n as usize - 1) % 3];
+
+		// Stored route of full length, worse than the new one because of the fees of Omnipool round trip
+		let middle_asset = match asset_out {
+			DAI => USDC,
+			USDC => USDT,
+			_ => USDC,
+		};
+		let stored_trades = vec![
+			trade(PoolType::Omnipool, HDX, DAI),
+			trade(PoolType::Omnipool, DAI, HDX),
+			trade(PoolType::Omnipool, HDX, DAI),
+			trade(PoolType::Stableswap(STABLE_POOL), DAI, middle_asset),
+			trade(PoolType::Stableswap(STABLE_POOL), middle_asset, asset_out),
+		];
+		RouteExecutor::<Runtime>::force_insert_route(RawOrigin::Root.into(), asset_in, asset_out, stored_trades)?;
 
+		let caller: AccountId = create_funded_account::<Runtime>("caller", 0, 100 * UNITS, HDX);
+	}: {
+		RouteExecutor::<Runtime>::set_route(RawOrigin::Signed(caller).into(), asset_in, asset_out, trades.clone())?
+	}
+	verify{
+		assert_eq!(RouteExecutor::<Runtime>::route(AssetPair::new(asset_in, asset_out)).unwrap().into_inner(), trades);
+	}
 
+	force_insert_route {
+		let asset_in = HDX;
+		let asset_out = DAI;
+		let trades = vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: HDX,
+			asset_out: DAI
+		}];
+	}: {
+		RouteExecutor::<Runtime>::force_insert_route(RawOrigin::Root.into(), asset_in, asset_out, trades.clone())?
+	}
+	verify{
+		assert_eq!(RouteExecutor::<Runtime>::route(AssetPair::new(asset_in, asset_out)).unwrap().into_inner(), trades);
+	}
 }
 
 #[cfg(test)]
@@ -96,7 +96,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("hydradx"),
 	impl_name: create_runtime_str!("hydradx"),
 	authoring_version: 1,
-	spec_version: 165,
+	spec_version: 190,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -188,6 +188,10 @@ construct_runtime!(
 		CircuitBreaker: pallet_circuit_breaker = 65,
 		Router: pallet_route_executor = 67,
 		DynamicFees: pallet_dynamic_fees = 68,
+		OmnipoolSubpools: pallet_omnipool_subpools = 69,
+		Stableswap: pallet_stableswap = 70,
+		LBP: pallet_lbp = 71,
+		XYK: pallet_xyk = 72,
 
 		// ORML related modules
 		Tokens: orml_tokens = 77,
@@ -272,6 +276,7 @@ pub type Executive = frame_executive::Executive<
 		migrations::OnRuntimeUpgradeMigration,
 		migrations::MigrateRegistryLocationToV3<Runtime>,
 		migrations::XcmRateLimitMigration,
+		migrations::MigrateOtcOrdersToV1,
 	),
 >;
 
@@ -409,6 +414,52 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_omnipool_rpc_runtime_api::OmnipoolApi<Block, AssetId, Balance> for Runtime {
+		fn sell_quote(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+		) -> Result<pallet_omnipool::types::TradeQuote<Balance>, sp_runtime::DispatchError> {
+			Omnipool::calculate_sell_quote(asset_in, asset_out, amount_in)
+		}
+
+		fn buy_quote(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_out: Balance,
+		) -> Result<pallet_omnipool::types::TradeQuote<Balance>, sp_runtime::DispatchError> {
+			Omnipool::calculate_buy_quote(asset_in, asset_out, amount_out)
+		}
+	}
+
+	impl pallet_omnipool_rpc_runtime_api::OmnipoolFeeApi<Block, AssetId, Balance, BlockNumber> for Runtime {
+		fn fee_ledger(asset_id: AssetId) -> pallet_omnipool::types::FeeLedgerEntry<Balance, BlockNumber> {
+			Omnipool::fee_ledger(asset_id)
+		}
+	}
+
+	impl pallet_ema_oracle_rpc_runtime_api::EmaOracleApi<Block, AssetId, Balance, BlockNumber> for Runtime {
+		fn get_entry(
+			asset_a: AssetId,
+			asset_b: AssetId,
+			period: pallet_ema_oracle::OraclePeriod,
+			source: pallet_ema_oracle::Source,
+		) -> Result<hydradx_traits::AggregatedEntry<Balance, BlockNumber, pallet_ema_oracle::Price>, pallet_ema_oracle::OracleError> {
+			EmaOracle::get_current_entry(asset_a, asset_b, period, source)
+		}
+
+		fn get_entries(
+			pairs: Vec<(AssetId, AssetId)>,
+			period: pallet_ema_oracle::OraclePeriod,
+			source: pallet_ema_oracle::Source,
+		) -> Vec<Result<hydradx_traits::AggregatedEntry<Balance, BlockNumber, pallet_ema_oracle::Price>, pallet_ema_oracle::OracleError>> {
+			pairs
+				.into_iter()
+				.map(|(asset_a, asset_b)| EmaOracle::get_current_entry(asset_a, asset_b, period, source))
+				.collect()
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -448,6 +499,10 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, pallet_transaction_pause, TransactionPause);
 
 			list_benchmark!(list, extra, pallet_otc, OTC);
+			list_benchmark!(list, extra, pallet_stableswap, Stableswap);
+			list_benchmark!(list, extra, pallet_xyk, XYK);
+			list_benchmark!(list, extra, pallet_lbp, LBP);
+			list_benchmark!(list, extra, pallet_omnipool_subpools, OmnipoolSubpools);
 			list_benchmark!(list, extra, pallet_xcm, PolkadotXcm);
 
 			orml_list_benchmark!(list, extra, pallet_currencies, benchmarking::currencies);
@@ -513,6 +568,10 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, pallet_transaction_pause, TransactionPause);
 
 			add_benchmark!(params, batches, pallet_otc, OTC);
+			add_benchmark!(params, batches, pallet_stableswap, Stableswap);
+			add_benchmark!(params, batches, pallet_xyk, XYK);
+			add_benchmark!(params, batches, pallet_lbp, LBP);
+			add_benchmark!(params, batches, pallet_omnipool_subpools, OmnipoolSubpools);
 			add_benchmark!(params, batches, pallet_xcm, PolkadotXcm);
 
 			orml_add_benchmark!(params, batches, pallet_currencies, benchmarking::currencies);
@@ -111,3 +111,32 @@ impl OnRuntimeUpgrade for XcmRateLimitMigration {
 		Ok(())
 	}
 }
+
+pub struct MigrateOtcOrdersToV1;
+impl OnRuntimeUpgrade for MigrateOtcOrdersToV1 {
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		frame_support::log::info!("PreMigrate OTC Pallet start");
+		pallet_otc::migration::v1::pre_migrate::<Runtime>();
+		frame_support::log::info!("PreMigrate OTC Pallet end");
+
+		Ok(vec![])
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		log::info!(
+			target: "runtime::otc",
+			"MigrateOtcOrdersToV1::on_runtime_upgrade: migrating orders to include expiry and min fill amount"
+		);
+
+		pallet_otc::migration::v1::migrate::<Runtime>()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		frame_support::log::info!("PostMigrate OTC Pallet start");
+		pallet_otc::migration::v1::post_migrate::<Runtime>();
+		frame_support::log::info!("PostMigrate OTC Pallet end");
+		Ok(())
+	}
+}
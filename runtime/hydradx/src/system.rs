@@ -337,7 +337,10 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			ProxyType::Liquidity => matches!(
 				c,
 				RuntimeCall::Omnipool(pallet_omnipool::Call::add_liquidity { .. })
+					| RuntimeCall::Omnipool(pallet_omnipool::Call::add_liquidity_with_limit { .. })
+					| RuntimeCall::Omnipool(pallet_omnipool::Call::add_liquidity_multiple { .. })
 					| RuntimeCall::Omnipool(pallet_omnipool::Call::remove_liquidity { .. })
+					| RuntimeCall::Omnipool(pallet_omnipool::Call::remove_liquidity_with_limit { .. })
 			),
 			ProxyType::LiquidityMining => matches!(
 				c,
@@ -100,4 +100,30 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 		// Minimum execution time: 17_723 nanoseconds.
 		Weight::from_ref_time(18_231_000 as u64).saturating_add(T::DbWeight::get().reads(2 as u64))
 	}
+	// Storage: EmaOracle WhitelistedFeeders (r:1 w:1)
+	// Proof: EmaOracle WhitelistedFeeders (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	fn add_feeder() -> Weight {
+		// Minimum execution time: 15_284 nanoseconds.
+		Weight::from_ref_time(15_697_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EmaOracle WhitelistedFeeders (r:1 w:1)
+	// Proof: EmaOracle WhitelistedFeeders (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	fn remove_feeder() -> Weight {
+		// Minimum execution time: 16_102 nanoseconds.
+		Weight::from_ref_time(16_531_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EmaOracle WhitelistedFeeders (r:1 w:0)
+	// Proof: EmaOracle WhitelistedFeeders (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	// Storage: EmaOracle Accumulator (r:1 w:1)
+	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(2961), added: 3456, mode: MaxEncodedLen)
+	fn update_external_oracle() -> Weight {
+		// Minimum execution time: 30_417 nanoseconds.
+		Weight::from_ref_time(31_128_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
@@ -9,8 +9,10 @@ pub mod democracy;
 pub mod duster;
 pub mod ema_oracle;
 pub mod identity;
+pub mod lbp;
 pub mod omnipool;
 pub mod omnipool_lm;
+pub mod omnipool_subpools;
 pub mod otc;
 pub mod payment;
 pub mod preimage;
@@ -18,6 +20,7 @@ pub mod proxy;
 pub mod registry;
 pub mod route_executor;
 pub mod scheduler;
+pub mod stableswap;
 pub mod system;
 pub mod technical_comittee;
 pub mod timestamp;
@@ -28,3 +31,4 @@ pub mod utility;
 pub mod vesting;
 pub mod xcm;
 pub mod xcmp_queue;
+pub mod xyk;
@@ -116,7 +116,7 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
 	// Storage: Omnipool Assets (r:2 w:1)
 	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
-	// Storage: EmaOracle Oracles (r:2 w:0)
+	// Storage: EmaOracle Oracles (r:3 w:0)
 	// Proof: EmaOracle Oracles (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
 	// Storage: Omnipool HubAssetImbalance (r:1 w:1)
 	// Proof: Omnipool HubAssetImbalance (max_values: Some(1), max_size: Some(17), added: 512, mode: MaxEncodedLen)
@@ -151,9 +151,9 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Storage: Omnipool Positions (r:0 w:1)
 	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
 	fn add_liquidity() -> Weight {
-		// Minimum execution time: 225_812 nanoseconds.
-		Weight::from_ref_time(227_537_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(23 as u64))
+		// Minimum execution time: 231_407 nanoseconds.
+		Weight::from_ref_time(233_215_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(24 as u64))
 			.saturating_add(T::DbWeight::get().writes(14 as u64))
 	}
 	// Storage: Uniques Asset (r:1 w:1)
@@ -164,7 +164,7 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
 	// Storage: Tokens Accounts (r:4 w:4)
 	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
-	// Storage: EmaOracle Oracles (r:2 w:0)
+	// Storage: EmaOracle Oracles (r:3 w:0)
 	// Proof: EmaOracle Oracles (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
 	// Storage: Omnipool HubAssetImbalance (r:1 w:1)
 	// Proof: Omnipool HubAssetImbalance (max_values: Some(1), max_size: Some(17), added: 512, mode: MaxEncodedLen)
@@ -195,9 +195,9 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Storage: Uniques ItemPriceOf (r:0 w:1)
 	// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
 	fn remove_liquidity() -> Weight {
-		// Minimum execution time: 282_512 nanoseconds.
-		Weight::from_ref_time(284_772_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(23 as u64))
+		// Minimum execution time: 288_163 nanoseconds.
+		Weight::from_ref_time(290_481_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(24 as u64))
 			.saturating_add(T::DbWeight::get().writes(16 as u64))
 	}
 	// Storage: Tokens Accounts (r:4 w:4)
@@ -226,11 +226,12 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: CircuitBreaker LiquidityRemoveLimitPerAsset (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
 	// Storage: CircuitBreaker AllowedRemoveLiquidityAmountPerAsset (r:1 w:0)
 	// Proof: CircuitBreaker AllowedRemoveLiquidityAmountPerAsset (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Omnipool FeeLedger (r:1 w:1) is not part of the benchmark results and is added on top of them.
 	fn sell() -> Weight {
 		// Minimum execution time: 231_087 nanoseconds.
 		Weight::from_ref_time(232_886_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(21 as u64))
-			.saturating_add(T::DbWeight::get().writes(14 as u64))
+			.saturating_add(T::DbWeight::get().reads(22 as u64))
+			.saturating_add(T::DbWeight::get().writes(15 as u64))
 	}
 	// Storage: Omnipool Assets (r:3 w:3)
 	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
@@ -258,11 +259,12 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: CircuitBreaker LiquidityRemoveLimitPerAsset (max_values: None, max_size: Some(29), added: 2504, mode: MaxEncodedLen)
 	// Storage: CircuitBreaker AllowedRemoveLiquidityAmountPerAsset (r:1 w:0)
 	// Proof: CircuitBreaker AllowedRemoveLiquidityAmountPerAsset (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Omnipool FeeLedger (r:1 w:1) is not part of the benchmark results and is added on top of them.
 	fn buy() -> Weight {
 		// Minimum execution time: 232_699 nanoseconds.
 		Weight::from_ref_time(235_189_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(21 as u64))
-			.saturating_add(T::DbWeight::get().writes(14 as u64))
+			.saturating_add(T::DbWeight::get().reads(22 as u64))
+			.saturating_add(T::DbWeight::get().writes(15 as u64))
 	}
 	// Storage: Omnipool Assets (r:1 w:1)
 	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
@@ -316,4 +318,27 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Not benchmarked yet - bounded by `remove_liquidity`, which updates the same asset state, hub asset
+	// and imbalance and transfers the asset out of the pool.
+	fn remove_token() -> Weight {
+		Self::remove_liquidity()
+	}
+	// Not benchmarked yet - bounded by `add_liquidity`, which also reads and updates the position storage and
+	// mints a new position NFT.
+	fn split_position() -> Weight {
+		Self::add_liquidity()
+	}
+	// Not benchmarked yet - bounded by `remove_liquidity`, which also loads the asset state and burns a position
+	// NFT. One more read accounts for the second position.
+	fn merge_positions() -> Weight {
+		Self::remove_liquidity().saturating_add(T::DbWeight::get().reads(1 as u64))
+	}
+	// Not benchmarked yet - bounded by `sell`, which also updates the hub asset imbalance and token balances.
+	fn reduce_hub_asset_imbalance() -> Weight {
+		Self::sell()
+	}
+	// Not benchmarked yet - bounded by `add_liquidity` for each asset.
+	fn add_liquidity_multiple(n: u32) -> Weight {
+		Self::add_liquidity().saturating_mul(n as u64)
+	}
 }
@@ -62,16 +62,22 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: OTC ExpiryCursor (r:1 w:1)
+	// Proof: OTC ExpiryCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: OTC OrderExpiries (r:0 w:1)
+	// Proof: OTC OrderExpiries (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	// Storage: OTC Orders (r:0 w:1)
-	// Proof: OTC Orders (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	// Proof: OTC Orders (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	// Storage: OTC AllowedTakers (r:0 w:1)
+	// Proof: OTC AllowedTakers (max_values: None, max_size: Some(341), added: 2816, mode: MaxEncodedLen)
 	fn place_order() -> Weight {
-		// Minimum execution time: 50_115 nanoseconds.
-		Weight::from_ref_time(50_779_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(5 as u64))
-			.saturating_add(T::DbWeight::get().writes(4 as u64))
+		// Minimum execution time: 58_904 nanoseconds.
+		Weight::from_ref_time(59_712_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: OTC Orders (r:1 w:1)
-	// Proof: OTC Orders (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	// Proof: OTC Orders (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
 	// Storage: AssetRegistry Assets (r:2 w:0)
 	// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
 	// Storage: Tokens Accounts (r:2 w:2)
@@ -80,14 +86,16 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: Balances Reserves (r:1 w:1)
 	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	// Storage: OTC AllowedTakers (r:1 w:0)
+	// Proof: OTC AllowedTakers (max_values: None, max_size: Some(341), added: 2816, mode: MaxEncodedLen)
 	fn partial_fill_order() -> Weight {
-		// Minimum execution time: 113_797 nanoseconds.
-		Weight::from_ref_time(114_944_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(8 as u64))
+		// Minimum execution time: 117_215 nanoseconds.
+		Weight::from_ref_time(118_306_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
 			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
 	// Storage: OTC Orders (r:1 w:1)
-	// Proof: OTC Orders (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	// Proof: OTC Orders (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
 	// Storage: Tokens Accounts (r:2 w:2)
 	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
 	// Storage: AssetRegistry Assets (r:1 w:0)
@@ -96,22 +104,60 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: Balances Reserves (r:1 w:1)
 	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	// Storage: OTC OrderExpiries (r:0 w:1)
+	// Proof: OTC OrderExpiries (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: OTC AllowedTakers (r:1 w:1)
+	// Proof: OTC AllowedTakers (max_values: None, max_size: Some(341), added: 2816, mode: MaxEncodedLen)
 	fn fill_order() -> Weight {
-		// Minimum execution time: 109_213 nanoseconds.
-		Weight::from_ref_time(109_978_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(7 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+		// Minimum execution time: 115_560 nanoseconds.
+		Weight::from_ref_time(116_284_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	// Storage: OTC Orders (r:1 w:1)
-	// Proof: OTC Orders (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	// Proof: OTC Orders (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
 	// Storage: Balances Reserves (r:1 w:1)
 	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: OTC OrderExpiries (r:0 w:1)
+	// Proof: OTC OrderExpiries (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: OTC AllowedTakers (r:0 w:1)
+	// Proof: OTC AllowedTakers (max_values: None, max_size: Some(341), added: 2816, mode: MaxEncodedLen)
 	fn cancel_order() -> Weight {
-		// Minimum execution time: 39_010 nanoseconds.
-		Weight::from_ref_time(39_491_000 as u64)
+		// Minimum execution time: 43_018 nanoseconds.
+		Weight::from_ref_time(43_652_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: OTC Orders (r:1 w:1)
+	// Proof: OTC Orders (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: OTC OrderExpiries (r:0 w:1)
+	// Proof: OTC OrderExpiries (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: OTC AllowedTakers (r:0 w:1)
+	// Proof: OTC AllowedTakers (max_values: None, max_size: Some(341), added: 2816, mode: MaxEncodedLen)
+	fn expire_order() -> Weight {
+		// Minimum execution time: 42_641 nanoseconds.
+		Weight::from_ref_time(43_170_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: OTC Orders (r:1 w:1)
+	// Proof: OTC Orders (max_values: None, max_size: Some(115), added: 2590, mode: MaxEncodedLen)
+	// Storage: AssetRegistry Assets (r:2 w:0)
+	// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn update_order() -> Weight {
+		// Minimum execution time: 45_212 nanoseconds.
+		Weight::from_ref_time(45_870_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 }
@@ -74,12 +74,13 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(2961), added: 3456, mode: MaxEncodedLen)
 	// Storage: CircuitBreaker AllowedTradeVolumeLimitPerAsset (r:2 w:2)
 	// Proof: CircuitBreaker AllowedTradeVolumeLimitPerAsset (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	// Omnipool FeeLedger (r:1 w:1) is not part of the benchmark results and is added on top of them.
 	/// The range of component `n` is `[1, 2]`.
 	fn sell(_n: u32) -> Weight {
 		// Minimum execution time: 240_244 nanoseconds.
 		Weight::from_ref_time(252_571_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(16 as u64))
-			.saturating_add(T::DbWeight::get().writes(12 as u64))
+			.saturating_add(T::DbWeight::get().reads(17 as u64))
+			.saturating_add(T::DbWeight::get().writes(13 as u64))
 	}
 	// Storage: System Account (r:2 w:2)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
@@ -101,12 +102,44 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(2961), added: 3456, mode: MaxEncodedLen)
 	// Storage: CircuitBreaker AllowedTradeVolumeLimitPerAsset (r:2 w:2)
 	// Proof: CircuitBreaker AllowedTradeVolumeLimitPerAsset (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	// Omnipool FeeLedger (r:1 w:1) is not part of the benchmark results and is added on top of them.
 	/// The range of component `n` is `[1, 2]`.
 	fn buy(n: u32) -> Weight {
 		// Minimum execution time: 237_842 nanoseconds.
 		Weight::from_ref_time(238_705_550 as u64) // Standard Error: 227_230
 			.saturating_add(Weight::from_ref_time(1_211_375 as u64).saturating_mul(n as u64))
-			.saturating_add(T::DbWeight::get().reads(16 as u64))
-			.saturating_add(T::DbWeight::get().writes(12 as u64))
+			.saturating_add(T::DbWeight::get().reads(17 as u64))
+			.saturating_add(T::DbWeight::get().writes(13 as u64))
+	}
+	// Storage: Router Routes (r:1 w:1)
+	// Proof: Router Routes (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:0)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Omnipool Assets (r:6 w:0)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:4 w:0)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: DynamicFees AssetFee (r:6 w:0)
+	// Proof: DynamicFees AssetFee (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	// Storage: Stableswap Pools (r:1 w:0)
+	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:0)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: AssetRegistry Assets (r:4 w:0)
+	// Proof Skipped: AssetRegistry Assets (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `n` is `[1, 5]`.
+	fn set_route(n: u32) -> Weight {
+		// Minimum execution time: 312_086 nanoseconds.
+		Weight::from_ref_time(268_519_830 as u64) // Standard Error: 141_722
+			.saturating_add(Weight::from_ref_time(47_911_452 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(24 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Router Routes (r:0 w:1)
+	// Proof: Router Routes (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	fn force_insert_route() -> Weight {
+		// Minimum execution time: 21_104 nanoseconds.
+		Weight::from_ref_time(21_937_000 as u64).saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 }
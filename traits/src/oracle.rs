@@ -42,6 +42,7 @@ where
 ///
 /// Note: Some of the oracles are named after certain periods of time.
 /// This description relies on the mapping of the enum to the internal implementation and can thus not be guaranteed.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum OraclePeriod {
 	/// The oracle data is from the last block, thus unaggregated.
@@ -72,6 +73,7 @@ impl OraclePeriod {
 
 /// Struct to represent oracle data aggregated over a time period. Includes the age of the oracle
 /// as metadata. Age is the blocks between first data and the timestamp of the most recent value.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Eq, PartialEq, Clone, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct AggregatedEntry<Balance, BlockNumber, Price> {
 	pub price: Price,
@@ -1,5 +1,8 @@
 use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::dispatch::DispatchResult;
+use frame_support::weights::Weight;
 use scale_info::TypeInfo;
+use sp_std::vec::Vec;
 
 #[derive(Encode, Decode, Clone, Copy, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
 pub enum PoolType<AssetId> {
@@ -9,6 +12,14 @@ pub enum PoolType<AssetId> {
 	Omnipool,
 }
 
+///A single trade for buy/sell, describing the asset pair and the pool type in which the trade is executed
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Copy, Clone, TypeInfo, MaxEncodedLen)]
+pub struct Trade<AssetId> {
+	pub pool: PoolType<AssetId>,
+	pub asset_in: AssetId,
+	pub asset_out: AssetId,
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum ExecutorError<E> {
 	NotSupported,
@@ -51,6 +62,23 @@ pub trait TradeExecution<Origin, AccountId, AssetId, Balance> {
 	) -> Result<(), ExecutorError<Self::Error>>;
 }
 
+/// Router executing a series of trades, to be used by other pallets without depending on the router pallet.
+pub trait RouterT<Origin, AssetId, Balance> {
+	/// Buy `amount_out` of `asset_out` for at most `max_amount_in` of `asset_in`.
+	/// If `route` is empty, the stored route of the asset pair is used.
+	fn buy(
+		origin: Origin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		max_amount_in: Balance,
+		route: Vec<Trade<AssetId>>,
+	) -> DispatchResult;
+
+	/// Weight of a buy along `route`. Empty route is weighed as the longest possible route.
+	fn buy_weight(route: &[Trade<AssetId>]) -> Weight;
+}
+
 #[allow(clippy::redundant_clone)] //Needed as it complains about redundant clone, but clone is needed as Origin is moved and it is not copy type.
 #[impl_trait_for_tuples::impl_for_tuples(1, 5)]
 impl<E: PartialEq, Origin: Clone, AccountId, AssetId: Copy, Balance: Copy>